@@ -60,12 +60,98 @@ fn parse_inspectable_attributes(
 pub fn extract_inspector_attributes(
     attrs: &[syn::Attribute],
 ) -> syn::Result<Vec<InspectorAttribute>> {
-    Ok(attrs
+    let attributes: Vec<_> = attrs
         .iter()
-        .filter(|attr| attr.path().get_ident().map_or(false, |p| p == "inspector"))
+        .filter(|attr| attr.path().is_ident("inspector"))
         .map(|attr| attr.parse_args_with(parse_inspectable_attributes))
         .collect::<syn::Result<Vec<_>>>()?
         .into_iter()
         .flatten()
-        .collect())
+        .collect();
+
+    for (i, attribute) in attributes.iter().enumerate() {
+        let name = attribute.lhs();
+        if attributes[..i].iter().any(|other| other.lhs() == name) {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("duplicate inspector attribute `{}`", quote! { #name }),
+            ));
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Fields with `#[reflect(ignore)]` are not visible in the inspector, so options on them would be silently dropped.
+pub fn ensure_no_inspector_attributes_on_ignored(field: &syn::Field) -> syn::Result<()> {
+    if !is_reflect_ignore_field(field) {
+        return Ok(());
+    }
+    match field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("inspector"))
+    {
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            "`#[inspector(..)]` has no effect on fields with `#[reflect(ignore)]`",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Options given in the `#[inspector(..)]` attributes on the type itself
+#[derive(Default)]
+pub struct TypeAttributes {
+    /// `button = "method"`, displayed as buttons calling the method
+    pub buttons: Vec<syn::Ident>,
+    /// `on_change = "method"`, called after the value was edited
+    pub on_change: Option<syn::Ident>,
+    /// `transparent`, displaying a newtype like its only field
+    pub transparent: Option<syn::Path>,
+    /// `bound = "T: Trait"`, replacing the bounds inferred from the fields
+    pub bound: Option<Vec<syn::WherePredicate>>,
+}
+
+/// Parses the predicates of `bound = "T: Trait, U: Other"`
+pub fn parse_bound(bound: &syn::LitStr) -> syn::Result<Vec<syn::WherePredicate>> {
+    let predicates = bound.parse_with(
+        syn::punctuated::Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated,
+    )?;
+    Ok(predicates.into_iter().collect())
+}
+
+pub fn extract_type_attributes(attrs: &[syn::Attribute]) -> syn::Result<TypeAttributes> {
+    let mut attributes = TypeAttributes::default();
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("inspector"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("button") {
+                let method: syn::LitStr = meta.value()?.parse()?;
+                attributes.buttons.push(method.parse()?);
+            } else if meta.path.is_ident("on_change") {
+                if attributes.on_change.is_some() {
+                    return Err(meta.error("duplicate inspector attribute `on_change`"));
+                }
+                let method: syn::LitStr = meta.value()?.parse()?;
+                attributes.on_change = Some(method.parse()?);
+            } else if meta.path.is_ident("transparent") {
+                attributes.transparent = Some(meta.path);
+            } else if meta.path.is_ident("bound") {
+                if attributes.bound.is_some() {
+                    return Err(meta.error("duplicate inspector attribute `bound`"));
+                }
+                let bound: syn::LitStr = meta.value()?.parse()?;
+                attributes.bound = Some(parse_bound(&bound)?);
+            } else {
+                return Err(meta.error(
+                    "unknown inspector attribute, expected `button = \"method\"`, `on_change = \"method\"`, `transparent` or `bound = \"T: Trait\"`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attributes)
 }
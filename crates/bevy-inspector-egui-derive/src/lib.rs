@@ -1,5 +1,6 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 use syn::{DataEnum, DataStruct, DataUnion, DeriveInput};
 
 mod attributes;
@@ -21,49 +22,46 @@ pub fn inspectable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
     let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
 
+    for field in &data.fields {
+        attributes::ensure_no_inspector_attributes_on_ignored(field)?;
+    }
+
     let fields = data
         .fields
         .iter()
         .filter(|field| !attributes::is_reflect_ignore_field(field))
         .enumerate()
         .filter_map(|(i, field)| {
-            let ty = &field.ty;
-            let attrs = match attributes::extract_inspector_attributes(&field.attrs) {
-                Ok(attrs) => attrs,
-                Err(e) => return Some(Err(e)),
-            };
-            if attrs.is_empty() {
-                return None;
-            }
-            let attrs = attrs.into_iter().map(|attribute| {
-                let name = attribute.lhs();
-                let value = attribute.rhs();
-                quote! {
-                    field_options.#name = ::std::convert::Into::into(#value);
-                }
-            });
-
-            Some(Ok(quote! {
-                let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                #(#attrs)*
-                options.insert(::bevy_inspector_egui::inspector_options::Target::Field(#i), <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options));
-            }))
+            let target = quote! { ::bevy_inspector_egui::inspector_options::Target::Field(#i) };
+            expand_field(input, field, target).transpose()
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
     let type_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let attributes = attributes::extract_type_attributes(&input.attrs)?;
+    let generics = bounded_generics(input, &data.fields, &attributes)?;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let transparent = match &attributes.transparent {
+        Some(transparent) => Some(expand_transparent(input, data, transparent)?),
+        None => None,
+    };
+    let type_attributes = expand_type_attributes(input, &attributes);
+    let body = expand_from_type_body(quote! {
+        #(#fields)*
+        #type_attributes
+    });
 
     Ok(quote! {
+        #transparent
+
         impl #impl_generics #bevy_reflect::FromType<#type_name #ty_generics> for ::bevy_inspector_egui::InspectorOptions
         #where_clause
         {
+            // the field options are created with `Default::default()` and then assigned one attribute at a time
+            #[allow(clippy::field_reassign_with_default)]
             fn from_type() -> Self {
-                let mut options = ::bevy_inspector_egui::InspectorOptions::default();
-
-                #(#fields)*
-
-                options
+                #body
             }
         }
     })
@@ -77,39 +75,23 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
         .iter()
         .enumerate()
         .map(|(variant_index, variant)| {
+            for field in &variant.fields {
+                attributes::ensure_no_inspector_attributes_on_ignored(field)?;
+            }
+
             let attrs = variant
                 .fields
                 .iter()
                 .filter(|field| !attributes::is_reflect_ignore_field(field))
                 .enumerate()
                 .filter_map(|(field_index, field)| {
-                    let ty = &field.ty;
-                    let attrs = match attributes::extract_inspector_attributes(&field.attrs) {
-                        Ok(attrs) => attrs,
-                        Err(e) => return Some(Err(e)),
-                    };
-                    if attrs.is_empty() {
-                        return None;
-                    }
-                    let attrs = attrs.into_iter().map(|attribute| {
-                        let name = attribute.lhs();
-                        let value = attribute.rhs();
-                        quote! {
-                            field_options.#name = std::convert::Into::into(#value);
+                    let target = quote! {
+                        ::bevy_inspector_egui::inspector_options::Target::VariantField {
+                            variant_index: #variant_index,
+                            field_index: #field_index,
                         }
-                    });
-
-                    Some(Ok(quote! {
-                        let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                        #(#attrs)*
-                        options.insert(
-                            ::bevy_inspector_egui::inspector_options::Target::VariantField {
-                                variant_index: #variant_index,
-                                field_index: #field_index,
-                            },
-                            <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options)
-                        );
-                    }))
+                    };
+                    expand_field(input, field, target).transpose()
                 })
                 .collect::<syn::Result<Vec<_>>>()?;
             Ok(attrs)
@@ -117,22 +99,687 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
         .collect::<syn::Result<Vec<_>>>()?;
 
     let type_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let attributes = attributes::extract_type_attributes(&input.attrs)?;
+    if let Some(transparent) = &attributes.transparent {
+        return Err(syn::Error::new_spanned(
+            transparent,
+            "`transparent` is only supported on structs",
+        ));
+    }
+    let variant_fields = data.variants.iter().flat_map(|variant| &variant.fields);
+    let generics = bounded_generics(input, variant_fields, &attributes)?;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let type_attributes = expand_type_attributes(input, &attributes);
+    let body = expand_from_type_body(quote! {
+        #(#(#fields)*)*
+        #type_attributes
+    });
 
     Ok(quote! {
         impl #impl_generics #bevy_reflect::FromType<#type_name #ty_generics> for ::bevy_inspector_egui::InspectorOptions
         #where_clause
         {
+            // the field options are created with `Default::default()` and then assigned one attribute at a time
+            #[allow(clippy::field_reassign_with_default)]
             fn from_type() -> Self {
-                let mut options = ::bevy_inspector_egui::InspectorOptions::default();
+                #body
+            }
+        }
+    })
+}
+
+/// The body of `from_type`, assigning the options of the attributes one at a time.
+///
+/// Without the `enabled` feature the assignments never run and the options stay empty, but they are still type-checked,
+/// which keeps the functions and imports named in the attributes from becoming unused.
+fn expand_from_type_body(assignments: TokenStream) -> TokenStream {
+    let body = quote! {
+        let mut options = ::bevy_inspector_egui::InspectorOptions::default();
+        #assignments
+        options
+    };
+    if cfg!(feature = "enabled") {
+        body
+    } else {
+        quote! {
+            if false {
+                return { #body };
+            }
+            ::bevy_inspector_egui::InspectorOptions::default()
+        }
+    }
+}
+
+/// The generics of the generated impl: the generics of the type, with the bounds of `#[inspector(bound = "..")]`
+/// on the type or otherwise the bounds inferred from the attributes on fields whose types contain type parameters.
+fn bounded_generics<'a>(
+    input: &DeriveInput,
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+    attributes: &attributes::TypeAttributes,
+) -> syn::Result<syn::Generics> {
+    let fields = fields
+        .into_iter()
+        .filter(|field| !attributes::is_reflect_ignore_field(field));
+    // also inferred when replaced, so that invalid `bound`s on fields are reported
+    let inferred = inferred_bounds(input, fields)?;
+
+    let mut generics = input.generics.clone();
+    let predicates = match &attributes.bound {
+        Some(bound) => bound.clone(),
+        None => inferred,
+    };
+    generics.make_where_clause().predicates.extend(predicates);
+    Ok(generics)
+}
+
+/// The bounds needed by the code generated for the attributes of `fields`.
+///
+/// A field with `#[inspector(bound = "..")]` gets exactly the given bounds instead.
+fn inferred_bounds<'a>(
+    input: &DeriveInput,
+    fields: impl Iterator<Item = &'a syn::Field>,
+) -> syn::Result<Vec<syn::WherePredicate>> {
+    let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
+    let options_type = quote! { ::bevy_inspector_egui::inspector_options::InspectorOptionsType };
+    let type_params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+
+    let mut predicates = Vec::new();
+    if !type_params.is_empty() {
+        // closures like the ones of `visible_if` downcast to the type
+        let type_name = &input.ident;
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        predicates.push(syn::parse_quote! { #type_name #ty_generics: #bevy_reflect::Reflect });
+    }
+    for field in fields {
+        let mut attrs = attributes::extract_inspector_attributes(&field.attrs)?;
+        if let Some(bound) = take_attribute(&mut attrs, "bound") {
+            predicates.extend(field_bound(&bound)?);
+            continue;
+        }
+        let ty = &field.ty;
+        if !mentions_type_param(quote! { #ty }, &type_params) {
+            continue;
+        }
+
+        take_attribute(&mut attrs, "visible_if");
+        take_attribute(&mut attrs, "validate");
+        take_attribute(&mut attrs, "label");
+        take_attribute(&mut attrs, "reset");
+        let default = take_attribute(&mut attrs, "default");
+        let getter = take_attribute(&mut attrs, "getter");
+        let setter = take_attribute(&mut attrs, "setter");
+        let with = take_attribute(&mut attrs, "with");
+        if default.is_some() || getter.is_some() || setter.is_some() || with.is_some() {
+            predicates.push(syn::parse_quote! { #ty: #bevy_reflect::Reflect });
+        }
+        if !attrs.is_empty() {
+            predicates.push(syn::parse_quote! { #ty: #options_type });
+        }
+    }
+    Ok(predicates)
+}
+
+/// Parses `bound = "T: Trait"` on a field
+fn field_bound(
+    attribute: &attributes::InspectorAttribute,
+) -> syn::Result<Vec<syn::WherePredicate>> {
+    match attribute {
+        attributes::InspectorAttribute::Assignment(
+            _,
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(bound),
+                ..
+            }),
+        ) => attributes::parse_bound(bound),
+        attribute => Err(syn::Error::new_spanned(
+            attribute.lhs(),
+            "expected `bound = \"T: Trait\"`",
+        )),
+    }
+}
+
+/// Whether `tokens` contain one of the `type_params`
+fn mentions_type_param(tokens: TokenStream, type_params: &[&syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => type_params.contains(&&ident),
+        proc_macro2::TokenTree::Group(group) => mentions_type_param(group.stream(), type_params),
+        _ => false,
+    })
+}
+
+/// Expands the `#[inspector(..)]` attributes of the type into code registering its buttons, `on_change` method and transparency
+fn expand_type_attributes(
+    input: &DeriveInput,
+    attributes: &attributes::TypeAttributes,
+) -> TokenStream {
+    let type_name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let call = |method: &syn::Ident| {
+        quote_spanned! {method.span()=>
+            |value| {
+                if let ::std::option::Option::Some(value) =
+                    value.downcast_mut::<#type_name #ty_generics>()
+                {
+                    ::bevy_inspector_egui::__macro_exports::discard(value.#method());
+                }
+            }
+        }
+    };
+    let buttons = attributes.buttons.iter().map(|method| {
+        let name = method.to_string();
+        let call = call(method);
+        quote! { options.add_button(#name, #call); }
+    });
+    let on_change = attributes.on_change.as_ref().map(|method| {
+        let call = call(method);
+        quote! { options.set_on_change(#call); }
+    });
+
+    let transparent = attributes
+        .transparent
+        .as_ref()
+        .map(|_| quote! { options.set_transparent(); });
+
+    quote! {
+        #(#buttons)*
+        #on_change
+        #transparent
+    }
+}
+
+/// Expands `#[inspector(transparent)]` into an `InspectorOptionsType` impl forwarding to the type of the only field,
+/// so that attributes on fields of the newtype are interpreted like attributes on its inner type.
+fn expand_transparent(
+    input: &DeriveInput,
+    data: &DataStruct,
+    transparent: &syn::Path,
+) -> syn::Result<TokenStream> {
+    let mut fields = data
+        .fields
+        .iter()
+        .filter(|field| !attributes::is_reflect_ignore_field(field));
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(syn::Error::new_spanned(
+            transparent,
+            "`transparent` requires exactly one field without `#[reflect(ignore)]`",
+        ));
+    };
+
+    let inner = &field.ty;
+    let options_type = quote! { ::bevy_inspector_egui::inspector_options::InspectorOptionsType };
+    let type_name = &input.ident;
+    let mut generics = input.generics.clone();
+    // the higher-ranked bound keeps the impl valid for inner types without `InspectorOptionsType`,
+    // which then only fail to compile when the newtype is used with attributes
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! { for<'__inspector> #inner: #options_type });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #options_type for #type_name #ty_generics
+        #where_clause
+        {
+            type DeriveOptions = <#inner as #options_type>::DeriveOptions;
+            type Options = <#inner as #options_type>::Options;
+
+            fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+                <#inner as #options_type>::options_from_derive(options)
+            }
+        }
+    })
+}
+
+fn with_member() -> syn::Member {
+    syn::Member::Named(syn::Ident::new("with", proc_macro2::Span::call_site()))
+}
+
+/// Removes the attribute called `name` from `attrs`, if present
+fn take_attribute(
+    attrs: &mut Vec<attributes::InspectorAttribute>,
+    name: &str,
+) -> Option<attributes::InspectorAttribute> {
+    let i = attrs.iter().position(
+        |attribute| matches!(attribute.lhs(), syn::Member::Named(ident) if ident == name),
+    )?;
+    Some(attrs.remove(i))
+}
+
+/// Parses the method name of `name = "method"`
+fn method_name(attribute: &attributes::InspectorAttribute) -> syn::Result<syn::Ident> {
+    match attribute {
+        attributes::InspectorAttribute::Assignment(
+            _,
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(method),
+                ..
+            }),
+        ) => method.parse(),
+        attribute => {
+            let name = attribute.lhs();
+            Err(syn::Error::new_spanned(
+                name,
+                format!("expected `{} = \"method\"`", quote! { #name }),
+            ))
+        }
+    }
+}
+
+/// Replaces every `self` in `tokens` with `this`, so that a `visible_if` expression can be used in a closure
+fn replace_self(tokens: TokenStream, this: &syn::Ident) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "self" => {
+                proc_macro2::TokenTree::Ident(syn::Ident::new(&this.to_string(), ident.span()))
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced =
+                    proc_macro2::Group::new(group.delimiter(), replace_self(group.stream(), this));
+                replaced.set_span(group.span());
+                proc_macro2::TokenTree::Group(replaced)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+/// Expands `visible_if = "expr"` or `visible_if = function` into code registering the condition at `target`.
+///
+/// In the string form, `self` refers to the value containing the field.
+fn expand_visible_if(
+    input: &DeriveInput,
+    attribute: &attributes::InspectorAttribute,
+    target: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let type_name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let this = syn::Ident::new("__this", proc_macro2::Span::call_site());
+
+    let condition = match attribute {
+        attributes::InspectorAttribute::Assignment(
+            _,
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(expr),
+                ..
+            }),
+        ) => {
+            let expr: syn::Expr = expr.parse()?;
+            replace_self(quote! { #expr }, &this)
+        }
+        attributes::InspectorAttribute::Assignment(_, function) => {
+            quote_spanned! {function.span()=> (#function)(#this) }
+        }
+        attributes::InspectorAttribute::Tag(name) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "expected `visible_if = \"expr\"` or `visible_if = function`",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        options.set_visible_if(#target, |value| {
+            value
+                .downcast_ref::<#type_name #ty_generics>()
+                .map_or(true, |#this| #condition)
+        });
+    })
+}
+
+/// Expands `validate = "method"` or `validate = function` into code registering the validator of the field at `target`.
+///
+/// Both take the value containing the field and return a `Result` whose error implements `Display`.
+fn expand_validate(
+    input: &DeriveInput,
+    attribute: &attributes::InspectorAttribute,
+    target: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let type_name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let this = syn::Ident::new("__this", proc_macro2::Span::call_site());
+
+    let validate = match attribute {
+        attributes::InspectorAttribute::Assignment(
+            _,
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(_),
+                ..
+            }),
+        ) => {
+            let method = method_name(attribute)?;
+            quote_spanned! {method.span()=> #this.#method() }
+        }
+        attributes::InspectorAttribute::Assignment(_, function) => {
+            quote_spanned! {function.span()=> (#function)(#this) }
+        }
+        attributes::InspectorAttribute::Tag(name) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "expected `validate = \"method\"` or `validate = function`",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        options.set_validator(#target, |value| {
+            match value.downcast_ref::<#type_name #ty_generics>() {
+                ::std::option::Option::Some(#this) => #validate
+                    .map_err(|error| ::std::string::ToString::to_string(&error)),
+                ::std::option::Option::None => ::std::result::Result::Ok(()),
+            }
+        });
+    })
+}
+
+/// Expands the `#[inspector(..)]` attributes of a single field into code inserting its options at `target`.
+///
+/// The generated code is spanned to the field type and attribute names, so that
+/// missing `InspectorOptionsType` impls and unknown options are reported at the offending field.
+/// `#[inspector(with = my_widget)]` instead inserts a `CustomWidget` and doesn't require `InspectorOptionsType`,
+/// and neither do `#[inspector(visible_if = ..)]`, `#[inspector(validate = ..)]`, `#[inspector(label = "..")]` and
+/// `#[inspector(reset)]` on their own.
+fn expand_field(
+    input: &DeriveInput,
+    field: &syn::Field,
+    target: TokenStream,
+) -> syn::Result<Option<TokenStream>> {
+    let mut attrs = attributes::extract_inspector_attributes(&field.attrs)?;
+    // already part of the where clause, see `bounded_generics`
+    take_attribute(&mut attrs, "bound");
+    let visible_if = match take_attribute(&mut attrs, "visible_if") {
+        Some(attribute) => Some(expand_visible_if(input, &attribute, &target)?),
+        None => None,
+    };
+    let validate = match take_attribute(&mut attrs, "validate") {
+        Some(attribute) => Some(expand_validate(input, &attribute, &target)?),
+        None => None,
+    };
+    let label = match take_attribute(&mut attrs, "label") {
+        Some(attribute) => Some(expand_label(&attribute, &target)?),
+        None => None,
+    };
+    let reset = take_attribute(&mut attrs, "reset");
+    let default = take_attribute(&mut attrs, "default");
+    let reset = expand_reset(field, reset.as_ref(), default.as_ref(), &target)?;
+    let getter = take_attribute(&mut attrs, "getter");
+    let setter = take_attribute(&mut attrs, "setter");
+    let accessor = match (getter, setter) {
+        (Some(getter), setter) => Some(expand_accessor(
+            input,
+            field,
+            &getter,
+            setter.as_ref(),
+            &target,
+        )?),
+        (None, Some(setter)) => {
+            return Err(syn::Error::new_spanned(
+                setter.lhs(),
+                "`setter` requires a `getter` returning the field",
+            ))
+        }
+        (None, None) => None,
+    };
+    let options = expand_field_options(input, field, target, attrs)?;
+    if visible_if.is_none()
+        && validate.is_none()
+        && label.is_none()
+        && reset.is_none()
+        && accessor.is_none()
+        && options.is_none()
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(quote! {
+        #visible_if
+        #validate
+        #label
+        #reset
+        #accessor
+        #options
+    }))
+}
+
+/// Expands `label = "Display name"` into code setting the label of the field at `target`
+fn expand_label(
+    attribute: &attributes::InspectorAttribute,
+    target: &TokenStream,
+) -> syn::Result<TokenStream> {
+    match attribute {
+        attributes::InspectorAttribute::Assignment(
+            _,
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(label),
+                ..
+            }),
+        ) => Ok(quote! { options.set_label(#target, #label); }),
+        attribute => Err(syn::Error::new_spanned(
+            attribute.lhs(),
+            "expected `label = \"Display name\"`",
+        )),
+    }
+}
+
+/// Expands `reset` and `default = value` into code registering the reset button of the field at `target`
+fn expand_reset(
+    field: &syn::Field,
+    reset: Option<&attributes::InspectorAttribute>,
+    default: Option<&attributes::InspectorAttribute>,
+    target: &TokenStream,
+) -> syn::Result<Option<TokenStream>> {
+    let field_reset = quote! { ::bevy_inspector_egui::inspector_options::FieldReset };
+    match (reset, default) {
+        (Some(reset), Some(_)) => Err(syn::Error::new_spanned(
+            reset.lhs(),
+            "`reset` is implied by `default = value`",
+        )),
+        (Some(attributes::InspectorAttribute::Assignment(name, _)), None) => Err(
+            syn::Error::new_spanned(name, "expected `reset` or `default = value`"),
+        ),
+        (Some(_), None) => Ok(Some(quote! {
+            options.set_reset(#target, #field_reset::Default);
+        })),
+        (None, Some(attributes::InspectorAttribute::Tag(name))) => Err(syn::Error::new_spanned(
+            name,
+            "expected `default = value`, or `reset` to reset to the `Default` of the field type",
+        )),
+        (None, Some(attributes::InspectorAttribute::Assignment(_, value))) => {
+            let ty = &field.ty;
+            let value = quote_spanned! {value.span()=>
+                let value: #ty = #value;
+            };
+            Ok(Some(quote! {
+                options.set_reset(
+                    #target,
+                    #field_reset::Value(|| {
+                        #value
+                        ::std::boxed::Box::new(value)
+                    }),
+                );
+            }))
+        }
+        (None, None) => Ok(None),
+    }
+}
 
-                #(#(#fields)*)*
+/// Expands `getter = "method"` and `setter = "method"` into code registering a `FieldAccessor` at `target`.
+///
+/// The getter returns the field type and the setter takes it by value.
+fn expand_accessor(
+    input: &DeriveInput,
+    field: &syn::Field,
+    getter: &attributes::InspectorAttribute,
+    setter: Option<&attributes::InspectorAttribute>,
+    target: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
+    let type_name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let ty = &field.ty;
 
-                options
+    let getter = method_name(getter)?;
+    let get = quote_spanned! {getter.span()=>
+        |value| {
+            let value = value.downcast_ref::<#type_name #ty_generics>()?;
+            let field: #ty = value.#getter();
+            ::std::option::Option::Some(::std::boxed::Box::new(field))
+        }
+    };
+    let set = match setter {
+        Some(setter) => {
+            let setter = method_name(setter)?;
+            quote_spanned! {setter.span()=>
+                ::std::option::Option::Some(
+                    |value: &mut dyn #bevy_reflect::Reflect,
+                     field: ::std::boxed::Box<dyn #bevy_reflect::Reflect>| {
+                        if let (::std::option::Option::Some(value), ::std::result::Result::Ok(field)) =
+                            (value.downcast_mut::<#type_name #ty_generics>(), field.take::<#ty>())
+                        {
+                            value.#setter(field);
+                        }
+                    },
+                )
             }
         }
+        None => quote! { ::std::option::Option::None },
+    };
+
+    Ok(quote! {
+        options.set_accessor(
+            #target,
+            ::bevy_inspector_egui::inspector_options::FieldAccessor {
+                get: #get,
+                set: #set,
+            },
+        );
     })
 }
+
+/// The expression of an attribute like `max = "self.max_speed"`, with `self` replaced by `this`,
+/// or `None` if the attribute doesn't refer to the value containing the field
+fn dynamic_attribute_value(
+    attribute: &attributes::InspectorAttribute,
+    this: &syn::Ident,
+) -> Option<TokenStream> {
+    let attributes::InspectorAttribute::Assignment(
+        _,
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(expr),
+            ..
+        }),
+    ) = attribute
+    else {
+        return None;
+    };
+    let expr: syn::Expr = expr.parse().ok()?;
+    let tokens = quote! { #expr };
+    let replaced = replace_self(tokens.clone(), this);
+    (replaced.to_string() != tokens.to_string()).then_some(replaced)
+}
+
+/// Expands the remaining attributes of a field into its options.
+///
+/// Attributes referring to other fields like `max = "self.max_speed"` are evaluated every time the field is displayed,
+/// using options which are registered with `set_dynamic_options` in addition to the options without them.
+fn expand_field_options(
+    input: &DeriveInput,
+    field: &syn::Field,
+    target: TokenStream,
+    attrs: Vec<attributes::InspectorAttribute>,
+) -> syn::Result<Option<TokenStream>> {
+    if attrs.is_empty() {
+        return Ok(None);
+    }
+
+    let ty = &field.ty;
+    if let Some(with) = attrs
+        .iter()
+        .find(|attribute| attribute.lhs() == &with_member())
+    {
+        if attrs.len() > 1 {
+            return Err(syn::Error::new_spanned(
+                with.lhs(),
+                "`with` can't be combined with other inspector attributes",
+            ));
+        }
+        // the widget takes an `egui::Ui`, which only exists with the `enabled` feature
+        if !cfg!(feature = "enabled") {
+            return Ok(None);
+        }
+        let widget = with.rhs();
+        return Ok(Some(quote_spanned! {ty.span()=>
+            options.insert(
+                #target,
+                ::bevy_inspector_egui::inspector_options::CustomWidget::new::<#ty>(#widget),
+            );
+        }));
+    }
+
+    let options_type = quote_spanned! {ty.span()=>
+        <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>
+    };
+    let this = syn::Ident::new("__this", proc_macro2::Span::call_site());
+    let mut static_attrs = Vec::new();
+    let mut dynamic_attrs = Vec::new();
+    for attribute in &attrs {
+        let name = attribute.lhs();
+        match dynamic_attribute_value(attribute, &this) {
+            Some(value) => dynamic_attrs.push(quote_spanned! {name.span()=>
+                field_options.#name = ::std::convert::Into::into(#value);
+            }),
+            None => {
+                let value = attribute.rhs();
+                static_attrs.push(quote_spanned! {name.span()=>
+                    field_options.#name = ::std::convert::Into::into(#value);
+                });
+            }
+        }
+    }
+
+    let init = quote_spanned! {ty.span()=>
+        let mut field_options = #options_type::DeriveOptions::default();
+    };
+    let insert = quote_spanned! {ty.span()=>
+        options.insert(#target, #options_type::options_from_derive(field_options));
+    };
+
+    let dynamic = (!dynamic_attrs.is_empty()).then(|| {
+        let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
+        let type_name = &input.ident;
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let options_from_derive = quote_spanned! {ty.span()=>
+            #options_type::options_from_derive(field_options)
+        };
+        quote! {
+            options.set_dynamic_options(#target, |value| {
+                let #this = value.downcast_ref::<#type_name #ty_generics>()?;
+                #init
+                #(#static_attrs)*
+                #(#dynamic_attrs)*
+                ::std::option::Option::Some(
+                    ::std::boxed::Box::new(#options_from_derive)
+                        as ::std::boxed::Box<dyn #bevy_reflect::TypeData>,
+                )
+            });
+        }
+    });
+
+    Ok(Some(quote! {
+        #init
+        #(#static_attrs)*
+        #insert
+        #dynamic
+    }))
+}
+
 fn expand_union(_: &DeriveInput, data: &DataUnion) -> syn::Result<TokenStream> {
     Err(syn::Error::new_spanned(
         data.union_token,
@@ -1,10 +1,13 @@
 use std::marker::PhantomData;
 
 use bevy_inspector_egui::{
-    inspector_options::{std_options::NumberOptions, Target},
+    inspector_options::{
+        std_options::{NumberDisplay, NumberOptions},
+        InspectorOptionsType, Target,
+    },
     InspectorOptions,
 };
-use bevy_reflect::{FromType, Reflect};
+use bevy_reflect::{FromType, Reflect, TypePath};
 
 #[test]
 fn generic_without_options() {
@@ -46,3 +49,81 @@ fn phantom_data() {
         .unwrap();
     assert_eq!(options.min, Some(0.0));
 }
+
+#[test]
+fn generic_field_with_options() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Generic<T: Reflect + TypePath> {
+        #[inspector(visible_if = "self.enabled")]
+        value: Option<T>,
+        #[inspector(min = 1.0)]
+        scale: f32,
+        enabled: bool,
+    }
+
+    let options = <InspectorOptions as FromType<Generic<f32>>>::from_type();
+
+    let scale_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(scale_options.min, Some(1.0));
+
+    let hidden = Generic {
+        value: Some(1.0f32),
+        scale: 1.0,
+        enabled: false,
+    };
+    assert!(!options.is_visible(Target::Field(0), &hidden));
+}
+
+#[test]
+fn field_bound() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Bounded<T: Reflect + TypePath + Copy> {
+        max: T,
+        #[inspector(
+            bound = "T: InspectorOptionsType<DeriveOptions = NumberOptions<T>>",
+            max = "self.max",
+            display = NumberDisplay::Slider
+        )]
+        value: T,
+    }
+
+    let options = <InspectorOptions as FromType<Bounded<u8>>>::from_type();
+
+    let value_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<NumberOptions<u8>>()
+        .unwrap();
+    assert!(matches!(value_options.display, NumberDisplay::Slider));
+
+    let bounded = Bounded { max: 7u8, value: 3 };
+    let dynamic = options.dynamic_options(Target::Field(1), &bounded).unwrap();
+    let dynamic = dynamic.downcast_ref::<NumberOptions<u8>>().unwrap();
+    assert_eq!(dynamic.max, Some(7));
+}
+
+#[test]
+fn type_bound() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(bound = "T: InspectorOptionsType<DeriveOptions = NumberOptions<T>>")]
+    enum Either<T: Reflect + TypePath> {
+        Left(#[inspector(display = NumberDisplay::Slider)] T),
+        Right(#[inspector(min = 0.0)] f32),
+    }
+
+    let options = <InspectorOptions as FromType<Either<i32>>>::from_type();
+
+    let left_options = options
+        .get(Target::VariantField {
+            variant_index: 0,
+            field_index: 0,
+        })
+        .unwrap()
+        .downcast_ref::<NumberOptions<i32>>()
+        .unwrap();
+    assert!(matches!(left_options.display, NumberDisplay::Slider));
+}
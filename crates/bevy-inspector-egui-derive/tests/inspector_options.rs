@@ -1,13 +1,17 @@
 use bevy_ecs::entity::Entity;
 use bevy_inspector_egui::{
+    egui,
     inspector_options::{
-        std_options::{EntityDisplay, EntityOptions, NumberOptions, QuatDisplay, QuatOptions},
-        Target,
+        std_options::{
+            EntityDisplay, EntityOptions, ListOptions, NumberOptions, QuatDisplay, QuatOptions,
+        },
+        CustomWidget, FieldReset, Target,
     },
+    reflect_inspector::Context,
     InspectorOptions,
 };
 use bevy_math::Quat;
-use bevy_reflect::{FromType, Reflect};
+use bevy_reflect::{FromType, Reflect, TypeData};
 
 #[test]
 fn expr_attribute() {
@@ -50,7 +54,465 @@ fn expr_attribute() {
     let entity_options = options
         .get(Target::Field(2))
         .unwrap()
+        .downcast_ref::<ListOptions<Box<dyn TypeData>>>()
+        .unwrap()
+        .element
+        .as_any()
         .downcast_ref::<EntityOptions>()
         .unwrap();
     assert!(matches!(entity_options.display, EntityDisplay::Id));
 }
+
+#[test]
+fn tuple_and_array_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(min = 0.0)]
+        array: [f32; 3],
+
+        #[inspector(1 = NumberOptions::at_least(2))]
+        tuple: (f32, u32),
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let array_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<ListOptions<Box<dyn TypeData>>>()
+        .unwrap();
+    assert!(array_options.fixed_size);
+    let element_options = array_options
+        .element
+        .as_any()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(element_options.min, Some(0.0));
+
+    let tuple_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<InspectorOptions>()
+        .unwrap();
+    let first_options = tuple_options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(first_options.min, None);
+    let second_options = tuple_options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<NumberOptions<u32>>()
+        .unwrap();
+    assert_eq!(second_options.min, Some(2));
+}
+
+#[test]
+fn list_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(fixed_size, min = 1.0)]
+        list: Vec<f32>,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let list_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<ListOptions<Box<dyn TypeData>>>()
+        .unwrap();
+    assert!(list_options.fixed_size);
+    let element_options = list_options
+        .element
+        .as_any()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(element_options.min, Some(1.0));
+}
+
+#[test]
+fn with_attribute() {
+    fn reset(value: &mut f32, _: &mut egui::Ui, _: &mut Context) -> bool {
+        *value = 1.0;
+        true
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        a: f32,
+        #[inspector(with = reset)]
+        b: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let widget = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<CustomWidget>()
+        .unwrap();
+
+    let mut value = 0.0f32;
+    let mut changed = false;
+    let ctx = egui::Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let mut context = Context {
+                world: None,
+                queue: None,
+            };
+            changed = widget.ui(&mut value, ui, &mut context);
+        });
+    });
+    assert!(changed);
+    assert_eq!(value, 1.0);
+}
+
+#[test]
+fn button_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(button = "reset", button = "double")]
+    struct Test {
+        value: f32,
+    }
+
+    impl Test {
+        fn reset(&mut self) {
+            self.value = 1.0;
+        }
+
+        fn double(&mut self) -> f32 {
+            self.value *= 2.0;
+            self.value
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let buttons = options.buttons();
+    assert_eq!(buttons.len(), 2);
+    assert_eq!(buttons[0].name, "reset");
+
+    let mut value = Test { value: 0.0 };
+    for button in buttons {
+        (button.call)(&mut value);
+    }
+    assert_eq!(value.value, 2.0);
+}
+
+#[test]
+fn on_change_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(on_change = "recompute")]
+    struct Test {
+        value: f32,
+        #[reflect(ignore)]
+        squared: f32,
+    }
+
+    impl Test {
+        fn recompute(&mut self) {
+            self.squared = self.value * self.value;
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let mut value = Test {
+        value: 3.0,
+        squared: 0.0,
+    };
+    options.on_change().unwrap()(&mut value);
+    assert_eq!(value.squared, 9.0);
+}
+
+#[test]
+fn visible_if_attribute() {
+    #[derive(Reflect, PartialEq)]
+    enum Mode {
+        Simple,
+        Advanced,
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        mode: Mode,
+        #[inspector(visible_if = "self.mode == Mode::Advanced", min = 0.0)]
+        tolerance: f32,
+        #[inspector(visible_if = Test::has_tolerance)]
+        iterations: u32,
+    }
+
+    impl Test {
+        fn has_tolerance(&self) -> bool {
+            self.tolerance > 0.0
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let number_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(number_options.min, Some(0.0));
+    assert!(options.get(Target::Field(2)).is_none());
+
+    let mut value = Test {
+        mode: Mode::Simple,
+        tolerance: 0.0,
+        iterations: 10,
+    };
+    assert!(options.is_visible(Target::Field(0), &value));
+    assert!(!options.is_visible(Target::Field(1), &value));
+    assert!(!options.is_visible(Target::Field(2), &value));
+
+    value.mode = Mode::Advanced;
+    value.tolerance = 0.5;
+    assert!(options.is_visible(Target::Field(1), &value));
+    assert!(options.is_visible(Target::Field(2), &value));
+}
+
+#[test]
+fn sibling_field_bounds() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(min = 0.0)]
+        max_speed: f32,
+        #[inspector(min = 0.0, max = "self.max_speed", suffix = " m/s")]
+        speed: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let static_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(static_options.max, None);
+    assert!(options
+        .dynamic_options(
+            Target::Field(0),
+            &Test {
+                max_speed: 1.0,
+                speed: 0.0
+            }
+        )
+        .is_none());
+
+    let value = Test {
+        max_speed: 4.0,
+        speed: 1.0,
+    };
+    let dynamic_options = options.dynamic_options(Target::Field(1), &value).unwrap();
+    let dynamic_options = dynamic_options
+        .as_any()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(dynamic_options.min, Some(0.0));
+    assert_eq!(dynamic_options.max, Some(4.0));
+    assert_eq!(dynamic_options.suffix, " m/s");
+}
+
+#[test]
+fn accessor_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(getter = "value", setter = "set_value", min = 0.0)]
+        value: f32,
+        #[inspector(getter = "doubled")]
+        doubled: f32,
+    }
+
+    impl Test {
+        fn value(&self) -> f32 {
+            self.value
+        }
+
+        fn set_value(&mut self, value: f32) {
+            self.value = value;
+            self.doubled = value * 2.0;
+        }
+
+        fn doubled(&self) -> f32 {
+            self.doubled
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let number_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(number_options.min, Some(0.0));
+
+    let mut value = Test {
+        value: 1.0,
+        doubled: 2.0,
+    };
+    let accessor = options.accessor(Target::Field(0)).unwrap();
+    let field = (accessor.get)(&value).unwrap();
+    assert_eq!(field.downcast_ref::<f32>(), Some(&1.0));
+    (accessor.set.unwrap())(&mut value, Box::new(3.0f32));
+    assert_eq!(value.value, 3.0);
+    assert_eq!(value.doubled, 6.0);
+
+    assert!(options.accessor(Target::Field(1)).unwrap().set.is_none());
+}
+
+#[test]
+fn label_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(label = "Max HP", min = 1.0)]
+        max_hp: f32,
+        speed: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert_eq!(options.label(Target::Field(0)), Some("Max HP"));
+    assert_eq!(options.label(Target::Field(1)), None);
+    assert!(options.get(Target::Field(0)).is_some());
+}
+
+#[test]
+fn reset_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(default = 1.5)]
+        zoom: f32,
+        #[inspector(reset, min = 0.0)]
+        offset: f32,
+        speed: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let Some(FieldReset::Value(zoom)) = options.reset(Target::Field(0)) else {
+        panic!("expected a default value");
+    };
+    assert_eq!(zoom().downcast_ref::<f32>(), Some(&1.5));
+    assert!(matches!(
+        options.reset(Target::Field(1)),
+        Some(FieldReset::Default)
+    ));
+    assert!(options.reset(Target::Field(2)).is_none());
+}
+
+#[test]
+fn validate_attribute() {
+    fn positive_width(value: &Test) -> Result<(), &'static str> {
+        match value.width > 0.0 {
+            true => Ok(()),
+            false => Err("must be positive"),
+        }
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        min: f32,
+        #[inspector(validate = "check_max", min = 0.0)]
+        max: f32,
+        #[inspector(validate = positive_width)]
+        width: f32,
+    }
+
+    impl Test {
+        fn check_max(&self) -> Result<(), String> {
+            match self.max >= self.min {
+                true => Ok(()),
+                false => Err(format!("below the min of {}", self.min)),
+            }
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let value = Test {
+        min: 2.0,
+        max: 1.0,
+        width: 1.0,
+    };
+    assert_eq!(
+        options.validate(Target::Field(1), &value),
+        Err("below the min of 2".to_owned())
+    );
+    assert_eq!(options.validate(Target::Field(2), &value), Ok(()));
+    assert_eq!(options.validate(Target::Field(0), &value), Ok(()));
+}
+
+#[test]
+fn labels_attribute() {
+    fn track_name(index: usize) -> String {
+        format!("Track {}", index + 1)
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(labels = ["X", "Y"])]
+        array: [f32; 3],
+        #[inspector(labels = track_name)]
+        tracks: Vec<f32>,
+        #[inspector(labels = |index: usize| format!("#{index}"))]
+        slots: Vec<u32>,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let labels = |field| {
+        let list_options = options
+            .get(Target::Field(field))
+            .unwrap()
+            .downcast_ref::<ListOptions<Box<dyn TypeData>>>()
+            .unwrap();
+        (0..3)
+            .map(|index| list_options.labels.label(index))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(labels(0), ["X", "Y", "2"]);
+    assert_eq!(labels(1), ["Track 1", "Track 2", "Track 3"]);
+    assert_eq!(labels(2), ["#0", "#1", "#2"]);
+}
+
+#[test]
+fn transparent_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(transparent)]
+    struct Meters(#[inspector(min = 0.0)] f32);
+
+    #[derive(Reflect)]
+    struct NoOptions;
+
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(transparent)]
+    struct Wrapper {
+        inner: NoOptions,
+        #[reflect(ignore)]
+        _cache: u32,
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(max = 10.0)]
+        range: Meters,
+        wrapper: Wrapper,
+    }
+
+    let meters_options = <InspectorOptions as FromType<Meters>>::from_type();
+    assert!(meters_options.is_transparent());
+    let inner_options = meters_options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(inner_options.min, Some(0.0));
+    assert!(<InspectorOptions as FromType<Wrapper>>::from_type().is_transparent());
+
+    // attributes on newtype fields are options of the inner type
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let range_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(range_options.max, Some(10.0));
+    assert_eq!(range_options.min, None);
+}
@@ -0,0 +1,11 @@
+use bevy_inspector_egui::InspectorOptions;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, InspectorOptions)]
+struct Config {
+    #[reflect(ignore)]
+    #[inspector(min = 0.0)]
+    speed: f32,
+}
+
+fn main() {}
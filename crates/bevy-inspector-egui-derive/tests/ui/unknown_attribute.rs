@@ -0,0 +1,23 @@
+use bevy_inspector_egui::inspector_options::InspectorOptionsType;
+use bevy_inspector_egui::InspectorOptions;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, Default)]
+struct Speed(f32);
+
+impl InspectorOptionsType for Speed {
+    type DeriveOptions = ();
+    type Options = ();
+
+    fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+        options
+    }
+}
+
+#[derive(Reflect, InspectorOptions)]
+struct Config {
+    #[inspector(minimum = 0.0)]
+    speed: Speed,
+}
+
+fn main() {}
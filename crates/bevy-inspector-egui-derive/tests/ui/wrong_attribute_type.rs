@@ -0,0 +1,10 @@
+use bevy_inspector_egui::InspectorOptions;
+use bevy_reflect::Reflect;
+
+#[derive(Reflect, InspectorOptions)]
+struct Config {
+    #[inspector(display = "slider")]
+    speed: f32,
+}
+
+fn main() {}
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy_egui::EguiContext;
-use bevy_inspector_egui::inspector_options::std_options::NumberDisplay;
+use bevy_inspector_egui::inspector_options::std_options::{
+    CodeLanguage, ColorChannels, NumberCommit, NumberDisplay,
+};
 use bevy_inspector_egui::{prelude::*, DefaultInspectorConfigPlugin};
 use bevy_utils::HashMap;
 use bevy_window::PrimaryWindow;
@@ -11,24 +13,86 @@ struct Config {
     // `f32` uses `NumberOptions<f32>`
     #[inspector(min = 10.0, max = 70.0, display = NumberDisplay::Slider)]
     font_size: f32,
+    // only write the value once dragging stops, for values whose changes are expensive
+    #[inspector(min = 1, commit = NumberCommit::OnRelease)]
+    subdivisions: u32,
+    // ranges show both bounds, keeping the start from going past the end
+    lifetime: std::ops::RangeInclusive<f32>,
+    #[inspector(angle)] // stored in radians, edited in degrees
+    heading: f32,
+    // the slider starts at 0..=10 and grows when dragging past its ends, but never below the hard `min`
+    #[inspector(min = 0.0, soft_max = 10.0, display = NumberDisplay::Slider)]
+    spawn_rate: f32,
+    // a progress bar between `min` and `max`, which can be dragged to set the value
+    #[inspector(min = 0, max = 100, display = NumberDisplay::Bar { show_value: true })]
+    health: u32,
+    // a monospace editor highlighting the syntax of the language, e.g. for shader snippets
+    #[inspector(code = CodeLanguage::Wgsl)]
+    shader: String,
+    // edited through the accessor methods, which keep the direction normalized
+    #[inspector(getter = "direction", setter = "set_direction")]
+    direction: Vec3,
+    // `NonZero*` integers skip over zero when edited
+    #[inspector(max = 8)]
+    samples: std::num::NonZeroU8,
+    // shown like the `f32` inside, with the options of the newtype's field
+    distance: Meters,
     #[inspector(min = -1.0, speed = 0.001)] // you can specify inner options for `Option<T>`
     option: Option<f32>,
+    // only shown while the condition holds, with `self` referring to the `Config`
+    #[inspector(visible_if = "self.option.is_some()")]
+    option_label: String,
     #[inspector(min = 10, max = 20)] // same for Vec<T>
     vec: Vec<u32>,
+    #[inspector(fixed_size)] // `Vec<T>` uses `ListOptions` to disable adding and removing elements
+    fixed_vec: Vec<f32>,
+    // elements are labeled with the names instead of their index
+    #[inspector(labels = ["Primary", "Secondary", "Melee"])]
+    weapons: [String; 3],
     hash_map: HashMap<u32, String>,
+    #[inspector(alpha = false, channels = ColorChannels::Hsv)] // `Color` uses `ColorOptions`
+    color: Color,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             font_size: 0.,
+            subdivisions: 4,
+            lifetime: 0.5..=2.0,
+            heading: 0.0,
+            spawn_rate: 2.0,
+            health: 80,
+            shader: "fn brightness(color: vec3<f32>) -> f32 {\n    return dot(color, vec3(0.2126, 0.7152, 0.0722));\n}".to_owned(),
+            direction: Vec3::NEG_Y,
+            samples: std::num::NonZeroU8::MIN,
+            distance: Meters(20.0),
             option: None,
+            option_label: "offset".to_owned(),
             vec: Vec::default(),
+            fixed_vec: vec![1.0, 2.0, 3.0],
+            weapons: ["Rifle".to_owned(), "Pistol".to_owned(), "Knife".to_owned()],
             hash_map: HashMap::from([(0, "foo".to_owned()), (1, "bar".to_owned())]),
+            color: Color::ORANGE,
         }
     }
 }
 
+#[derive(Reflect, InspectorOptions)]
+#[reflect(InspectorOptions)]
+#[inspector(transparent)]
+struct Meters(#[inspector(min = 0.0, suffix = " m")] f32);
+
+impl Config {
+    fn direction(&self) -> Vec3 {
+        self.direction
+    }
+
+    fn set_direction(&mut self, direction: Vec3) {
+        self.direction = direction.try_normalize().unwrap_or(self.direction);
+    }
+}
+
 // Enums can be have `InspectorOptions` as well.
 // Note that in order to switch to another enum variant, all its fields need to have [`ReflectDefault`] type data.
 #[derive(Default, Reflect, InspectorOptions)]
@@ -71,6 +135,7 @@ fn main() {
         // types need to be registered
         .init_resource::<UiData>()
         .register_type::<Config>()
+        .register_type::<Meters>()
         .register_type::<Shape>()
         .register_type::<UiData>()
         .add_systems(Startup, setup)
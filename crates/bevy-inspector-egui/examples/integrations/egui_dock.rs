@@ -255,7 +255,7 @@ impl egui_dock::TabViewer for TabViewer<'_> {
     }
 }
 
-#[allow(unused)]
+#[allow(unused, clippy::needless_return)]
 fn draw_gizmo(
     ui: &mut egui::Ui,
     world: &mut World,
@@ -316,7 +316,7 @@ fn select_resource(
             )
         })
         .collect();
-    resources.sort_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b));
+    resources.sort_by_key(|(name, _)| *name);
 
     for (resource_name, type_id) in resources {
         let selected = match *selection {
@@ -347,7 +347,7 @@ fn select_asset(
             ))
         })
         .collect();
-    assets.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    assets.sort_by_key(|(name, ..)| *name);
 
     for (asset_name, asset_type_id, reflect_asset) in assets {
         let handles: Vec<_> = reflect_asset.ids(world).collect();
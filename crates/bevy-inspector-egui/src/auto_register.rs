@@ -0,0 +1,74 @@
+//! Registration of types by the crates defining them, see [`register_inspectable!`](crate::register_inspectable).
+//!
+//! A library crate lists the types it wants to be inspectable once, and every app depending on it gets them registered
+//! by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin), which the [`quick`](crate::quick)
+//! plugins add, without calling `register_type` for each of them:
+//! ```rust
+//! use bevy_ecs::prelude::*;
+//! use bevy_reflect::Reflect;
+//!
+//! #[derive(Component, Reflect, Default)]
+//! #[reflect(Component)]
+//! pub struct Health(f32);
+//!
+//! #[derive(Component, Reflect, Default)]
+//! #[reflect(Component)]
+//! pub struct Mana(f32);
+//!
+//! bevy_inspector_egui::register_inspectable!(Health, Mana);
+//! ```
+//!
+//! The registrations are collected with [`inventory`], which relies on functions the platform runs before `main`.
+//! This doesn't work on the web, where using the macro is a compile error.
+
+use bevy_reflect::TypeRegistry;
+
+/// A function registering the types of one [`register_inspectable!`](crate::register_inspectable)
+#[doc(hidden)]
+pub struct Registration(pub fn(&mut TypeRegistry));
+
+inventory::collect!(Registration);
+
+/// Register the types of every [`register_inspectable!`](crate::register_inspectable) in the linked crates
+pub fn register_all(type_registry: &mut TypeRegistry) {
+    for registration in inventory::iter::<Registration> {
+        (registration.0)(type_registry);
+    }
+}
+
+/// Register types in the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) of every app using the inspector,
+/// from the crate defining them. See the [`auto_register`](crate::auto_register) module.
+#[macro_export]
+macro_rules! register_inspectable {
+    ($($ty:ty),* $(,)?) => {
+        #[cfg(target_arch = "wasm32")]
+        compile_error!(
+            "`register_inspectable!` is not available on the web, register the types with `app.register_type` instead"
+        );
+
+        $crate::__macro_exports::inventory::submit! {
+            $crate::auto_register::Registration(|type_registry| {
+                $(type_registry.register::<$ty>();)*
+            })
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    #[derive(Reflect)]
+    struct Registered(u32);
+
+    crate::register_inspectable!(Registered);
+
+    #[test]
+    fn registered_types_are_added_to_the_registry() {
+        let mut type_registry = TypeRegistry::empty();
+        super::register_all(&mut type_registry);
+        assert!(type_registry.get(TypeId::of::<Registered>()).is_some());
+    }
+}
@@ -0,0 +1,198 @@
+use std::{any::TypeId, marker::PhantomData};
+
+use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+use bevy_reflect::{Reflect, ReflectFromReflect};
+use pretty_type_name::pretty_type_name;
+
+use super::errors;
+
+/// Two labeled sets of values for the resource `R`, to quickly flip between while watching the game.
+///
+/// Use [`ui_for_ab_comparison`] to store the current value into a slot and [`toggle_ab_comparison`] to switch.
+/// The slot that is currently active always receives the live value upon switching, so tweaks aren't lost.
+///
+/// Switching replaces the whole value, so lists and maps lose the entries added since the slot was stored.
+/// This needs `R` to be registered with [`ReflectFromReflect`], which `#[derive(Reflect)]` does by default,
+/// otherwise the stored values are only applied on top of the live one.
+#[derive(Resource)]
+pub struct AbComparison<R> {
+    /// The names of both slots, shown in the UI and editable there
+    pub labels: [String; 2],
+    slots: [Option<Box<dyn Reflect>>; 2],
+    active: usize,
+    from_reflect: Option<ReflectFromReflect>,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: 'static> FromWorld for AbComparison<R> {
+    fn from_world(world: &mut World) -> Self {
+        let from_reflect = world
+            .get_resource::<AppTypeRegistry>()
+            .and_then(|registry| {
+                registry
+                    .read()
+                    .get_type_data::<ReflectFromReflect>(TypeId::of::<R>())
+                    .cloned()
+            });
+        Self {
+            labels: ["A".to_owned(), "B".to_owned()],
+            slots: [None, None],
+            active: 0,
+            from_reflect,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<R> AbComparison<R> {
+    /// Index of the slot the resource currently corresponds to
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Whether a value has been stored into `slot` yet
+    pub fn is_stored(&self, slot: usize) -> bool {
+        self.slots[slot].is_some()
+    }
+
+    /// A copy of `value` which is of the concrete type `R` if possible, so it can be [`set`](Reflect::set)
+    fn snapshot(&self, value: &dyn Reflect) -> Box<dyn Reflect> {
+        self.from_reflect
+            .as_ref()
+            .and_then(|from_reflect| from_reflect.from_reflect(value))
+            .unwrap_or_else(|| value.clone_value())
+    }
+}
+
+impl<R: Resource + Reflect> AbComparison<R> {
+    /// Store the current value of `resource` into `slot` and make it the active one
+    pub fn store(&mut self, slot: usize, resource: &R) {
+        self.slots[slot] = Some(self.snapshot(resource));
+        self.active = slot;
+    }
+
+    /// Save the current value into the active slot and switch to the other one, if it was stored already
+    pub fn toggle(&mut self, resource: &mut R) -> bool {
+        let other = 1 - self.active;
+        let Some(other_value) = &self.slots[other] else {
+            return false;
+        };
+        let value = self.snapshot(&**other_value);
+        self.slots[self.active] = Some(self.snapshot(resource));
+        if let Err(value) = resource.set(value) {
+            resource.apply(&*value);
+        }
+        self.active = other;
+        true
+    }
+}
+
+/// Switch the resource `R` to the other slot of its [`AbComparison<R>`]
+pub fn toggle_ab_comparison<R: Resource + Reflect>(world: &mut World) {
+    world.resource_scope(|world, mut comparison: Mut<AbComparison<R>>| {
+        if let Some(mut resource) = world.get_resource_mut::<R>() {
+            comparison.toggle(&mut resource);
+        }
+    });
+}
+
+/// Display controls for storing the current value of `R` into either slot of its [`AbComparison<R>`] and switching between them.
+///
+/// Inserts the [`AbComparison<R>`] resource if it doesn't exist yet.
+pub fn ui_for_ab_comparison<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    if !world.contains_resource::<R>() {
+        errors::resource_does_not_exist(ui, &pretty_type_name::<R>());
+        return;
+    }
+    world.init_resource::<AbComparison<R>>();
+
+    world.resource_scope(|world, mut comparison: Mut<AbComparison<R>>| {
+        let mut resource = world.resource_mut::<R>();
+
+        ui.horizontal(|ui| {
+            for slot in 0..2 {
+                let active = comparison.active == slot && comparison.is_stored(slot);
+                ui.add(
+                    egui::TextEdit::singleline(&mut comparison.labels[slot]).desired_width(60.0),
+                );
+                if ui
+                    .selectable_label(active, "Store")
+                    .on_hover_text("Store the current values into this slot")
+                    .clicked()
+                {
+                    comparison.store(slot, &resource);
+                }
+            }
+        });
+
+        let other = 1 - comparison.active;
+        let label = format!("Switch to {}", comparison.labels[other]);
+        if ui
+            .add_enabled(comparison.is_stored(other), egui::Button::new(label))
+            .clicked()
+        {
+            comparison.toggle(&mut resource);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+    use bevy_reflect::Reflect;
+
+    use super::AbComparison;
+
+    #[derive(Resource, Reflect, Default)]
+    struct Tuning {
+        speed: f32,
+        waypoints: Vec<f32>,
+    }
+
+    fn comparison() -> AbComparison<Tuning> {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Tuning>();
+        world.insert_resource(type_registry);
+        AbComparison::from_world(&mut world)
+    }
+
+    #[test]
+    fn toggle_keeps_tweaks_of_active_slot() {
+        let mut comparison = comparison();
+        let mut tuning = Tuning {
+            speed: 1.0,
+            ..Default::default()
+        };
+
+        assert!(!comparison.toggle(&mut tuning));
+
+        comparison.store(0, &tuning);
+        tuning.speed = 2.0;
+        comparison.store(1, &tuning);
+        tuning.speed = 3.0;
+
+        assert!(comparison.toggle(&mut tuning));
+        assert_eq!(comparison.active(), 0);
+        assert_eq!(tuning.speed, 1.0);
+
+        assert!(comparison.toggle(&mut tuning));
+        assert_eq!(comparison.active(), 1);
+        assert_eq!(tuning.speed, 3.0);
+    }
+
+    #[test]
+    fn toggle_replaces_lists() {
+        let mut comparison = comparison();
+        let mut tuning = Tuning::default();
+
+        comparison.store(0, &tuning);
+        tuning.waypoints = vec![1.0, 2.0];
+        comparison.store(1, &tuning);
+
+        assert!(comparison.toggle(&mut tuning));
+        assert!(tuning.waypoints.is_empty());
+        assert!(comparison.toggle(&mut tuning));
+        assert_eq!(tuning.waypoints, [1.0, 2.0]);
+    }
+}
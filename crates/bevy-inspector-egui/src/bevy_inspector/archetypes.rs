@@ -0,0 +1,195 @@
+//! Statistics about the archetypes of the world, for diagnosing archetype fragmentation.
+//!
+//! Every distinct set of components is stored in its own archetype, so adding and removing marker components at runtime
+//! can create many archetypes with only a few entities each. [`ui_for_archetypes`] lists them with their entity counts
+//! and estimated memory usage, and can filter the world inspector to the entities of one archetype.
+
+use bevy_ecs::{archetype::ArchetypeId, prelude::*};
+
+use super::filter_archetype;
+
+/// The components, entity count and estimated memory usage of an archetype
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchetypeStats {
+    pub id: ArchetypeId,
+    /// Sorted names of the components of the archetype
+    pub components: Vec<String>,
+    pub entities: usize,
+    /// Estimated size of the stored entities and components in bytes.
+    ///
+    /// This only counts the inline size of the components, not memory allocated by them like the contents of a `Vec`,
+    /// nor unused capacity of the storages.
+    pub memory: usize,
+}
+
+impl ArchetypeStats {
+    /// Collect the statistics of all archetypes of the world, including empty ones
+    pub fn collect(world: &World) -> Vec<ArchetypeStats> {
+        let components = world.components();
+        world
+            .archetypes()
+            .iter()
+            .map(|archetype| {
+                let infos: Vec<_> = archetype
+                    .components()
+                    .filter_map(|id| components.get_info(id))
+                    .collect();
+                let mut names: Vec<_> = infos
+                    .iter()
+                    .map(|info| pretty_type_name::pretty_type_name_str(info.name()))
+                    .collect();
+                names.sort();
+
+                let row_size = std::mem::size_of::<Entity>()
+                    + infos.iter().map(|info| info.layout().size()).sum::<usize>();
+                ArchetypeStats {
+                    id: archetype.id(),
+                    components: names,
+                    entities: archetype.len(),
+                    memory: archetype.len() * row_size,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The column the archetypes are sorted by, in descending order
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArchetypeSort {
+    #[default]
+    Entities,
+    Memory,
+    Components,
+}
+
+impl ArchetypeSort {
+    pub fn sort(self, archetypes: &mut [ArchetypeStats]) {
+        match self {
+            ArchetypeSort::Entities => {
+                archetypes.sort_by_key(|stats| std::cmp::Reverse(stats.entities))
+            }
+            ArchetypeSort::Memory => {
+                archetypes.sort_by_key(|stats| std::cmp::Reverse(stats.memory))
+            }
+            ArchetypeSort::Components => {
+                archetypes.sort_by_key(|stats| std::cmp::Reverse(stats.components.len()))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct ArchetypesUiState {
+    sort: ArchetypeSort,
+    show_empty: bool,
+}
+
+/// Display a sortable table of all archetypes, see [`ArchetypeStats`].
+///
+/// The "Filter" button of an archetype only shows its entities in the world inspector, see [`filter_archetype`].
+pub fn ui_for_archetypes(world: &mut World, ui: &mut egui::Ui) {
+    let id = egui::Id::new("archetypes ui");
+    let mut state = ui.data_mut(|data| data.get_temp::<ArchetypesUiState>(id).unwrap_or_default());
+
+    let mut archetypes = ArchetypeStats::collect(world);
+    let total_entities: usize = archetypes.iter().map(|stats| stats.entities).sum();
+    let total_memory: usize = archetypes.iter().map(|stats| stats.memory).sum();
+    let empty = archetypes
+        .iter()
+        .filter(|stats| stats.entities == 0)
+        .count();
+
+    ui.horizontal(|ui| {
+        ui.label(format!(
+            "{} archetypes, {} empty, {total_entities} entities, ~{}",
+            archetypes.len(),
+            empty,
+            format_bytes(total_memory)
+        ));
+        ui.checkbox(&mut state.show_empty, "Show empty");
+    });
+
+    if !state.show_empty {
+        archetypes.retain(|stats| stats.entities > 0);
+    }
+    state.sort.sort(&mut archetypes);
+
+    let filtered = super::archetype_filter(ui.ctx());
+    egui::Grid::new(id.with("grid"))
+        .striped(true)
+        .num_columns(4)
+        .show(ui, |ui| {
+            for (sort, label) in [
+                (ArchetypeSort::Entities, "Entities"),
+                (ArchetypeSort::Memory, "Memory"),
+                (ArchetypeSort::Components, "Components"),
+            ] {
+                ui.selectable_value(&mut state.sort, sort, label)
+                    .on_hover_text("Sort by this column");
+            }
+            ui.label("");
+            ui.end_row();
+
+            for stats in &archetypes {
+                ui.label(stats.entities.to_string());
+                ui.label(format_bytes(stats.memory));
+                let components = match stats.components.is_empty() {
+                    true => "(no components)".to_owned(),
+                    false => stats.components.join(", "),
+                };
+                ui.add(egui::Label::new(components).wrap(true));
+
+                let is_filtered = filtered == Some(stats.id);
+                if ui
+                    .selectable_label(is_filtered, "Filter")
+                    .on_hover_text(
+                        "Only show the entities of this archetype in the world inspector",
+                    )
+                    .clicked()
+                {
+                    filter_archetype(ui.ctx(), (!is_filtered).then_some(stats.id));
+                }
+                ui.end_row();
+            }
+        });
+
+    ui.data_mut(|data| data.insert_temp(id, state));
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes} B"),
+        1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use super::{format_bytes, ArchetypeSort, ArchetypeStats};
+
+    #[derive(Component)]
+    struct Health(#[allow(dead_code)] u32);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn archetypes_count_entities_and_component_sizes() {
+        let mut world = World::new();
+        world.spawn_batch([Health(1), Health(2), Health(3)]);
+        world.spawn((Health(4), Marker));
+
+        let mut stats = ArchetypeStats::collect(&world);
+        ArchetypeSort::Entities.sort(&mut stats);
+        assert_eq!(stats[0].components, ["Health"]);
+        assert_eq!(stats[0].entities, 3);
+        assert_eq!(stats[0].memory, 3 * (std::mem::size_of::<Entity>() + 4));
+        assert_eq!(stats[1].components, ["Health", "Marker"]);
+        assert_eq!(stats[1].memory, std::mem::size_of::<Entity>() + 4);
+
+        assert_eq!(format_bytes(2048), "2.0 KiB");
+    }
+}
@@ -0,0 +1,265 @@
+//! Finding assets nothing refers to and handles to assets which don't exist, for debugging asset leaks.
+//!
+//! Usage is cross-referenced with the handles held by components of entities, like the counts of the
+//! [assets browser](super::assets_browser). Bevy only tracks all strong handles of assets loaded by the
+//! [`AssetServer`], so assets added to [`Assets`](bevy_asset::Assets) directly can also be referenced by resources
+//! or other assets without the inspector knowing about it.
+
+use bevy_asset::{AssetServer, LoadState, ReflectAsset, UntypedAssetId, UntypedHandle};
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use bevy_utils::HashMap;
+
+use super::{assets_browser, handle_name, selection};
+use crate::utils::guess_entity_name::guess_entity_name;
+
+/// The number of entities listed for a missing asset, before the rest is summarized
+const MAX_HOLDERS_SHOWN: usize = 5;
+
+/// An asset without any strong handles held by components of entities, see [`AssetUsage`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreferencedAsset {
+    pub id: UntypedAssetId,
+    pub name: String,
+    pub asset_type: String,
+    pub weak_handles: usize,
+}
+
+/// A handle held by components of entities to an asset which isn't in its [`Assets`](bevy_asset::Assets) collection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingAsset {
+    pub id: UntypedAssetId,
+    pub name: String,
+    pub asset_type: String,
+    /// The load state of the asset, if it was loaded by the [`AssetServer`]
+    pub load_state: Option<LoadState>,
+    /// The entities holding a handle to the asset
+    pub holders: Vec<Entity>,
+}
+
+/// Assets which look leaked and handles which are dangling, in the assets of every reflected asset type
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetUsage {
+    /// Assets with neither strong handles in components nor, for assets loaded by the [`AssetServer`], anywhere else
+    pub unreferenced: Vec<UnreferencedAsset>,
+    /// Handles, either strong or weak, to assets which aren't loaded or loading
+    pub missing: Vec<MissingAsset>,
+}
+
+impl AssetUsage {
+    pub fn collect(
+        world: &World,
+        type_registry: &TypeRegistry,
+        asset_server: Option<&AssetServer>,
+    ) -> AssetUsage {
+        let asset_type = |id: UntypedAssetId| {
+            type_registry
+                .get(id.type_id())
+                .map_or("?", |registration| {
+                    registration.type_info().type_path_table().short_path()
+                })
+                .to_owned()
+        };
+
+        let mut holders = HashMap::<UntypedAssetId, (UntypedHandle, Vec<Entity>, usize)>::default();
+        assets_browser::for_each_component_handle(world, type_registry, |entity, handle| {
+            let strong = matches!(handle, UntypedHandle::Strong(_));
+            let (_, entities, strong_count) = holders
+                .entry(handle.id())
+                .or_insert_with(|| (handle, Vec::new(), 0));
+            entities.push(entity);
+            *strong_count += usize::from(strong);
+        });
+
+        let mut usage = AssetUsage::default();
+        for registration in type_registry.iter() {
+            let Some(reflect_asset) = registration.data::<ReflectAsset>() else {
+                continue;
+            };
+            for id in reflect_asset.ids(world) {
+                let (weak_handles, strong_in_components) =
+                    holders.get(&id).map_or((0, 0), |(_, entities, strong)| {
+                        (entities.len() - strong, *strong)
+                    });
+                // a handle from the asset server is only returned while there are strong handles to the asset
+                let referenced_elsewhere =
+                    asset_server.is_some_and(|server| server.get_id_handle_untyped(id).is_some());
+                if strong_in_components == 0 && !referenced_elsewhere {
+                    usage.unreferenced.push(UnreferencedAsset {
+                        id,
+                        name: handle_name(id, asset_server),
+                        asset_type: asset_type(id),
+                        weak_handles,
+                    });
+                }
+            }
+        }
+
+        for (id, (handle, entities, _)) in holders {
+            let exists = type_registry
+                .get_type_data::<ReflectAsset>(id.type_id())
+                .is_some_and(|reflect_asset| reflect_asset.get(world, handle).is_some());
+            let load_state = asset_server.and_then(|server| server.get_load_state(id));
+            if exists || load_state == Some(LoadState::Loading) {
+                continue;
+            }
+            usage.missing.push(MissingAsset {
+                id,
+                name: handle_name(id, asset_server),
+                asset_type: asset_type(id),
+                load_state,
+                holders: entities,
+            });
+        }
+
+        usage
+            .unreferenced
+            .sort_by(|a, b| (&a.asset_type, &a.name).cmp(&(&b.asset_type, &b.name)));
+        usage
+            .missing
+            .sort_by(|a, b| (&a.asset_type, &a.name).cmp(&(&b.asset_type, &b.name)));
+        usage
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.unreferenced.is_empty() && self.missing.is_empty()
+    }
+}
+
+enum UsageAction {
+    Remove(UntypedAssetId),
+    Select(Entity),
+}
+
+/// Display the unreferenced assets and the missing assets of the [`AssetUsage`] of the world.
+///
+/// Unreferenced assets can be removed, and the entities holding a handle to a missing asset can be selected.
+pub fn ui_for_asset_usage(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let asset_server = world.get_resource::<AssetServer>().cloned();
+    let usage = AssetUsage::collect(world, &type_registry, asset_server.as_ref());
+
+    if usage.is_empty() {
+        ui.label("No unreferenced or missing assets");
+        return;
+    }
+
+    let mut action = None;
+    egui::CollapsingHeader::new(format!(
+        "Unreferenced assets ({})",
+        usage.unreferenced.len()
+    ))
+    .id_source("asset usage unreferenced")
+    .default_open(true)
+    .show(ui, |ui| {
+        egui::Grid::new("asset usage unreferenced grid")
+            .striped(true)
+            .num_columns(3)
+            .show(ui, |ui| {
+                for asset in &usage.unreferenced {
+                    ui.label(&asset.name).on_hover_text(
+                        "No strong handles to this asset are held by components of entities",
+                    );
+                    ui.weak(match asset.weak_handles {
+                        0 => asset.asset_type.clone(),
+                        weak => format!("{}, {weak} weak", asset.asset_type),
+                    });
+                    if ui
+                        .button("Remove")
+                        .on_hover_text("Remove the asset from its collection")
+                        .clicked()
+                    {
+                        action = Some(UsageAction::Remove(asset.id));
+                    }
+                    ui.end_row();
+                }
+            });
+    });
+
+    egui::CollapsingHeader::new(format!("Missing assets ({})", usage.missing.len()))
+        .id_source("asset usage missing")
+        .default_open(true)
+        .show(ui, |ui| {
+            egui::Grid::new("asset usage missing grid")
+                .striped(true)
+                .num_columns(3)
+                .show(ui, |ui| {
+                    for asset in &usage.missing {
+                        ui.horizontal(|ui| {
+                            assets_browser::load_state_indicator(ui, asset.load_state);
+                            ui.label(&asset.name);
+                        });
+                        ui.weak(&asset.asset_type);
+                        ui.horizontal_wrapped(|ui| {
+                            for &entity in asset.holders.iter().take(MAX_HOLDERS_SHOWN) {
+                                if ui
+                                    .small_button(guess_entity_name(world, entity))
+                                    .on_hover_text("Select the entity holding the handle")
+                                    .clicked()
+                                {
+                                    action = Some(UsageAction::Select(entity));
+                                }
+                            }
+                            if asset.holders.len() > MAX_HOLDERS_SHOWN {
+                                ui.weak(format!(
+                                    "and {} more",
+                                    asset.holders.len() - MAX_HOLDERS_SHOWN
+                                ));
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+
+    match action {
+        Some(UsageAction::Remove(id)) => {
+            if let Some(reflect_asset) = type_registry.get_type_data::<ReflectAsset>(id.type_id()) {
+                reflect_asset.remove(world, UntypedHandle::Weak(id));
+            }
+        }
+        Some(UsageAction::Select(entity)) if !selection::is_selected(world, entity) => {
+            selection::toggle_selected(world, entity);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{Asset, AssetApp, AssetId, AssetPlugin, Assets, Handle};
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+
+    use super::AssetUsage;
+
+    #[derive(Asset, Reflect, Default)]
+    struct Level;
+
+    #[test]
+    fn unreferenced_and_missing_assets_are_found() {
+        let mut app = App::new();
+        app.add_plugins((bevy_core::TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Level>()
+            .register_asset_reflect::<Level>();
+
+        let world = &mut app.world;
+        let mut levels = world.resource_mut::<Assets<Level>>();
+        let used = levels.add(Level);
+        let unused = AssetId::<Level>::from(bevy_utils::Uuid::from_u128(1));
+        levels.insert(unused, Level);
+        let missing = Handle::<Level>::weak_from_u128(2);
+        world.spawn(used.clone());
+        let holder = world.spawn(missing.clone()).id();
+
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let usage = AssetUsage::collect(world, &type_registry.read(), None);
+        let unreferenced: Vec<_> = usage.unreferenced.iter().map(|asset| asset.id).collect();
+        assert_eq!(unreferenced, [unused.untyped()]);
+        assert_eq!(usage.missing.len(), 1);
+        assert_eq!(usage.missing[0].id, missing.id().untyped());
+        assert_eq!(usage.missing[0].holders, [holder]);
+    }
+}
@@ -0,0 +1,209 @@
+use std::any::TypeId;
+
+use bevy_asset::{
+    AssetServer, LoadState, ReflectAsset, ReflectHandle, UntypedAssetId, UntypedHandle,
+};
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use bevy_utils::HashMap;
+
+use super::{by_type_id, dnd, handle_name};
+
+/// How many handles to an asset are held by components
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HandleCount {
+    pub strong: usize,
+    pub weak: usize,
+}
+
+/// Count the handles of every asset held by components of entities.
+///
+/// Handles stored elsewhere, like in resources or other assets, aren't counted.
+pub fn handle_counts(
+    world: &World,
+    type_registry: &TypeRegistry,
+) -> HashMap<UntypedAssetId, HandleCount> {
+    let mut counts = HashMap::<UntypedAssetId, HandleCount>::default();
+    for_each_component_handle(world, type_registry, |_, handle| {
+        let count = counts.entry(handle.id()).or_default();
+        match handle {
+            UntypedHandle::Strong(_) => count.strong += 1,
+            UntypedHandle::Weak(_) => count.weak += 1,
+        }
+    });
+    counts
+}
+
+/// Call `f` with every handle held by a reflected component and the entity holding it
+pub(super) fn for_each_component_handle(
+    world: &World,
+    type_registry: &TypeRegistry,
+    mut f: impl FnMut(Entity, UntypedHandle),
+) {
+    for info in world.components().iter() {
+        let Some(registration) = info
+            .type_id()
+            .and_then(|type_id| type_registry.get(type_id))
+        else {
+            continue;
+        };
+        let (Some(reflect_handle), Some(reflect_component)) = (
+            registration.data::<ReflectHandle>(),
+            registration.data::<ReflectComponent>(),
+        ) else {
+            continue;
+        };
+
+        let entities = world
+            .archetypes()
+            .iter()
+            .filter(|archetype| archetype.contains(info.id()))
+            .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()));
+        for entity in entities {
+            let Some(handle) = reflect_component
+                .reflect(world.entity(entity))
+                .and_then(|handle| reflect_handle.downcast_handle_untyped(handle.as_any()))
+            else {
+                continue;
+            };
+            f(entity, handle);
+        }
+    }
+}
+
+/// Display every asset of every registered asset type with its load state and handle counts, and edit it inline.
+///
+/// The assets can be searched by name using the text field at the top.
+pub fn ui_for_assets_browser(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let asset_server = world.get_resource::<AssetServer>().cloned();
+
+    let search_id = egui::Id::new("assets browser search");
+    let mut search =
+        ui.data_mut(|data| data.get_persisted::<String>(search_id).unwrap_or_default());
+    ui.add(egui::TextEdit::singleline(&mut search).hint_text("Search assets"));
+    ui.data_mut(|data| data.insert_persisted(search_id, search.clone()));
+    let search = search.to_lowercase();
+
+    let counts = handle_counts(world, &type_registry);
+
+    let mut asset_types: Vec<_> = type_registry
+        .iter()
+        .filter_map(|registration| {
+            let reflect_asset = registration.data::<ReflectAsset>()?;
+            Some((
+                registration.type_info().type_path_table().short_path(),
+                registration.type_id(),
+                reflect_asset.clone(),
+            ))
+        })
+        .collect();
+    asset_types.sort_by_key(|(name, ..)| *name);
+
+    for (name, type_id, reflect_asset) in asset_types {
+        let mut assets: Vec<_> = reflect_asset
+            .ids(world)
+            .map(|id| (handle_name(id, asset_server.as_ref()), id))
+            .filter(|(name, _)| search.is_empty() || name.to_lowercase().contains(&search))
+            .collect();
+        if assets.is_empty() && !search.is_empty() {
+            continue;
+        }
+        assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        egui::CollapsingHeader::new(format!("{name} ({})", assets.len()))
+            .id_source(search_id.with(type_id))
+            .show(ui, |ui| {
+                for (name, id) in assets {
+                    let count = counts.get(&id).copied().unwrap_or_default();
+                    ui_for_asset_entry(
+                        world,
+                        ui,
+                        type_id,
+                        id,
+                        &name,
+                        count,
+                        asset_server.as_ref(),
+                        &type_registry,
+                    );
+                }
+            });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn ui_for_asset_entry(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    asset_type_id: TypeId,
+    id: UntypedAssetId,
+    name: &str,
+    count: HandleCount,
+    asset_server: Option<&AssetServer>,
+    type_registry: &TypeRegistry,
+) {
+    let state = egui::collapsing_header::CollapsingState::load_with_default_open(
+        ui.ctx(),
+        ui.make_persistent_id(id),
+        false,
+    );
+    state
+        .show_header(ui, |ui| {
+            load_state_indicator(
+                ui,
+                asset_server.and_then(|server| server.get_load_state(id)),
+            );
+            dnd::asset_drag_source(ui, id, asset_server, |ui| ui.label(name));
+            ui.weak(format!("{} strong, {} weak", count.strong, count.weak))
+                .on_hover_text("Handles held by components of entities");
+        })
+        .body(|ui| {
+            by_type_id::ui_for_asset(world, asset_type_id, id, ui, type_registry);
+        });
+}
+
+pub(super) fn load_state_indicator(ui: &mut egui::Ui, load_state: Option<LoadState>) {
+    let (color, text) = match load_state {
+        Some(LoadState::Loaded) => (egui::Color32::from_rgb(80, 200, 120), "Loaded"),
+        Some(LoadState::Loading) => (egui::Color32::from_rgb(230, 180, 60), "Loading"),
+        Some(LoadState::Failed) => (ui.visuals().error_fg_color, "Failed to load"),
+        Some(LoadState::NotLoaded) => (egui::Color32::GRAY, "Not loaded"),
+        None => (egui::Color32::GRAY, "Not loaded from a file"),
+    };
+    ui.colored_label(color, "●").on_hover_text(text);
+}
+
+#[cfg(all(test, feature = "bevy_render"))]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{AssetApp, AssetPlugin, Assets, Handle};
+    use bevy_ecs::prelude::*;
+    use bevy_render::mesh::Mesh;
+
+    use super::{handle_counts, HandleCount};
+
+    #[test]
+    fn counts_handles_in_components() {
+        let mut app = App::new();
+        app.add_plugins((bevy_core::TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .register_asset_reflect::<Mesh>();
+
+        let world = &mut app.world;
+        let handle = world.resource_mut::<Assets<Mesh>>().add(Mesh::new(
+            bevy_render::mesh::PrimitiveTopology::TriangleList,
+            Default::default(),
+        ));
+        world.spawn(handle.clone());
+        world.spawn(handle.clone_weak());
+        world.spawn(Handle::<Mesh>::default());
+
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let counts = handle_counts(world, &type_registry.read());
+        assert_eq!(
+            counts[&handle.id().untyped()],
+            HandleCount { strong: 1, weak: 1 }
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::{io::Cursor, time::Duration};
+
+use bevy_asset::{Assets, Handle};
+use bevy_audio::{
+    AudioBundle, AudioSink, AudioSinkPlayback, AudioSource, PlaybackSettings, Source,
+    SpatialAudioSink,
+};
+use bevy_ecs::{entity::Entity, system::CommandQueue};
+
+use super::commands;
+use crate::restricted_world_view::RestrictedWorldView;
+
+/// The length of `source`, if its format is supported and stores it
+pub fn duration(source: &AudioSource) -> Option<Duration> {
+    // `Decodable::decoder` panics for formats whose cargo feature isn't enabled
+    rodio::Decoder::new(Cursor::new(source.clone()))
+        .ok()?
+        .total_duration()
+}
+
+/// Display the [`duration`] of the source of `handle`, computed once per source, and a button playing it once.
+///
+/// The button is disabled if there is no `queue` or the source isn't loaded yet.
+pub(crate) fn ui_for_audio_source(
+    ui: &mut egui::Ui,
+    world: Option<&mut RestrictedWorldView<'_>>,
+    queue: Option<&mut CommandQueue>,
+    handle: &Handle<AudioSource>,
+) {
+    let sources = world.and_then(|world| world.get_resource_mut::<Assets<AudioSource>>().ok());
+    let source = sources.as_ref().and_then(|sources| sources.get(handle));
+
+    let id = egui::Id::new(("audio duration", handle.id()));
+    let duration = match ui.data(|data| data.get_temp::<Option<Duration>>(id)) {
+        Some(duration) => duration,
+        None => {
+            let duration = source.and_then(duration);
+            if source.is_some() {
+                ui.data_mut(|data| data.insert_temp(id, duration));
+            }
+            duration
+        }
+    };
+
+    ui.horizontal(|ui| {
+        let can_play = source.is_some() && queue.is_some();
+        if ui
+            .add_enabled(can_play, egui::Button::new("▶ Preview"))
+            .on_hover_text("Play the sound once")
+            .clicked()
+        {
+            if let Some(queue) = queue {
+                let source = handle.clone();
+                commands::push_deferred(queue, "Preview audio", None, move |world| {
+                    world.spawn(AudioBundle {
+                        source,
+                        settings: PlaybackSettings::DESPAWN,
+                    });
+                });
+            }
+        }
+        match duration {
+            Some(duration) => ui.label(format!("{:.2}s", duration.as_secs_f32())),
+            None if source.is_none() => ui.label("not loaded"),
+            None => ui.label("unknown duration"),
+        };
+    });
+}
+
+/// Display pause, stop, volume and speed controls for the [`AudioSink`] or [`SpatialAudioSink`] of `entity`, if it has one.
+///
+/// The sinks are created by bevy once the sound starts playing.
+pub(crate) fn ui_for_audio_sink(
+    ui: &mut egui::Ui,
+    world: &RestrictedWorldView<'_>,
+    entity: Entity,
+) {
+    if let Ok(sink) = world.get_entity_component::<AudioSink>(entity) {
+        sink_controls(ui, sink);
+    } else if let Ok(sink) = world.get_entity_component::<SpatialAudioSink>(entity) {
+        sink_controls(ui, sink);
+    }
+}
+
+fn sink_controls(ui: &mut egui::Ui, sink: &impl AudioSinkPlayback) {
+    egui::Grid::new("audio sink").num_columns(2).show(ui, |ui| {
+        ui.label("playback");
+        ui.horizontal(|ui| {
+            let label = match sink.is_paused() {
+                true => "▶ Play",
+                false => "⏸ Pause",
+            };
+            if ui
+                .add_enabled(!sink.empty(), egui::Button::new(label))
+                .clicked()
+            {
+                sink.toggle();
+            }
+            if ui
+                .add_enabled(!sink.empty(), egui::Button::new("⏹ Stop"))
+                .clicked()
+            {
+                sink.stop();
+            }
+        });
+        ui.end_row();
+
+        ui.label("volume");
+        let mut volume = sink.volume();
+        if ui.add(egui::Slider::new(&mut volume, 0.0..=2.0)).changed() {
+            sink.set_volume(volume);
+        }
+        ui.end_row();
+
+        ui.label("speed");
+        let mut speed = sink.speed();
+        if ui
+            .add(
+                egui::DragValue::new(&mut speed)
+                    .speed(0.01)
+                    .clamp_range(0.01..=8.0),
+            )
+            .changed()
+        {
+            sink.set_speed(speed);
+        }
+        ui.end_row();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_audio::AudioSource;
+
+    use super::duration;
+
+    #[test]
+    fn unsupported_sources_have_no_duration() {
+        let source = AudioSource {
+            bytes: b"RIFF not really a wave file".as_slice().into(),
+        };
+        assert_eq!(duration(&source), None);
+    }
+}
@@ -0,0 +1,252 @@
+use bevy_ecs::prelude::*;
+use bevy_math::{Rect, Vec2, Vec3, Vec3A};
+use bevy_render::{camera::Camera, primitives::Aabb, view::InheritedVisibility};
+use bevy_transform::components::GlobalTransform;
+
+use super::hierarchy::SelectedEntities;
+use crate::events::{self, InspectorEvent};
+
+/// Marquee selection of all entities whose bounds intersect a rectangle dragged over a camera's viewport.
+///
+/// Sprites and meshes both get an [`Aabb`] computed by bevy, so this works for 2D and 3D scenes alike,
+/// but is most useful with orthographic cameras.
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_inspector_egui::bevy_inspector::{box_select::BoxSelect, hierarchy::SelectedEntities};
+/// fn viewport_ui(
+///     world: &mut World,
+///     ui: &mut egui::Ui,
+///     camera: Entity,
+///     box_select: &mut BoxSelect,
+///     selected: &mut SelectedEntities,
+/// ) {
+///     // the response needs to cover the viewport the camera renders to
+///     let response = ui.allocate_rect(ui.clip_rect(), egui::Sense::drag());
+///     box_select.show(ui, &response, world, camera, selected);
+/// }
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct BoxSelect {
+    drag_start: Option<egui::Pos2>,
+}
+
+impl BoxSelect {
+    /// Draw the rectangle while `response` is being dragged and update the selection when the drag stops.
+    ///
+    /// Holding `Ctrl` or `Shift` adds to the existing selection instead of replacing it.
+    /// Returns `true` if the selection was changed.
+    pub fn show(
+        &mut self,
+        ui: &egui::Ui,
+        response: &egui::Response,
+        world: &mut World,
+        camera: Entity,
+        selected: &mut SelectedEntities,
+    ) -> bool {
+        if response.drag_started() {
+            self.drag_start = response.interact_pointer_pos();
+        }
+        let (Some(start), Some(current)) = (self.drag_start, ui.ctx().pointer_latest_pos()) else {
+            return false;
+        };
+        let rect = egui::Rect::from_two_pos(start, current);
+
+        if !response.drag_stopped() {
+            let stroke = ui.visuals().selection.stroke;
+            let fill = ui.visuals().selection.bg_fill.gamma_multiply(0.25);
+            ui.painter().rect(rect, 0.0, fill, stroke);
+            return false;
+        }
+        self.drag_start = None;
+
+        let Some(viewport_rect) = viewport_rect(world, camera, response.rect, rect) else {
+            return false;
+        };
+        let entities = entities_in_viewport_rect(world, camera, viewport_rect);
+
+        let add = ui.input(|input| input.modifiers.command || input.modifiers.shift);
+        for entity in update_selection(selected, entities, add) {
+            events::send(world, InspectorEvent::EntitySelected { entity });
+        }
+        true
+    }
+}
+
+/// Select `entities`, in addition to the current selection if `add` is set, and return the ones which weren't selected before
+fn update_selection(
+    selected: &mut SelectedEntities,
+    entities: Vec<Entity>,
+    add: bool,
+) -> Vec<Entity> {
+    let newly_selected = entities
+        .iter()
+        .copied()
+        .filter(|&entity| !selected.contains(entity))
+        .collect();
+    if !add {
+        selected.clear();
+    }
+    selected.extend(entities);
+    newly_selected
+}
+
+/// Map `rect` inside of `ui_rect`, which covers the camera's viewport, to logical viewport coordinates
+fn viewport_rect(
+    world: &World,
+    camera: Entity,
+    ui_rect: egui::Rect,
+    rect: egui::Rect,
+) -> Option<Rect> {
+    let viewport_size = world.get::<Camera>(camera)?.logical_viewport_size()?;
+    let scale = viewport_size / Vec2::new(ui_rect.width(), ui_rect.height());
+    let to_viewport = |pos: egui::Pos2| {
+        let relative = pos - ui_rect.min;
+        Vec2::new(relative.x, relative.y) * scale
+    };
+    Some(Rect::from_corners(
+        to_viewport(rect.min),
+        to_viewport(rect.max),
+    ))
+}
+
+/// All visible entities with an [`Aabb`] whose projection onto the viewport of `camera` intersects `rect`.
+///
+/// `rect` is in logical viewport coordinates, with the origin at the top left.
+pub fn entities_in_viewport_rect(world: &mut World, camera: Entity, rect: Rect) -> Vec<Entity> {
+    let Some((camera, camera_transform)) = world
+        .query::<(&Camera, &GlobalTransform)>()
+        .get(world, camera)
+        .ok()
+        .map(|(camera, transform)| (camera.clone(), *transform))
+    else {
+        return Vec::new();
+    };
+
+    world
+        .query::<(
+            Entity,
+            &Aabb,
+            &GlobalTransform,
+            Option<&InheritedVisibility>,
+        )>()
+        .iter(world)
+        .filter(|(.., visibility)| visibility.map_or(true, |visibility| visibility.get()))
+        .filter(|(_, aabb, transform, _)| {
+            let mut bounds = Rect {
+                min: Vec2::MAX,
+                max: Vec2::MIN,
+            };
+            for corner in aabb_corners(aabb) {
+                let world_pos = transform.transform_point(Vec3::from(corner));
+                let Some(pos) = camera.world_to_viewport(&camera_transform, world_pos) else {
+                    return false;
+                };
+                bounds = bounds.union_point(pos);
+            }
+            !bounds.intersect(rect).is_empty()
+        })
+        .map(|(entity, ..)| entity)
+        .collect()
+}
+
+fn aabb_corners(aabb: &Aabb) -> [Vec3A; 8] {
+    let (min, max) = (aabb.min(), aabb.max());
+    [
+        Vec3A::new(min.x, min.y, min.z),
+        Vec3A::new(max.x, min.y, min.z),
+        Vec3A::new(min.x, max.y, min.z),
+        Vec3A::new(max.x, max.y, min.z),
+        Vec3A::new(min.x, min.y, max.z),
+        Vec3A::new(max.x, min.y, max.z),
+        Vec3A::new(min.x, max.y, max.z),
+        Vec3A::new(max.x, max.y, max.z),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_ecs::{prelude::*, system::RunSystemOnce};
+    use bevy_math::{Rect, Vec2, Vec3};
+    use bevy_render::{
+        camera::{camera_system, Camera, ManualTextureViews, OrthographicProjection},
+        primitives::Aabb,
+        texture::Image,
+        view::InheritedVisibility,
+    };
+    use bevy_transform::components::GlobalTransform;
+    use bevy_window::{
+        PrimaryWindow, Window, WindowCreated, WindowResized, WindowScaleFactorChanged,
+    };
+
+    use super::{entities_in_viewport_rect, update_selection};
+    use crate::bevy_inspector::hierarchy::SelectedEntities;
+
+    fn spawn_box(world: &mut World, x: f32) -> Entity {
+        world
+            .spawn((
+                Aabb::from_min_max(Vec3::splat(-5.0), Vec3::splat(5.0)),
+                GlobalTransform::from_xyz(x, 0.0, 0.0),
+                InheritedVisibility::VISIBLE,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn entities_inside_the_rect_are_found() {
+        let mut world = World::new();
+        world.init_resource::<Events<WindowCreated>>();
+        world.init_resource::<Events<WindowResized>>();
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<ManualTextureViews>();
+        world.spawn((
+            Window {
+                resolution: (200.0, 100.0).into(),
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ));
+        let camera = world
+            .spawn((
+                Camera::default(),
+                OrthographicProjection::default(),
+                GlobalTransform::from_xyz(0.0, 0.0, 100.0),
+            ))
+            .id();
+        world.run_system_once(camera_system::<OrthographicProjection>);
+
+        // one world unit is one logical pixel, and the world origin is at the center of the viewport
+        let left = spawn_box(&mut world, -50.0);
+        let right = spawn_box(&mut world, 50.0);
+        let hidden = spawn_box(&mut world, -50.0);
+        world.entity_mut(hidden).insert(InheritedVisibility::HIDDEN);
+
+        let left_half = Rect::from_corners(Vec2::ZERO, Vec2::new(100.0, 100.0));
+        assert_eq!(
+            entities_in_viewport_rect(&mut world, camera, left_half),
+            [left]
+        );
+        let touching_both = Rect::from_corners(Vec2::new(54.0, 40.0), Vec2::new(146.0, 60.0));
+        let mut found = entities_in_viewport_rect(&mut world, camera, touching_both);
+        found.sort();
+        assert_eq!(found, [left, right]);
+        let between = Rect::from_corners(Vec2::new(60.0, 0.0), Vec2::new(140.0, 100.0));
+        assert!(entities_in_viewport_rect(&mut world, camera, between).is_empty());
+    }
+
+    #[test]
+    fn only_newly_selected_entities_are_reported() {
+        let [a, b, c] = [1, 2, 3].map(Entity::from_raw);
+        let mut selected = SelectedEntities::default();
+        selected.extend([a, b]);
+
+        assert_eq!(update_selection(&mut selected, vec![b, c], true), [c]);
+        assert_eq!(selected.as_slice(), [a, b, c]);
+
+        assert_eq!(update_selection(&mut selected, vec![c], false), []);
+        assert_eq!(selected.as_slice(), [c]);
+    }
+}
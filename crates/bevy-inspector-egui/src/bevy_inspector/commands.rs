@@ -0,0 +1,165 @@
+use bevy_ecs::{prelude::*, system::CommandQueue};
+
+use crate::events::{self, InspectorEvent};
+
+type CommandFn = dyn FnOnce(&mut World) + Send + Sync;
+
+/// A world mutation requested from the inspector UI, like despawning an entity or pasting a component
+pub struct InspectorCommand {
+    name: String,
+    entity: Option<Entity>,
+    command: Box<CommandFn>,
+}
+
+impl InspectorCommand {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The entity the command targets, if any
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
+
+impl std::fmt::Debug for InspectorCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InspectorCommand")
+            .field("name", &self.name)
+            .field("entity", &self.entity)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Structural changes made in the inspector, which are applied together in the [`Last`](bevy_app::Last) schedule
+/// instead of in the middle of drawing the UI.
+///
+/// An [`InspectorEvent::CommandApplied`] is sent for every applied command,
+/// so that e.g. undo history or macro recording can follow along.
+#[derive(Resource, Default, Debug)]
+pub struct InspectorCommands {
+    pending: Vec<InspectorCommand>,
+}
+
+impl InspectorCommands {
+    /// Queue `command`, described by `name` and the `entity` it targets
+    pub fn push(
+        &mut self,
+        name: impl Into<String>,
+        entity: Option<Entity>,
+        command: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) {
+        self.pending.push(InspectorCommand {
+            name: name.into(),
+            entity,
+            command: Box::new(command),
+        });
+    }
+
+    /// The commands which will be applied next, in order
+    pub fn pending(&self) -> &[InspectorCommand] {
+        &self.pending
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// Queue `command` in the [`InspectorCommands`], or apply it right away if that resource doesn't exist
+pub fn push(
+    world: &mut World,
+    name: impl Into<String>,
+    entity: Option<Entity>,
+    command: impl FnOnce(&mut World) + Send + Sync + 'static,
+) {
+    match world.get_resource_mut::<InspectorCommands>() {
+        Some(mut commands) => commands.push(name, entity, command),
+        None => apply(
+            world,
+            InspectorCommand {
+                name: name.into(),
+                entity,
+                command: Box::new(command),
+            },
+        ),
+    }
+}
+
+/// [`push`] `command` once `queue` is applied
+pub(crate) fn push_deferred(
+    queue: &mut CommandQueue,
+    name: impl Into<String>,
+    entity: Option<Entity>,
+    command: impl FnOnce(&mut World) + Send + Sync + 'static,
+) {
+    let name = name.into();
+    queue.push(move |world: &mut World| push(world, name, entity, command));
+}
+
+/// Apply all pending [`InspectorCommands`] in order
+pub fn apply_inspector_commands(world: &mut World) {
+    let Some(mut commands) = world.get_resource_mut::<InspectorCommands>() else {
+        return;
+    };
+    if commands.is_empty() {
+        return;
+    }
+    let pending = std::mem::take(&mut commands.pending);
+    for command in pending {
+        apply(world, command);
+    }
+}
+
+fn apply(world: &mut World, command: InspectorCommand) {
+    let InspectorCommand {
+        name,
+        entity,
+        command,
+    } = command;
+    command(world);
+    events::send(world, InspectorEvent::CommandApplied { name, entity });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{event::Events, world::World};
+
+    use super::{apply_inspector_commands, push, InspectorCommands};
+    use crate::events::InspectorEvent;
+
+    #[test]
+    fn commands_are_applied_in_batch() {
+        let mut world = World::new();
+        world.init_resource::<Events<InspectorEvent>>();
+        world.init_resource::<InspectorCommands>();
+        let entity = world.spawn_empty().id();
+
+        push(&mut world, "Despawn", Some(entity), move |world| {
+            world.despawn(entity);
+        });
+        assert!(world.get_entity(entity).is_some());
+        assert_eq!(
+            world.resource::<InspectorCommands>().pending()[0].name(),
+            "Despawn"
+        );
+
+        apply_inspector_commands(&mut world);
+        assert!(world.get_entity(entity).is_none());
+        assert!(world.resource::<InspectorCommands>().is_empty());
+
+        let events = world.resource::<Events<InspectorEvent>>();
+        let sent: Vec<_> = events.get_reader().read(events).cloned().collect();
+        assert_eq!(
+            sent,
+            [InspectorEvent::CommandApplied {
+                name: "Despawn".to_owned(),
+                entity: Some(entity),
+            }]
+        );
+    }
+}
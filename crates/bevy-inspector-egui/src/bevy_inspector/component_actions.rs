@@ -0,0 +1,140 @@
+use std::{any::TypeId, sync::Arc};
+
+use bevy_ecs::{prelude::*, system::CommandQueue, world::EntityWorldMut};
+use bevy_utils::HashMap;
+
+use super::commands;
+
+type ActionFn = dyn Fn(EntityWorldMut) + Send + Sync;
+
+/// A named action on entities with a specific component, see [`ComponentActions`]
+#[derive(Clone)]
+pub struct ComponentAction {
+    name: String,
+    action: Arc<ActionFn>,
+}
+
+impl ComponentAction {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn run(&self, entity: EntityWorldMut) {
+        (self.action)(entity)
+    }
+}
+
+/// Project-specific actions shown in the context menu of component headers in the world inspector.
+///
+/// Actions are run with exclusive world access as [`InspectorCommands`](super::commands::InspectorCommands).
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_transform::components::Transform;
+/// use bevy_inspector_egui::bevy_inspector::component_actions::ComponentActions;
+///
+/// fn setup(mut actions: ResMut<ComponentActions>) {
+///     actions.add_for_component::<Transform>("Recenter", |transform| {
+///         transform.translation = Default::default();
+///     });
+///     actions.add::<Transform>("Despawn", |entity| entity.despawn());
+/// }
+/// ```
+#[derive(Resource, Default, Clone)]
+pub struct ComponentActions {
+    actions: HashMap<TypeId, Vec<ComponentAction>>,
+}
+
+impl ComponentActions {
+    /// Add an action for entities with the component `C`, which receives the entity
+    pub fn add<C: Component>(
+        &mut self,
+        name: impl Into<String>,
+        action: impl Fn(EntityWorldMut) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.actions
+            .entry(TypeId::of::<C>())
+            .or_default()
+            .push(ComponentAction {
+                name: name.into(),
+                action: Arc::new(action),
+            });
+        self
+    }
+
+    /// Add an action for entities with the component `C`, which receives the component
+    pub fn add_for_component<C: Component>(
+        &mut self,
+        name: impl Into<String>,
+        action: impl Fn(&mut C) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.add::<C>(name, move |mut entity| {
+            if let Some(mut component) = entity.get_mut::<C>() {
+                action(&mut component);
+            }
+        })
+    }
+
+    /// The actions for the component with the type id `type_id`
+    pub fn actions(&self, type_id: TypeId) -> &[ComponentAction] {
+        self.actions.get(&type_id).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Display buttons for `actions`, which are pushed to `queue` when clicked.
+///
+/// The buttons are disabled if there is no `queue`.
+pub(crate) fn ui_for_component_actions(
+    ui: &mut egui::Ui,
+    actions: &[ComponentAction],
+    entity: Entity,
+    queue: Option<&mut CommandQueue>,
+) {
+    if actions.is_empty() {
+        return;
+    }
+    ui.separator();
+    ui.add_enabled_ui(queue.is_some(), |ui| {
+        let mut clicked = None;
+        for action in actions {
+            if ui.button(action.name()).clicked() {
+                clicked = Some(action.clone());
+            }
+        }
+        if let (Some(action), Some(queue)) = (clicked, queue) {
+            let name = action.name().to_owned();
+            commands::push_deferred(queue, name, Some(entity), move |world| {
+                if let Some(entity) = world.get_entity_mut(entity) {
+                    action.run(entity);
+                }
+            });
+            ui.close_menu();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{prelude::*, system::CommandQueue};
+
+    use super::ComponentActions;
+
+    #[derive(Component)]
+    struct Counter(u32);
+
+    #[test]
+    fn actions_run_on_entity() {
+        let mut actions = ComponentActions::default();
+        actions.add_for_component::<Counter>("Increment", |counter| counter.0 += 1);
+
+        let mut world = World::new();
+        let entity = world.spawn(Counter(0)).id();
+
+        let action = actions.actions(std::any::TypeId::of::<Counter>())[0].clone();
+        assert_eq!(action.name(), "Increment");
+
+        let mut queue = CommandQueue::default();
+        queue.push(move |world: &mut World| action.run(world.entity_mut(entity)));
+        queue.apply(&mut world);
+        assert_eq!(world.get::<Counter>(entity).unwrap().0, 1);
+    }
+}
@@ -0,0 +1,339 @@
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use bevy_reflect::{serde::TypedReflectDeserializer, GetPath, Reflect, TypeRegistry};
+use serde::de::DeserializeSeed;
+
+use super::locks;
+use crate::events::{self, InspectorEvent};
+
+const USAGE: &str = "expected `Resource.field`, `entity.Component.field` or `path = value`";
+
+/// A command entered into the console and its result
+#[derive(Debug, Clone)]
+pub struct ConsoleEntry {
+    pub command: String,
+    pub output: Result<String, String>,
+}
+
+/// Commands run in the console window, see [`execute`]
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ConsoleHistory {
+    entries: Vec<ConsoleEntry>,
+}
+
+impl ConsoleHistory {
+    pub fn entries(&self) -> &[ConsoleEntry] {
+        &self.entries
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// [`execute`] the `command` and add it to the history
+    pub fn run(&mut self, world: &mut World, command: &str) -> &ConsoleEntry {
+        let output = execute(world, command);
+        self.entries.push(ConsoleEntry {
+            command: command.to_owned(),
+            output,
+        });
+        self.entries.last().unwrap()
+    }
+}
+
+/// The value a command refers to
+enum Target {
+    Resource(TypeIdPath),
+    Component(Entity, TypeIdPath),
+}
+
+/// A registered type together with its type path, for looking up locks
+struct TypeIdPath {
+    type_id: std::any::TypeId,
+    type_path: &'static str,
+}
+
+/// Read or assign a reflected value by its path.
+///
+/// The path starts with either the short type name of a resource, or the [`Name`] of an entity followed by the short type
+/// name of one of its components. Names containing dots or spaces can be quoted, and entities without names are referred
+/// to like they are displayed, e.g. `3v1`. The rest of the path is a [reflect path](bevy_reflect::GetPath) into the value:
+///
+/// ```text
+/// Time.delta
+/// player.Transform.translation.x = 5.0
+/// "Main Camera".Projection = Perspective((fov: 1.2, near: 0.1, far: 1000.0, aspect_ratio: 1.0))
+/// ```
+///
+/// Assigned values are written in [RON](https://docs.rs/ron), using the same type registrations as the inspector.
+/// Returns the debug representation of the value after the command, or why it failed.
+pub fn execute(world: &mut World, command: &str) -> Result<String, String> {
+    let (path, value) = match command.split_once('=') {
+        Some((path, value)) => (path.trim(), Some(value.trim())),
+        None => (command.trim(), None),
+    };
+    let (name, rest) = split_first(path)?;
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let (target, field_path) = match find_resource(world, &type_registry, name) {
+        Some(resource) => (Target::Resource(resource), rest),
+        None => {
+            let entity = find_entity(world, name)?;
+            let (component, field_path) = split_first(rest)?;
+            let component = find_component(world, &type_registry, entity, component)?;
+            (Target::Component(entity, component), field_path)
+        }
+    };
+
+    let current = match &target {
+        Target::Resource(resource) => type_registry
+            .get_type_data::<ReflectResource>(resource.type_id)
+            .and_then(|reflect_resource| reflect_resource.reflect(world)),
+        Target::Component(entity, component) => type_registry
+            .get_type_data::<ReflectComponent>(component.type_id)
+            .and_then(|reflect_component| reflect_component.reflect(world.entity(*entity))),
+    }
+    .ok_or("value does not exist")?;
+    let field = current
+        .reflect_path(field_path)
+        .map_err(|error| error.to_string())?;
+    let Some(value) = value else {
+        return Ok(format!("{field:?}"));
+    };
+
+    let (type_path, entity) = match &target {
+        Target::Resource(resource) => (resource.type_path, None),
+        Target::Component(entity, component) => (component.type_path, Some(*entity)),
+    };
+    if let Some(reason) = locks::path_lock_reason(world, type_path) {
+        return Err(format!("{type_path} is locked: {reason}"));
+    }
+    // parsed before borrowing the value mutably, so that it is only marked as changed if the command succeeds
+    let value = parse(field, value, &type_registry)?;
+
+    let output = match target {
+        Target::Resource(resource) => {
+            let reflect_resource = type_registry
+                .get_type_data::<ReflectResource>(resource.type_id)
+                .unwrap();
+            let mut current = reflect_resource.reflect_mut(world).unwrap();
+            apply(&mut *current, field_path, value.as_ref())
+        }
+        Target::Component(entity, component) => {
+            let reflect_component = type_registry
+                .get_type_data::<ReflectComponent>(component.type_id)
+                .unwrap();
+            let mut entity_mut = world.entity_mut(entity);
+            let mut current = reflect_component.reflect_mut(&mut entity_mut).unwrap();
+            apply(&mut *current, field_path, value.as_ref())
+        }
+    };
+
+    let path = type_path.to_owned();
+    events::send(world, InspectorEvent::ValueChanged { entity, path });
+    Ok(output)
+}
+
+/// Apply `value` to the field at the already checked `path` of `current`, returning the new field value
+fn apply(current: &mut dyn Reflect, path: &str, value: &dyn Reflect) -> String {
+    let field = current.reflect_path_mut(path).unwrap();
+    field.apply(value);
+    format!("{field:?}")
+}
+
+/// Split off the first segment of `path`, which may be quoted
+fn split_first(path: &str) -> Result<(&str, &str), String> {
+    let (first, rest) = match path.strip_prefix('"') {
+        Some(quoted) => quoted
+            .split_once('"')
+            .ok_or_else(|| "missing closing quote".to_owned())?,
+        None => {
+            let end = path.find(['.', '[']).unwrap_or(path.len());
+            path.split_at(end)
+        }
+    };
+    if first.is_empty() {
+        return Err(USAGE.to_owned());
+    }
+    Ok((first, rest.strip_prefix('.').unwrap_or(rest)))
+}
+
+fn find_resource(world: &World, type_registry: &TypeRegistry, name: &str) -> Option<TypeIdPath> {
+    type_registry.iter().find_map(|registration| {
+        let table = registration.type_info().type_path_table();
+        let matches = table.short_path() == name || table.path() == name;
+        let reflect_resource = registration.data::<ReflectResource>()?;
+        (matches && reflect_resource.reflect(world).is_some()).then(|| TypeIdPath {
+            type_id: registration.type_id(),
+            type_path: table.path(),
+        })
+    })
+}
+
+fn find_entity(world: &mut World, name: &str) -> Result<Entity, String> {
+    let named = world
+        .query::<(Entity, &Name)>()
+        .iter(world)
+        .find(|(_, entity_name)| entity_name.as_str() == name)
+        .map(|(entity, _)| entity);
+    named
+        .or_else(|| {
+            // entities without names are displayed like `3v1`
+            world
+                .iter_entities()
+                .map(|entity| entity.id())
+                .find(|entity| format!("{entity:?}") == name)
+        })
+        .ok_or_else(|| format!("no resource or entity named `{name}`"))
+}
+
+fn find_component(
+    world: &World,
+    type_registry: &TypeRegistry,
+    entity: Entity,
+    name: &str,
+) -> Result<TypeIdPath, String> {
+    world
+        .entity(entity)
+        .archetype()
+        .components()
+        .filter_map(|component_id| world.components().get_info(component_id)?.type_id())
+        .filter_map(|type_id| type_registry.get(type_id))
+        .filter(|registration| registration.data::<ReflectComponent>().is_some())
+        .find_map(|registration| {
+            let table = registration.type_info().type_path_table();
+            (table.short_path() == name || table.path() == name).then(|| TypeIdPath {
+                type_id: registration.type_id(),
+                type_path: table.path(),
+            })
+        })
+        .ok_or_else(|| format!("{entity:?} has no reflected component `{name}`"))
+}
+
+/// Parse `text` as RON into the type of `field`
+fn parse(
+    field: &dyn Reflect,
+    text: &str,
+    type_registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, String> {
+    let registration = field
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get(info.type_id()))
+        .ok_or_else(|| format!("{} is not registered", field.reflect_type_path()))?;
+    let mut deserializer =
+        ron::de::Deserializer::from_str(text).map_err(|error| error.to_string())?;
+    let value = TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|error| deserializer.span_error(error).to_string())?;
+    Ok(value)
+}
+
+fn input_id() -> egui::Id {
+    egui::Id::new("inspector console input")
+}
+
+/// Display the [`ConsoleHistory`] and a line for entering commands, see [`execute`].
+///
+/// Pressing `Up` in the empty line recalls the last command.
+pub fn ui_for_console(world: &mut World, ui: &mut egui::Ui) {
+    world.init_resource::<ConsoleHistory>();
+
+    world.resource_scope(|world, mut history: Mut<ConsoleHistory>| {
+        let history = history.bypass_change_detection();
+        let error_color = ui.visuals().error_fg_color;
+
+        egui::ScrollArea::vertical()
+            .max_height(ui.available_height() - ui.spacing().interact_size.y * 2.0)
+            .stick_to_bottom(true)
+            .show(ui, |ui| {
+                for entry in history.entries() {
+                    ui.monospace(format!("> {}", entry.command));
+                    match &entry.output {
+                        Ok(output) => ui.monospace(output),
+                        Err(error) => ui.colored_label(error_color, error),
+                    };
+                }
+            });
+
+        let mut input = ui.data_mut(|data| data.get_temp::<String>(input_id()).unwrap_or_default());
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut input)
+                .font(egui::TextStyle::Monospace)
+                .hint_text("player.Transform.translation.x = 5.0")
+                .desired_width(f32::INFINITY),
+        );
+        if response.has_focus()
+            && input.is_empty()
+            && ui.input(|i| i.key_pressed(egui::Key::ArrowUp))
+        {
+            if let Some(last) = history.entries().last() {
+                input = last.command.clone();
+            }
+        }
+        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+            if !input.trim().is_empty() {
+                history.run(world, &input);
+            }
+            input.clear();
+            response.request_focus();
+        }
+        ui.data_mut(|data| data.insert_temp(input_id(), input));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+
+    use super::execute;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        current: f32,
+        regeneration: Option<f32>,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Gravity(f32);
+
+    #[test]
+    fn commands_read_and_assign_paths() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Health>();
+            type_registry.register::<Gravity>();
+            type_registry.register::<f32>();
+            type_registry.register::<Option<f32>>();
+        }
+        world.init_resource::<Gravity>();
+        let player = world
+            .spawn((Name::new("the player"), Health::default()))
+            .id();
+
+        assert_eq!(
+            execute(&mut world, "\"the player\".Health.current = 5"),
+            Ok("5.0".to_owned())
+        );
+        assert_eq!(world.get::<Health>(player).unwrap().current, 5.0);
+        execute(&mut world, "\"the player\".Health.regeneration = Some(0.5)").unwrap();
+        assert_eq!(world.get::<Health>(player).unwrap().regeneration, Some(0.5));
+
+        execute(&mut world, "Gravity.0 = -9.81").unwrap();
+        assert_eq!(execute(&mut world, "Gravity.0"), Ok("-9.81".to_owned()));
+        assert_eq!(
+            execute(&mut world, &format!("{player:?}.Health.current")),
+            Ok("5.0".to_owned())
+        );
+
+        assert!(execute(&mut world, "enemy.Health.current").is_err());
+        assert!(execute(&mut world, "\"the player\".Health.current = \"five\"").is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Dragging assets from the [assets browser](super::assets_browser) onto handle fields.
+//!
+//! The payload is stored by egui's drag-and-drop support in the [`Context`](egui::Context), so assets can be dragged
+//! between all inspector windows of the same context. Any widget can be made a drag source with [`asset_drag_source`]
+//! or a drop target with [`dropped_asset`].
+
+use std::any::TypeId;
+
+use bevy_asset::{Asset, AssetServer, Handle, UntypedAssetId, UntypedHandle};
+
+/// An asset being dragged, which can be dropped onto handles of its asset type
+#[derive(Debug, Clone)]
+pub struct AssetPayload {
+    pub handle: UntypedHandle,
+}
+
+impl AssetPayload {
+    /// The payload for the asset `id`.
+    ///
+    /// Dropping it assigns a strong handle if the asset is managed by the `asset_server`, and a weak handle otherwise,
+    /// so assets added to `Assets<A>` directly stay alive only as long as some other strong handle does.
+    pub fn new(id: UntypedAssetId, asset_server: Option<&AssetServer>) -> Self {
+        let handle = asset_server
+            .and_then(|asset_server| asset_server.get_id_handle_untyped(id))
+            .unwrap_or(UntypedHandle::Weak(id));
+        AssetPayload { handle }
+    }
+
+    pub fn asset_type_id(&self) -> TypeId {
+        self.handle.type_id()
+    }
+}
+
+/// Make the widgets of `add_contents` draggable, carrying an [`AssetPayload`] for the asset `id`
+pub fn asset_drag_source<R>(
+    ui: &mut egui::Ui,
+    id: UntypedAssetId,
+    asset_server: Option<&AssetServer>,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    let payload = AssetPayload::new(id, asset_server);
+    let mut inner = ui.dnd_drag_source(
+        egui::Id::new(("asset drag source", id)),
+        payload,
+        add_contents,
+    );
+    inner.response = inner
+        .response
+        .on_hover_text("Drag onto a handle field to assign this asset");
+    inner
+}
+
+/// Highlight `response` while an asset of the type `asset_type_id` is dragged over it,
+/// and return the handle of the asset once it is dropped onto it.
+pub fn dropped_asset(
+    ui: &egui::Ui,
+    response: &egui::Response,
+    asset_type_id: TypeId,
+) -> Option<UntypedHandle> {
+    let payload = response.dnd_hover_payload::<AssetPayload>()?;
+    if payload.asset_type_id() != asset_type_id {
+        return None;
+    }
+    ui.painter().rect_stroke(
+        response.rect.expand(2.0),
+        ui.visuals().widgets.hovered.rounding,
+        ui.visuals().selection.stroke,
+    );
+    response
+        .dnd_release_payload::<AssetPayload>()
+        .map(|payload| payload.handle.clone())
+}
+
+/// Display `add_contents` for `handle`, and assign assets of type `A` dropped onto it.
+///
+/// Useful for custom [`InspectorPrimitive`](crate::inspector_egui_impls::InspectorPrimitive)s of handles.
+pub fn ui_with_asset_drop<A: Asset>(
+    ui: &mut egui::Ui,
+    handle: &mut Handle<A>,
+    add_contents: impl FnOnce(&mut egui::Ui, &mut Handle<A>) -> bool,
+) -> bool {
+    let egui::InnerResponse {
+        inner: changed,
+        response,
+    } = ui.scope(|ui| add_contents(ui, handle));
+    match dropped_asset(ui, &response, TypeId::of::<A>()) {
+        Some(dropped) => {
+            *handle = dropped.typed();
+            true
+        }
+        None => changed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{Asset, AssetApp, AssetPlugin, AssetServer, Assets, UntypedHandle};
+    use bevy_reflect::TypePath;
+
+    use super::AssetPayload;
+
+    #[derive(Asset, TypePath)]
+    struct TestAsset;
+
+    #[test]
+    fn assets_not_managed_by_the_server_are_dropped_as_weak_handles() {
+        let mut app = App::new();
+        app.add_plugins((bevy_core::TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<TestAsset>();
+
+        let handle = app.world.resource_mut::<Assets<TestAsset>>().add(TestAsset);
+        let asset_server = app.world.resource::<AssetServer>();
+        let payload = AssetPayload::new(handle.id().untyped(), Some(asset_server));
+        assert!(matches!(payload.handle, UntypedHandle::Weak(id) if id == handle.id().untyped()));
+        assert_eq!(payload.handle.typed::<TestAsset>(), handle);
+    }
+}
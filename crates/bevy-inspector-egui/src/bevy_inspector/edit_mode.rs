@@ -0,0 +1,283 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy_reflect::{Reflect, ReflectFromReflect, TypeRegistry};
+
+/// When edits in the inspector are written to the world.
+///
+/// Systems which react expensively to changes, like rebuilding a mesh, can be spared the changes of every frame
+/// of a drag by using [`EditMode::Deferred`]. The mode is configured per layer of the egui context, usually a window,
+/// using [`set_edit_mode`] or `with_edit_mode` on the [`quick`](crate::quick) plugins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EditMode {
+    /// Write every change right away, e.g. in every frame of dragging a value
+    #[default]
+    Immediate,
+    /// Collect changes in a copy of the value, which is highlighted and only written when pressing "Apply".
+    ///
+    /// Applies to components and resources. The whole copy is written, overwriting changes the game made in the meantime.
+    Deferred,
+}
+
+/// How often edits made in [`EditMode::Immediate`] are reported to the world.
+///
+/// Values are edited in place without copying them, and the world only learns about an edit when the value is
+/// marked as changed and an [`InspectorEvent::ValueChanged`](crate::events::InspectorEvent::ValueChanged) is sent.
+/// Heavyweight values, e.g. components which are turned into meshes whenever they change, can use
+/// [`InspectorUpdateMode::OnEdit`] or [`InspectorUpdateMode::Interval`] to not be marked as changed in every frame of a drag.
+/// The mode is configured per layer using [`set_update_mode`] or `with_update_mode` on the [`quick`](crate::quick) plugins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum InspectorUpdateMode {
+    /// Mark the value as changed in every frame it is edited
+    #[default]
+    EveryFrame,
+    /// Mark the value as changed once the edit is finished, i.e. no mouse button is held and no text field has focus
+    OnEdit,
+    /// Mark the value as changed at most once per interval while it is being edited
+    Interval(Duration),
+}
+
+fn mode_id(layer: egui::Id) -> egui::Id {
+    layer.with("bevy-inspector-egui edit mode")
+}
+
+/// Use `mode` for the values displayed in the layer with the id `layer`.
+///
+/// The layer of a window titled `title` has the id `egui::Id::new(title)`, see [`egui::Ui::layer_id`].
+pub fn set_edit_mode(ctx: &egui::Context, layer: egui::Id, mode: EditMode) {
+    ctx.data_mut(|data| data.insert_temp(mode_id(layer), mode));
+}
+
+/// The edit mode of the layer `ui` belongs to
+pub fn edit_mode(ui: &egui::Ui) -> EditMode {
+    ui.data(|data| data.get_temp(mode_id(ui.layer_id().id)))
+        .unwrap_or_default()
+}
+
+fn update_mode_id(layer: egui::Id) -> egui::Id {
+    layer.with("bevy-inspector-egui update mode")
+}
+
+/// Use `mode` for reporting the edits of the values displayed in the layer with the id `layer`.
+///
+/// The layer of a window titled `title` has the id `egui::Id::new(title)`, see [`egui::Ui::layer_id`].
+pub fn set_update_mode(ctx: &egui::Context, layer: egui::Id, mode: InspectorUpdateMode) {
+    ctx.data_mut(|data| data.insert_temp(update_mode_id(layer), mode));
+}
+
+/// The update mode of the layer `ui` belongs to
+pub fn update_mode(ui: &egui::Ui) -> InspectorUpdateMode {
+    ui.data(|data| data.get_temp(update_mode_id(ui.layer_id().id)))
+        .unwrap_or_default()
+}
+
+/// Edits of a value which weren't reported yet in the [`InspectorUpdateMode`], and when they were last reported
+#[derive(Clone, Copy)]
+struct UnreportedEdit {
+    dirty: bool,
+    last_report: f64,
+}
+
+/// Whether the edits of the value with the `id` should be reported in this frame, given whether it was `changed` in it
+fn report_edit(ui: &egui::Ui, id: egui::Id, changed: bool) -> bool {
+    let mode = update_mode(ui);
+    if mode == InspectorUpdateMode::EveryFrame {
+        return changed;
+    }
+
+    let id = id.with("unreported edit");
+    let now = ui.input(|input| input.time);
+    let stored = ui.data(|data| data.get_temp::<UnreportedEdit>(id));
+    if stored.is_none() && !changed {
+        return false;
+    }
+    let mut edit = stored.unwrap_or(UnreportedEdit {
+        dirty: false,
+        last_report: f64::NEG_INFINITY,
+    });
+    edit.dirty |= changed;
+
+    let editing = ui.input(|input| input.pointer.any_down())
+        || ui.memory(|memory| memory.focused().is_some());
+    let report = edit.dirty
+        && match mode {
+            InspectorUpdateMode::EveryFrame => true,
+            InspectorUpdateMode::OnEdit => !editing,
+            InspectorUpdateMode::Interval(interval) => {
+                let remaining = interval.as_secs_f64() - (now - edit.last_report);
+                if remaining > 0.0 {
+                    ui.ctx()
+                        .request_repaint_after(Duration::from_secs_f64(remaining));
+                }
+                remaining <= 0.0
+            }
+        };
+    if report {
+        edit = UnreportedEdit {
+            dirty: false,
+            last_report: now,
+        };
+    }
+
+    // the time of the last report is kept during the edit, so that the interval also holds between reports
+    ui.data_mut(|data| match edit.dirty || editing {
+        true => data.insert_temp(id, edit),
+        false => data.remove::<UnreportedEdit>(id),
+    });
+    report
+}
+
+/// The edited copy of a value in [`EditMode::Deferred`]
+#[derive(Clone)]
+struct PendingEdit(Arc<Mutex<Box<dyn Reflect>>>);
+
+/// A copy of `value` of the same concrete type if it is registered with `ReflectFromReflect`, so that custom UIs still apply
+pub(crate) fn clone_concrete(
+    value: &dyn Reflect,
+    type_registry: &TypeRegistry,
+) -> Box<dyn Reflect> {
+    value
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get_type_data::<ReflectFromReflect>(info.type_id()))
+        .and_then(|reflect_from_reflect| reflect_from_reflect.from_reflect(value))
+        .unwrap_or_else(|| value.clone_value())
+}
+
+/// Display `value` using `add_contents` in the [`edit_mode`] of `ui`, keeping pending edits under `id`.
+///
+/// Returns whether `value` was changed, which in [`EditMode::Deferred`] is only the case when the edits are applied,
+/// and otherwise depends on the [`update_mode`].
+pub(crate) fn ui_for_edit_mode(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    value: &mut dyn Reflect,
+    type_registry: &TypeRegistry,
+    add_contents: impl FnOnce(&mut egui::Ui, &mut dyn Reflect) -> bool,
+) -> bool {
+    if edit_mode(ui) == EditMode::Immediate {
+        let changed = add_contents(ui, value);
+        return report_edit(ui, id, changed);
+    }
+
+    let id = id.with("pending edit");
+    let pending = ui.data(|data| data.get_temp::<PendingEdit>(id));
+    let was_pending = pending.is_some();
+    let pending = pending
+        .unwrap_or_else(|| PendingEdit(Arc::new(Mutex::new(clone_concrete(value, type_registry)))));
+    let mut edited = pending.0.lock().unwrap();
+
+    let stroke = match was_pending {
+        true => egui::Stroke::new(1.0, ui.visuals().selection.bg_fill),
+        false => ui.visuals().widgets.noninteractive.bg_stroke,
+    };
+    let mut changed = false;
+    egui::Frame::group(ui.style())
+        .stroke(stroke)
+        .show(ui, |ui| {
+            changed = add_contents(ui, edited.as_mut());
+        });
+
+    let mut keep = was_pending || changed;
+    let mut applied = false;
+    if keep {
+        ui.horizontal(|ui| {
+            if ui.button("Apply").clicked() {
+                value.apply(edited.as_ref());
+                applied = true;
+                keep = false;
+            }
+            if ui.button("Discard").clicked() {
+                keep = false;
+            }
+        });
+    }
+    drop(edited);
+
+    ui.data_mut(|data| match keep {
+        true => data.insert_temp(id, pending),
+        false => data.remove::<PendingEdit>(id),
+    });
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    use super::{ui_for_edit_mode, EditMode, InspectorUpdateMode};
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    struct Radius(f32);
+
+    #[test]
+    fn deferred_edits_stay_pending() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Radius>();
+
+        let ctx = egui::Context::default();
+        super::set_edit_mode(&ctx, egui::LayerId::background().id, EditMode::Deferred);
+        let mut value = Radius(1.0);
+        let mut frame = |edit: &mut dyn FnMut(&mut dyn Reflect) -> bool| {
+            let mut changed = false;
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let id = egui::Id::new("radius");
+                    changed = ui_for_edit_mode(ui, id, &mut value, &type_registry, |_, value| {
+                        edit(value)
+                    });
+                });
+            });
+            changed
+        };
+
+        let changed = frame(&mut |value| {
+            value.downcast_mut::<Radius>().unwrap().0 = 5.0;
+            true
+        });
+        assert!(!changed);
+
+        let mut pending = None;
+        frame(&mut |value| {
+            pending = value.downcast_ref::<Radius>().map(|radius| radius.0);
+            false
+        });
+        assert_eq!(pending, Some(5.0));
+        assert_eq!(value, Radius(1.0));
+    }
+
+    #[test]
+    fn on_edit_reports_after_release() {
+        let type_registry = TypeRegistry::default();
+        let ctx = egui::Context::default();
+        let layer = egui::LayerId::background().id;
+        super::set_update_mode(&ctx, layer, InspectorUpdateMode::OnEdit);
+
+        let mut value = Radius(1.0);
+        let mut frame = |pressed: Option<bool>, edited: bool| {
+            let mut input = egui::RawInput::default();
+            if let Some(pressed) = pressed {
+                input.events.push(egui::Event::PointerButton {
+                    pos: egui::pos2(10.0, 10.0),
+                    button: egui::PointerButton::Primary,
+                    pressed,
+                    modifiers: egui::Modifiers::NONE,
+                });
+            }
+            let mut changed = false;
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let id = egui::Id::new("radius");
+                    changed = ui_for_edit_mode(ui, id, &mut value, &type_registry, |_, _| edited);
+                });
+            });
+            changed
+        };
+
+        assert!(!frame(Some(true), true));
+        assert!(!frame(None, true));
+        assert!(frame(Some(false), false));
+        assert!(!frame(None, false));
+    }
+}
@@ -3,8 +3,11 @@ use std::collections::HashSet;
 use bevy_ecs::{prelude::*, query::QueryFilter};
 use bevy_hierarchy::{Children, Parent};
 use bevy_reflect::TypeRegistry;
-use egui::{CollapsingHeader, RichText};
+use egui::CollapsingHeader;
 
+use super::{params, tags};
+use crate::egui_utils;
+use crate::events::{self, InspectorEvent};
 use crate::utils::guess_entity_name;
 
 /// Display UI of the entity hierarchy.
@@ -51,12 +54,17 @@ impl<T> Hierarchy<'_, T> {
             .collect();
 
         let mut entities: Vec<_> = root_query.iter(self.world).collect();
-        entities.sort();
+        params::retain_shown_entities(self.world, &mut entities);
+        params::sort_entities(self.world, &mut entities);
 
         let mut selected = false;
-        for &entity in &entities {
+        let is_expanded = |ui: &egui::Ui, entity: &Entity| {
+            always_open.contains(entity) || egui_utils::is_header_open(ui, entity)
+        };
+        let id = egui::Id::new("hierarchy rows");
+        egui_utils::lazy_rows(ui, id, &entities, is_expanded, |ui, &entity| {
             selected |= self.entity_ui(ui, entity, &always_open, &entities);
-        }
+        });
         selected
     }
 
@@ -71,15 +79,12 @@ impl<T> Hierarchy<'_, T> {
         let selected = self.selected.contains(entity);
 
         let entity_name = guess_entity_name::guess_entity_name(self.world, entity);
-        let mut name = RichText::new(entity_name);
-        if selected {
-            name = name.strong();
-        }
+        let name = tags::entity_label(ui, self.world, entity, &entity_name, selected);
 
         let has_children = self
             .world
             .get::<Children>(entity)
-            .map_or(false, |children| children.len() > 0);
+            .is_some_and(|children| !children.is_empty());
 
         let open = if !has_children {
             Some(false)
@@ -108,10 +113,15 @@ impl<T> Hierarchy<'_, T> {
             .show(ui, |ui| {
                 let children = self.world.get::<Children>(entity);
                 if let Some(children) = children {
-                    let children = children.to_vec();
-                    for &child in children.iter() {
+                    let mut children = children.to_vec();
+                    params::retain_shown_entities(self.world, &mut children);
+                    let is_expanded = |ui: &egui::Ui, child: &Entity| {
+                        always_open.contains(child) || egui_utils::is_header_open(ui, child)
+                    };
+                    let id = ui.id().with("hierarchy rows");
+                    egui_utils::lazy_rows(ui, id, &children, is_expanded, |ui, &child| {
                         new_selection |= self.entity_ui(ui, child, always_open, &children);
-                    }
+                    });
                 } else {
                     ui.label("No children");
                 }
@@ -122,21 +132,12 @@ impl<T> Hierarchy<'_, T> {
             let selection_mode = ui.input(|input| {
                 SelectionMode::from_ctrl_shift(input.modifiers.ctrl, input.modifiers.shift)
             });
-            let extend_with = |from, to| {
-                // PERF: this could be done in one scan
-                let from_position = at_same_level.iter().position(|&entity| entity == from);
-                let to_position = at_same_level.iter().position(|&entity| entity == to);
-                from_position
-                    .zip(to_position)
-                    .map(|(from, to)| {
-                        let (min, max) = if from < to { (from, to) } else { (to, from) };
-                        at_same_level[min..=max].iter().copied()
-                    })
-                    .into_iter()
-                    .flatten()
-            };
+            let extend_with = |from, to| entities_between(at_same_level, from, to);
             self.selected.select(selection_mode, entity, extend_with);
             new_selection = true;
+            if self.selected.contains(entity) {
+                events::send(self.world, InspectorEvent::EntitySelected { entity });
+            }
         }
 
         if let Some(context_menu) = self.context_menu.as_mut() {
@@ -171,6 +172,25 @@ fn paint_default_icon(ui: &mut egui::Ui, openness: f32, response: &egui::Respons
     ui.painter().add(egui::Shape::closed_line(points, stroke));
 }
 
+/// The entities from `from` to `to` in `entities`, including both, or none if one of them is missing
+pub(crate) fn entities_between(
+    entities: &[Entity],
+    from: Entity,
+    to: Entity,
+) -> impl Iterator<Item = Entity> + '_ {
+    // PERF: this could be done in one scan
+    let from_position = entities.iter().position(|&entity| entity == from);
+    let to_position = entities.iter().position(|&entity| entity == to);
+    from_position
+        .zip(to_position)
+        .map(|(from, to)| {
+            let (min, max) = if from < to { (from, to) } else { (to, from) };
+            entities[min..=max].iter().copied()
+        })
+        .into_iter()
+        .flatten()
+}
+
 /// Collection of currently selected entities
 #[derive(Default, Debug)]
 pub struct SelectedEntities {
@@ -249,6 +269,13 @@ impl SelectedEntities {
         self.last_action = Some((mode, entity));
     }
 
+    /// Add all `entities` to the selection, without changing the last action
+    pub fn extend(&mut self, entities: impl IntoIterator<Item = Entity>) {
+        for entity in entities {
+            self.insert(entity);
+        }
+    }
+
     pub fn contains(&self, entity: Entity) -> bool {
         self.entities.contains(&entity)
     }
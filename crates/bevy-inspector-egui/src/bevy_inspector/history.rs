@@ -0,0 +1,297 @@
+//! When components were added and how they changed since, for answering "when did this flip to zero?" from the inspector.
+//!
+//! Only the component types registered with [`TrackComponentHistory::track_component_history`] are tracked. For each
+//! of their components, the [`ComponentHistory`] keeps the time it was added and its last mutations, with the fields
+//! each of them changed. The world inspector shows them in the "History" section of each entity.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::history::TrackComponentHistory;
+//! use bevy_inspector_egui::quick::WorldInspectorPlugin;
+//!
+//! #[derive(Component, Reflect, Default)]
+//! #[reflect(Component)]
+//! struct Health(f32);
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(WorldInspectorPlugin::new())
+//!         .register_type::<Health>()
+//!         .track_component_history::<Health>()
+//!         .run();
+//! }
+//! ```
+
+use std::{
+    any::TypeId,
+    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
+    time::Duration,
+};
+
+use bevy_app::{App, Last};
+use bevy_core::FrameCount;
+use bevy_ecs::prelude::*;
+use bevy_reflect::{Reflect, TypeRegistry};
+use bevy_time::{Real, Time};
+
+use super::snapshots::{self, FieldChange};
+
+/// Extension trait for recording the [`ComponentHistory`] of a component type
+pub trait TrackComponentHistory {
+    /// Record when components of type `C` are added and how they change from now on
+    fn track_component_history<C: Component + Reflect>(&mut self) -> &mut Self;
+}
+
+impl TrackComponentHistory for App {
+    fn track_component_history<C: Component + Reflect>(&mut self) -> &mut Self {
+        self.init_resource::<ComponentHistory>();
+        let newly_tracked = self
+            .world
+            .resource_mut::<ComponentHistory>()
+            .tracked
+            .insert(TypeId::of::<C>());
+        if newly_tracked {
+            self.add_systems(Last, record_history::<C>);
+        }
+        self
+    }
+}
+
+/// When something happened in the [`ComponentHistory`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HistoryTime {
+    /// The [`FrameCount`] of the frame
+    pub frame: u32,
+    /// The real time since startup
+    pub time: Duration,
+}
+
+/// A change to a component recorded in the [`ComponentHistory`]
+#[derive(Clone, Debug)]
+pub struct Mutation {
+    pub at: HistoryTime,
+    /// The fields which changed, see [`snapshots::diff`]
+    pub changes: Vec<FieldChange>,
+}
+
+/// The history of a single component
+#[derive(Debug)]
+pub struct ComponentRecord {
+    /// When the component was added, or `None` if it was added before its type was tracked
+    pub added: Option<HistoryTime>,
+    /// The latest mutations, the latest first
+    pub mutations: VecDeque<Mutation>,
+    last_value: Box<dyn Reflect>,
+}
+
+/// When the components of the types registered with [`TrackComponentHistory::track_component_history`] were added,
+/// and their last [`capacity`](ComponentHistory::capacity) mutations
+#[derive(Resource, Debug)]
+pub struct ComponentHistory {
+    /// How many mutations are kept per component, older ones are dropped first
+    pub capacity: usize,
+    tracked: HashSet<TypeId>,
+    records: HashMap<(Entity, TypeId), ComponentRecord>,
+}
+
+impl Default for ComponentHistory {
+    fn default() -> Self {
+        ComponentHistory {
+            capacity: 16,
+            tracked: HashSet::new(),
+            records: HashMap::new(),
+        }
+    }
+}
+
+impl ComponentHistory {
+    /// Whether components of the type `type_id` are tracked
+    pub fn is_tracked(&self, type_id: TypeId) -> bool {
+        self.tracked.contains(&type_id)
+    }
+
+    /// The history of the component of type `type_id` of `entity`
+    pub fn record(&self, entity: Entity, type_id: TypeId) -> Option<&ComponentRecord> {
+        self.records.get(&(entity, type_id))
+    }
+
+    /// The histories of all tracked components of `entity`
+    pub fn entity_records(
+        &self,
+        entity: Entity,
+    ) -> impl Iterator<Item = (TypeId, &ComponentRecord)> + '_ {
+        self.records
+            .iter()
+            .filter(move |((record_entity, _), _)| *record_entity == entity)
+            .map(|((_, type_id), record)| (*type_id, record))
+    }
+
+    /// Forget the mutations of all components, keeping when they were added
+    pub fn clear_mutations(&mut self) {
+        for record in self.records.values_mut() {
+            record.mutations.clear();
+        }
+    }
+
+    fn update(
+        &mut self,
+        entity: Entity,
+        type_id: TypeId,
+        value: &dyn Reflect,
+        is_added: bool,
+        at: HistoryTime,
+    ) {
+        let capacity = self.capacity;
+        let record = match self.records.entry((entity, type_id)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                // components existing when the system first runs count as added, as it is added at startup
+                entry.insert(ComponentRecord {
+                    added: is_added.then_some(at),
+                    mutations: VecDeque::new(),
+                    last_value: value.clone_value(),
+                });
+                return;
+            }
+        };
+        let changes = snapshots::diff(record.last_value.as_ref(), value);
+        if changes.is_empty() {
+            return;
+        }
+        record.mutations.push_front(Mutation { at, changes });
+        record.mutations.truncate(capacity);
+        record.last_value = value.clone_value();
+    }
+}
+
+fn record_history<C: Component + Reflect>(
+    components: Query<(Entity, Ref<C>), Changed<C>>,
+    mut removed: RemovedComponents<C>,
+    frame_count: Option<Res<FrameCount>>,
+    time: Option<Res<Time<Real>>>,
+    mut history: ResMut<ComponentHistory>,
+) {
+    let type_id = TypeId::of::<C>();
+    let at = HistoryTime {
+        frame: frame_count.map_or(0, |frame_count| frame_count.0),
+        time: time.map_or(Duration::ZERO, |time| time.elapsed()),
+    };
+    for entity in removed.read() {
+        history.records.remove(&(entity, type_id));
+    }
+    for (entity, component) in &components {
+        let is_added = component.is_added();
+        history.update(entity, type_id, component.as_reflect(), is_added, at);
+    }
+}
+
+/// Display when the tracked components of `entity` were added and their mutations, if any are tracked
+pub(crate) fn ui_for_entity_history(
+    ui: &mut egui::Ui,
+    history: &ComponentHistory,
+    entity: Entity,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) {
+    let mut records: Vec<_> = history
+        .entity_records(entity)
+        .map(|(type_id, record)| {
+            let name = type_registry.get(type_id).map_or_else(
+                || format!("{type_id:?}"),
+                |registration| {
+                    registration
+                        .type_info()
+                        .type_path_table()
+                        .short_path()
+                        .to_owned()
+                },
+            );
+            (name, record)
+        })
+        .collect();
+    if records.is_empty() {
+        return;
+    }
+    records.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    egui::CollapsingHeader::new("History")
+        .id_source(id.with("history"))
+        .show(ui, |ui| {
+            for (name, record) in records {
+                ui.strong(&name);
+                match record.added {
+                    Some(added) => ui.label(format!("Added {}", format_time(added))),
+                    None => ui.weak("Added before tracking started"),
+                };
+                if record.mutations.is_empty() {
+                    ui.weak("No mutations");
+                }
+                egui::Grid::new(id.with(("history", &name)))
+                    .striped(true)
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for mutation in &record.mutations {
+                            ui.weak(format_time(mutation.at));
+                            ui.vertical(|ui| {
+                                for change in &mutation.changes {
+                                    let path = match change.path.is_empty() {
+                                        true => "value",
+                                        false => &change.path,
+                                    };
+                                    ui.label(format!(
+                                        "{path}: {} → {}",
+                                        change.before, change.after
+                                    ));
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            }
+        });
+}
+
+fn format_time(at: HistoryTime) -> String {
+    format!("frame {} ({:.2}s)", at.frame, at.time.as_secs_f32())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_app::App;
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+
+    use super::{ComponentHistory, TrackComponentHistory};
+
+    #[derive(Component, Reflect)]
+    struct Health(f32);
+
+    #[test]
+    fn mutations_are_recorded() {
+        let mut app = App::new();
+        app.track_component_history::<Health>();
+        let entity = app.world.spawn(Health(10.0)).id();
+        app.update();
+
+        app.world.get_mut::<Health>(entity).unwrap().0 = 0.0;
+        app.update();
+        // marks the component as changed without changing it
+        app.world.get_mut::<Health>(entity).unwrap().0 = 0.0;
+        app.update();
+
+        let history = app.world.resource::<ComponentHistory>();
+        let record = history.record(entity, TypeId::of::<Health>()).unwrap();
+        assert!(record.added.is_some());
+        assert_eq!(record.mutations.len(), 1);
+        assert_eq!(record.mutations[0].changes[0].path, "0");
+        assert_eq!(record.mutations[0].changes[0].after, "0.0");
+
+        app.world.entity_mut(entity).remove::<Health>();
+        app.update();
+        let history = app.world.resource::<ComponentHistory>();
+        assert!(history.record(entity, TypeId::of::<Health>()).is_none());
+    }
+}
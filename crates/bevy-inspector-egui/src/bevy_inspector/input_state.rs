@@ -0,0 +1,350 @@
+//! The current state of the keyboard, mouse and gamepads, for debugging input mappings.
+//!
+//! Bevy's `just_pressed` state only lasts for a single frame, so [`record_recent_inputs`] keeps the latest presses in
+//! the [`RecentInputs`], and [`ui_for_input_state`] highlights inputs for a moment after they were pressed.
+
+use std::{collections::VecDeque, time::Duration};
+
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+    Axis, ButtonInput,
+};
+use bevy_utils::Instant;
+use bevy_window::{PrimaryWindow, Window};
+
+/// How many presses the [`RecentInputs`] remember
+const RECENT_CAPACITY: usize = 16;
+/// How long inputs are highlighted after they were pressed
+const HIGHLIGHT_DURATION: Duration = Duration::from_millis(400);
+
+const MOUSE_BUTTONS: [MouseButton; 5] = [
+    MouseButton::Left,
+    MouseButton::Middle,
+    MouseButton::Right,
+    MouseButton::Back,
+    MouseButton::Forward,
+];
+
+/// A keyboard key, mouse button or gamepad button
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputButton {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl InputButton {
+    pub fn name(&self) -> String {
+        match self {
+            InputButton::Key(key) => format!("{key:?}"),
+            InputButton::Mouse(button) => format!("Mouse {button:?}"),
+            InputButton::Gamepad(button) => {
+                format!("Gamepad {} {:?}", button.gamepad.id, button.button_type)
+            }
+        }
+    }
+}
+
+/// The latest presses of any [`InputButton`], recorded by [`record_recent_inputs`]
+#[derive(Resource, Default, Clone, Debug)]
+pub struct RecentInputs {
+    /// The pressed buttons with the time they were pressed at, the latest first
+    pub presses: VecDeque<(InputButton, Instant)>,
+}
+
+impl RecentInputs {
+    /// Whether `button` was pressed within the last moment
+    pub fn was_just_pressed(&self, button: InputButton) -> bool {
+        self.presses
+            .iter()
+            .take_while(|(_, time)| time.elapsed() < HIGHLIGHT_DURATION)
+            .any(|(pressed, _)| *pressed == button)
+    }
+
+    fn record(&mut self, buttons: impl Iterator<Item = InputButton>, now: Instant) {
+        for button in buttons {
+            self.presses.push_front((button, now));
+        }
+        self.presses.truncate(RECENT_CAPACITY);
+    }
+}
+
+/// Record the buttons pressed this frame in the [`RecentInputs`]
+pub fn record_recent_inputs(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    gamepad_buttons: Option<Res<ButtonInput<GamepadButton>>>,
+    mut recent: ResMut<RecentInputs>,
+) {
+    let now = Instant::now();
+    if let Some(keys) = keys {
+        recent.record(keys.get_just_pressed().copied().map(InputButton::Key), now);
+    }
+    if let Some(mouse_buttons) = mouse_buttons {
+        recent.record(
+            mouse_buttons
+                .get_just_pressed()
+                .copied()
+                .map(InputButton::Mouse),
+            now,
+        );
+    }
+    if let Some(gamepad_buttons) = gamepad_buttons {
+        recent.record(
+            gamepad_buttons
+                .get_just_pressed()
+                .copied()
+                .map(InputButton::Gamepad),
+            now,
+        );
+    }
+}
+
+/// Display the pressed keys, the cursor position and mouse buttons, and the sticks, triggers and buttons of every
+/// gamepad, highlighting inputs which were just pressed
+pub fn ui_for_input_state(world: &mut World, ui: &mut egui::Ui) {
+    if !world.contains_resource::<RecentInputs>() {
+        world.init_resource::<RecentInputs>();
+    }
+    // inputs change every frame without anything else happening in the UI
+    ui.ctx().request_repaint();
+    let recent = world.resource::<RecentInputs>().clone();
+
+    egui::CollapsingHeader::new("Keyboard")
+        .default_open(true)
+        .show(ui, |ui| {
+            match world.get_resource::<ButtonInput<KeyCode>>() {
+                Some(keys) => {
+                    let mut pressed: Vec<_> = keys.get_pressed().copied().collect();
+                    pressed.sort_by_key(|key| format!("{key:?}"));
+                    ui.horizontal_wrapped(|ui| {
+                        if pressed.is_empty() {
+                            ui.weak("No keys pressed");
+                        }
+                        for key in pressed {
+                            ui_for_button(ui, &recent, InputButton::Key(key), true);
+                        }
+                    });
+                }
+                None => {
+                    ui.weak("No ButtonInput<KeyCode> resource, add the InputPlugin");
+                }
+            }
+        });
+
+    egui::CollapsingHeader::new("Mouse")
+        .default_open(true)
+        .show(ui, |ui| ui_for_mouse(world, ui, &recent));
+
+    egui::CollapsingHeader::new("Gamepads")
+        .default_open(true)
+        .show(ui, |ui| ui_for_gamepads(world, ui, &recent));
+
+    egui::CollapsingHeader::new("Recent presses").show(ui, |ui| {
+        if recent.presses.is_empty() {
+            ui.weak("Nothing pressed yet");
+        }
+        egui::Grid::new("input state recent")
+            .striped(true)
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (button, time) in &recent.presses {
+                    ui.label(button.name());
+                    ui.weak(format!("{:.1}s ago", time.elapsed().as_secs_f32()));
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+fn ui_for_mouse(world: &mut World, ui: &mut egui::Ui, recent: &RecentInputs) {
+    let cursor = world
+        .query_filtered::<&Window, With<PrimaryWindow>>()
+        .get_single(world)
+        .ok()
+        .map(Window::cursor_position);
+    egui::Grid::new("input state mouse")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Cursor");
+            match cursor {
+                Some(Some(position)) => {
+                    ui.monospace(format!("{:.0}, {:.0}", position.x, position.y))
+                }
+                Some(None) => ui.weak("outside the window"),
+                None => ui.weak("no primary window"),
+            };
+            ui.end_row();
+
+            ui.label("Buttons");
+            match world.get_resource::<ButtonInput<MouseButton>>() {
+                Some(buttons) => {
+                    ui.horizontal_wrapped(|ui| {
+                        for button in MOUSE_BUTTONS {
+                            let pressed = buttons.pressed(button);
+                            ui_for_button(ui, recent, InputButton::Mouse(button), pressed);
+                        }
+                    });
+                }
+                None => {
+                    ui.weak("No ButtonInput<MouseButton> resource");
+                }
+            }
+            ui.end_row();
+        });
+}
+
+fn ui_for_gamepads(world: &World, ui: &mut egui::Ui, recent: &RecentInputs) {
+    let Some(gamepads) = world.get_resource::<Gamepads>() else {
+        ui.weak("No Gamepads resource, add the InputPlugin");
+        return;
+    };
+    let mut connected: Vec<Gamepad> = gamepads.iter().collect();
+    if connected.is_empty() {
+        ui.weak("No gamepads connected");
+        return;
+    }
+    connected.sort_by_key(|gamepad| gamepad.id);
+
+    let axes = world.get_resource::<Axis<GamepadAxis>>();
+    let button_axes = world.get_resource::<Axis<GamepadButton>>();
+    let buttons = world.get_resource::<ButtonInput<GamepadButton>>();
+    for gamepad in connected {
+        let name = gamepads.name(gamepad).unwrap_or("Gamepad");
+        ui.strong(format!("{name} ({})", gamepad.id));
+
+        let axis = |axis_type| {
+            axes.and_then(|axes| axes.get(GamepadAxis::new(gamepad, axis_type)))
+                .unwrap_or(0.0)
+        };
+        let trigger = |button_type| {
+            button_axes
+                .and_then(|axes| axes.get(GamepadButton::new(gamepad, button_type)))
+                .unwrap_or(0.0)
+        };
+        ui.horizontal(|ui| {
+            ui_for_stick(
+                ui,
+                "Left stick",
+                axis(GamepadAxisType::LeftStickX),
+                axis(GamepadAxisType::LeftStickY),
+            );
+            ui_for_stick(
+                ui,
+                "Right stick",
+                axis(GamepadAxisType::RightStickX),
+                axis(GamepadAxisType::RightStickY),
+            );
+            ui.vertical(|ui| {
+                for (label, button_type) in [
+                    ("LT", GamepadButtonType::LeftTrigger2),
+                    ("RT", GamepadButtonType::RightTrigger2),
+                ] {
+                    let value = trigger(button_type);
+                    ui.add(
+                        egui::ProgressBar::new(value)
+                            .desired_width(80.0)
+                            .text(format!("{label} {value:.2}")),
+                    );
+                }
+            });
+        });
+
+        let mut pressed: Vec<GamepadButton> = buttons
+            .map(|buttons| {
+                buttons
+                    .get_pressed()
+                    .filter(|button| button.gamepad == gamepad)
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+        pressed.sort_by_key(|button| format!("{:?}", button.button_type));
+        ui.horizontal_wrapped(|ui| {
+            if pressed.is_empty() {
+                ui.weak("No buttons pressed");
+            }
+            for button in pressed {
+                let response = ui_for_button(ui, recent, InputButton::Gamepad(button), true);
+                response.on_hover_text(format!("{:?}", button.button_type));
+            }
+        });
+        ui.separator();
+    }
+}
+
+/// A button label, filled while `pressed` and highlighted for a moment after it was pressed
+fn ui_for_button(
+    ui: &mut egui::Ui,
+    recent: &RecentInputs,
+    button: InputButton,
+    pressed: bool,
+) -> egui::Response {
+    let label = match button {
+        InputButton::Key(key) => format!("{key:?}"),
+        InputButton::Mouse(button) => format!("{button:?}"),
+        InputButton::Gamepad(button) => format!("{:?}", button.button_type),
+    };
+    let visuals = ui.visuals();
+    let mut text = egui::RichText::new(label).monospace();
+    if recent.was_just_pressed(button) {
+        text = text
+            .background_color(visuals.warn_fg_color)
+            .color(visuals.extreme_bg_color);
+    } else if pressed {
+        text = text
+            .background_color(visuals.selection.bg_fill)
+            .color(visuals.selection.stroke.color);
+    } else {
+        text = text.weak();
+    }
+    ui.label(text)
+}
+
+/// A circle with a dot at the position of the stick
+fn ui_for_stick(ui: &mut egui::Ui, label: &str, x: f32, y: f32) {
+    let size = egui::vec2(48.0, 48.0);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let visuals = ui.visuals();
+    let radius = rect.width() / 2.0 - 2.0;
+    let painter = ui.painter();
+    painter.circle_stroke(rect.center(), radius, visuals.widgets.inactive.fg_stroke);
+    painter.circle_filled(
+        rect.center() + egui::vec2(x, -y) * radius,
+        4.0,
+        visuals.selection.bg_fill,
+    );
+    response.on_hover_text(format!("{label}: {x:.2}, {y:.2}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_input::{keyboard::KeyCode, ButtonInput};
+
+    use super::{record_recent_inputs, InputButton, RecentInputs};
+
+    #[test]
+    fn presses_are_recorded() {
+        let mut world = World::new();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<RecentInputs>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(record_recent_inputs);
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        schedule.run(&mut world);
+        // still held, but no longer just pressed
+        world.resource_mut::<ButtonInput<KeyCode>>().clear();
+        schedule.run(&mut world);
+
+        let recent = world.resource::<RecentInputs>();
+        assert_eq!(recent.presses.len(), 1);
+        assert!(recent.was_just_pressed(InputButton::Key(KeyCode::Space)));
+    }
+}
@@ -0,0 +1,121 @@
+use bevy_ecs::prelude::*;
+use bevy_utils::HashMap;
+
+/// Temporarily read-only values and windows, e.g. during a network rollback or a cutscene.
+///
+/// Locked values are shown disabled with a lock icon, whose tooltip explains the reason,
+/// so that edits in the inspector can't corrupt game state at the wrong time.
+/// Values are identified by the type path of the component, resource or asset
+/// and windows of the [`quick`](crate::quick) plugins by their title.
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::bevy_inspector::locks::InspectorLocks;
+///
+/// fn start_cutscene(mut locks: ResMut<InspectorLocks>) {
+///     locks
+///         .lock_path("bevy_transform::components::transform::Transform", "Cutscene is playing")
+///         .lock_window("World Inspector", "Cutscene is playing");
+/// }
+///
+/// fn end_cutscene(mut locks: ResMut<InspectorLocks>) {
+///     locks.clear();
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone)]
+pub struct InspectorLocks {
+    paths: HashMap<String, String>,
+    windows: HashMap<String, String>,
+}
+
+impl InspectorLocks {
+    /// Make the component, resource or asset with the type path `path` read-only, explaining why with `reason`
+    pub fn lock_path(&mut self, path: impl Into<String>, reason: impl Into<String>) -> &mut Self {
+        self.paths.insert(path.into(), reason.into());
+        self
+    }
+
+    pub fn unlock_path(&mut self, path: &str) -> &mut Self {
+        self.paths.remove(path);
+        self
+    }
+
+    /// Make the whole window titled `title` read-only, explaining why with `reason`
+    pub fn lock_window(
+        &mut self,
+        title: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> &mut Self {
+        self.windows.insert(title.into(), reason.into());
+        self
+    }
+
+    pub fn unlock_window(&mut self, title: &str) -> &mut Self {
+        self.windows.remove(title);
+        self
+    }
+
+    /// Unlock all values and windows
+    pub fn clear(&mut self) {
+        self.paths.clear();
+        self.windows.clear();
+    }
+
+    /// The reason the value with the type path `path` is locked, if it is
+    pub fn path_lock(&self, path: &str) -> Option<&str> {
+        self.paths.get(path).map(String::as_str)
+    }
+
+    /// The reason the window titled `title` is locked, if it is
+    pub fn window_lock(&self, title: &str) -> Option<&str> {
+        self.windows.get(title).map(String::as_str)
+    }
+}
+
+/// The reason the value with the type path `path` is locked in `world`
+pub(crate) fn path_lock_reason(world: &World, path: &str) -> Option<String> {
+    let locks = world.get_resource::<InspectorLocks>()?;
+    locks.path_lock(path).map(str::to_owned)
+}
+
+/// The reason the window titled `title` is locked in `world`
+pub(crate) fn window_lock_reason(world: &World, title: &str) -> Option<String> {
+    let locks = world.get_resource::<InspectorLocks>()?;
+    locks.window_lock(title).map(str::to_owned)
+}
+
+/// Display `add_contents` disabled with a lock icon explaining `reason` if it is locked, or normally otherwise
+pub(crate) fn ui_locked<R>(
+    ui: &mut egui::Ui,
+    reason: Option<&str>,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let Some(reason) = reason else {
+        return add_contents(ui);
+    };
+    ui.label("🔒 Locked").on_hover_text(reason);
+    ui.add_enabled_ui(false, add_contents).inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InspectorLocks;
+
+    #[test]
+    fn lock_and_unlock() {
+        let mut locks = InspectorLocks::default();
+        locks
+            .lock_path("game::Health", "Rollback in progress")
+            .lock_window("World Inspector", "Cutscene");
+        assert_eq!(
+            locks.path_lock("game::Health"),
+            Some("Rollback in progress")
+        );
+        assert_eq!(locks.path_lock("game::Mana"), None);
+        assert_eq!(locks.window_lock("World Inspector"), Some("Cutscene"));
+
+        locks.unlock_path("game::Health");
+        assert_eq!(locks.path_lock("game::Health"), None);
+        locks.clear();
+        assert_eq!(locks.window_lock("World Inspector"), None);
+    }
+}
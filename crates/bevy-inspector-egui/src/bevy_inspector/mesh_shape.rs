@@ -0,0 +1,184 @@
+use bevy_asset::{AssetId, Assets, Handle};
+use bevy_ecs::prelude::*;
+use bevy_math::{primitives, Vec2, Vec3};
+use bevy_reflect::Reflect;
+use bevy_render::mesh::{Mesh, Meshable};
+use bevy_utils::HashMap;
+
+/// Parameters of a mesh generated from one of bevy's primitive shapes.
+///
+/// Meshes added through [`MeshShapes::add`] display their shape in the inspector,
+/// and editing it regenerates the mesh, e.g. for tweaking the subdivisions of a sphere live.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum MeshShape {
+    UvSphere {
+        radius: f32,
+        sectors: usize,
+        stacks: usize,
+    },
+    Icosphere {
+        radius: f32,
+        subdivisions: usize,
+    },
+    Cuboid {
+        size: Vec3,
+    },
+    Plane {
+        size: Vec2,
+    },
+    Cylinder {
+        radius: f32,
+        height: f32,
+        resolution: u32,
+        segments: u32,
+    },
+    Capsule {
+        radius: f32,
+        length: f32,
+        rings: usize,
+        latitudes: usize,
+        longitudes: usize,
+    },
+    Torus {
+        minor_radius: f32,
+        major_radius: f32,
+        minor_resolution: usize,
+        major_resolution: usize,
+    },
+    Circle {
+        radius: f32,
+        resolution: usize,
+    },
+    RegularPolygon {
+        circumradius: f32,
+        sides: usize,
+    },
+}
+
+impl MeshShape {
+    /// Generate the mesh for this shape
+    pub fn mesh(&self) -> Result<Mesh, String> {
+        let mesh = match *self {
+            MeshShape::UvSphere {
+                radius,
+                sectors,
+                stacks,
+            } => primitives::Sphere::new(radius).mesh().uv(sectors, stacks),
+            MeshShape::Icosphere {
+                radius,
+                subdivisions,
+            } => primitives::Sphere::new(radius)
+                .mesh()
+                .ico(subdivisions)
+                .map_err(|error| error.to_string())?,
+            MeshShape::Cuboid { size } => primitives::Cuboid::new(size.x, size.y, size.z).mesh(),
+            MeshShape::Plane { size } => primitives::Plane3d::default()
+                .mesh()
+                .size(size.x, size.y)
+                .build(),
+            MeshShape::Cylinder {
+                radius,
+                height,
+                resolution,
+                segments,
+            } => primitives::Cylinder::new(radius, height)
+                .mesh()
+                .resolution(resolution)
+                .segments(segments)
+                .build(),
+            MeshShape::Capsule {
+                radius,
+                length,
+                rings,
+                latitudes,
+                longitudes,
+            } => primitives::Capsule3d::new(radius, length)
+                .mesh()
+                .rings(rings)
+                .latitudes(latitudes)
+                .longitudes(longitudes)
+                .build(),
+            MeshShape::Torus {
+                minor_radius,
+                major_radius,
+                minor_resolution,
+                major_resolution,
+            } => primitives::Torus {
+                minor_radius,
+                major_radius,
+            }
+            .mesh()
+            .minor_resolution(minor_resolution)
+            .major_resolution(major_resolution)
+            .build(),
+            MeshShape::Circle { radius, resolution } => primitives::Circle::new(radius)
+                .mesh()
+                .resolution(resolution)
+                .build(),
+            MeshShape::RegularPolygon {
+                circumradius,
+                sides,
+            } => primitives::RegularPolygon::new(circumradius, sides).mesh(),
+        };
+        Ok(mesh)
+    }
+}
+
+/// The [`MeshShape`]s meshes were generated from, added by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MeshShapes {
+    shapes: HashMap<AssetId<Mesh>, MeshShape>,
+}
+
+impl MeshShapes {
+    /// Generate the mesh for `shape` and add it to `meshes`, remembering the shape for the inspector
+    pub fn add(
+        &mut self,
+        meshes: &mut Assets<Mesh>,
+        shape: MeshShape,
+    ) -> Result<Handle<Mesh>, String> {
+        let handle = meshes.add(shape.mesh()?);
+        self.shapes.insert(handle.id(), shape);
+        Ok(handle)
+    }
+
+    /// Remember that the mesh `id` was generated from `shape`
+    pub fn insert(&mut self, id: impl Into<AssetId<Mesh>>, shape: MeshShape) {
+        self.shapes.insert(id.into(), shape);
+    }
+
+    pub fn get(&self, id: impl Into<AssetId<Mesh>>) -> Option<&MeshShape> {
+        self.shapes.get(&id.into())
+    }
+
+    pub fn remove(&mut self, id: impl Into<AssetId<Mesh>>) -> Option<MeshShape> {
+        self.shapes.remove(&id.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MeshShape;
+
+    #[test]
+    fn regenerated_sphere_has_more_vertices() {
+        let coarse = MeshShape::UvSphere {
+            radius: 1.0,
+            sectors: 8,
+            stacks: 4,
+        };
+        let fine = MeshShape::UvSphere {
+            radius: 1.0,
+            sectors: 32,
+            stacks: 16,
+        };
+        let count = |shape: MeshShape| shape.mesh().unwrap().count_vertices();
+        assert!(count(fine) > count(coarse));
+
+        let too_fine = MeshShape::Icosphere {
+            radius: 1.0,
+            subdivisions: 1000,
+        };
+        assert!(too_fine.mesh().is_err());
+    }
+}
@@ -6,7 +6,7 @@
 //! use bevy_inspector_egui::bevy_inspector;
 //! # use bevy_ecs::prelude::*;
 //! # use bevy_reflect::Reflect;
-//! # use bevy_render::prelude::Msaa;
+//! # use bevy::render::prelude::Msaa;
 //! # use bevy_math::Vec3;
 //!
 //! #[derive(States, Debug, Clone, Eq, PartialEq, Hash, Reflect, Default)]
@@ -39,10 +39,14 @@
 use std::any::TypeId;
 
 use bevy_asset::{Asset, AssetServer, Assets, ReflectAsset, UntypedAssetId};
-use bevy_ecs::query::{QueryFilter, WorldQuery};
+use bevy_ecs::query::{Access, FilteredAccess, QueryData, QueryFilter, WorldQuery};
 use bevy_ecs::system::CommandQueue;
-use bevy_ecs::{component::ComponentId, prelude::*};
-use bevy_hierarchy::{Children, Parent};
+use bevy_ecs::{
+    archetype::ArchetypeId,
+    component::{ComponentId, Components},
+    prelude::*,
+};
+use bevy_hierarchy::{Children, DespawnRecursiveExt, Parent};
 use bevy_reflect::{Reflect, TypeRegistry};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
@@ -50,10 +54,85 @@ use pretty_type_name::pretty_type_name;
 
 pub(crate) mod errors;
 
+/// Flipping a resource between two stored sets of values
+pub mod ab_comparison;
+/// Entity counts and memory usage of archetypes, for diagnosing archetype fragmentation
+pub mod archetypes;
+/// Unreferenced assets and handles to missing assets, for finding asset leaks
+pub mod asset_usage;
+/// Browsing all assets with their load states and handle counts
+pub mod assets_browser;
+/// Previewing audio sources and controlling the sinks playing them
+#[cfg(feature = "bevy_audio")]
+pub mod audio;
+/// Marquee selection of entities in a camera's viewport
+#[cfg(feature = "bevy_render")]
+pub mod box_select;
+/// Batched application of structural changes made in the inspector
+pub mod commands;
+/// Project-specific actions in the context menu of components
+pub mod component_actions;
+/// Reading and assigning values by their path from a console window
+pub mod console;
+/// Dragging assets from the assets browser onto handle fields
+pub mod dnd;
+/// Writing edits immediately or only when pressing "Apply"
+pub mod edit_mode;
 /// UI for displaying the entity hierarchy
 pub mod hierarchy;
-
-use crate::reflect_inspector::{Context, InspectorUi};
+/// When components of tracked types were added and their recent mutations
+pub mod history;
+/// The current state of the keyboard, mouse and gamepads
+pub mod input_state;
+/// Temporarily making values and windows read-only
+pub mod locks;
+/// Primitive shapes of generated meshes, for regenerating them with different parameters
+#[cfg(feature = "bevy_render")]
+pub mod mesh_shape;
+/// Configuration of the entities and components shown by the world inspector
+pub mod params;
+/// Selecting entities by clicking on them in the viewport
+#[cfg(feature = "picking")]
+pub mod picking;
+/// Pinning components and fields to a compact HUD window
+pub mod pins;
+/// Display of `bevy_rapier3d` colliders
+#[cfg(feature = "rapier")]
+pub mod rapier;
+/// Panel for debugging the serialization of save games
+pub mod savegame;
+/// Writing the world or the subtrees of entities to a scene file
+pub mod scene_export;
+/// Spawning more instances of scenes and listing the entities instantiated from them
+pub mod scenes;
+/// The entities selected in the inspector, shared with game code
+pub mod selection;
+/// Axis handles for editing the [`Transform`](bevy_transform::components::Transform) of selected entities in a viewport
+#[cfg(feature = "bevy_render")]
+pub mod selection_gizmo;
+/// Stored component values, for seeing which fields changed since
+pub mod snapshots;
+/// Previews of the selected frames of texture atlases
+#[cfg(feature = "bevy_sprite")]
+pub mod sprites;
+/// Listing the systems of all schedules and disabling them at runtime
+pub mod systems;
+/// User-defined tags for organizing entities in the inspector
+pub mod tags;
+/// Task pool threads and the progress of named background tasks
+pub mod tasks;
+/// Pausing, single-stepping and slowing down the virtual clock
+pub mod time_controls;
+/// Debugging view of which entities a camera culls and why
+#[cfg(feature = "bevy_render")]
+pub mod visibility;
+/// Snapshots of entity, resource and asset counts for finding leaks
+pub mod world_stats;
+
+use crate::egui_utils;
+use crate::events::{self, InspectorEvent};
+use crate::i18n::InspectorI18n;
+use crate::reflect_inspector::{field_menu, highlight, Context, InspectorUi};
 use crate::restricted_world_view::RestrictedWorldView;
 
 /// Display a single [`&mut dyn Reflect`](bevy_reflect::Reflect).
@@ -78,8 +157,24 @@ pub fn ui_for_value(value: &mut dyn Reflect, ui: &mut egui::Ui, world: &mut Worl
     changed
 }
 
-/// Display `Entities`, `Resources` and `Assets` using their respective functions inside headers
+/// Display `Entities`, `Resources` and `Assets` using their respective functions inside headers.
+///
+/// `Ctrl`- and `Shift`-clicking entities adds them to the [`InspectorSelection`](selection::InspectorSelection).
+/// While more than one entity is selected, their shared components are displayed first for editing them all at once,
+/// see [`ui_for_entities_shared_components`]. "Export scene" writes the selected entities with their descendants, or the
+/// whole world if none are selected, to a scene file, see [`scene_export`].
 pub fn ui_for_world(world: &mut World, ui: &mut egui::Ui) {
+    let selected = world
+        .get_resource::<selection::InspectorSelection>()
+        .map(|selection| selection.as_slice().to_vec())
+        .unwrap_or_default();
+    if selected.len() > 1 {
+        egui::CollapsingHeader::new(format!("Selected entities ({})", selected.len()))
+            .default_open(true)
+            .show(ui, |ui| {
+                ui_for_entities_shared_components(world, &selected, ui);
+            });
+    }
     egui::CollapsingHeader::new("Entities")
         .default_open(true)
         .show(ui, |ui| {
@@ -91,6 +186,11 @@ pub fn ui_for_world(world: &mut World, ui: &mut egui::Ui) {
     egui::CollapsingHeader::new("Assets").show(ui, |ui| {
         ui_for_all_assets(world, ui);
     });
+    egui::CollapsingHeader::new("Export scene").show(ui, |ui| {
+        // the selected subtrees, or everything if nothing is selected
+        let roots = (!selected.is_empty()).then_some(&selected[..]);
+        scene_export::ui_for_scene_export(world, ui, roots);
+    });
 }
 
 /// Display all reflectable resources in the world
@@ -108,18 +208,32 @@ pub fn ui_for_resources(world: &mut World, ui: &mut egui::Ui) {
             )
         })
         .collect();
-    resources.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
-    for (name, type_id) in resources {
-        ui.collapsing(name, |ui| {
-            by_type_id::ui_for_resource(world, type_id, ui, name, &type_registry);
-        });
-    }
+    resources.sort_by_key(|(name, ..)| *name);
+    let id = egui::Id::new("world ui resources");
+    let is_expanded =
+        |ui: &egui::Ui, (name, _): &(&str, TypeId)| egui_utils::is_header_open(ui, name);
+    let i18n = InspectorI18n::get(ui.ctx());
+    egui_utils::lazy_rows(ui, id, &resources, is_expanded, |ui, &(name, type_id)| {
+        let type_path = type_registry
+            .get_type_info(type_id)
+            .map_or(name, |info| info.type_path());
+        egui::CollapsingHeader::new(&*i18n.type_label(type_path, name))
+            .id_source(name)
+            .show(ui, |ui| {
+                by_type_id::ui_for_resource(world, type_id, ui, name, &type_registry);
+            });
+    });
 }
 
 /// Display the resource `R`
 pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
+    let lock = type_registry
+        .get(TypeId::of::<R>())
+        .and_then(|registration| {
+            locks::path_lock_reason(world, registration.type_info().type_path())
+        });
 
     // create a context with access to the world except for the `R` resource
     let Some((mut resource, world_view)) =
@@ -135,8 +249,20 @@ pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
 
-    if env.ui_for_reflect(resource.bypass_change_detection(), ui) {
+    let id = ui.id().with(TypeId::of::<R>());
+    let changed = locks::ui_locked(ui, lock.as_deref(), |ui| {
+        let resource = resource.bypass_change_detection();
+        edit_mode::ui_for_edit_mode(ui, id, resource, &type_registry, |ui, resource| {
+            env.ui_for_reflect(resource, ui)
+        })
+    });
+    if changed {
         resource.set_changed();
+        let path = resource.reflect_type_path().to_owned();
+        events::send_deferred(
+            &mut queue,
+            InspectorEvent::ValueChanged { entity: None, path },
+        );
     }
 
     queue.apply(world);
@@ -157,12 +283,15 @@ pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
             )
         })
         .collect();
-    assets.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
-    for (name, type_id) in assets {
+    assets.sort_by_key(|(name, ..)| *name);
+    let id = egui::Id::new("world ui assets");
+    let is_expanded =
+        |ui: &egui::Ui, (name, _): &(&str, TypeId)| egui_utils::is_header_open(ui, name);
+    egui_utils::lazy_rows(ui, id, &assets, is_expanded, |ui, &(name, type_id)| {
         ui.collapsing(name, |ui| {
             by_type_id::ui_for_assets(world, type_id, ui, &type_registry);
         });
-    }
+    });
 }
 
 /// Display all assets of the specified asset type `A`
@@ -187,7 +316,7 @@ pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     };
 
     let mut assets: Vec<_> = assets.iter_mut().collect();
-    assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+    assets.sort_by_key(|(id, _)| *id);
     for (handle_id, asset) in assets {
         let id = egui::Id::new(handle_id);
 
@@ -240,14 +369,73 @@ pub fn ui_for_world_entities(world: &mut World, ui: &mut egui::Ui) {
     ui_for_world_entities_filtered::<Without<Parent>>(world, ui, true);
 }
 
+fn focus_id() -> egui::Id {
+    egui::Id::new("world ui focused entity")
+}
+
+/// Expand `entity` and its ancestors the next time the world entities are displayed using `ctx`, and scroll to it
+pub fn focus_entity(ctx: &egui::Context, entity: Entity) {
+    ctx.data_mut(|data| data.insert_temp(focus_id(), entity));
+}
+
+/// Take the entity passed to [`focus_entity`], together with its ancestors
+fn take_focus_path(world: &World, ui: &egui::Ui) -> Vec<Entity> {
+    let focused = ui.data_mut(|data| {
+        let focused = data.get_temp::<Entity>(focus_id());
+        data.remove::<Entity>(focus_id());
+        focused
+    });
+    let mut path: Vec<Entity> = focused.into_iter().collect();
+    while let Some(parent) = path.last().and_then(|&entity| world.get::<Parent>(entity)) {
+        path.push(parent.get());
+    }
+    path
+}
+
+/// Expand the header if it is on the path to the focused entity, and scroll to the focused entity itself
+fn focus_header(
+    header: egui::CollapsingHeader,
+    entity: Entity,
+    focus: &[Entity],
+) -> egui::CollapsingHeader {
+    header.open(focus.contains(&entity).then_some(true))
+}
+
+fn archetype_filter_id() -> egui::Id {
+    egui::Id::new("world ui filter archetype")
+}
+
+/// Only show the entities of `archetype` in the world entities displayed using `ctx`, or all entities for `None`.
+///
+/// Parents of matching entities are still shown, so that the matching entities can be expanded.
+pub fn filter_archetype(ctx: &egui::Context, archetype: Option<ArchetypeId>) {
+    ctx.data_mut(|data| match archetype {
+        Some(archetype) => data.insert_temp(archetype_filter_id(), archetype),
+        None => data.remove::<ArchetypeId>(archetype_filter_id()),
+    });
+}
+
+/// The archetype passed to [`filter_archetype`]
+pub fn archetype_filter(ctx: &egui::Context) -> Option<ArchetypeId> {
+    ctx.data(|data| data.get_temp::<ArchetypeId>(archetype_filter_id()))
+}
+
+fn scroll_to_focused(response: &egui::Response, entity: Entity, focus: &[Entity]) {
+    if focus.first() == Some(&entity) {
+        response.scroll_to_me(Some(egui::Align::Center));
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Filter {
     word: String,
     is_fuzzy: bool,
+    tag: Option<String>,
+    archetype: Option<ArchetypeId>,
 }
 
 impl Filter {
-    fn from_ui(ui: &mut egui::Ui) -> Self {
+    fn from_ui(world: &World, ui: &mut egui::Ui) -> Self {
         let word = {
             // filter, using eguis memory and a hardcoded id
             let filter_id = egui::Id::new("world ui filter word");
@@ -261,9 +449,7 @@ impl Filter {
             });
 
             // improves overall matching
-            let filter = filter.to_lowercase();
-
-            filter
+            filter.to_lowercase()
         };
 
         // filter kind
@@ -271,16 +457,37 @@ impl Filter {
             let filter_kind_id = egui::Id::new("world ui filter fuzzy");
             let mut is_fuzzy = ui.memory_mut(|mem| {
                 let fuzzy: &mut bool = mem.data.get_persisted_mut_or_default(filter_kind_id);
-                fuzzy.clone()
+                *fuzzy
             });
             ui.checkbox(&mut is_fuzzy, "Fuzzy Match");
             ui.memory_mut(|mem| {
-                *mem.data.get_persisted_mut_or_default(filter_kind_id) = is_fuzzy.clone();
+                *mem.data.get_persisted_mut_or_default(filter_kind_id) = is_fuzzy;
             });
             is_fuzzy
         };
 
-        Filter { word, is_fuzzy }
+        let tag = tags::ui_for_tag_filter(world, ui, egui::Id::new("world ui filter tag"));
+
+        let archetype = archetype_filter(ui.ctx());
+        if let Some(archetype) = archetype {
+            ui.horizontal(|ui| {
+                ui.label(format!("Archetype {}", archetype.index()));
+                if ui
+                    .small_button("✖")
+                    .on_hover_text("Show all archetypes")
+                    .clicked()
+                {
+                    filter_archetype(ui.ctx(), None);
+                }
+            });
+        }
+
+        Filter {
+            word,
+            is_fuzzy,
+            tag,
+            archetype,
+        }
     }
 
     /// empty filter which does nothing
@@ -288,18 +495,18 @@ impl Filter {
         Self {
             word: String::from(""),
             is_fuzzy: false,
+            tag: None,
+            archetype: None,
         }
     }
 
     /// filter entities based on internal state
     fn filter_entities(&self, world: &mut World, entities: &mut Vec<Entity>) {
-        if self.word.is_empty() {
+        if self.word.is_empty() && self.tag.is_none() && self.archetype.is_none() {
             return;
         }
 
-        entities.retain(|entity| {
-            self_or_children_satisfy_filter(world, *entity, self.word.as_str(), self.is_fuzzy)
-        });
+        entities.retain(|entity| self_or_children_satisfy_filter(world, *entity, self));
     }
 }
 
@@ -309,64 +516,180 @@ pub fn ui_for_world_entities_filtered<F: WorldQuery + QueryFilter>(
     ui: &mut egui::Ui,
     with_children: bool,
 ) {
-    let filter = Filter::from_ui(ui);
+    let filter = Filter::from_ui(world, ui);
+    params::ui_for_entity_sort(world, ui);
+    let focus = take_focus_path(world, ui);
 
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
     let mut root_entities = world.query_filtered::<Entity, F>();
     let mut entities = root_entities.iter(world).collect::<Vec<_>>();
+    params::retain_shown_entities(world, &mut entities);
+    filter.filter_entities(world, &mut entities);
+    params::sort_entities(world, &mut entities);
+
+    let rows = EntityRows {
+        id: egui::Id::new("world ui"),
+        type_registry: &type_registry,
+        filter: &filter,
+        focus: &focus,
+        with_children,
+        access: None,
+    };
+    rows.show(world, ui, &entities);
+}
+
+/// Display all entities matching the query data `Q` and the filter `F`, with only the components accessed by `Q`.
+///
+/// Components which `Q` only reads are shown read-only, so e.g. `ui_for_query::<(&mut Transform, &Name), With<Player>>`
+/// displays the editable transforms of all players next to their names, without the rest of their components:
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_core::Name;
+/// # use bevy_transform::prelude::Transform;
+/// use bevy_inspector_egui::bevy_inspector;
+///
+/// # #[derive(Component)] struct Player;
+/// fn ui(world: &mut World, ui: &mut egui::Ui) {
+///     bevy_inspector::ui_for_query::<(&mut Transform, &Name), With<Player>>(world, ui);
+/// }
+/// ```
+pub fn ui_for_query<Q: QueryData, F: QueryFilter>(world: &mut World, ui: &mut egui::Ui) {
+    let filter = Filter::from_ui(world, ui);
+    params::ui_for_entity_sort(world, ui);
+    let focus = take_focus_path(world, ui);
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let access = query_access::<Q>(world);
+    let mut query = world.query_filtered::<(Entity, Q), F>();
+    let mut entities: Vec<Entity> = query.iter_mut(world).map(|(entity, _)| entity).collect();
+    params::retain_shown_entities(world, &mut entities);
     filter.filter_entities(world, &mut entities);
-    entities.sort();
+    params::sort_entities(world, &mut entities);
+
+    let rows = EntityRows {
+        id: egui::Id::new("query ui").with(std::any::type_name::<(Q, F)>()),
+        type_registry: &type_registry,
+        filter: &filter,
+        focus: &focus,
+        with_children: false,
+        access: Some(&access),
+    };
+    rows.show(world, ui, &entities);
+}
 
-    let id = egui::Id::new("world ui");
-    for entity in entities {
-        let id = id.with(entity);
+/// The components read or written by the query data `Q`
+fn query_access<Q: QueryData>(world: &mut World) -> Access<ComponentId> {
+    let state = Q::init_state(world);
+    let mut access = FilteredAccess::default();
+    Q::update_component_access(&state, &mut access);
+    access.access().clone()
+}
 
-        let entity_name = guess_entity_name(world, entity);
+/// The collapsible rows of a list of entities
+struct EntityRows<'a> {
+    id: egui::Id,
+    type_registry: &'a TypeRegistry,
+    filter: &'a Filter,
+    focus: &'a [Entity],
+    with_children: bool,
+    /// Only show these components, with the ones which aren't written being read-only
+    access: Option<&'a Access<ComponentId>>,
+}
 
-        egui::CollapsingHeader::new(&entity_name)
-            .id_source(id)
-            .show(ui, |ui| {
+impl EntityRows<'_> {
+    fn show(&self, world: &mut World, ui: &mut egui::Ui, entities: &[Entity]) {
+        let EntityRows {
+            id,
+            type_registry,
+            filter,
+            focus,
+            with_children,
+            access,
+        } = *self;
+        let is_expanded = |ui: &egui::Ui, &entity: &Entity| {
+            focus.contains(&entity) || egui_utils::is_header_open(ui, id.with(entity))
+        };
+        egui_utils::lazy_rows(ui, id.with("rows"), entities, is_expanded, |ui, &entity| {
+            let id = id.with(entity);
+
+            let entity_name = guess_entity_name(world, entity);
+            let selected = selection::is_selected(world, entity);
+            let label = tags::entity_label(ui, world, entity, &entity_name, selected);
+
+            let header = egui::CollapsingHeader::new(label).id_source(id);
+            let response = focus_header(header, entity, focus).show(ui, |ui| {
                 if with_children {
                     ui_for_entity_with_children_inner(
                         world,
                         entity,
                         ui,
                         id,
-                        &type_registry,
-                        &filter,
+                        type_registry,
+                        filter,
+                        focus,
                     );
                 } else {
                     let mut queue = CommandQueue::default();
-                    ui_for_entity_components(
+                    ui_for_entity_components_with_access(
                         &mut world.into(),
                         Some(&mut queue),
                         entity,
                         ui,
                         id,
-                        &type_registry,
+                        type_registry,
+                        access,
                     );
                     queue.apply(world);
                 }
             });
+            if response.header_response.clicked() {
+                let modifiers = ui.input(|input| input.modifiers);
+                if modifiers.ctrl || modifiers.shift {
+                    // clicks with modifiers select the entity instead of expanding it
+                    let header_id = response.header_response.id;
+                    if let Some(mut state) =
+                        egui::collapsing_header::CollapsingState::load(ui.ctx(), header_id)
+                    {
+                        state.toggle(ui);
+                        state.store(ui.ctx());
+                    }
+                    let mode =
+                        hierarchy::SelectionMode::from_ctrl_shift(modifiers.ctrl, modifiers.shift);
+                    selection::select_clicked(world, entity, mode, entities);
+                }
+            }
+            response.header_response.context_menu(|ui| {
+                ui_for_entity_context_menu(world, ui, entity);
+            });
+            scroll_to_focused(&response.header_response, entity, focus);
+        });
     }
 }
 
-fn self_or_children_satisfy_filter(
-    world: &mut World,
-    entity: Entity,
-    filter: &str,
-    is_fuzzy: bool,
-) -> bool {
+fn self_or_children_satisfy_filter(world: &mut World, entity: Entity, filter: &Filter) -> bool {
     let name = guess_entity_name(world, entity);
-    let self_matches = if is_fuzzy {
+    let name_matches = if filter.is_fuzzy {
         let matcher = SkimMatcherV2::default();
-        matcher.fuzzy_match(name.as_str(), filter).is_some()
+        matcher.fuzzy_match(name.as_str(), &filter.word).is_some()
     } else {
-        name.to_lowercase().contains(&filter)
+        name.to_lowercase().contains(&filter.word)
     };
-    self_matches || {
+    let tag_matches = filter.tag.as_ref().map_or(true, |tag| {
+        world
+            .get_resource::<tags::EntityTags>()
+            .is_some_and(|tags| tags.has(entity, tag))
+    });
+    let archetype_matches = filter.archetype.map_or(true, |archetype| {
+        world
+            .entities()
+            .get(entity)
+            .is_some_and(|location| location.archetype_id == archetype)
+    });
+    (name_matches && tag_matches && archetype_matches) || {
         world
             .query::<&Children>()
             .get(world, entity)
@@ -374,11 +697,45 @@ fn self_or_children_satisfy_filter(
             .is_ok_and(|children| {
                 children
                     .iter()
-                    .any(|child| self_or_children_satisfy_filter(world, *child, filter, is_fuzzy))
+                    .any(|child| self_or_children_satisfy_filter(world, *child, filter))
             })
     }
 }
 
+/// Selection toggle and tags of the entity, and the "Despawn" button if enabled in the [`WorldInspectorParams`](params::WorldInspectorParams)
+fn ui_for_entity_context_menu(world: &mut World, ui: &mut egui::Ui, entity: Entity) {
+    selection::ui_for_selection_toggle(world, ui, entity);
+    ui.separator();
+    tags::ui_for_entity_tags(world, ui, entity);
+    ui.separator();
+    if ui
+        .button("Snapshot components")
+        .on_hover_text("Store the current component values, to see which fields change from now on")
+        .clicked()
+    {
+        snapshots::store_entity_snapshot(ui.ctx(), world, entity);
+        ui.close_menu();
+    }
+    ui.menu_button("Export scene", |ui| {
+        scene_export::ui_for_scene_export(world, ui, Some(&[entity]));
+    });
+
+    let despawnable = world
+        .get_resource::<params::WorldInspectorParams>()
+        .is_some_and(|params| params.despawnable_entities);
+    if despawnable {
+        ui.separator();
+        if ui.button("Despawn").clicked() {
+            commands::push(world, "Despawn", Some(entity), move |world| {
+                if let Some(entity) = world.get_entity_mut(entity) {
+                    entity.despawn_recursive();
+                }
+            });
+            ui.close_menu();
+        }
+    }
+}
+
 /// Display the given entity with all its components and children
 pub fn ui_for_entity_with_children(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
@@ -394,6 +751,7 @@ pub fn ui_for_entity_with_children(world: &mut World, entity: Entity, ui: &mut e
         egui::Id::new(entity),
         &type_registry,
         &Filter::empty(),
+        &[],
     )
 }
 
@@ -404,6 +762,7 @@ fn ui_for_entity_with_children_inner(
     id: egui::Id,
     type_registry: &TypeRegistry,
     filter: &Filter,
+    focus: &[Entity],
 ) {
     let mut queue = CommandQueue::default();
     ui_for_entity_components(
@@ -420,27 +779,37 @@ fn ui_for_entity_with_children_inner(
         .map(|children| children.iter().copied().collect::<Vec<_>>());
     if let Some(mut children) = children {
         if !children.is_empty() {
+            params::retain_shown_entities(world, &mut children);
             filter.filter_entities(world, &mut children);
             ui.label("Children");
-            for &child in children.iter() {
+            let is_expanded = |ui: &egui::Ui, &child: &Entity| {
+                focus.contains(&child) || egui_utils::is_header_open(ui, id.with(child))
+            };
+            egui_utils::lazy_rows(ui, id.with("rows"), &children, is_expanded, |ui, &child| {
                 let id = id.with(child);
 
                 let child_entity_name = guess_entity_name(world, child);
-                egui::CollapsingHeader::new(&child_entity_name)
-                    .id_source(id)
-                    .show(ui, |ui| {
-                        ui.label(&child_entity_name);
-
-                        ui_for_entity_with_children_inner(
-                            world,
-                            child,
-                            ui,
-                            id,
-                            type_registry,
-                            filter,
-                        );
-                    });
-            }
+                let selected = selection::is_selected(world, child);
+                let label = tags::entity_label(ui, world, child, &child_entity_name, selected);
+                let header = egui::CollapsingHeader::new(label).id_source(id);
+                let response = focus_header(header, child, focus).show(ui, |ui| {
+                    ui.label(&child_entity_name);
+
+                    ui_for_entity_with_children_inner(
+                        world,
+                        child,
+                        ui,
+                        id,
+                        type_registry,
+                        filter,
+                        focus,
+                    );
+                });
+                response.header_response.context_menu(|ui| {
+                    ui_for_entity_context_menu(world, ui, child);
+                });
+                scroll_to_focused(&response.header_response, child, focus);
+            });
         }
     }
 
@@ -469,22 +838,55 @@ pub fn ui_for_entity(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
 
 /// Display the components of the given entity
 pub(crate) fn ui_for_entity_components(
+    world: &mut RestrictedWorldView<'_>,
+    queue: Option<&mut CommandQueue>,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) {
+    ui_for_entity_components_with_access(world, queue, entity, ui, id, type_registry, None);
+}
+
+/// Display the components of the given entity, or only the ones in `access` if set, with the ones which aren't written being read-only
+fn ui_for_entity_components_with_access(
     world: &mut RestrictedWorldView<'_>,
     mut queue: Option<&mut CommandQueue>,
     entity: Entity,
     ui: &mut egui::Ui,
     id: egui::Id,
     type_registry: &TypeRegistry,
+    access: Option<&Access<ComponentId>>,
 ) {
-    let Some(components) = components_of_entity(world, entity) else {
+    let Some(mut components) = components_of_entity(world, entity) else {
         errors::entity_does_not_exist(ui, entity);
         return;
     };
-
+    if let Some(access) = access {
+        components.retain(|&(_, component_id, ..)| access.has_read(component_id));
+    }
+    let mut highlight_changed_fields = false;
+    if let Ok(params) = world.get_resource_mut::<params::WorldInspectorParams>() {
+        components.retain(|(.., type_id, _)| {
+            !type_id.is_some_and(|type_id| params.is_component_ignored(type_id))
+        });
+        highlight_changed_fields = params.highlight_changed_fields;
+    }
+    let actions = world
+        .get_resource_mut::<component_actions::ComponentActions>()
+        .map(|actions| actions.clone())
+        .unwrap_or_default();
+    let locks = world
+        .get_resource_mut::<locks::InspectorLocks>()
+        .map(|locks| locks.clone())
+        .unwrap_or_default();
+
+    let i18n = InspectorI18n::get(ui.ctx());
     for (name, component_id, component_type_id, size) in components {
         let id = id.with(component_id);
 
-        let header = egui::CollapsingHeader::new(&name).id_source(id);
+        let label = component_label(world.world().components(), &i18n, component_id, &name);
+        let header = egui::CollapsingHeader::new(label).id_source(id);
 
         let Some(component_type_id) = component_type_id else {
             header.show(ui, |ui| errors::no_type_id(ui, &name));
@@ -504,6 +906,14 @@ pub(crate) fn ui_for_entity_components(
             queue: queue.as_deref_mut(),
         };
 
+        #[cfg(feature = "rapier")]
+        if let Ok(collider) =
+            component_view.get_entity_component::<bevy_rapier3d::geometry::Collider>(entity)
+        {
+            header.show(ui, |ui| rapier::ui_for_collider(ui, id, collider));
+            continue;
+        }
+
         let (value, is_changed, set_changed) = match component_view.get_entity_component_reflect(
             entity,
             component_type_id,
@@ -521,23 +931,174 @@ pub(crate) fn ui_for_entity_components(
             set_highlight_style(ui);
         }
 
-        header.show(ui, |ui| {
+        let is_read_only = access.is_some_and(|access| !access.has_write(component_id));
+        let lock = locks
+            .path_lock(value.reflect_type_path())
+            .or(is_read_only.then_some("The query only reads this component"));
+        let mut changed = false;
+        let response = header.show(ui, |ui| {
             ui.reset_style();
 
-            let inspector_changed = InspectorUi::for_bevy(type_registry, &mut cx)
-                .ui_for_reflect_with_options(value, ui, id.with(component_id), &());
-
-            if inspector_changed {
-                set_changed();
+            snapshots::ui_for_snapshot_diff(ui, entity, component_type_id, value);
+            locks::ui_locked(ui, lock, |ui| {
+                let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                let type_path = value.reflect_type_path().to_owned();
+                let pin_field = move |ui: &mut egui::Ui, field: &str| {
+                    let pin = pins::Pin {
+                        entity,
+                        component: component_type_id,
+                        field: Some(field.to_owned()),
+                    };
+                    pins::ui_for_pin_toggle(ui, &pin);
+                };
+                changed |= highlight::with_changed_fields_highlighted(
+                    ui,
+                    highlight_changed_fields,
+                    |ui| {
+                        field_menu::with_field_menu(ui, type_path, pin_field, |ui| {
+                            edit_mode::ui_for_edit_mode(
+                                ui,
+                                id,
+                                value,
+                                type_registry,
+                                |ui, value| {
+                                    env.ui_for_reflect_with_options(
+                                        value,
+                                        ui,
+                                        id.with(component_id),
+                                        &(),
+                                    )
+                                },
+                            )
+                        })
+                    },
+                );
+            });
+            #[cfg(feature = "bevy_sprite")]
+            if let (Some(atlas), Some(world)) = (
+                value.downcast_ref::<bevy_sprite::TextureAtlas>(),
+                cx.world.as_mut(),
+            ) {
+                sprites::ui_for_atlas_preview(ui, world, entity, atlas, type_registry);
+            }
+            if let (true, Some(world)) = (
+                value.is::<bevy_asset::Handle<bevy_scene::Scene>>(),
+                cx.world.as_mut(),
+            ) {
+                scenes::ui_for_scene_instance(ui, world, entity);
+            }
+            #[cfg(feature = "bevy_audio")]
+            if let (true, Some(world)) = (
+                value.is::<bevy_asset::Handle<bevy_audio::AudioSource>>(),
+                cx.world.as_ref(),
+            ) {
+                audio::ui_for_audio_sink(ui, world, entity);
             }
         });
         ui.reset_style();
+        response.header_response.context_menu(|ui| {
+            locks::ui_locked(ui, lock, |ui| {
+                // pasted values are applied as a command, together with other structural changes
+                let mut pasted = value.clone_value();
+                let is_pasted = crate::reflect_inspector::clipboard::ui_for_clipboard_menu(
+                    ui,
+                    id,
+                    pasted.as_mut(),
+                    type_registry,
+                );
+                if let (true, Some(queue)) = (is_pasted, queue.as_deref_mut()) {
+                    queue_apply(queue, "Paste", entity, component_type_id, pasted);
+                }
+                if let Some(default) =
+                    crate::reflect_inspector::reset::value_default(value, type_registry)
+                {
+                    let is_default = value.reflect_partial_eq(default.as_ref()) == Some(true);
+                    let reset = ui
+                        .add_enabled(
+                            !is_default && queue.is_some(),
+                            egui::Button::new("Reset all"),
+                        )
+                        .on_hover_text("Reset every field to its default")
+                        .clicked();
+                    if let (true, Some(queue)) = (reset, queue.as_deref_mut()) {
+                        queue_apply(queue, "Reset", entity, component_type_id, default);
+                        ui.close_menu();
+                    }
+                }
+                snapshots::ui_for_snapshot_menu(ui, entity, component_type_id, value);
+                component_actions::ui_for_component_actions(
+                    ui,
+                    actions.actions(component_type_id),
+                    entity,
+                    queue.as_deref_mut(),
+                );
+            });
+            let pin = pins::Pin {
+                entity,
+                component: component_type_id,
+                field: None,
+            };
+            pins::ui_for_pin_toggle(ui, &pin);
+        });
+
+        if changed {
+            set_changed();
+            if let Some(queue) = queue.as_deref_mut() {
+                let path = value.reflect_type_path().to_owned();
+                let event = InspectorEvent::ValueChanged {
+                    entity: Some(entity),
+                    path,
+                };
+                events::send_deferred(queue, event);
+            }
+        }
+    }
+
+    if let Ok(history) = world.get_resource_mut::<history::ComponentHistory>() {
+        history::ui_for_entity_history(ui, &history, entity, id, type_registry);
     }
 }
 
+/// Apply `new` to the component with the type id `component_type_id` of `entity` as an [`InspectorCommand`](commands::InspectorCommand) called `name`
+fn queue_apply(
+    queue: &mut CommandQueue,
+    name: &'static str,
+    entity: Entity,
+    component_type_id: TypeId,
+    new: Box<dyn Reflect>,
+) {
+    commands::push_deferred(queue, name, Some(entity), move |world| {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let Some(reflect_component) =
+            type_registry.get_type_data::<ReflectComponent>(component_type_id)
+        else {
+            return;
+        };
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut component) = reflect_component.reflect_mut(&mut entity_mut) else {
+            return;
+        };
+        let path = new.reflect_type_path().to_owned();
+        crate::reflect_inspector::reset::set(component.as_reflect_mut(), new);
+
+        events::send(
+            world,
+            InspectorEvent::ValueChanged {
+                entity: Some(entity),
+                path,
+            },
+        );
+    });
+}
+
 #[cfg(feature = "highlight_changes")]
 fn set_highlight_style(ui: &mut egui::Ui) {
-    let highlight_color = egui::Color32::GOLD;
+    let highlight_color = crate::theme::InspectorTheme::get(ui.ctx())
+        .palette(ui.visuals().dark_mode)
+        .highlight;
 
     let visuals = &mut ui.style_mut().visuals;
     visuals.collapsing_header_frame = true;
@@ -559,6 +1120,19 @@ fn set_highlight_style(ui: &mut egui::Ui) {
     };
 }
 
+/// The [`InspectorI18n`] label of the component displayed as `name`
+fn component_label(
+    components: &Components,
+    i18n: &InspectorI18n,
+    component_id: ComponentId,
+    name: &str,
+) -> String {
+    let type_path = components
+        .get_info(component_id)
+        .map_or(name, |info| info.name());
+    i18n.type_label(type_path, name).into_owned()
+}
+
 fn components_of_entity(
     world: &mut RestrictedWorldView<'_>,
     entity: Entity,
@@ -612,10 +1186,18 @@ pub fn ui_for_entities_shared_components(
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
 
+    let mut changed_paths = Vec::new();
     let id = egui::Id::NULL;
+    let i18n = InspectorI18n::get(ui.ctx());
     for (name, component_id, component_type_id, size) in components {
         let id = id.with(component_id);
-        egui::CollapsingHeader::new(&name)
+        let label = component_label(
+            components_view.world().components(),
+            &i18n,
+            component_id,
+            &name,
+        );
+        egui::CollapsingHeader::new(label)
             .id_source(id)
             .show(ui, |ui| {
                 if size == 0 {
@@ -664,10 +1246,22 @@ pub fn ui_for_entities_shared_components(
                 );
                 if changed {
                     mark_changeds.into_iter().for_each(|f| f());
+                    changed_paths.push(values[0].reflect_type_path().to_owned());
                 }
             });
     }
 
+    for path in changed_paths {
+        for &entity in entities {
+            let path = path.clone();
+            let event = InspectorEvent::ValueChanged {
+                entity: Some(entity),
+                path,
+            };
+            events::send_deferred(&mut queue, event);
+        }
+    }
+
     queue.apply(world);
 }
 
@@ -679,13 +1273,15 @@ pub mod by_type_id {
     use bevy_reflect::TypeRegistry;
 
     use crate::{
+        events::{self, InspectorEvent},
         reflect_inspector::{Context, InspectorUi},
         restricted_world_view::RestrictedWorldView,
     };
 
     use super::{
+        edit_mode,
         errors::{self, name_of_type},
-        handle_name,
+        handle_name, locks,
     };
 
     /// Display the resource with the given [`TypeId`]
@@ -696,6 +1292,11 @@ pub mod by_type_id {
         name_of_type: &str,
         type_registry: &TypeRegistry,
     ) {
+        let lock = type_registry
+            .get(resource_type_id)
+            .and_then(|registration| {
+                locks::path_lock_reason(world, registration.type_info().type_path())
+            });
         let mut queue = CommandQueue::default();
 
         {
@@ -715,9 +1316,19 @@ pub mod by_type_id {
                 Err(err) => return errors::show_error(err, ui, name_of_type),
             };
 
-            let changed = env.ui_for_reflect(resource, ui);
+            let id = ui.id().with(resource_type_id);
+            let changed = locks::ui_locked(ui, lock.as_deref(), |ui| {
+                edit_mode::ui_for_edit_mode(ui, id, resource, type_registry, |ui, resource| {
+                    env.ui_for_reflect(resource, ui)
+                })
+            });
             if changed {
                 set_changed();
+                let path = resource.reflect_type_path().to_owned();
+                events::send_deferred(
+                    &mut queue,
+                    InspectorEvent::ValueChanged { entity: None, path },
+                );
             }
         }
 
@@ -817,6 +1428,7 @@ pub mod by_type_id {
         };
 
         let _: Vec<_> = reflect_asset.ids(world).collect();
+        let lock = locks::path_lock_reason(world, registration.type_info().type_path());
 
         // Create a context with access to the entire world. Displaying the `Handle<T>` will short circuit into
         // displaying the T with a world view excluding Assets<T>.
@@ -831,7 +1443,16 @@ pub mod by_type_id {
         let mut handle = reflect_handle.typed(UntypedHandle::Weak(handle));
 
         let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
-        let changed = env.ui_for_reflect_with_options(&mut *handle, ui, id, &());
+        let changed = locks::ui_locked(ui, lock.as_deref(), |ui| {
+            env.ui_for_reflect_with_options(&mut *handle, ui, id, &())
+        });
+        if changed {
+            let path = registration.type_info().type_path().to_owned();
+            events::send_deferred(
+                &mut queue,
+                InspectorEvent::ValueChanged { entity: None, path },
+            );
+        }
 
         queue.apply(world);
 
@@ -839,7 +1460,7 @@ pub mod by_type_id {
     }
 }
 
-fn handle_name(handle: UntypedAssetId, asset_server: Option<&AssetServer>) -> String {
+pub(crate) fn handle_name(handle: UntypedAssetId, asset_server: Option<&AssetServer>) -> String {
     if let Some(path) = asset_server
         .as_ref()
         .and_then(|server| server.get_path(handle))
@@ -877,12 +1498,15 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
 pub mod short_circuit {
     use std::any::{Any, TypeId};
 
-    use bevy_asset::ReflectAsset;
+    use bevy_asset::{ReflectAsset, ReflectHandle};
     use bevy_reflect::Reflect;
 
     use crate::reflect_inspector::{Context, InspectorUi};
 
-    use super::errors::{self, name_of_type};
+    use super::{
+        dnd,
+        errors::{self, name_of_type},
+    };
 
     pub fn short_circuit(
         env: &mut InspectorUi,
@@ -895,69 +1519,83 @@ pub mod short_circuit {
             .type_registry
             .get_type_data::<bevy_asset::ReflectHandle>(Any::type_id(value))
         {
-            let handle = reflect_handle
-                .downcast_handle_untyped(value.as_any())
-                .unwrap();
-            let handle_id = handle.id();
-            let Some(reflect_asset) = env
-                .type_registry
-                .get_type_data::<ReflectAsset>(reflect_handle.asset_type_id())
-            else {
-                errors::no_type_data(
-                    ui,
-                    &name_of_type(reflect_handle.asset_type_id(), env.type_registry),
-                    "ReflectAsset",
-                );
-                return Some(false);
-            };
+            let type_id = reflect_handle.asset_type_id();
+            let egui::InnerResponse {
+                inner: changed,
+                response,
+            } = ui.scope(|ui| ui_for_handle(env, reflect_handle, value, ui, id, options));
+            if let Some(dropped) = dnd::dropped_asset(ui, &response, type_id) {
+                value.apply(&*reflect_handle.typed(dropped));
+                return Some(true);
+            }
+            return Some(changed);
+        }
 
-            let Context {
-                world: Some(world),
-                queue,
-            } = &mut env.context
-            else {
-                errors::no_world_in_context(ui, value.reflect_short_type_path());
-                return Some(false);
-            };
+        None
+    }
 
-            let (assets_view, world) =
-                world.split_off_resource(reflect_asset.assets_resource_type_id());
+    /// Display the asset of the handle `value`, which can also be replaced by dropping another asset onto it
+    fn ui_for_handle(
+        env: &mut InspectorUi,
+        reflect_handle: &ReflectHandle,
+        value: &mut dyn Reflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) -> bool {
+        let handle = reflect_handle
+            .downcast_handle_untyped(value.as_any())
+            .unwrap();
+        let handle_id = handle.id();
+        let Some(reflect_asset) = env
+            .type_registry
+            .get_type_data::<ReflectAsset>(reflect_handle.asset_type_id())
+        else {
+            errors::no_type_data(
+                ui,
+                &name_of_type(reflect_handle.asset_type_id(), env.type_registry),
+                "ReflectAsset",
+            );
+            return false;
+        };
 
-            let asset_value = {
-                assert!(
-                    assets_view.allows_access_to_resource(reflect_asset.assets_resource_type_id())
-                );
-                let asset_value =
-                // SAFETY: the world allows mutable access to `Assets<T>`
-                unsafe { reflect_asset.get_unchecked_mut(world.world(), handle) };
-                match asset_value {
-                    Some(value) => value,
-                    None => {
-                        errors::dead_asset_handle(ui, handle_id);
-                        return Some(false);
-                    }
-                }
-            };
+        let Context {
+            world: Some(world),
+            queue,
+        } = &mut env.context
+        else {
+            errors::no_world_in_context(ui, value.reflect_short_type_path());
+            return false;
+        };
 
-            let mut restricted_env = InspectorUi {
-                type_registry: env.type_registry,
-                context: &mut Context {
-                    world: Some(world),
-                    queue: queue.as_deref_mut(),
-                },
-                short_circuit: env.short_circuit,
-                short_circuit_readonly: env.short_circuit_readonly,
-                short_circuit_many: env.short_circuit_many,
-            };
-            return Some(restricted_env.ui_for_reflect_with_options(
-                asset_value,
-                ui,
-                id.with("asset"),
-                options,
-            ));
-        }
+        let (assets_view, world) =
+            world.split_off_resource(reflect_asset.assets_resource_type_id());
+
+        let asset_value = {
+            assert!(assets_view.allows_access_to_resource(reflect_asset.assets_resource_type_id()));
+            let asset_value =
+            // SAFETY: the world allows mutable access to `Assets<T>`
+            unsafe { reflect_asset.get_unchecked_mut(world.world(), handle) };
+            match asset_value {
+                Some(value) => value,
+                None => {
+                    errors::dead_asset_handle(ui, handle_id);
+                    return false;
+                }
+            }
+        };
 
-        None
+        let mut restricted_env = InspectorUi {
+            type_registry: env.type_registry,
+            context: &mut Context {
+                world: Some(world),
+                queue: queue.as_deref_mut(),
+            },
+            short_circuit: env.short_circuit,
+            short_circuit_readonly: env.short_circuit_readonly,
+            short_circuit_many: env.short_circuit_many,
+        };
+        restricted_env.ui_for_reflect_with_options(asset_value, ui, id.with("asset"), options)
     }
 
     pub fn short_circuit_many(
@@ -1134,3 +1772,23 @@ pub mod short_circuit {
 }
 
 pub use crate::utils::guess_entity_name::guess_entity_name;
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_transform::prelude::Transform;
+
+    use super::query_access;
+
+    #[test]
+    fn query_access_marks_read_only_components() {
+        let mut world = World::new();
+        let access = query_access::<(Entity, &mut Transform, Option<&Name>)>(&mut world);
+        let transform = world.component_id::<Transform>().unwrap();
+        let name = world.component_id::<Name>().unwrap();
+
+        assert!(access.has_write(transform));
+        assert!(access.has_read(name) && !access.has_write(name));
+    }
+}
@@ -0,0 +1,223 @@
+use std::any::TypeId;
+
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use bevy_utils::HashSet;
+
+use crate::utils::guess_entity_name::guess_entity_name;
+
+/// Configures which entities and components the world inspector shows.
+///
+/// Insert or modify this resource to hide noisy internal components or entities from the entity tree:
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::bevy_inspector::params::{EntitySort, WorldInspectorParams};
+///
+/// # #[derive(Component)] struct RenderCache;
+/// fn configure(mut params: ResMut<WorldInspectorParams>) {
+///     params
+///         .ignore_component::<RenderCache>()
+///         .ignore_entities_named("Debug *");
+///     params.despawnable_entities = true;
+///     params.sort = EntitySort::Name;
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone)]
+pub struct WorldInspectorParams {
+    ignored_components: HashSet<TypeId>,
+    ignored_names: Vec<String>,
+    /// Show a "Despawn" entry in the context menu of entities
+    pub despawnable_entities: bool,
+    /// Order of the top-level entities. Children are listed in the order of their [`Children`](bevy_hierarchy::Children).
+    pub sort: EntitySort,
+    /// Tint the labels of component fields whose value changed since the previous frame,
+    /// see [`highlight`](crate::reflect_inspector::highlight)
+    pub highlight_changed_fields: bool,
+}
+
+/// Order of the entities in the world inspector, see [`WorldInspectorParams::sort`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persistence", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntitySort {
+    #[default]
+    Id,
+    /// Alphabetically by [`Name`], followed by the unnamed entities
+    Name,
+    /// Grouped by archetype, so entities with the same components are next to each other
+    Archetype,
+    /// Entities with the most components first
+    ComponentCount,
+}
+
+impl EntitySort {
+    pub const ALL: [EntitySort; 4] = [
+        EntitySort::Id,
+        EntitySort::Name,
+        EntitySort::Archetype,
+        EntitySort::ComponentCount,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EntitySort::Id => "Entity id",
+            EntitySort::Name => "Name",
+            EntitySort::Archetype => "Archetype",
+            EntitySort::ComponentCount => "Component count",
+        }
+    }
+
+    /// Sort `entities` in this order, using the entity id to break ties
+    pub fn sort(self, world: &World, entities: &mut [Entity]) {
+        match self {
+            EntitySort::Id => entities.sort(),
+            EntitySort::Name => entities.sort_by_cached_key(|&entity| {
+                let name = world
+                    .get::<Name>(entity)
+                    .map(|name| name.as_str().to_owned());
+                (name.is_none(), name, entity)
+            }),
+            EntitySort::Archetype => entities.sort_by_cached_key(|&entity| {
+                let archetype = world
+                    .entities()
+                    .get(entity)
+                    .map(|location| location.archetype_id.index());
+                (archetype, entity)
+            }),
+            EntitySort::ComponentCount => entities.sort_by_cached_key(|&entity| {
+                let count = world
+                    .get_entity(entity)
+                    .map_or(0, |entity| entity.archetype().components().count());
+                (std::cmp::Reverse(count), entity)
+            }),
+        }
+    }
+}
+
+impl WorldInspectorParams {
+    /// Don't show the component `T` in the list of components of an entity
+    pub fn ignore_component<T: 'static>(&mut self) -> &mut Self {
+        self.ignored_components.insert(TypeId::of::<T>());
+        self
+    }
+
+    pub fn unignore_component<T: 'static>(&mut self) -> &mut Self {
+        self.ignored_components.remove(&TypeId::of::<T>());
+        self
+    }
+
+    pub fn is_component_ignored(&self, type_id: TypeId) -> bool {
+        self.ignored_components.contains(&type_id)
+    }
+
+    /// Don't show entities whose name matches `pattern`, where `*` matches any sequence of characters.
+    ///
+    /// Children of hidden entities are hidden as well.
+    pub fn ignore_entities_named(&mut self, pattern: impl Into<String>) -> &mut Self {
+        self.ignored_names.push(pattern.into());
+        self
+    }
+
+    pub fn ignored_names(&self) -> &[String] {
+        &self.ignored_names
+    }
+
+    pub fn is_name_ignored(&self, name: &str) -> bool {
+        self.ignored_names
+            .iter()
+            .any(|pattern| matches_pattern(pattern, name))
+    }
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any sequence of characters
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // no wildcard
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Sort `entities` as configured in [`WorldInspectorParams::sort`]
+pub(crate) fn sort_entities(world: &World, entities: &mut [Entity]) {
+    let sort = world
+        .get_resource::<WorldInspectorParams>()
+        .map_or_else(EntitySort::default, |params| params.sort);
+    sort.sort(world, entities);
+}
+
+/// Display a combo box for choosing the [`WorldInspectorParams::sort`] order
+pub fn ui_for_entity_sort(world: &mut World, ui: &mut egui::Ui) {
+    let mut params = world.get_resource_or_insert_with(WorldInspectorParams::default);
+    let mut sort = params.sort;
+    egui::ComboBox::from_id_source("world ui entity sort")
+        .selected_text(format!("Sort: {}", sort.label()))
+        .show_ui(ui, |ui| {
+            for option in EntitySort::ALL {
+                ui.selectable_value(&mut sort, option, option.label());
+            }
+        });
+    if sort != params.sort {
+        params.sort = sort;
+    }
+}
+
+/// Remove the entities hidden by [`WorldInspectorParams::ignore_entities_named`] from `entities`
+pub(crate) fn retain_shown_entities(world: &World, entities: &mut Vec<Entity>) {
+    let Some(params) = world.get_resource::<WorldInspectorParams>() else {
+        return;
+    };
+    if params.ignored_names.is_empty() {
+        return;
+    }
+    entities.retain(|&entity| !params.is_name_ignored(&guess_entity_name(world, entity)));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::world::World;
+
+    use super::{matches_pattern, EntitySort};
+
+    #[test]
+    fn sort_by_name_and_component_count() {
+        let mut world = World::new();
+        let unnamed = world.spawn_empty().id();
+        let b = world.spawn(Name::new("b")).id();
+        let a = world.spawn(Name::new("a")).id();
+
+        let mut entities = vec![unnamed, b, a];
+        EntitySort::Name.sort(&world, &mut entities);
+        assert_eq!(entities, [a, b, unnamed]);
+
+        EntitySort::ComponentCount.sort(&world, &mut entities);
+        assert_eq!(entities, [b, a, unnamed]);
+
+        EntitySort::Id.sort(&world, &mut entities);
+        assert_eq!(entities, [unnamed, b, a]);
+    }
+
+    #[test]
+    fn wildcard_patterns() {
+        assert!(matches_pattern("Camera", "Camera"));
+        assert!(!matches_pattern("Camera", "Camera 2"));
+        assert!(matches_pattern("Debug *", "Debug Line"));
+        assert!(matches_pattern("*Window*", "Primary Window 1"));
+        assert!(matches_pattern("a*b*c", "abc"));
+        assert!(!matches_pattern("a*b*c", "acb"));
+        assert!(!matches_pattern("*abc", "bc"));
+        assert!(matches_pattern("*", "anything"));
+    }
+}
@@ -0,0 +1,178 @@
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiContext;
+use bevy_input::{keyboard::KeyCode, mouse::MouseButton, ButtonInput};
+use bevy_math::{Ray3d, Vec2, Vec3A};
+use bevy_render::{
+    camera::{Camera, NormalizedRenderTarget},
+    primitives::Aabb,
+    view::InheritedVisibility,
+};
+use bevy_transform::components::GlobalTransform;
+use bevy_window::{PrimaryWindow, Window, WindowRef};
+
+use super::selection::InspectorSelection;
+use crate::events::{self, InspectorEvent};
+
+/// Controls how [`InspectorPickingPlugin`] selects entities
+#[derive(Resource, Debug, Clone)]
+pub struct InspectorPicking {
+    pub button: MouseButton,
+    /// Key that needs to be held while clicking, so that clicks still reach the game otherwise
+    pub modifier: Option<KeyCode>,
+}
+
+impl Default for InspectorPicking {
+    fn default() -> Self {
+        InspectorPicking {
+            button: MouseButton::Left,
+            modifier: Some(KeyCode::ControlLeft),
+        }
+    }
+}
+
+/// Plugin selecting and focusing entities in the world inspector when clicking on them in the primary window.
+///
+/// Hits are tested against the [`Aabb`] of meshes and sprites, so no extra components are needed.
+/// By default, this requires holding `Ctrl` while clicking, see [`InspectorPicking`].
+pub struct InspectorPickingPlugin;
+
+impl Plugin for InspectorPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InspectorPicking>()
+            .add_systems(PostUpdate, pick_on_click);
+    }
+}
+
+fn pick_on_click(world: &mut World) {
+    let picking = world.resource::<InspectorPicking>().clone();
+    let Some(input) = world.get_resource::<ButtonInput<MouseButton>>() else {
+        return;
+    };
+    if !input.just_pressed(picking.button) {
+        return;
+    }
+    if let Some(modifier) = picking.modifier {
+        let keys = world.get_resource::<ButtonInput<KeyCode>>();
+        if !keys.is_some_and(|keys| keys.pressed(modifier)) {
+            return;
+        }
+    }
+
+    let Ok((window, cursor, mut egui_context)) = world
+        .query_filtered::<(Entity, &Window, &mut EguiContext), With<PrimaryWindow>>()
+        .get_single_mut(world)
+        .map(|(entity, window, context)| (entity, window.cursor_position(), context.clone()))
+    else {
+        return;
+    };
+    let Some(cursor) = cursor else {
+        return;
+    };
+    let ctx = egui_context.get_mut();
+    if ctx.wants_pointer_input() {
+        return;
+    }
+
+    if let Some(entity) = pick_entity_in_window(world, window, cursor) {
+        super::focus_entity(ctx, entity);
+        world
+            .get_resource_or_insert_with(InspectorSelection::default)
+            .select_replace(entity);
+        events::send(world, InspectorEvent::EntitySelected { entity });
+    }
+}
+
+/// The closest entity under `cursor` in the window `window`, considering all active cameras rendering to it.
+pub fn pick_entity_in_window(world: &mut World, window: Entity, cursor: Vec2) -> Option<Entity> {
+    let target = NormalizedRenderTarget::Window(WindowRef::Entity(window).normalize(None)?);
+    let primary_window = world
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .get_single(world)
+        .ok();
+
+    let mut cameras: Vec<_> = world
+        .query::<(Entity, &Camera)>()
+        .iter(world)
+        .filter(|(_, camera)| camera.is_active)
+        .filter(|(_, camera)| camera.target.normalize(primary_window).as_ref() == Some(&target))
+        .filter(|(_, camera)| {
+            camera
+                .logical_viewport_rect()
+                .is_some_and(|rect| rect.contains(cursor))
+        })
+        .map(|(entity, camera)| (camera.order, entity))
+        .collect();
+    cameras.sort();
+
+    cameras.into_iter().rev().find_map(|(_, camera)| {
+        let rect = world.get::<Camera>(camera)?.logical_viewport_rect()?;
+        pick_entity(world, camera, cursor - rect.min)
+    })
+}
+
+/// The closest visible entity with an [`Aabb`] under `viewport_position`, in logical pixels relative to the viewport of `camera`.
+pub fn pick_entity(world: &mut World, camera: Entity, viewport_position: Vec2) -> Option<Entity> {
+    let ray = world
+        .query::<(&Camera, &GlobalTransform)>()
+        .get(world, camera)
+        .ok()
+        .and_then(|(camera, transform)| camera.viewport_to_world(transform, viewport_position))?;
+
+    world
+        .query::<(
+            Entity,
+            &Aabb,
+            &GlobalTransform,
+            Option<&InheritedVisibility>,
+        )>()
+        .iter(world)
+        .filter(|(.., visibility)| visibility.map_or(true, |visibility| visibility.get()))
+        .filter_map(|(entity, aabb, transform, _)| {
+            Some((ray_aabb_distance(ray, aabb, transform)?, entity))
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, entity)| entity)
+}
+
+/// Distance along `ray` to the first intersection with the oriented bounding box, using the slab method
+fn ray_aabb_distance(ray: Ray3d, aabb: &Aabb, transform: &GlobalTransform) -> Option<f32> {
+    let world_to_local = transform.affine().inverse();
+    let origin = Vec3A::from(world_to_local.transform_point3(ray.origin));
+    let direction = Vec3A::from(world_to_local.transform_vector3(*ray.direction));
+
+    let t1 = (aabb.min() - origin) / direction;
+    let t2 = (aabb.max() - origin) / direction;
+    let t_near = t1.min(t2).max_element();
+    let t_far = t1.max(t2).min_element();
+
+    (t_near <= t_far && t_far >= 0.0).then_some(t_near.max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{primitives::Direction3d, Ray3d, Vec3};
+    use bevy_render::primitives::Aabb;
+    use bevy_transform::components::{GlobalTransform, Transform};
+
+    use super::ray_aabb_distance;
+
+    #[test]
+    fn ray_hits_transformed_aabb() {
+        let aabb = Aabb::from_min_max(Vec3::splat(-1.0), Vec3::splat(1.0));
+        let transform = GlobalTransform::from(Transform::from_xyz(0.0, 0.0, -10.0));
+        let ray = Ray3d {
+            origin: Vec3::ZERO,
+            direction: Direction3d::NEG_Z,
+        };
+
+        let distance = ray_aabb_distance(ray, &aabb, &transform).unwrap();
+        assert!((distance - 9.0).abs() < 1e-4);
+
+        let miss = Ray3d {
+            origin: Vec3::new(5.0, 0.0, 0.0),
+            direction: Direction3d::NEG_Z,
+        };
+        assert!(ray_aabb_distance(miss, &aabb, &transform).is_none());
+    }
+}
@@ -0,0 +1,211 @@
+use std::any::{Any, TypeId};
+
+use bevy_ecs::{prelude::*, system::CommandQueue};
+use bevy_reflect::{GetPath, TypeInfo, TypeRegistry};
+
+use super::locks;
+use crate::{
+    egui_utils,
+    events::{self, InspectorEvent},
+    i18n::InspectorI18n,
+    inspector_options::{ReflectInspectorOptions, Target},
+    reflect_inspector::{Context, InspectorUi},
+    restricted_world_view::RestrictedWorldView,
+    utils::guess_entity_name::guess_entity_name,
+};
+
+/// A component of an entity, or a field of it, pinned to the HUD shown by [`ui_for_pins`].
+///
+/// Pins are stored in the egui [`Context`](egui::Context)'s memory, like the [`notes`](crate::notes), and are added by
+/// right-clicking a component or one of its fields in the world inspector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pin {
+    pub entity: Entity,
+    pub component: TypeId,
+    /// The [reflect path](bevy_reflect::GetPath) of the pinned field in the component, or `None` to pin all of it
+    pub field: Option<String>,
+}
+
+fn id() -> egui::Id {
+    egui::Id::new("bevy-inspector-egui pins")
+}
+
+/// All pins of `ctx`, in the order they were pinned in
+pub fn pins(ctx: &egui::Context) -> Vec<Pin> {
+    ctx.data(|data| data.get_temp(id())).unwrap_or_default()
+}
+
+pub fn is_pinned(ctx: &egui::Context, pin: &Pin) -> bool {
+    pins(ctx).contains(pin)
+}
+
+/// Pin `pin`, or unpin it if it is already pinned
+pub fn toggle_pin(ctx: &egui::Context, pin: Pin) {
+    ctx.data_mut(|data| {
+        let pins = data.get_temp_mut_or_default::<Vec<Pin>>(id());
+        match pins.iter().position(|pinned| *pinned == pin) {
+            Some(index) => drop(pins.remove(index)),
+            None => pins.push(pin),
+        }
+    });
+}
+
+pub fn unpin(ctx: &egui::Context, pin: &Pin) {
+    ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<Vec<Pin>>(id())
+            .retain(|pinned| pinned != pin);
+    });
+}
+
+/// A button pinning or unpinning `pin`, for use inside of a context menu
+pub(crate) fn ui_for_pin_toggle(ui: &mut egui::Ui, pin: &Pin) {
+    let text = match is_pinned(ui.ctx(), pin) {
+        true => "Unpin from HUD",
+        false => "Pin to HUD",
+    };
+    if ui.button(text).clicked() {
+        toggle_pin(ui.ctx(), pin.clone());
+        ui.close_menu();
+    }
+}
+
+/// The options of the direct field `field` of the component with the type id `component`
+fn field_options<'a>(
+    type_registry: &'a TypeRegistry,
+    component: TypeId,
+    field: &str,
+) -> Option<&'a dyn Any> {
+    let registration = type_registry.get(component)?;
+    let TypeInfo::Struct(info) = registration.type_info() else {
+        return None;
+    };
+    let index = info.index_of(field)?;
+    registration
+        .data::<ReflectInspectorOptions>()?
+        .0
+        .get(Target::Field(index))
+}
+
+/// Display the pinned values of the [pins](Pin) in a compact grid, with a button for unpinning each.
+///
+/// Pins of despawned entities or removed components are forgotten.
+pub fn ui_for_pins(world: &mut World, ui: &mut egui::Ui) {
+    let pins = pins(ui.ctx());
+    if pins.is_empty() {
+        ui.weak("Nothing pinned yet, right-click a component or field to pin it");
+        return;
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let i18n = InspectorI18n::get(ui.ctx());
+    let mut queue = CommandQueue::default();
+
+    egui::Grid::new("inspector pins")
+        .num_columns(3)
+        .show(ui, |ui| {
+            for pin in &pins {
+                let Some(registration) = type_registry.get(pin.component) else {
+                    unpin(ui.ctx(), pin);
+                    continue;
+                };
+                let type_path = registration.type_info().type_path();
+                let short_path = registration.type_info().type_path_table().short_path();
+                let mut label = format!(
+                    "{} {}",
+                    guess_entity_name(world, pin.entity),
+                    i18n.type_label(type_path, short_path)
+                );
+                if let Some(field) = &pin.field {
+                    label.push('.');
+                    label.push_str(field);
+                }
+                let lock = locks::path_lock_reason(world, type_path);
+
+                let mut world_view = RestrictedWorldView::new(world);
+                let (mut component_view, world_view) =
+                    world_view.split_off_component((pin.entity, pin.component));
+                let Ok((value, _, set_changed)) = component_view.get_entity_component_reflect(
+                    pin.entity,
+                    pin.component,
+                    &type_registry,
+                ) else {
+                    unpin(ui.ctx(), pin);
+                    continue;
+                };
+
+                ui.label(label);
+                let value = match &pin.field {
+                    Some(field) => match value.reflect_path_mut(field.as_str()) {
+                        Ok(value) => value,
+                        Err(error) => {
+                            ui.colored_label(ui.visuals().error_fg_color, error.to_string());
+                            ui.end_row();
+                            continue;
+                        }
+                    },
+                    None => value,
+                };
+                let options = pin
+                    .field
+                    .as_deref()
+                    .and_then(|field| field_options(&type_registry, pin.component, field))
+                    .unwrap_or(&());
+
+                let mut cx = Context {
+                    world: Some(world_view),
+                    queue: Some(&mut queue),
+                };
+                let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+                let id = egui::Id::new(("pin", pin));
+                let changed = locks::ui_locked(ui, lock.as_deref(), |ui| {
+                    env.ui_for_reflect_with_options(value, ui, id, options)
+                });
+                if changed {
+                    set_changed();
+                    let event = InspectorEvent::ValueChanged {
+                        entity: Some(pin.entity),
+                        path: type_path.to_owned(),
+                    };
+                    events::send_deferred(&mut queue, event);
+                }
+
+                if egui_utils::remove_button(ui)
+                    .on_hover_text("Unpin")
+                    .clicked()
+                {
+                    unpin(ui.ctx(), pin);
+                }
+                ui.end_row();
+            }
+        });
+
+    queue.apply(world);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_ecs::entity::Entity;
+
+    use super::{is_pinned, pins, toggle_pin, Pin};
+
+    #[test]
+    fn pins_are_toggled() {
+        let ctx = egui::Context::default();
+        let pin = |field: Option<&str>| Pin {
+            entity: Entity::from_raw(3),
+            component: TypeId::of::<f32>(),
+            field: field.map(str::to_owned),
+        };
+
+        toggle_pin(&ctx, pin(Some("x")));
+        toggle_pin(&ctx, pin(None));
+        assert_eq!(pins(&ctx), [pin(Some("x")), pin(None)]);
+
+        toggle_pin(&ctx, pin(Some("x")));
+        assert!(!is_pinned(&ctx, &pin(Some("x"))));
+        assert!(is_pinned(&ctx, &pin(None)));
+    }
+}
@@ -0,0 +1,118 @@
+//! Display of the [`Collider`] of `bevy_rapier3d`, which isn't reflected and would only show an error otherwise.
+//!
+//! The reflected rapier components like [`Velocity`](bevy_rapier3d::dynamics::Velocity) and
+//! [`Damping`](bevy_rapier3d::dynamics::Damping) are edited like any other component.
+//! Rapier's sync systems pick up the changed components, so edits reach the physics world on the next step.
+
+use bevy_math::Vec3;
+use bevy_rapier3d::geometry::{Collider, ColliderView};
+
+/// The name of a collider shape and its dimensions, like `("Ball", [("radius", "0.500")])`
+pub fn collider_shape(shape: ColliderView<'_>) -> (&'static str, Vec<(&'static str, String)>) {
+    match shape {
+        ColliderView::Ball(ball) => ("Ball", vec![("radius", number(ball.radius()))]),
+        ColliderView::Cuboid(cuboid) => (
+            "Cuboid",
+            vec![("half extents", vector(cuboid.half_extents()))],
+        ),
+        ColliderView::Capsule(capsule) => (
+            "Capsule",
+            vec![
+                ("radius", number(capsule.radius())),
+                ("height", number(capsule.height())),
+            ],
+        ),
+        ColliderView::Cylinder(cylinder) => (
+            "Cylinder",
+            vec![
+                ("radius", number(cylinder.radius())),
+                ("half height", number(cylinder.half_height())),
+            ],
+        ),
+        ColliderView::Cone(cone) => (
+            "Cone",
+            vec![
+                ("radius", number(cone.radius())),
+                ("half height", number(cone.half_height())),
+            ],
+        ),
+        ColliderView::Segment(_) => ("Segment", Vec::new()),
+        ColliderView::Triangle(_) => ("Triangle", Vec::new()),
+        ColliderView::TriMesh(mesh) => (
+            "Triangle mesh",
+            vec![("triangles", mesh.num_triangles().to_string())],
+        ),
+        ColliderView::Polyline(_) => ("Polyline", Vec::new()),
+        ColliderView::HalfSpace(_) => ("Half-space", Vec::new()),
+        ColliderView::HeightField(_) => ("Heightfield", Vec::new()),
+        ColliderView::Compound(compound) => (
+            "Compound",
+            vec![("shapes", compound.shapes().len().to_string())],
+        ),
+        ColliderView::ConvexPolyhedron(_) => ("Convex polyhedron", Vec::new()),
+        ColliderView::RoundCuboid(round) => round_shape("Round cuboid", round.border_radius()),
+        ColliderView::RoundTriangle(round) => round_shape("Round triangle", round.border_radius()),
+        ColliderView::RoundCylinder(round) => round_shape("Round cylinder", round.border_radius()),
+        ColliderView::RoundCone(round) => round_shape("Round cone", round.border_radius()),
+        ColliderView::RoundConvexPolyhedron(round) => {
+            round_shape("Round convex polyhedron", round.border_radius())
+        }
+    }
+}
+
+fn round_shape(
+    name: &'static str,
+    border_radius: f32,
+) -> (&'static str, Vec<(&'static str, String)>) {
+    (name, vec![("border radius", number(border_radius))])
+}
+
+fn number(value: f32) -> String {
+    format!("{value:.3}")
+}
+
+fn vector(value: Vec3) -> String {
+    format!("[{:.3}, {:.3}, {:.3}]", value.x, value.y, value.z)
+}
+
+/// Display the shape of `collider` with its dimensions and scale, read-only
+pub fn ui_for_collider(ui: &mut egui::Ui, id: egui::Id, collider: &Collider) {
+    let (name, dimensions) = collider_shape(collider.as_typed_shape());
+    egui::Grid::new(id.with("collider"))
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("shape");
+            ui.label(name);
+            ui.end_row();
+            for (label, value) in dimensions {
+                ui.label(label);
+                ui.label(value);
+                ui.end_row();
+            }
+            ui.label("scale");
+            ui.label(vector(collider.scale()));
+            ui.end_row();
+        });
+    ui.weak("Collider shapes aren't reflected and can't be edited here");
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_rapier3d::geometry::Collider;
+
+    use super::collider_shape;
+
+    #[test]
+    fn shapes_are_described_with_their_dimensions() {
+        let (name, dimensions) = collider_shape(Collider::ball(0.5).as_typed_shape());
+        assert_eq!(name, "Ball");
+        assert_eq!(dimensions, [("radius", "0.500".to_owned())]);
+
+        let (name, dimensions) = collider_shape(Collider::cuboid(1.0, 2.0, 3.0).as_typed_shape());
+        assert_eq!(name, "Cuboid");
+        assert_eq!(
+            dimensions,
+            [("half extents", "[1.000, 2.000, 3.000]".to_owned())]
+        );
+    }
+}
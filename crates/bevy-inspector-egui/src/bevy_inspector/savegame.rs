@@ -0,0 +1,167 @@
+use bevy_ecs::entity::EntityHashMap;
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::QueryFilter;
+use bevy_scene::serde::SceneDeserializer;
+use bevy_scene::DynamicSceneBuilder;
+use serde::de::DeserializeSeed;
+
+use crate::inspector_egui_impls::highlight;
+use crate::inspector_options::std_options::CodeLanguage;
+
+/// Custom save and load functions used by [`ui_for_savegame`] instead of extracting a [`DynamicScene`].
+///
+/// Insert this as a resource to debug the serialization of your own save system.
+#[derive(Resource)]
+pub struct SavegameFunctions {
+    /// Serialize the world, returning the text shown in the panel or an error message displayed instead
+    pub save: fn(&mut World) -> Result<String, String>,
+    /// Apply the possibly edited text of the panel to the world, returning an error message if it can't be loaded
+    pub load: fn(&mut World, &str) -> Result<(), String>,
+}
+
+#[derive(Clone, Default)]
+struct SavegameState {
+    text: String,
+    error: Option<String>,
+    /// The entities written by the last "Save"
+    saved: Vec<Entity>,
+}
+
+/// Display a panel for round-trip debugging of save systems.
+///
+/// "Save" serializes all entities matching `F` into a [`DynamicScene`] and shows the resulting RON,
+/// which can be edited and written back into the world using "Load".
+/// Entities written by the last "Save" which still exist are overwritten in place, all other entities of the scene
+/// are spawned anew, so that pasted text can't overwrite unrelated entities which happen to have the same id.
+///
+/// If the [`SavegameFunctions`] resource exists, its functions are used instead.
+pub fn ui_for_savegame<F: QueryFilter>(world: &mut World, ui: &mut egui::Ui) {
+    let id = egui::Id::new(("savegame", std::any::type_name::<F>()));
+    let mut state = ui.memory_mut(|mem| mem.data.get_temp::<SavegameState>(id).unwrap_or_default());
+
+    ui.horizontal(|ui| {
+        if ui.button("Save").clicked() {
+            match save::<F>(world) {
+                Ok((text, saved)) => {
+                    state.text = text;
+                    state.error = None;
+                    state.saved = saved;
+                }
+                Err(error) => state.error = Some(error),
+            }
+        }
+        if ui.button("Load").clicked() {
+            state.error = load(world, &state.text, &state.saved).err();
+        }
+        if ui.button("Copy").clicked() {
+            ui.output_mut(|output| output.copied_text = state.text.clone());
+        }
+    });
+
+    ui.label(format!(
+        "{} bytes, {} lines",
+        state.text.len(),
+        state.text.lines().count()
+    ));
+    if let Some(error) = &state.error {
+        ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+
+    let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+        let mut job = highlight(ui, text, CodeLanguage::Ron);
+        job.wrap.max_width = wrap_width;
+        ui.fonts(|fonts| fonts.layout_job(job))
+    };
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        ui.add(
+            egui::TextEdit::multiline(&mut state.text)
+                .code_editor()
+                .desired_width(f32::INFINITY)
+                .layouter(&mut layouter),
+        );
+    });
+
+    ui.memory_mut(|mem| mem.data.insert_temp(id, state));
+}
+
+/// Serialize the entities matching `F`, returning the text and the saved entities
+fn save<F: QueryFilter>(world: &mut World) -> Result<(String, Vec<Entity>), String> {
+    if let Some(functions) = world.get_resource::<SavegameFunctions>() {
+        let save = functions.save;
+        return save(world).map(|text| (text, Vec::new()));
+    }
+
+    let entities: Vec<Entity> = world.query_filtered::<Entity, F>().iter(world).collect();
+    let scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(entities.iter().copied())
+        .build();
+
+    let type_registry = world.resource::<AppTypeRegistry>();
+    let text = scene
+        .serialize_ron(type_registry)
+        .map_err(|error| error.to_string())?;
+    Ok((text, entities))
+}
+
+/// Write the scene in `text` into the world, overwriting the `saved` entities which still exist
+fn load(world: &mut World, text: &str, saved: &[Entity]) -> Result<(), String> {
+    if let Some(functions) = world.get_resource::<SavegameFunctions>() {
+        let load = functions.load;
+        return load(world, text);
+    }
+
+    let scene = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let mut deserializer =
+            ron::de::Deserializer::from_str(text).map_err(|error| error.to_string())?;
+        SceneDeserializer {
+            type_registry: &type_registry,
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|error| deserializer.span_error(error).to_string())?
+    };
+
+    let mut entity_map: EntityHashMap<Entity> = saved
+        .iter()
+        .filter(|&&entity| world.get_entity(entity).is_some())
+        .map(|&entity| (entity, entity))
+        .collect();
+    scene
+        .write_to_world(world, &mut entity_map)
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+    use bevy_reflect::Reflect;
+
+    use super::{load, save};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Health>();
+        world.insert_resource(type_registry);
+
+        let entity = world.spawn(Health(1.0)).id();
+        let (text, saved) = save::<With<Health>>(&mut world).unwrap();
+        assert_eq!(saved, [entity]);
+
+        world.get_mut::<Health>(entity).unwrap().0 = 2.0;
+        load(&mut world, &text, &saved).unwrap();
+        assert_eq!(world.get::<Health>(entity).unwrap().0, 1.0);
+        assert_eq!(world.query::<&Health>().iter(&world).count(), 1);
+
+        // text which wasn't saved by the panel doesn't overwrite the entity with the same id
+        load(&mut world, &text, &[]).unwrap();
+        let mut values: Vec<f32> = world.query::<&Health>().iter(&world).map(|h| h.0).collect();
+        values.sort_by(f32::total_cmp);
+        assert_eq!(values, [1.0, 1.0]);
+    }
+}
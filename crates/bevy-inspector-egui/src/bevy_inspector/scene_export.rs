@@ -0,0 +1,346 @@
+//! Writing the whole world or the subtrees of some entities to a `.scn.ron` file, which can be loaded as a scene.
+//!
+//! Only components whose type is registered with `#[reflect(Component)]` can be written into a [`DynamicScene`], and
+//! only if all their fields can be serialized. [`export_scene`] leaves the other components out and lists them as
+//! [`SkippedComponent`]s, so the missing `register_type` calls and `#[reflect(...)]` attributes can be added.
+
+use std::{any::TypeId, collections::BTreeMap, path::Path};
+
+use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::{serde::ReflectSerializer, TypeRegistry};
+use bevy_scene::{DynamicScene, DynamicSceneBuilder};
+use bevy_utils::HashSet;
+
+/// Why a component was left out of an exported scene
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The component isn't a Rust type, for example one added by scripting
+    NotReflected,
+    /// The type isn't registered in the [`AppTypeRegistry`]
+    NotRegistered,
+    /// The type is registered, but without `#[reflect(Component)]`
+    MissingReflectComponent,
+    /// Serializing the value failed, usually because a field type lacks `#[reflect(Serialize)]`
+    Unserializable(String),
+}
+
+impl SkipReason {
+    pub fn hint(&self) -> String {
+        match self {
+            SkipReason::NotReflected => "not a reflectable type".to_owned(),
+            SkipReason::NotRegistered => "not registered, add `app.register_type`".to_owned(),
+            SkipReason::MissingReflectComponent => "missing `#[reflect(Component)]`".to_owned(),
+            SkipReason::Unserializable(error) => format!("not serializable: {error}"),
+        }
+    }
+}
+
+/// A component type which was left out of an exported scene
+#[derive(Clone, Debug)]
+pub struct SkippedComponent {
+    pub name: String,
+    pub reason: SkipReason,
+    /// How many of the exported entities have the component
+    pub entities: usize,
+}
+
+/// A scene built by [`export_scene`], with the components which were left out
+pub struct SceneExport {
+    pub scene: DynamicScene,
+    pub skipped: Vec<SkippedComponent>,
+}
+
+impl SceneExport {
+    /// Serialize the scene to the RON format read by Bevy's scene loader
+    pub fn serialize_ron(&self, world: &World) -> Result<String, String> {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        self.scene
+            .serialize_ron(type_registry)
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// Build a scene from the `roots` and all their descendants, or from all entities of the world if `roots` is `None`.
+///
+/// The exported roots lose their [`Parent`] if it isn't exported as well, so they can be spawned on their own.
+pub fn export_scene(world: &World, roots: Option<&[Entity]>) -> SceneExport {
+    let entities = match roots {
+        Some(roots) => with_descendants(world, roots),
+        None => world.iter_entities().map(|entity| entity.id()).collect(),
+    };
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut skipped = BTreeMap::new();
+    for &entity in &entities {
+        skip_unreflectable(world, &type_registry, entity, &mut skipped);
+    }
+
+    let mut scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(entities.iter().copied())
+        .build();
+
+    let exported: HashSet<Entity> = entities.iter().copied().collect();
+    for dynamic_entity in &mut scene.entities {
+        let dangling_parent = world
+            .get::<Parent>(dynamic_entity.entity)
+            .is_some_and(|parent| !exported.contains(&parent.get()));
+        dynamic_entity.components.retain(|component| {
+            let Some(type_info) = component.get_represented_type_info() else {
+                return true;
+            };
+            if dangling_parent && type_info.type_id() == TypeId::of::<Parent>() {
+                return false;
+            }
+            let serializer = ReflectSerializer::new(component.as_reflect(), &type_registry);
+            match bevy_scene::serialize_ron(serializer) {
+                Ok(_) => true,
+                Err(error) => {
+                    let name = type_info.type_path_table().short_path().to_owned();
+                    skip(
+                        &mut skipped,
+                        name,
+                        SkipReason::Unserializable(error.to_string()),
+                    );
+                    false
+                }
+            }
+        });
+    }
+
+    SceneExport {
+        scene,
+        skipped: skipped
+            .into_iter()
+            .map(|(name, (reason, entities))| SkippedComponent {
+                name,
+                reason,
+                entities,
+            })
+            .collect(),
+    }
+}
+
+/// Write the scene built by [`export_scene`] to the file at `path`, creating its directory if necessary
+pub fn export_scene_to_file(
+    world: &World,
+    roots: Option<&[Entity]>,
+    path: &Path,
+) -> Result<SceneExport, String> {
+    let export = export_scene(world, roots);
+    let ron = export.serialize_ron(world)?;
+    if let Some(directory) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(directory).map_err(|error| error.to_string())?;
+    }
+    std::fs::write(path, ron).map_err(|error| error.to_string())?;
+    Ok(export)
+}
+
+fn with_descendants(world: &World, roots: &[Entity]) -> Vec<Entity> {
+    let mut seen = HashSet::new();
+    let mut entities = Vec::new();
+    let mut stack: Vec<Entity> = roots.iter().rev().copied().collect();
+    while let Some(entity) = stack.pop() {
+        if world.get_entity(entity).is_none() || !seen.insert(entity) {
+            continue;
+        }
+        entities.push(entity);
+        if let Some(children) = world.get::<Children>(entity) {
+            stack.extend(children.iter().rev().copied());
+        }
+    }
+    entities
+}
+
+fn skip_unreflectable(
+    world: &World,
+    type_registry: &TypeRegistry,
+    entity: Entity,
+    skipped: &mut BTreeMap<String, (SkipReason, usize)>,
+) {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return;
+    };
+    for component_id in entity_ref.archetype().components() {
+        let Some(info) = world.components().get_info(component_id) else {
+            continue;
+        };
+        let (name, reason) = match info.type_id().map(|type_id| type_registry.get(type_id)) {
+            None => (info.name().to_owned(), SkipReason::NotReflected),
+            Some(None) => (info.name().to_owned(), SkipReason::NotRegistered),
+            Some(Some(registration)) if registration.data::<ReflectComponent>().is_none() => (
+                registration
+                    .type_info()
+                    .type_path_table()
+                    .short_path()
+                    .to_owned(),
+                SkipReason::MissingReflectComponent,
+            ),
+            Some(Some(_)) => continue,
+        };
+        skip(skipped, name, reason);
+    }
+}
+
+fn skip(skipped: &mut BTreeMap<String, (SkipReason, usize)>, name: String, reason: SkipReason) {
+    skipped.entry(name).or_insert((reason, 0)).1 += 1;
+}
+
+#[derive(Clone)]
+struct ExportState {
+    path: String,
+    result: Option<Result<ExportSummary, String>>,
+}
+
+#[derive(Clone)]
+struct ExportSummary {
+    path: String,
+    entities: usize,
+    skipped: Vec<SkippedComponent>,
+}
+
+impl Default for ExportState {
+    fn default() -> Self {
+        ExportState {
+            path: "scenes/export.scn.ron".to_owned(),
+            result: None,
+        }
+    }
+}
+
+/// A file name prompt with an "Export" button writing the `roots` and their descendants, or the whole world if
+/// `roots` is `None`, to a scene file, and listing the components which were left out of the last export
+pub fn ui_for_scene_export(world: &World, ui: &mut egui::Ui, roots: Option<&[Entity]>) {
+    let id = egui::Id::new("scene export");
+    let mut state = ui.memory_mut(|mem| mem.data.get_temp::<ExportState>(id).unwrap_or_default());
+
+    ui.horizontal(|ui| {
+        ui.label("File");
+        ui.add(egui::TextEdit::singleline(&mut state.path).desired_width(200.0));
+    });
+    let label = match roots {
+        Some([_]) => "Export entity".to_owned(),
+        Some(roots) => format!("Export {} entities", roots.len()),
+        None => "Export world".to_owned(),
+    };
+    let button = egui::Button::new(label);
+    let response = ui
+        .add_enabled(!state.path.trim().is_empty(), button)
+        .on_hover_text("Write the entities and their descendants to a scene file");
+    if response.clicked() {
+        let path = state.path.trim().to_owned();
+        state.result = Some(
+            export_scene_to_file(world, roots, Path::new(&path)).map(|export| ExportSummary {
+                path,
+                entities: export.scene.entities.len(),
+                skipped: export.skipped,
+            }),
+        );
+    }
+
+    match &state.result {
+        Some(Ok(summary)) => {
+            ui.label(format!(
+                "Exported {} entities to {}",
+                summary.entities, summary.path
+            ));
+            if !summary.skipped.is_empty() {
+                let error_color = ui.visuals().warn_fg_color;
+                ui.colored_label(
+                    error_color,
+                    format!("{} component types were left out:", summary.skipped.len()),
+                );
+                egui::Grid::new(id.with("skipped"))
+                    .striped(true)
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for skipped in &summary.skipped {
+                            ui.label(&skipped.name).on_hover_text(format!(
+                                "on {} exported entities",
+                                skipped.entities
+                            ));
+                            ui.weak(skipped.reason.hint());
+                            ui.end_row();
+                        }
+                    });
+            }
+        }
+        Some(Err(error)) => {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+        None => {}
+    }
+
+    ui.memory_mut(|mem| mem.data.insert_temp(id, state));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+    use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
+    use bevy_reflect::Reflect;
+
+    use super::{export_scene, SkipReason};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    #[derive(Component)]
+    struct Unregistered;
+
+    #[derive(Reflect, Clone, Default)]
+    #[reflect_value]
+    struct NoSerialize;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Opaque(NoSerialize);
+
+    #[test]
+    fn subtrees_are_exported_with_skipped_components() {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut type_registry = type_registry.write();
+            type_registry.register::<Health>();
+            type_registry.register::<Opaque>();
+            type_registry.register::<Parent>();
+            type_registry.register::<Children>();
+            type_registry.register::<Entity>();
+        }
+        world.insert_resource(type_registry);
+
+        let mut root = Entity::PLACEHOLDER;
+        world.spawn(Health(1.0)).with_children(|parent| {
+            root = parent
+                .spawn((Health(2.0), Unregistered))
+                .with_children(|parent| {
+                    parent.spawn((Health(3.0), Opaque(NoSerialize)));
+                })
+                .id();
+        });
+
+        let export = export_scene(&world, Some(&[root]));
+        assert_eq!(export.scene.entities.len(), 2);
+        let exported_root = &export.scene.entities[0];
+        assert_eq!(exported_root.entity, root);
+        // the parent of the root isn't exported, but its children are
+        assert_eq!(exported_root.components.len(), 2);
+
+        let reasons: Vec<_> = export
+            .skipped
+            .iter()
+            .map(|skipped| (skipped.name.as_str(), &skipped.reason))
+            .collect();
+        assert!(matches!(
+            reasons[..],
+            [("Opaque", SkipReason::Unserializable(_)), (name, SkipReason::NotRegistered)]
+                if name.ends_with("Unregistered")
+        ));
+        assert!(export.serialize_ron(&world).is_ok());
+    }
+}
@@ -0,0 +1,110 @@
+use bevy_asset::{AssetServer, Assets, Handle};
+use bevy_core::Name;
+use bevy_ecs::{entity::Entity, system::CommandQueue, world::World};
+use bevy_scene::{Scene, SceneInstance, SceneSpawner};
+use bevy_transform::TransformBundle;
+
+use super::commands;
+use crate::{
+    restricted_world_view::RestrictedWorldView,
+    utils::guess_entity_name::guess_entity_name_restricted,
+};
+
+/// Spawn a new root entity instantiating the scene of `handle`, named after the scene's path.
+///
+/// Like with a `SceneBundle`, the scene is spawned as children of the root, which gets a [`SceneInstance`].
+pub fn spawn_scene_instance(world: &mut World, handle: Handle<Scene>) {
+    let name = world
+        .get_resource::<AssetServer>()
+        .and_then(|asset_server| asset_server.get_path(handle.id()))
+        .map_or_else(|| "Scene".to_owned(), |path| path.to_string());
+    let root = (
+        handle,
+        TransformBundle::default(),
+        Name::new(format!("{name} instance")),
+    );
+    #[cfg(feature = "bevy_render")]
+    world.spawn((root, bevy_render::view::VisibilityBundle::default()));
+    #[cfg(not(feature = "bevy_render"))]
+    world.spawn(root);
+}
+
+/// A button spawning another instance of the scene of `handle`, see [`spawn_scene_instance`].
+///
+/// The button is disabled if there is no `queue` or the scene isn't loaded yet.
+pub(crate) fn ui_for_scene_spawn(
+    ui: &mut egui::Ui,
+    world: Option<&mut RestrictedWorldView<'_>>,
+    queue: Option<&mut CommandQueue>,
+    handle: &Handle<Scene>,
+) {
+    let is_loaded = world
+        .and_then(|world| world.get_resource_mut::<Assets<Scene>>().ok())
+        .is_some_and(|scenes| scenes.contains(handle));
+    let button = egui::Button::new("Spawn instance");
+    let response = ui
+        .add_enabled(is_loaded && queue.is_some(), button)
+        .on_hover_text("Spawn another root entity with this scene")
+        .on_disabled_hover_text("The scene isn't loaded yet");
+    if let (true, Some(queue)) = (response.clicked(), queue) {
+        let handle = handle.clone();
+        commands::push_deferred(queue, "Spawn scene", None, move |world| {
+            spawn_scene_instance(world, handle);
+        });
+    }
+}
+
+/// List the entities instantiated from the scene of `entity`, if it is the root of a spawned scene
+pub(crate) fn ui_for_scene_instance(
+    ui: &mut egui::Ui,
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+) {
+    let Ok(instance) = world.get_entity_component::<SceneInstance>(entity) else {
+        return;
+    };
+    let instance = **instance;
+    let Ok(spawner) = world.get_resource_mut::<SceneSpawner>() else {
+        return;
+    };
+    if !spawner.instance_is_ready(instance) {
+        ui.weak("Spawning scene…");
+        return;
+    }
+    let entities: Vec<Entity> = spawner.iter_instance_entities(instance).collect();
+
+    let id = egui::Id::new(("scene instance", entity));
+    egui::CollapsingHeader::new(format!("{} entities instantiated", entities.len()))
+        .id_source(id)
+        .show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_source(id)
+                .max_height(160.0)
+                .show(ui, |ui| {
+                    for &instantiated in &entities {
+                        ui.label(guess_entity_name_restricted(world, instantiated));
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_core::Name;
+    use bevy_ecs::world::World;
+    use bevy_scene::Scene;
+    use bevy_transform::components::GlobalTransform;
+
+    use super::spawn_scene_instance;
+
+    #[test]
+    fn spawned_instances_are_scene_roots() {
+        let mut world = World::new();
+        spawn_scene_instance(&mut world, Handle::<Scene>::default());
+
+        let mut roots = world.query::<(&Handle<Scene>, &Name, &GlobalTransform)>();
+        let (_, name, _) = roots.single(&world);
+        assert_eq!(name.as_str(), "Scene instance");
+    }
+}
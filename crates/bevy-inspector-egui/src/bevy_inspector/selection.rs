@@ -0,0 +1,128 @@
+use bevy_ecs::prelude::*;
+
+use super::hierarchy::{entities_between, hierarchy_ui, SelectedEntities, SelectionMode};
+use crate::events::{self, InspectorEvent};
+
+/// The entities selected in the inspector, shared by the world inspector, picking and game code.
+///
+/// Selected entities are highlighted in the world inspector and can be selected from its entity context menu.
+/// Systems can read this resource to react to the selection, or modify it to select entities programmatically:
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::bevy_inspector::selection::InspectorSelection;
+///
+/// # #[derive(Component)] struct Player;
+/// fn select_player(mut selection: ResMut<InspectorSelection>, player: Query<Entity, With<Player>>) {
+///     if let Ok(player) = player.get_single() {
+///         selection.select_replace(player);
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct InspectorSelection(pub SelectedEntities);
+
+impl std::ops::Deref for InspectorSelection {
+    type Target = SelectedEntities;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for InspectorSelection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Whether `entity` is part of the [`InspectorSelection`]
+pub fn is_selected(world: &World, entity: Entity) -> bool {
+    world
+        .get_resource::<InspectorSelection>()
+        .is_some_and(|selection| selection.contains(entity))
+}
+
+/// Add `entity` to the [`InspectorSelection`], or remove it if it is already selected
+pub fn toggle_selected(world: &mut World, entity: Entity) {
+    let mut selection = world.get_resource_or_insert_with(InspectorSelection::default);
+    if selection.remove(entity).is_none() {
+        selection.select_maybe_add(entity, true);
+        events::send(world, InspectorEvent::EntitySelected { entity });
+    }
+}
+
+/// Change the [`InspectorSelection`] for a click on `entity` in the list of `entities`, see [`SelectionMode`]
+pub fn select_clicked(world: &mut World, entity: Entity, mode: SelectionMode, entities: &[Entity]) {
+    let mut selection = world.get_resource_or_insert_with(InspectorSelection::default);
+    selection.select(mode, entity, |from, to| {
+        entities_between(entities, from, to)
+    });
+    if selection.contains(entity) {
+        events::send(world, InspectorEvent::EntitySelected { entity });
+    }
+}
+
+/// Display a button for adding `entity` to the selection or removing it
+pub(crate) fn ui_for_selection_toggle(world: &mut World, ui: &mut egui::Ui, entity: Entity) {
+    let text = match is_selected(world, entity) {
+        true => "Deselect",
+        false => "Select",
+    };
+    if ui.button(text).clicked() {
+        toggle_selected(world, entity);
+        ui.close_menu();
+    }
+}
+
+/// Display the entity hierarchy using the [`InspectorSelection`], see [`hierarchy_ui`].
+///
+/// Returns `true` if a new entity was selected.
+pub fn hierarchy_ui_for_selection(world: &mut World, ui: &mut egui::Ui) -> bool {
+    if !world.contains_resource::<InspectorSelection>() {
+        world.init_resource::<InspectorSelection>();
+    }
+    world.resource_scope(|world, mut selection: Mut<InspectorSelection>| {
+        hierarchy_ui(world, ui, &mut selection)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{event::Events, world::World};
+
+    use super::{is_selected, select_clicked, toggle_selected, InspectorSelection};
+    use crate::{bevy_inspector::hierarchy::SelectionMode, events::InspectorEvent};
+
+    #[test]
+    fn toggling_selection_sends_event() {
+        let mut world = World::new();
+        world.init_resource::<Events<InspectorEvent>>();
+        let entity = world.spawn_empty().id();
+
+        toggle_selected(&mut world, entity);
+        assert!(is_selected(&world, entity));
+        toggle_selected(&mut world, entity);
+        assert!(!is_selected(&world, entity));
+
+        let events = world.resource::<Events<InspectorEvent>>();
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn modified_clicks_add_and_extend_the_selection() {
+        let mut world = World::new();
+        world.init_resource::<Events<InspectorEvent>>();
+        let entities: Vec<_> = (0..4).map(|_| world.spawn_empty().id()).collect();
+
+        select_clicked(&mut world, entities[0], SelectionMode::Add, &entities);
+        select_clicked(&mut world, entities[2], SelectionMode::Extend, &entities);
+        assert_eq!(
+            world.resource::<InspectorSelection>().as_slice(),
+            &entities[0..=2]
+        );
+
+        select_clicked(&mut world, entities[1], SelectionMode::Add, &entities);
+        assert!(!is_selected(&world, entities[1]));
+        assert!(is_selected(&world, entities[2]));
+    }
+}
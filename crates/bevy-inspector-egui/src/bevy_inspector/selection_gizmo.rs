@@ -0,0 +1,161 @@
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::Parent;
+use bevy_math::{Vec2, Vec3};
+use bevy_render::camera::Camera;
+use bevy_transform::components::{GlobalTransform, Transform};
+
+use super::hierarchy::SelectedEntities;
+
+/// Length of the axis handles in points
+const AXIS_LENGTH: f32 = 80.0;
+const HANDLE_RADIUS: f32 = 6.0;
+
+/// What dragging the axis handles of a [`SelectionGizmo`] does
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GizmoMode {
+    /// Move the entity along the world axes
+    #[default]
+    Translate,
+    /// Scale the entity along its local axes
+    Scale,
+}
+
+/// Axis handles drawn over a camera's viewport at the origin of every selected entity.
+///
+/// Dragging a handle writes back into the entity's [`Transform`], so selected entities
+/// can be edited visually next to the numeric values in the inspector.
+///
+/// ```rust,no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_inspector_egui::bevy_inspector::{selection_gizmo::SelectionGizmo, hierarchy::SelectedEntities};
+/// fn viewport_ui(world: &mut World, ui: &mut egui::Ui, camera: Entity, selected: &SelectedEntities) {
+///     // the rect needs to cover the viewport the camera renders to
+///     let viewport = ui.clip_rect();
+///     SelectionGizmo::default().show(ui, viewport, world, camera, selected);
+/// }
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct SelectionGizmo {
+    pub mode: GizmoMode,
+}
+
+impl SelectionGizmo {
+    pub fn new(mode: GizmoMode) -> Self {
+        SelectionGizmo { mode }
+    }
+
+    /// Draw the handles of the selected entities inside `viewport`, the part of `ui` covered by the viewport of `camera`.
+    ///
+    /// Returns `true` if a [`Transform`] was changed.
+    pub fn show(
+        &self,
+        ui: &mut egui::Ui,
+        viewport: egui::Rect,
+        world: &mut World,
+        camera: Entity,
+        selected: &SelectedEntities,
+    ) -> bool {
+        let Some((camera, camera_transform)) = world
+            .query::<(&Camera, &GlobalTransform)>()
+            .get(world, camera)
+            .ok()
+            .map(|(camera, transform)| (camera.clone(), *transform))
+        else {
+            return false;
+        };
+        let Some(viewport_size) = camera.logical_viewport_size() else {
+            return false;
+        };
+        let scale = Vec2::new(viewport.width(), viewport.height()) / viewport_size;
+        let to_ui = |world_pos: Vec3| {
+            let pos = camera.world_to_viewport(&camera_transform, world_pos)? * scale;
+            Some(viewport.min + egui::vec2(pos.x, pos.y))
+        };
+
+        let mut changed = false;
+        for entity in selected.iter() {
+            let Some(global) = world.get::<GlobalTransform>(entity).copied() else {
+                continue;
+            };
+            if world.get::<Transform>(entity).is_none() {
+                continue;
+            }
+            let Some(origin) = to_ui(global.translation()) else {
+                continue;
+            };
+
+            let axes = match self.mode {
+                GizmoMode::Translate => [Vec3::X, Vec3::Y, Vec3::Z],
+                GizmoMode::Scale => [global.right(), global.up(), global.back()],
+            };
+            for (i, axis) in axes.into_iter().enumerate() {
+                // points per world unit along the axis
+                let Some(screen_axis) = to_ui(global.translation() + axis).map(|end| end - origin)
+                else {
+                    continue;
+                };
+                if screen_axis.length_sq() < f32::EPSILON {
+                    continue;
+                }
+                let direction = screen_axis.normalized();
+                let tip = origin + direction * AXIS_LENGTH;
+
+                let id = ui.id().with(("selection gizmo", entity, i));
+                let handle =
+                    egui::Rect::from_center_size(tip, egui::Vec2::splat(HANDLE_RADIUS * 3.0));
+                let response = ui.interact(handle, id, egui::Sense::drag());
+
+                let mut color = AXIS_COLORS[i];
+                if response.hovered() || response.dragged() {
+                    color = color.linear_multiply(1.5);
+                }
+                let painter = ui.painter_at(viewport);
+                painter.line_segment([origin, tip], egui::Stroke::new(2.0, color));
+                match self.mode {
+                    GizmoMode::Translate => {
+                        painter.circle_filled(tip, HANDLE_RADIUS, color);
+                    }
+                    GizmoMode::Scale => {
+                        let square = egui::Vec2::splat(HANDLE_RADIUS * 2.0);
+                        painter.rect_filled(egui::Rect::from_center_size(tip, square), 0.0, color);
+                    }
+                }
+
+                let delta = response.drag_delta();
+                if delta == egui::Vec2::ZERO {
+                    continue;
+                }
+                let parent_global = world
+                    .get::<Parent>(entity)
+                    .and_then(|parent| world.get::<GlobalTransform>(parent.get()))
+                    .copied();
+                let mut transform = world.get_mut::<Transform>(entity).unwrap();
+                match self.mode {
+                    GizmoMode::Translate => {
+                        let along = delta.dot(screen_axis) / screen_axis.length_sq();
+                        let world_delta = axis * along;
+                        let local_delta = match parent_global {
+                            Some(parent) => {
+                                parent.affine().inverse().transform_vector3(world_delta)
+                            }
+                            None => world_delta,
+                        };
+                        transform.translation += local_delta;
+                    }
+                    GizmoMode::Scale => {
+                        let factor = 1.0 + delta.dot(direction) / AXIS_LENGTH;
+                        transform.scale[i] *= factor.max(0.01);
+                    }
+                }
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+const AXIS_COLORS: [egui::Color32; 3] = [
+    egui::Color32::from_rgb(230, 60, 60),
+    egui::Color32::from_rgb(60, 200, 60),
+    egui::Color32::from_rgb(60, 110, 240),
+];
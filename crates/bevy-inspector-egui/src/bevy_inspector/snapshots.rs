@@ -0,0 +1,305 @@
+use std::{any::TypeId, sync::Arc};
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{Reflect, ReflectRef};
+use egui::RichText;
+
+use crate::theme::InspectorTheme;
+
+/// A field whose value differs between two values, see [`diff`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Path of the field, e.g. `translation.x` or `[2]`, empty if the whole value changed
+    pub path: String,
+    /// Debug representation of the field before
+    pub before: String,
+    /// Debug representation of the field after
+    pub after: String,
+}
+
+/// The fields of `after` whose values differ from `before`.
+///
+/// Structs, tuples, lists, maps and enums are compared field by field, all other values using
+/// [`Reflect::reflect_partial_eq`], or their debug representations if they don't support it.
+pub fn diff(before: &dyn Reflect, after: &dyn Reflect) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_into(&mut changes, String::new(), before, after);
+    changes
+}
+
+fn field_path(path: &str, field: &str) -> String {
+    match path.is_empty() {
+        true => field.to_owned(),
+        false => format!("{path}.{field}"),
+    }
+}
+
+fn changed(changes: &mut Vec<FieldChange>, path: String, before: String, after: String) {
+    changes.push(FieldChange {
+        path,
+        before,
+        after,
+    });
+}
+
+fn diff_into(
+    changes: &mut Vec<FieldChange>,
+    path: String,
+    before: &dyn Reflect,
+    after: &dyn Reflect,
+) {
+    match (before.reflect_ref(), after.reflect_ref()) {
+        (ReflectRef::Struct(before), ReflectRef::Struct(after)) => {
+            for (i, field) in before.iter_fields().enumerate() {
+                let name = before.name_at(i).unwrap_or_default();
+                if let Some(after) = after.field(name) {
+                    diff_into(changes, field_path(&path, name), field, after);
+                }
+            }
+        }
+        (ReflectRef::TupleStruct(before), ReflectRef::TupleStruct(after)) => {
+            for (i, (before, after)) in before.iter_fields().zip(after.iter_fields()).enumerate() {
+                diff_into(changes, field_path(&path, &i.to_string()), before, after);
+            }
+        }
+        (ReflectRef::Tuple(before), ReflectRef::Tuple(after)) => {
+            for (i, (before, after)) in before.iter_fields().zip(after.iter_fields()).enumerate() {
+                diff_into(changes, field_path(&path, &i.to_string()), before, after);
+            }
+        }
+        (ReflectRef::List(before), ReflectRef::List(after)) => {
+            for (i, (before, after)) in before.iter().zip(after.iter()).enumerate() {
+                diff_into(changes, format!("{path}[{i}]"), before, after);
+            }
+            if before.len() != after.len() {
+                let len = |len| format!("{len} elements");
+                changed(changes, path, len(before.len()), len(after.len()));
+            }
+        }
+        (ReflectRef::Array(before), ReflectRef::Array(after)) => {
+            for (i, (before, after)) in before.iter().zip(after.iter()).enumerate() {
+                diff_into(changes, format!("{path}[{i}]"), before, after);
+            }
+        }
+        (ReflectRef::Map(before), ReflectRef::Map(after)) => {
+            for (key, before_value) in before.iter() {
+                let path = format!("{path}[{key:?}]");
+                match after.get(key) {
+                    Some(after_value) => diff_into(changes, path, before_value, after_value),
+                    None => changed(changes, path, format!("{before_value:?}"), "removed".into()),
+                }
+            }
+            for (key, after_value) in after.iter() {
+                if before.get(key).is_none() {
+                    let path = format!("{path}[{key:?}]");
+                    changed(changes, path, "added".into(), format!("{after_value:?}"));
+                }
+            }
+        }
+        (ReflectRef::Enum(before_enum), ReflectRef::Enum(after_enum))
+            if before_enum.variant_name() == after_enum.variant_name() =>
+        {
+            for (i, (before, after)) in before_enum
+                .iter_fields()
+                .zip(after_enum.iter_fields())
+                .enumerate()
+            {
+                let name = before.name().map_or_else(|| i.to_string(), str::to_owned);
+                diff_into(
+                    changes,
+                    field_path(&path, &name),
+                    before.value(),
+                    after.value(),
+                );
+            }
+        }
+        _ => {
+            let is_equal = before.reflect_partial_eq(after);
+            let (before, after) = (format!("{before:?}"), format!("{after:?}"));
+            if !is_equal.unwrap_or(before == after) {
+                changed(changes, path, before, after);
+            }
+        }
+    }
+}
+
+/// The stored value of a component, kept in egui's memory
+#[derive(Clone)]
+struct Snapshot(Arc<dyn Reflect>);
+
+fn snapshot_id(entity: Entity, component: TypeId) -> egui::Id {
+    egui::Id::new(("component snapshot", entity, component))
+}
+
+/// Store the current `value` of the `component` of `entity`, replacing an earlier snapshot
+pub fn store_snapshot(ctx: &egui::Context, entity: Entity, component: TypeId, value: &dyn Reflect) {
+    let snapshot = Snapshot(Arc::from(value.clone_value()));
+    ctx.data_mut(|data| data.insert_temp(snapshot_id(entity, component), snapshot));
+}
+
+/// The value stored using [`store_snapshot`], if any
+pub fn snapshot(
+    ctx: &egui::Context,
+    entity: Entity,
+    component: TypeId,
+) -> Option<Arc<dyn Reflect>> {
+    ctx.data(|data| data.get_temp::<Snapshot>(snapshot_id(entity, component)))
+        .map(|snapshot| snapshot.0)
+}
+
+/// Remove the snapshot of the `component` of `entity`
+pub fn clear_snapshot(ctx: &egui::Context, entity: Entity, component: TypeId) {
+    ctx.data_mut(|data| data.remove::<Snapshot>(snapshot_id(entity, component)));
+}
+
+/// Store snapshots of all reflected components of `entity`
+pub fn store_entity_snapshot(ctx: &egui::Context, world: &World, entity: Entity) {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return;
+    };
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    for component_id in entity_ref.archetype().components() {
+        let Some(type_id) = world
+            .components()
+            .get_info(component_id)
+            .and_then(|info| info.type_id())
+        else {
+            continue;
+        };
+        let value = type_registry
+            .get_type_data::<ReflectComponent>(type_id)
+            .and_then(|reflect_component| reflect_component.reflect(entity_ref));
+        if let Some(value) = value {
+            store_snapshot(ctx, entity, type_id, value);
+        }
+    }
+}
+
+/// Buttons for the context menu of a component, storing or clearing its snapshot
+pub(crate) fn ui_for_snapshot_menu(
+    ui: &mut egui::Ui,
+    entity: Entity,
+    component: TypeId,
+    value: &dyn Reflect,
+) {
+    ui.separator();
+    if ui
+        .button("Snapshot")
+        .on_hover_text("Store the current value, to see which fields change from now on")
+        .clicked()
+    {
+        store_snapshot(ui.ctx(), entity, component, value);
+        ui.close_menu();
+    }
+    let has_snapshot = snapshot(ui.ctx(), entity, component).is_some();
+    if ui
+        .add_enabled(has_snapshot, egui::Button::new("Clear snapshot"))
+        .clicked()
+    {
+        clear_snapshot(ui.ctx(), entity, component);
+        ui.close_menu();
+    }
+}
+
+/// Display the fields of `value` which changed since the snapshot of the `component` of `entity`, if there is one
+pub(crate) fn ui_for_snapshot_diff(
+    ui: &mut egui::Ui,
+    entity: Entity,
+    component: TypeId,
+    value: &dyn Reflect,
+) {
+    let Some(snapshot) = snapshot(ui.ctx(), entity, component) else {
+        return;
+    };
+    let changes = diff(snapshot.as_ref(), value);
+    let palette = InspectorTheme::get(ui.ctx()).palette(ui.visuals().dark_mode);
+
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.strong("Since snapshot");
+            if ui.small_button("Clear").clicked() {
+                clear_snapshot(ui.ctx(), entity, component);
+            }
+        });
+        if changes.is_empty() {
+            ui.label("No changes");
+            return;
+        }
+        egui::Grid::new((snapshot_id(entity, component), "diff"))
+            .striped(true)
+            .show(ui, |ui| {
+                for change in &changes {
+                    let path = match change.path.is_empty() {
+                        true => "value",
+                        false => &change.path,
+                    };
+                    ui.label(path);
+                    ui.label(&change.before);
+                    ui.label(RichText::new(&change.after).color(palette.highlight));
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::Reflect;
+    use bevy_utils::HashMap;
+
+    use super::{diff, FieldChange};
+
+    #[derive(Reflect, Clone, Default)]
+    enum Shape {
+        #[default]
+        Point,
+        Circle {
+            radius: f32,
+        },
+    }
+
+    #[derive(Reflect, Clone, Default)]
+    struct Unit {
+        position: (f32, f32),
+        shape: Shape,
+        path: Vec<u32>,
+        stats: HashMap<String, u32>,
+    }
+
+    #[test]
+    fn diff_lists_changed_fields() {
+        let before = Unit {
+            position: (0.0, 1.0),
+            shape: Shape::Circle { radius: 1.0 },
+            path: vec![1, 2],
+            stats: HashMap::from([("hp".to_owned(), 10)]),
+        };
+        let snapshot = before.clone_value();
+        assert!(diff(snapshot.as_ref(), &before).is_empty());
+
+        let mut after = before.clone();
+        after.position.1 = 2.0;
+        after.shape = Shape::Circle { radius: 3.0 };
+        after.path.push(3);
+        after.stats.insert("hp".to_owned(), 5);
+        let paths: Vec<_> = diff(snapshot.as_ref(), &after)
+            .into_iter()
+            .map(|change| change.path)
+            .collect();
+        assert_eq!(
+            paths,
+            ["position.1", "shape.radius", "path", "stats[\"hp\"]"]
+        );
+
+        after.shape = Shape::Point;
+        let change = diff(&before.shape, &after.shape);
+        assert_eq!(
+            change,
+            [FieldChange {
+                path: String::new(),
+                before: "Circle { radius: 1.0 }".to_owned(),
+                after: "Point".to_owned(),
+            }]
+        );
+    }
+}
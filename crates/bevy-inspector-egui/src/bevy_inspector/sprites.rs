@@ -0,0 +1,77 @@
+use std::any::TypeId;
+
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::entity::Entity;
+use bevy_egui::EguiUserTextures;
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::TypeRegistry;
+use bevy_render::texture::Image;
+use bevy_sprite::{TextureAtlas, TextureAtlasLayout};
+use egui::load::SizedTexture;
+
+use crate::restricted_world_view::RestrictedWorldView;
+
+/// Length of the longer side of the frame preview
+const PREVIEW_SIZE: f32 = 96.0;
+
+/// The texture coordinates of `frame` in an atlas of the size `atlas_size`
+pub fn frame_uv(atlas_size: Vec2, frame: Rect) -> egui::Rect {
+    let min = frame.min / atlas_size;
+    let max = frame.max / atlas_size;
+    egui::Rect::from_min_max(egui::pos2(min.x, min.y), egui::pos2(max.x, max.y))
+}
+
+/// Display the selected frame of `atlas`, cut out of the [`Image`] of `entity`.
+///
+/// Shows nothing if the entity has no image or the layout isn't loaded yet.
+pub(crate) fn ui_for_atlas_preview(
+    ui: &mut egui::Ui,
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    atlas: &TextureAtlas,
+    type_registry: &TypeRegistry,
+) {
+    let Some(image) = world
+        .get_entity_component_reflect(entity, TypeId::of::<Handle<Image>>(), type_registry)
+        .ok()
+        .and_then(|(image, ..)| image.downcast_ref::<Handle<Image>>().cloned())
+    else {
+        return;
+    };
+    let (Ok(mut textures), Ok(layouts)) =
+        world.get_two_resources_mut::<EguiUserTextures, Assets<TextureAtlasLayout>>()
+    else {
+        return;
+    };
+    let Some(layout) = layouts.get(&atlas.layout) else {
+        return;
+    };
+    let Some(&frame) = layout.textures.get(atlas.index) else {
+        ui.label(format!(
+            "index {} is out of bounds for {} frames",
+            atlas.index,
+            layout.len()
+        ));
+        return;
+    };
+
+    let size = egui::vec2(frame.width(), frame.height());
+    let size = size * (PREVIEW_SIZE / size.max_elem().max(1.0));
+    let texture = SizedTexture::new(textures.add_image(image), size);
+    ui.add(egui::Image::new(texture).uv(frame_uv(layout.size, frame)))
+        .on_hover_text(format!("Frame {}", atlas.index));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Rect, Vec2};
+
+    use super::frame_uv;
+
+    #[test]
+    fn frame_uv_is_relative_to_atlas() {
+        let uv = frame_uv(Vec2::new(64.0, 32.0), Rect::new(16.0, 0.0, 32.0, 16.0));
+        assert_eq!(uv.min, egui::pos2(0.25, 0.0));
+        assert_eq!(uv.max, egui::pos2(0.5, 0.5));
+    }
+}
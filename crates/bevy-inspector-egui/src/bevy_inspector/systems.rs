@@ -0,0 +1,260 @@
+//! Listing the systems of all schedules, and disabling systems at runtime to isolate misbehaving ones.
+//!
+//! Bevy moves the systems out of a schedule while it runs, so the systems are captured into the [`ScheduleOverview`]
+//! by [`update_schedule_overview`] in [`First`](bevy_app::First) and [`Last`](bevy_app::Last) instead of being read
+//! by the UI directly. Bevy doesn't record how long individual systems take, use its `trace` feature for that.
+//!
+//! Only systems added with [`toggleable`] can be disabled, since run conditions can't be added to a schedule once it ran:
+//!
+//! ```rust
+//! use bevy_app::{App, Update};
+//! use bevy_inspector_egui::bevy_inspector::systems::toggleable;
+//!
+//! fn spawn_enemies() {}
+//!
+//! App::new().add_systems(Update, toggleable(spawn_enemies));
+//! ```
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    sync::Mutex,
+};
+
+use bevy_ecs::{
+    prelude::*,
+    schedule::{Schedule, SystemConfigs},
+};
+
+/// The systems disabled in the inspector, read by the condition added by [`toggleable`]
+#[derive(Resource, Default)]
+pub struct SystemToggles {
+    disabled: BTreeSet<Cow<'static, str>>,
+    // filled by the conditions the first time they run, which only have read access
+    toggleable: Mutex<BTreeSet<Cow<'static, str>>>,
+}
+
+impl SystemToggles {
+    pub fn is_enabled(&self, system: &str) -> bool {
+        !self.disabled.contains(system)
+    }
+
+    pub fn set_enabled(&mut self, system: impl Into<Cow<'static, str>>, enabled: bool) {
+        let system = system.into();
+        match enabled {
+            true => self.disabled.remove(&system),
+            false => self.disabled.insert(system),
+        };
+    }
+
+    /// The names of all disabled systems
+    pub fn disabled(&self) -> impl Iterator<Item = &str> {
+        self.disabled.iter().map(|name| &**name)
+    }
+
+    /// Whether the system was added with [`toggleable`] and ran at least once
+    pub fn is_toggleable(&self, system: &str) -> bool {
+        self.toggleable.lock().unwrap().contains(system)
+    }
+
+    fn check(&self, system: &str) -> bool {
+        let mut toggleable = self.toggleable.lock().unwrap();
+        if !toggleable.contains(system) {
+            toggleable.insert(Cow::Owned(system.to_owned()));
+        }
+        self.is_enabled(system)
+    }
+}
+
+/// Add a run condition to `system` which skips it while it is disabled in the [`SystemToggles`]
+pub fn toggleable<M>(system: impl IntoSystem<(), (), M>) -> SystemConfigs {
+    let system = IntoSystem::into_system(system);
+    let name = system.name();
+    system.run_if(move |toggles: Option<Res<SystemToggles>>| {
+        toggles.map_or(true, |toggles| toggles.check(&name))
+    })
+}
+
+/// A system of a schedule, see [`ScheduleOverview`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SystemInfo {
+    pub name: Cow<'static, str>,
+    /// The names of the run conditions of the system, which are only known until the schedule first runs
+    pub conditions: Option<Vec<Cow<'static, str>>>,
+}
+
+/// The systems of every schedule, captured by [`update_schedule_overview`]
+#[derive(Resource, Default, Clone, Debug)]
+pub struct ScheduleOverview {
+    /// The systems of each schedule by its label, in the order they are executed if the schedule already ran
+    pub schedules: BTreeMap<String, Vec<SystemInfo>>,
+}
+
+impl ScheduleOverview {
+    /// Replace the systems of all schedules in `schedules`, keeping the schedules which are currently running
+    pub fn update(&mut self, schedules: &Schedules) {
+        for (label, schedule) in schedules.iter() {
+            self.schedules
+                .insert(format!("{label:?}"), schedule_systems(schedule));
+        }
+    }
+}
+
+fn schedule_systems(schedule: &Schedule) -> Vec<SystemInfo> {
+    match schedule.systems() {
+        Ok(systems) => systems
+            .map(|(_, system)| SystemInfo {
+                name: system.name(),
+                conditions: None,
+            })
+            .collect(),
+        Err(_) => schedule
+            .graph()
+            .systems()
+            .map(|(_, system, conditions)| SystemInfo {
+                name: system.name(),
+                conditions: Some(
+                    conditions
+                        .iter()
+                        .map(|condition| condition.name())
+                        .collect(),
+                ),
+            })
+            .collect(),
+    }
+}
+
+/// Capture the systems of all schedules which aren't running into the [`ScheduleOverview`]
+pub fn update_schedule_overview(schedules: Res<Schedules>, mut overview: ResMut<ScheduleOverview>) {
+    overview.update(&schedules);
+}
+
+/// Display the systems of every schedule in the [`ScheduleOverview`], with a checkbox for toggling them if they are
+/// [`toggleable`].
+pub fn ui_for_systems(world: &mut World, ui: &mut egui::Ui) {
+    let Some(overview) = world.get_resource::<ScheduleOverview>().cloned() else {
+        ui.label("No ScheduleOverview resource, add the SystemsInspectorPlugin");
+        return;
+    };
+    if !world.contains_resource::<SystemToggles>() {
+        world.init_resource::<SystemToggles>();
+    }
+    let mut toggles = world.resource_mut::<SystemToggles>();
+
+    let search_id = egui::Id::new("systems ui search");
+    let mut search = ui.data_mut(|data| data.get_temp::<String>(search_id).unwrap_or_default());
+    ui.horizontal(|ui| {
+        ui.label("Search");
+        ui.text_edit_singleline(&mut search);
+    });
+    let search_lower = search.to_lowercase();
+    ui.data_mut(|data| data.insert_temp(search_id, search));
+
+    let disabled = toggles.disabled.len();
+    if disabled > 0 {
+        ui.horizontal(|ui| {
+            ui.label(format!("{disabled} systems disabled"));
+            if ui.button("Enable all").clicked() {
+                toggles.disabled.clear();
+            }
+        });
+    }
+
+    for (label, systems) in &overview.schedules {
+        let systems: Vec<_> = systems
+            .iter()
+            .filter(|system| system.name.to_lowercase().contains(&search_lower))
+            .collect();
+        if systems.is_empty() && !search_lower.is_empty() {
+            continue;
+        }
+
+        egui::CollapsingHeader::new(format!("{label} ({} systems)", systems.len()))
+            .id_source(("systems ui schedule", label))
+            .show(ui, |ui| {
+                egui::Grid::new(("systems ui grid", label))
+                    .striped(true)
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for system in systems {
+                            ui_for_system(ui, &mut toggles, system);
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
+
+fn ui_for_system(ui: &mut egui::Ui, toggles: &mut SystemToggles, system: &SystemInfo) {
+    let short_name = pretty_type_name::pretty_type_name_str(&system.name);
+    match toggles.is_toggleable(&system.name) {
+        true => {
+            let mut enabled = toggles.is_enabled(&system.name);
+            if ui
+                .checkbox(&mut enabled, short_name)
+                .on_hover_text(&*system.name)
+                .changed()
+            {
+                toggles.set_enabled(system.name.clone(), enabled);
+            }
+        }
+        false => {
+            ui.label(short_name).on_hover_text(format!(
+                "{}\nAdd the system with `toggleable` to disable it here",
+                system.name
+            ));
+        }
+    }
+
+    match &system.conditions {
+        Some(conditions) if !conditions.is_empty() => {
+            let conditions: Vec<_> = conditions
+                .iter()
+                .map(|condition| pretty_type_name::pretty_type_name_str(condition))
+                .collect();
+            ui.weak(format!("if {}", conditions.join(", ")));
+        }
+        _ => {
+            ui.label("");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{prelude::*, schedule::Schedule};
+
+    use super::{toggleable, SystemToggles};
+
+    #[derive(Resource, Default)]
+    struct Runs(usize);
+
+    fn count(mut runs: ResMut<Runs>) {
+        runs.0 += 1;
+    }
+
+    #[test]
+    fn disabled_systems_are_skipped() {
+        let mut world = World::new();
+        world.init_resource::<Runs>();
+        world.init_resource::<SystemToggles>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(toggleable(count));
+
+        schedule.run(&mut world);
+        let name = std::any::type_name_of_val(&count);
+        assert!(world.resource::<SystemToggles>().is_toggleable(name));
+
+        world
+            .resource_mut::<SystemToggles>()
+            .set_enabled(name, false);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Runs>().0, 1);
+
+        world
+            .resource_mut::<SystemToggles>()
+            .set_enabled(name, true);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Runs>().0, 2);
+    }
+}
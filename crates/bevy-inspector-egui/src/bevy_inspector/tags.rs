@@ -0,0 +1,243 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    hash::{Hash, Hasher},
+    io,
+    path::Path,
+};
+
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use egui::text::{LayoutJob, TextFormat};
+
+/// User-defined tags of entities, for organizing them in the world inspector without adding components to the game.
+///
+/// Tags are shown as colored labels next to the entity names and can be edited by right-clicking an entity.
+/// They can be written to and read from a file using [`save`] and [`load`], which match entities by their [`Name`].
+#[derive(Resource, Default, Debug, Clone)]
+pub struct EntityTags {
+    tags: HashMap<Entity, BTreeSet<String>>,
+}
+
+impl EntityTags {
+    pub fn add(&mut self, entity: Entity, tag: impl Into<String>) {
+        self.tags.entry(entity).or_default().insert(tag.into());
+    }
+
+    pub fn remove(&mut self, entity: Entity, tag: &str) {
+        if let Some(tags) = self.tags.get_mut(&entity) {
+            tags.remove(tag);
+            if tags.is_empty() {
+                self.tags.remove(&entity);
+            }
+        }
+    }
+
+    /// Remove all tags of `entity`
+    pub fn clear(&mut self, entity: Entity) {
+        self.tags.remove(&entity);
+    }
+
+    pub fn has(&self, entity: Entity, tag: &str) -> bool {
+        self.tags
+            .get(&entity)
+            .is_some_and(|tags| tags.contains(tag))
+    }
+
+    /// The tags of `entity` in alphabetical order
+    pub fn tags(&self, entity: Entity) -> impl Iterator<Item = &str> + '_ {
+        self.tags
+            .get(&entity)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// All tags used by any entity, in alphabetical order
+    pub fn all_tags(&self) -> BTreeSet<&str> {
+        self.tags.values().flatten().map(String::as_str).collect()
+    }
+
+    pub fn entities_with<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = Entity> + 'a {
+        self.tags
+            .iter()
+            .filter(move |(_, tags)| tags.contains(tag))
+            .map(|(entity, _)| *entity)
+    }
+
+    /// Forget the tags of entities which no longer exist
+    pub fn retain_existing(&mut self, world: &World) {
+        self.tags
+            .retain(|&entity, _| world.get_entity(entity).is_some());
+    }
+}
+
+/// The label color of `tag`, derived from its name
+pub fn tag_color(tag: &str) -> egui::Color32 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    tag.hash(&mut hasher);
+    let hue = (hasher.finish() % 360) as f32 / 360.0;
+    egui::ecolor::Hsva::new(hue, 0.6, 0.85, 1.0).into()
+}
+
+/// The entity `name` followed by the colored tags of `entity`, for use as a header text
+pub fn entity_label(
+    ui: &egui::Ui,
+    world: &World,
+    entity: Entity,
+    name: &str,
+    strong: bool,
+) -> egui::WidgetText {
+    let tags = world.get_resource::<EntityTags>();
+    let Some(tags) = tags.filter(|tags| tags.tags(entity).next().is_some()) else {
+        let name = egui::RichText::new(name);
+        return if strong { name.strong() } else { name }.into();
+    };
+
+    let font_id = egui::TextStyle::Button.resolve(ui.style());
+    let name_color = match strong {
+        true => ui.visuals().strong_text_color(),
+        false => ui.visuals().text_color(),
+    };
+    let mut job = LayoutJob::default();
+    job.append(name, 0.0, TextFormat::simple(font_id.clone(), name_color));
+    for tag in tags.tags(entity) {
+        let color = tag_color(tag);
+        let format = TextFormat {
+            background: color.gamma_multiply(0.2),
+            ..TextFormat::simple(font_id.clone(), color)
+        };
+        job.append(&format!("#{tag}"), 6.0, format);
+    }
+    job.into()
+}
+
+/// Display checkboxes for the existing tags and a text field for adding a new tag to `entity`
+pub fn ui_for_entity_tags(world: &mut World, ui: &mut egui::Ui, entity: Entity) {
+    let mut tags = world.get_resource_or_insert_with(EntityTags::default);
+
+    let all_tags: Vec<String> = tags.all_tags().into_iter().map(str::to_owned).collect();
+    for tag in all_tags {
+        let mut has = tags.has(entity, &tag);
+        let text = egui::RichText::new(format!("#{tag}")).color(tag_color(&tag));
+        if ui.checkbox(&mut has, text).changed() {
+            if has {
+                tags.add(entity, tag);
+            } else {
+                tags.remove(entity, &tag);
+            }
+        }
+    }
+
+    let id = egui::Id::new("bevy-inspector-egui new tag").with(entity);
+    let mut new_tag = ui.data_mut(|data| data.get_temp::<String>(id).unwrap_or_default());
+    let response = ui.add(egui::TextEdit::singleline(&mut new_tag).hint_text("New tag"));
+    if response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+        let tag = new_tag.trim().trim_start_matches('#');
+        if !tag.is_empty() {
+            tags.add(entity, tag);
+        }
+        new_tag.clear();
+    }
+    ui.data_mut(|data| data.insert_temp(id, new_tag));
+}
+
+/// Display a combo box for choosing a tag to filter by, stored in the memory of `ui` under `id`.
+///
+/// Returns the selected tag, or `None` if all entities should be shown.
+pub fn ui_for_tag_filter(world: &World, ui: &mut egui::Ui, id: egui::Id) -> Option<String> {
+    let mut selected = ui.data_mut(|data| data.get_persisted::<Option<String>>(id).flatten());
+    let all_tags = world
+        .get_resource::<EntityTags>()
+        .map(EntityTags::all_tags)
+        .unwrap_or_default();
+    if all_tags.is_empty() && selected.is_none() {
+        return None;
+    }
+
+    egui::ComboBox::from_id_source(id)
+        .selected_text(match &selected {
+            Some(tag) => egui::RichText::new(format!("#{tag}")).color(tag_color(tag)),
+            None => egui::RichText::new("All tags"),
+        })
+        .show_ui(ui, |ui| {
+            ui.selectable_value(&mut selected, None, "All tags");
+            for tag in all_tags {
+                let text = egui::RichText::new(format!("#{tag}")).color(tag_color(tag));
+                ui.selectable_value(&mut selected, Some(tag.to_owned()), text);
+            }
+        });
+    ui.data_mut(|data| data.insert_persisted(id, selected.clone()));
+    selected
+}
+
+/// Write the tags of all entities with a [`Name`] to the file at `path`
+pub fn save(world: &World, path: impl AsRef<Path>) -> io::Result<()> {
+    let Some(tags) = world.get_resource::<EntityTags>() else {
+        return std::fs::write(path, "{}");
+    };
+    let mut by_name = BTreeMap::<String, BTreeSet<String>>::new();
+    for (entity, entity_tags) in &tags.tags {
+        if let Some(name) = world.get::<Name>(*entity) {
+            by_name
+                .entry(name.as_str().to_owned())
+                .or_default()
+                .extend(entity_tags.iter().cloned());
+        }
+    }
+    let text = ron::ser::to_string_pretty(&by_name, ron::ser::PrettyConfig::default())
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    std::fs::write(path, text)
+}
+
+/// Add the tags in the file at `path` written by [`save`] to all entities with a matching [`Name`]
+pub fn load(world: &mut World, path: impl AsRef<Path>) -> io::Result<()> {
+    let text = std::fs::read_to_string(path)?;
+    let by_name: BTreeMap<String, BTreeSet<String>> =
+        ron::from_str(&text).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+    let entities: Vec<_> = world
+        .query::<(Entity, &Name)>()
+        .iter(world)
+        .filter_map(|(entity, name)| Some((entity, by_name.get(name.as_str())?.clone())))
+        .collect();
+    let mut tags = world.get_resource_or_insert_with(EntityTags::default);
+    for (entity, entity_tags) in entities {
+        for tag in entity_tags {
+            tags.add(entity, tag);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::world::World;
+
+    use super::EntityTags;
+
+    #[test]
+    fn tags_roundtrip_by_name() {
+        let mut world = World::new();
+        let player = world.spawn(Name::new("Player")).id();
+        let unnamed = world.spawn_empty().id();
+
+        let mut tags = EntityTags::default();
+        tags.add(player, "bug");
+        tags.add(player, "ai");
+        tags.add(unnamed, "bug");
+        assert_eq!(tags.tags(player).collect::<Vec<_>>(), ["ai", "bug"]);
+        assert_eq!(tags.entities_with("bug").count(), 2);
+        world.insert_resource(tags);
+
+        let path = std::env::temp_dir().join("bevy-inspector-egui-tags-test.ron");
+        super::save(&world, &path).unwrap();
+
+        let mut world = World::new();
+        let player = world.spawn(Name::new("Player")).id();
+        super::load(&mut world, &path).unwrap();
+        let tags = world.resource::<EntityTags>();
+        assert!(tags.has(player, "ai") && tags.has(player, "bug"));
+        assert_eq!(tags.entities_with("bug").count(), 1);
+    }
+}
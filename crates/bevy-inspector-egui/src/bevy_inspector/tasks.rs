@@ -0,0 +1,353 @@
+//! Observing long-running background work on Bevy's task pools.
+//!
+//! Bevy's task pools don't count the tasks running on them, so only tasks wrapped with [`TaskProgress::wrap`] or
+//! registered with [`TaskProgress::track`] are listed and counted per pool. Each of them has a name and can report
+//! its progress through the [`TaskHandle`] it is given, and is marked as finished once the handle is dropped:
+//!
+//! ```rust
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::tasks::{TaskPoolKind, TaskProgress};
+//! use bevy_tasks::AsyncComputeTaskPool;
+//!
+//! fn bake_lightmaps(tasks: Res<TaskProgress>) {
+//!     let bake = tasks.wrap(TaskPoolKind::AsyncCompute, "bake lightmaps", |handle| async move {
+//!         for i in 0..10 {
+//!             // ..
+//!             handle.set_progress((i + 1) as f32 / 10.0);
+//!         }
+//!     });
+//!     AsyncComputeTaskPool::get().spawn(bake).detach();
+//! }
+//! ```
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, VecDeque},
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy_ecs::prelude::*;
+use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPool};
+use bevy_utils::Instant;
+
+/// How many finished tasks the [`TaskProgress`] remembers
+const FINISHED_CAPACITY: usize = 32;
+
+/// One of Bevy's global task pools
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPoolKind {
+    /// The [`ComputeTaskPool`], for work which has to finish within the frame
+    Compute,
+    /// The [`AsyncComputeTaskPool`], for work which may take several frames
+    AsyncCompute,
+    /// The [`IoTaskPool`], for work waiting on files or the network
+    Io,
+}
+
+impl TaskPoolKind {
+    pub const ALL: [TaskPoolKind; 3] = [
+        TaskPoolKind::Compute,
+        TaskPoolKind::AsyncCompute,
+        TaskPoolKind::Io,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TaskPoolKind::Compute => "Compute",
+            TaskPoolKind::AsyncCompute => "Async compute",
+            TaskPoolKind::Io => "IO",
+        }
+    }
+
+    /// The pool, or `None` if it wasn't created yet, which the `TaskPoolPlugin` does
+    pub fn pool(self) -> Option<&'static TaskPool> {
+        match self {
+            TaskPoolKind::Compute => ComputeTaskPool::try_get().map(|pool| &**pool),
+            TaskPoolKind::AsyncCompute => AsyncComputeTaskPool::try_get().map(|pool| &**pool),
+            TaskPoolKind::Io => IoTaskPool::try_get().map(|pool| &**pool),
+        }
+    }
+}
+
+/// A task listed in the [`TaskProgress`]
+#[derive(Clone, Debug)]
+pub struct TrackedTask {
+    pub name: Cow<'static, str>,
+    /// The pool the task was spawned on, if it was listed with [`TaskProgress::wrap`] or [`TaskProgress::track_on`]
+    pub pool: Option<TaskPoolKind>,
+    /// The progress from `0.0` to `1.0` last reported with [`TaskHandle::set_progress`]
+    pub progress: Option<f32>,
+    pub started: Instant,
+    pub finished: Option<Instant>,
+}
+
+impl TrackedTask {
+    /// How long the task ran, or has been running so far
+    pub fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(Instant::now) - self.started
+    }
+}
+
+#[derive(Default)]
+struct TaskList {
+    next_id: u64,
+    running: BTreeMap<u64, TrackedTask>,
+    finished: VecDeque<TrackedTask>,
+}
+
+/// The tasks wrapped with [`TaskProgress::wrap`] or registered with [`TaskProgress::track`], shown by
+/// [`ui_for_tasks`].
+///
+/// Cloning it is cheap and the clones share their tasks, so it can be moved into tasks spawning further tasks.
+#[derive(Resource, Clone, Default)]
+pub struct TaskProgress {
+    tasks: Arc<Mutex<TaskList>>,
+}
+
+impl TaskProgress {
+    /// List a task which was spawned some other way, until the returned handle is dropped
+    pub fn track(&self, name: impl Into<Cow<'static, str>>) -> TaskHandle {
+        self.track_in(name.into(), None)
+    }
+
+    /// List a task spawned on the `pool`, until the returned handle is dropped
+    pub fn track_on(&self, pool: TaskPoolKind, name: impl Into<Cow<'static, str>>) -> TaskHandle {
+        self.track_in(name.into(), Some(pool))
+    }
+
+    /// List the future returned by `task` from now until it completes, for spawning it on the `pool`
+    pub fn wrap<Fut: Future>(
+        &self,
+        pool: TaskPoolKind,
+        name: impl Into<Cow<'static, str>>,
+        task: impl FnOnce(TaskHandle) -> Fut,
+    ) -> impl Future<Output = Fut::Output> {
+        let handle = self.track_on(pool, name);
+        let future = task(handle.clone());
+        async move {
+            let output = future.await;
+            drop(handle);
+            output
+        }
+    }
+
+    fn track_in(&self, name: Cow<'static, str>, pool: Option<TaskPoolKind>) -> TaskHandle {
+        let mut tasks = self.tasks.lock().unwrap();
+        let id = tasks.next_id;
+        tasks.next_id += 1;
+        tasks.running.insert(
+            id,
+            TrackedTask {
+                name,
+                pool,
+                progress: None,
+                started: Instant::now(),
+                finished: None,
+            },
+        );
+        TaskHandle(Arc::new(HandleInner {
+            id,
+            tasks: Arc::clone(&self.tasks),
+        }))
+    }
+
+    /// The running tasks, in the order they were started
+    pub fn running(&self) -> Vec<TrackedTask> {
+        self.tasks
+            .lock()
+            .unwrap()
+            .running
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// The most recently finished tasks, the latest first
+    pub fn finished(&self) -> Vec<TrackedTask> {
+        self.tasks
+            .lock()
+            .unwrap()
+            .finished
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// The number of running tasks listed as spawned on `pool`
+    pub fn running_on(&self, pool: TaskPoolKind) -> usize {
+        let tasks = self.tasks.lock().unwrap();
+        tasks
+            .running
+            .values()
+            .filter(|task| task.pool == Some(pool))
+            .count()
+    }
+
+    pub fn clear_finished(&self) {
+        self.tasks.lock().unwrap().finished.clear();
+    }
+}
+
+/// Reports the progress of a task listed in the [`TaskProgress`], which is marked as finished once the handle and all
+/// its clones are dropped
+#[derive(Clone)]
+pub struct TaskHandle(Arc<HandleInner>);
+
+struct HandleInner {
+    id: u64,
+    tasks: Arc<Mutex<TaskList>>,
+}
+
+impl TaskHandle {
+    /// Set the progress of the task from `0.0` to `1.0`
+    pub fn set_progress(&self, progress: f32) {
+        let mut tasks = self.0.tasks.lock().unwrap();
+        if let Some(task) = tasks.running.get_mut(&self.0.id) {
+            task.progress = Some(progress.clamp(0.0, 1.0));
+        }
+    }
+}
+
+impl Drop for HandleInner {
+    fn drop(&mut self) {
+        // the task may be dropped while the lock is poisoned, when a panic unwinds through it
+        let Ok(mut tasks) = self.tasks.lock() else {
+            return;
+        };
+        if let Some(mut task) = tasks.running.remove(&self.id) {
+            task.finished = Some(Instant::now());
+            tasks.finished.push_front(task);
+            tasks.finished.truncate(FINISHED_CAPACITY);
+        }
+    }
+}
+
+/// Display the threads of each task pool, and the tasks in the [`TaskProgress`] with their progress
+pub fn ui_for_tasks(world: &mut World, ui: &mut egui::Ui) {
+    if !world.contains_resource::<TaskProgress>() {
+        world.init_resource::<TaskProgress>();
+    }
+    let progress = world.resource::<TaskProgress>().clone();
+
+    egui::Grid::new("tasks ui pools")
+        .striped(true)
+        .num_columns(3)
+        .show(ui, |ui| {
+            ui.strong("Pool");
+            ui.strong("Threads");
+            ui.strong("Tasks");
+            ui.end_row();
+            for pool in TaskPoolKind::ALL {
+                ui.label(pool.name());
+                match pool.pool() {
+                    Some(task_pool) => ui.label(task_pool.thread_num().to_string()),
+                    None => ui.weak("not created"),
+                };
+                ui.label(progress.running_on(pool).to_string());
+                ui.end_row();
+            }
+        });
+
+    ui.separator();
+    let running = progress.running();
+    if running.is_empty() {
+        ui.weak("No running tasks. Wrap tasks with `TaskProgress::wrap` to list them here.");
+    } else {
+        // keep the progress bars and elapsed times moving
+        ui.ctx().request_repaint();
+        egui::Grid::new("tasks ui running")
+            .striped(true)
+            .num_columns(3)
+            .show(ui, |ui| {
+                for task in &running {
+                    ui_for_task_name(ui, task);
+                    match task.progress {
+                        Some(fraction) => {
+                            ui.add(egui::ProgressBar::new(fraction).show_percentage());
+                        }
+                        None => {
+                            ui.spinner();
+                        }
+                    }
+                    ui.label(format_elapsed(task.elapsed()));
+                    ui.end_row();
+                }
+            });
+    }
+
+    let finished = progress.finished();
+    if finished.is_empty() {
+        return;
+    }
+    egui::CollapsingHeader::new(format!("Finished ({})", finished.len()))
+        .id_source("tasks ui finished")
+        .show(ui, |ui| {
+            if ui.button("Clear").clicked() {
+                progress.clear_finished();
+            }
+            egui::Grid::new("tasks ui finished grid")
+                .striped(true)
+                .num_columns(2)
+                .show(ui, |ui| {
+                    for task in &finished {
+                        ui_for_task_name(ui, task);
+                        ui.label(format_elapsed(task.elapsed()));
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+fn ui_for_task_name(ui: &mut egui::Ui, task: &TrackedTask) {
+    let response = ui.label(&*task.name);
+    if let Some(pool) = task.pool {
+        response.on_hover_text(format!("{} task pool", pool.name()));
+    }
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    match elapsed.as_secs() {
+        0..=59 => format!("{:.1}s", elapsed.as_secs_f32()),
+        secs => format!("{}m {:02}s", secs / 60, secs % 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_tasks::block_on;
+
+    use super::{TaskPoolKind, TaskProgress};
+
+    #[test]
+    fn tasks_are_listed_until_they_finish() {
+        let progress = TaskProgress::default();
+        let handle = progress.track("loading");
+        handle.set_progress(0.25);
+        assert_eq!(progress.running()[0].progress, Some(0.25));
+
+        drop(handle);
+        assert!(progress.running().is_empty());
+        assert_eq!(progress.finished()[0].name, "loading");
+    }
+
+    #[test]
+    fn wrapped_tasks_are_counted_per_pool() {
+        let progress = TaskProgress::default();
+        let task = progress.wrap(
+            TaskPoolKind::AsyncCompute,
+            "computing",
+            |handle| async move {
+                handle.set_progress(1.0);
+                1
+            },
+        );
+        assert_eq!(progress.running_on(TaskPoolKind::AsyncCompute), 1);
+        assert_eq!(progress.running_on(TaskPoolKind::Io), 0);
+
+        assert_eq!(block_on(task), 1);
+        assert_eq!(progress.running_on(TaskPoolKind::AsyncCompute), 0);
+        assert_eq!(progress.finished()[0].progress, Some(1.0));
+    }
+}
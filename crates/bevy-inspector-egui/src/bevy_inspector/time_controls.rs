@@ -0,0 +1,168 @@
+//! Pausing, single-stepping and slowing down the game by controlling [`Time<Virtual>`].
+//!
+//! Gameplay reading [`Time`] in `Update` or running in `FixedUpdate` follows the virtual clock, so it stops while paused
+//! and can be advanced frame by frame with [`TimeControls::step`]. A step unpauses the clock for the next time update
+//! in [`First`](bevy_app::First) and pauses it again right after, which is done by [`step_virtual_time`] and
+//! [`finish_virtual_time_step`]. Rendering, the inspector and anything else reading [`Time<Real>`](bevy_time::Real)
+//! keep running.
+
+use bevy_ecs::prelude::*;
+use bevy_time::{Time, Virtual};
+
+/// The relative speeds offered as buttons next to the speed slider
+const SPEED_PRESETS: [f32; 5] = [0.1, 0.25, 0.5, 1.0, 2.0];
+
+/// Frame steps requested from the inspector, applied to [`Time<Virtual>`] by [`step_virtual_time`]
+#[derive(Resource, Default, Debug)]
+pub struct TimeControls {
+    steps: u32,
+    stepping: bool,
+}
+
+impl TimeControls {
+    /// Advance the paused virtual clock by `frames` frames, one per update. Does nothing while the clock is running.
+    pub fn step(&mut self, frames: u32) {
+        self.steps += frames;
+    }
+
+    /// The number of frames which still have to be stepped
+    pub fn pending_steps(&self) -> u32 {
+        self.steps
+    }
+}
+
+/// Unpause the virtual clock for this update if a step was requested, before it is advanced in [`TimeSystem`](bevy_time::TimeSystem)
+pub fn step_virtual_time(mut controls: ResMut<TimeControls>, mut time: ResMut<Time<Virtual>>) {
+    if !time.is_paused() {
+        controls.steps = 0;
+        return;
+    }
+    if controls.steps > 0 {
+        controls.steps -= 1;
+        controls.stepping = true;
+        time.unpause();
+    }
+}
+
+/// Pause the virtual clock again after it was advanced by a step
+pub fn finish_virtual_time_step(
+    mut controls: ResMut<TimeControls>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if std::mem::take(&mut controls.stepping) {
+        time.pause();
+    }
+}
+
+/// Display buttons for pausing and stepping the virtual clock and a slider for its relative speed.
+pub fn ui_for_time_controls(world: &mut World, ui: &mut egui::Ui) {
+    if !world.contains_resource::<Time<Virtual>>() {
+        ui.label("No Time<Virtual> resource, add the TimePlugin");
+        return;
+    }
+    if !world.contains_resource::<TimeControls>() {
+        world.init_resource::<TimeControls>();
+    }
+    world.resource_scope(|world, mut controls: Mut<TimeControls>| {
+        let mut time = world.resource_mut::<Time<Virtual>>();
+        let paused = time.is_paused();
+
+        ui.horizontal(|ui| {
+            let (label, hover) = match paused {
+                true => ("▶ Play", "Resume the virtual clock"),
+                false => ("⏸ Pause", "Stop the virtual clock"),
+            };
+            if ui.button(label).on_hover_text(hover).clicked() {
+                match paused {
+                    true => {
+                        controls.steps = 0;
+                        time.unpause();
+                    }
+                    false => time.pause(),
+                }
+            }
+            if ui
+                .add_enabled(paused, egui::Button::new("⏭ Step"))
+                .on_hover_text("Advance the paused clock by one frame")
+                .clicked()
+            {
+                controls.step(1);
+            }
+            if controls.steps > 0 {
+                ui.weak(format!("{} steps pending", controls.steps));
+            }
+        });
+
+        let mut speed = time.relative_speed();
+        ui.horizontal(|ui| {
+            ui.label("Speed");
+            let slider = egui::Slider::new(&mut speed, 0.01..=10.0)
+                .logarithmic(true)
+                .suffix("×");
+            if ui.add(slider).changed() {
+                time.set_relative_speed(speed);
+            }
+        });
+        ui.horizontal(|ui| {
+            for preset in SPEED_PRESETS {
+                if ui
+                    .selectable_label(speed == preset, format!("{preset}×"))
+                    .clicked()
+                {
+                    time.set_relative_speed(preset);
+                }
+            }
+        });
+
+        ui.weak(format!(
+            "elapsed {:.3}s, delta {:.1}ms",
+            time.elapsed_seconds_f64(),
+            time.delta_seconds_f64() * 1000.0
+        ));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy_app::{App, First};
+    use bevy_ecs::prelude::*;
+    use bevy_time::{Time, TimePlugin, TimeSystem, TimeUpdateStrategy, Virtual};
+
+    use super::{finish_virtual_time_step, step_virtual_time, TimeControls};
+
+    #[test]
+    fn steps_advance_the_paused_clock_by_one_frame() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .init_resource::<TimeControls>()
+            .add_systems(
+                First,
+                (
+                    step_virtual_time.before(TimeSystem),
+                    finish_virtual_time_step.after(TimeSystem),
+                ),
+            );
+        app.update();
+        app.world.resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        let elapsed = |app: &App| app.world.resource::<Time<Virtual>>().elapsed();
+        let paused_at = elapsed(&app);
+
+        app.world.resource_mut::<TimeControls>().step(1);
+        app.update();
+        assert_eq!(elapsed(&app), paused_at + Duration::from_millis(100));
+        assert_eq!(
+            app.world.resource::<Time>().delta(),
+            Duration::from_millis(100)
+        );
+        assert!(app.world.resource::<Time<Virtual>>().is_paused());
+
+        app.update();
+        assert_eq!(elapsed(&app), paused_at + Duration::from_millis(100));
+    }
+}
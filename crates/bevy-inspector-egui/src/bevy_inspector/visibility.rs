@@ -0,0 +1,259 @@
+use bevy_ecs::prelude::*;
+use bevy_math::{Vec3, Vec3A};
+use bevy_render::{
+    primitives::{Aabb, Frustum, HalfSpace},
+    view::{InheritedVisibility, NoFrustumCulling, VisibleEntities},
+};
+use bevy_transform::components::GlobalTransform;
+
+use super::guess_entity_name;
+
+/// Why an entity is or isn't rendered by a camera, as computed by bevy's visibility systems in the last frame
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VisibilityResult {
+    /// The entity is in the camera's [`VisibleEntities`]
+    Visible,
+    /// The entity or one of its ancestors is hidden through its [`Visibility`](bevy_render::view::Visibility)
+    Hidden,
+    /// The bounds of the entity lie outside of the camera's [`Frustum`]
+    OutsideFrustum,
+    /// The entity is inside the frustum but still not visible, usually because of its [`RenderLayers`](bevy_render::view::RenderLayers)
+    Excluded,
+}
+
+impl VisibilityResult {
+    pub fn description(self) -> &'static str {
+        match self {
+            VisibilityResult::Visible => "visible",
+            VisibilityResult::Hidden => "hidden",
+            VisibilityResult::OutsideFrustum => "outside of frustum",
+            VisibilityResult::Excluded => "excluded by render layers",
+        }
+    }
+
+    pub fn color(self) -> bevy_render::color::Color {
+        use bevy_render::color::Color;
+        match self {
+            VisibilityResult::Visible => Color::GREEN,
+            VisibilityResult::Hidden => Color::GRAY,
+            VisibilityResult::OutsideFrustum => Color::RED,
+            VisibilityResult::Excluded => Color::ORANGE,
+        }
+    }
+}
+
+/// The [`VisibilityResult`] of every entity with an [`Aabb`] for the camera entity `camera`.
+///
+/// Returns an empty list if `camera` doesn't have a [`Frustum`] and [`VisibleEntities`].
+pub fn visibility_results(world: &mut World, camera: Entity) -> Vec<(Entity, VisibilityResult)> {
+    let Some((frustum, visible)) = world
+        .query::<(&Frustum, &VisibleEntities)>()
+        .get(world, camera)
+        .ok()
+        .map(|(frustum, visible)| (*frustum, visible.entities.clone()))
+    else {
+        return Vec::new();
+    };
+
+    let mut results: Vec<_> = world
+        .query::<(
+            Entity,
+            &Aabb,
+            &GlobalTransform,
+            Option<&InheritedVisibility>,
+            Has<NoFrustumCulling>,
+        )>()
+        .iter(world)
+        .map(|(entity, aabb, transform, inherited, no_culling)| {
+            let result = if visible.contains(&entity) {
+                VisibilityResult::Visible
+            } else if inherited.is_some_and(|inherited| !inherited.get()) {
+                VisibilityResult::Hidden
+            } else if !no_culling && !frustum.intersects_obb(aabb, &transform.affine(), true, true)
+            {
+                VisibilityResult::OutsideFrustum
+            } else {
+                VisibilityResult::Excluded
+            };
+            (entity, result)
+        })
+        .collect();
+    results.sort_by_key(|&(entity, result)| (result, entity));
+    results
+}
+
+/// The eight corners of the frustum, near plane first
+pub fn frustum_corners(frustum: &Frustum) -> [Vec3; 8] {
+    let [left, right, bottom, top, near, far] = &frustum.half_spaces;
+    [
+        intersect(left, bottom, near),
+        intersect(right, bottom, near),
+        intersect(right, top, near),
+        intersect(left, top, near),
+        intersect(left, bottom, far),
+        intersect(right, bottom, far),
+        intersect(right, top, far),
+        intersect(left, top, far),
+    ]
+}
+
+fn intersect(a: &HalfSpace, b: &HalfSpace, c: &HalfSpace) -> Vec3 {
+    let (na, nb, nc) = (a.normal(), b.normal(), c.normal());
+    let denom = na.dot(nb.cross(nc));
+    let point: Vec3A =
+        -(a.d() * nb.cross(nc) + b.d() * nc.cross(na) + c.d() * na.cross(nb)) / denom;
+    point.into()
+}
+
+/// Whether the [`VisibilityDebugPlugin`] draws gizmos, and for which camera
+#[cfg(feature = "bevy_gizmos")]
+#[derive(Resource, Default, Debug, Clone)]
+pub struct VisibilityDebug {
+    pub camera: Option<Entity>,
+    /// Draw the frustum of the camera
+    pub frustum: bool,
+    /// Draw the bounds of all entities colored by their [`VisibilityResult::color`]
+    pub bounds: bool,
+}
+
+/// Plugin drawing the frustum and entity bounds configured in the [`VisibilityDebug`] resource using gizmos
+#[cfg(feature = "bevy_gizmos")]
+pub struct VisibilityDebugPlugin;
+
+#[cfg(feature = "bevy_gizmos")]
+impl bevy_app::Plugin for VisibilityDebugPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.init_resource::<VisibilityDebug>().add_systems(
+            bevy_app::PostUpdate,
+            draw_visibility_debug.after(bevy_render::view::VisibilitySystems::CheckVisibility),
+        );
+    }
+}
+
+#[cfg(feature = "bevy_gizmos")]
+fn draw_visibility_debug(world: &mut World) {
+    let debug = world.resource::<VisibilityDebug>().clone();
+    let Some(camera) = debug.camera else {
+        return;
+    };
+
+    let mut lines = Vec::new();
+    if debug.frustum {
+        if let Some(frustum) = world.get::<Frustum>(camera) {
+            let corners = frustum_corners(frustum);
+            for i in 0..4 {
+                let color = bevy_render::color::Color::YELLOW;
+                lines.push((corners[i], corners[(i + 1) % 4], color));
+                lines.push((corners[i + 4], corners[(i + 1) % 4 + 4], color));
+                lines.push((corners[i], corners[i + 4], color));
+            }
+        }
+    }
+
+    let mut boxes = Vec::new();
+    if debug.bounds {
+        for (entity, result) in visibility_results(world, camera) {
+            let Some(aabb) = world.get::<Aabb>(entity) else {
+                continue;
+            };
+            let transform = world.get::<GlobalTransform>(entity).copied();
+            let Some(transform) = transform else {
+                continue;
+            };
+            let local = bevy_transform::components::Transform::from_translation(aabb.center.into())
+                .with_scale((aabb.half_extents * 2.0).into());
+            boxes.push((transform * local, result.color()));
+        }
+    }
+
+    let mut state = bevy_ecs::system::SystemState::<bevy_gizmos::gizmos::Gizmos>::new(world);
+    let mut gizmos = state.get_mut(world);
+    for (start, end, color) in lines {
+        gizmos.line(start, end, color);
+    }
+    for (transform, color) in boxes {
+        gizmos.cuboid(transform, color);
+    }
+    state.apply(world);
+}
+
+/// Display the [`VisibilityResult`]s for the camera `camera`, and the entities not visible to it.
+///
+/// With the `bevy_gizmos` feature, if the `VisibilityDebug` resource exists, this also shows toggles for drawing the frustum and entity bounds of the camera.
+pub fn ui_for_visibility(world: &mut World, ui: &mut egui::Ui, camera: Entity) {
+    if world.get::<Frustum>(camera).is_none() {
+        ui.label(format!(
+            "{} has no frustum",
+            guess_entity_name(world, camera)
+        ));
+        return;
+    }
+
+    #[cfg(feature = "bevy_gizmos")]
+    if let Some(mut debug) = world.get_resource_mut::<VisibilityDebug>() {
+        let mut debugged = debug.camera == Some(camera);
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut debugged, "Draw gizmos");
+            ui.add_enabled_ui(debugged, |ui| {
+                ui.checkbox(&mut debug.frustum, "Frustum");
+                ui.checkbox(&mut debug.bounds, "Bounds");
+            });
+        });
+        if debugged {
+            debug.camera = Some(camera);
+        } else if debug.camera == Some(camera) {
+            debug.camera = None;
+        }
+    }
+
+    let results = visibility_results(world, camera);
+    let count = |result| results.iter().filter(|(_, r)| *r == result).count();
+
+    egui::Grid::new("visibility counts").show(ui, |ui| {
+        for result in [
+            VisibilityResult::Visible,
+            VisibilityResult::Hidden,
+            VisibilityResult::OutsideFrustum,
+            VisibilityResult::Excluded,
+        ] {
+            ui.label(result.description());
+            ui.label(count(result).to_string());
+            ui.end_row();
+        }
+    });
+
+    egui::CollapsingHeader::new("Not visible")
+        .id_source("visibility not visible")
+        .show(ui, |ui| {
+            egui::Grid::new("visibility results")
+                .striped(true)
+                .show(ui, |ui| {
+                    for &(entity, result) in &results {
+                        if result == VisibilityResult::Visible {
+                            continue;
+                        }
+                        ui.label(guess_entity_name(world, entity));
+                        ui.label(result.description());
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Mat4, Vec3};
+    use bevy_render::primitives::Frustum;
+
+    use super::frustum_corners;
+
+    #[test]
+    fn orthographic_frustum_corners() {
+        let projection = Mat4::orthographic_rh(-1.0, 1.0, -2.0, 2.0, 10.0, 0.0);
+        let frustum = Frustum::from_view_projection(&projection);
+        let corners = frustum_corners(&frustum);
+
+        assert!(corners[0].abs_diff_eq(Vec3::new(-1.0, -2.0, 0.0), 1e-4));
+        assert!(corners[6].abs_diff_eq(Vec3::new(1.0, 2.0, -10.0), 1e-4));
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use bevy_asset::ReflectAsset;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::RichText;
+
+use crate::theme::{InspectorTheme, ThemePalette};
+
+/// Counts of entities, resources and assets in the world at a single point in time.
+///
+/// Capture one before and one after e.g. loading and unloading a level, and compare them using [`WorldStats::diff`]
+/// to find out what was leaked.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorldStats {
+    /// Total number of entities
+    pub entities: usize,
+    /// Number of entities per archetype, keyed by the sorted component names of the archetype
+    pub archetypes: BTreeMap<String, usize>,
+    /// Names of all resources present in the world
+    pub resources: BTreeSet<String>,
+    /// Number of assets per reflected asset type
+    pub assets: BTreeMap<String, usize>,
+}
+
+/// Comparison of two [`WorldStats`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorldStatsDiff {
+    /// Entity count before and after
+    pub entities: (usize, usize),
+    /// Archetypes whose entity count changed, with the counts before and after
+    pub archetypes: Vec<(String, usize, usize)>,
+    /// Resources only present in the second snapshot
+    pub added_resources: Vec<String>,
+    /// Resources only present in the first snapshot
+    pub removed_resources: Vec<String>,
+    /// Asset types whose asset count changed, with the counts before and after
+    pub assets: Vec<(String, usize, usize)>,
+}
+
+impl WorldStatsDiff {
+    /// Whether the two snapshots are identical
+    pub fn is_empty(&self) -> bool {
+        self.entities.0 == self.entities.1
+            && self.archetypes.is_empty()
+            && self.added_resources.is_empty()
+            && self.removed_resources.is_empty()
+            && self.assets.is_empty()
+    }
+}
+
+impl WorldStats {
+    /// Capture the current statistics of the world.
+    ///
+    /// Asset counts are only collected for asset types registered with [`ReflectAsset`].
+    pub fn capture(world: &World, type_registry: &TypeRegistry) -> WorldStats {
+        let components = world.components();
+
+        let mut archetypes = BTreeMap::new();
+        for archetype in world.archetypes().iter() {
+            if archetype.is_empty() {
+                continue;
+            }
+            let mut names: Vec<_> = archetype
+                .components()
+                .filter_map(|id| components.get_info(id))
+                .map(|info| pretty_type_name::pretty_type_name_str(info.name()))
+                .collect();
+            names.sort();
+            *archetypes.entry(names.join(", ")).or_default() += archetype.len();
+        }
+
+        let resources = world
+            .storages()
+            .resources
+            .iter()
+            .filter(|(_, data)| data.is_present())
+            .filter_map(|(id, _)| components.get_info(id))
+            .map(|info| pretty_type_name::pretty_type_name_str(info.name()))
+            .collect();
+
+        let assets = type_registry
+            .iter()
+            .filter_map(|registration| {
+                let reflect_asset = registration.data::<ReflectAsset>()?;
+                let name = registration.type_info().type_path_table().short_path();
+                Some((name.to_string(), reflect_asset.len(world)))
+            })
+            .collect();
+
+        WorldStats {
+            entities: world.entities().len() as usize,
+            archetypes,
+            resources,
+            assets,
+        }
+    }
+
+    /// Compare these statistics to a later snapshot `after`
+    pub fn diff(&self, after: &WorldStats) -> WorldStatsDiff {
+        WorldStatsDiff {
+            entities: (self.entities, after.entities),
+            archetypes: diff_counts(&self.archetypes, &after.archetypes),
+            added_resources: after
+                .resources
+                .difference(&self.resources)
+                .cloned()
+                .collect(),
+            removed_resources: self
+                .resources
+                .difference(&after.resources)
+                .cloned()
+                .collect(),
+            assets: diff_counts(&self.assets, &after.assets),
+        }
+    }
+}
+
+fn diff_counts(
+    before: &BTreeMap<String, usize>,
+    after: &BTreeMap<String, usize>,
+) -> Vec<(String, usize, usize)> {
+    let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let before = before.get(key).copied().unwrap_or(0);
+            let after = after.get(key).copied().unwrap_or(0);
+            (before != after).then(|| (key.clone(), before, after))
+        })
+        .collect()
+}
+
+#[derive(Clone, Default)]
+struct Snapshots {
+    before: Option<WorldStats>,
+    after: Option<WorldStats>,
+}
+
+/// Display buttons to capture two [`WorldStats`] snapshots and the difference between them.
+///
+/// The snapshots are stored in egui's memory.
+pub fn ui_for_world_stats(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let id = egui::Id::new("world stats snapshots");
+    let mut snapshots = ui.memory_mut(|mem| mem.data.get_temp::<Snapshots>(id).unwrap_or_default());
+
+    ui.horizontal(|ui| {
+        if ui.button("Capture A").clicked() {
+            snapshots.before = Some(WorldStats::capture(world, &type_registry));
+        }
+        if ui.button("Capture B").clicked() {
+            snapshots.after = Some(WorldStats::capture(world, &type_registry));
+        }
+        if ui.button("Clear").clicked() {
+            snapshots = Snapshots::default();
+        }
+    });
+
+    match (&snapshots.before, &snapshots.after) {
+        (Some(before), Some(after)) => world_stats_diff_ui(ui, &before.diff(after)),
+        (Some(_), None) => {
+            ui.label("Capture snapshot B to compare.");
+        }
+        (None, _) => {
+            ui.label("Capture snapshot A to start.");
+        }
+    }
+
+    ui.memory_mut(|mem| mem.data.insert_temp(id, snapshots));
+}
+
+fn world_stats_diff_ui(ui: &mut egui::Ui, diff: &WorldStatsDiff) {
+    if diff.is_empty() {
+        ui.label("No differences");
+        return;
+    }
+    let palette = InspectorTheme::get(ui.ctx()).palette(ui.visuals().dark_mode);
+
+    egui::Grid::new("world stats diff")
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("");
+            ui.strong("A");
+            ui.strong("B");
+            ui.end_row();
+
+            let entities = diff.entities;
+            count_row(ui, &palette, "Entities", entities.0, entities.1);
+
+            for (name, before, after) in &diff.archetypes {
+                let name = format!("Archetype ({name})");
+                count_row(ui, &palette, &name, *before, *after);
+            }
+            for (name, before, after) in &diff.assets {
+                count_row(ui, &palette, &format!("Assets<{name}>"), *before, *after);
+            }
+            for name in &diff.added_resources {
+                ui.label(name);
+                ui.label("");
+                ui.label(RichText::new("added").color(palette.positive));
+                ui.end_row();
+            }
+            for name in &diff.removed_resources {
+                ui.label(name);
+                ui.label("");
+                ui.label(RichText::new("removed").color(palette.negative));
+                ui.end_row();
+            }
+        });
+}
+
+fn count_row(ui: &mut egui::Ui, palette: &ThemePalette, name: &str, before: usize, after: usize) {
+    let color = match after.cmp(&before) {
+        std::cmp::Ordering::Greater => palette.positive,
+        std::cmp::Ordering::Less => palette.negative,
+        std::cmp::Ordering::Equal => ui.visuals().text_color(),
+    };
+    ui.label(name);
+    ui.label(before.to_string());
+    ui.label(RichText::new(after.to_string()).color(color));
+    ui.end_row();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::TypeRegistry;
+
+    use super::WorldStats;
+
+    #[derive(Component)]
+    struct Leaked;
+
+    #[derive(Resource)]
+    struct LevelState;
+
+    #[test]
+    fn diff_detects_leaks() {
+        let type_registry = TypeRegistry::empty();
+        let mut world = World::new();
+        world.spawn_empty();
+
+        let before = WorldStats::capture(&world, &type_registry);
+        world.spawn(Leaked);
+        world.insert_resource(LevelState);
+        let after = WorldStats::capture(&world, &type_registry);
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.entities, (1, 2));
+        assert_eq!(diff.archetypes.len(), 1);
+        assert_eq!(diff.archetypes[0].1, 0);
+        assert_eq!(diff.archetypes[0].2, 1);
+        assert_eq!(diff.added_resources.len(), 1);
+        assert!(diff.removed_resources.is_empty());
+
+        assert!(after.diff(&after).is_empty());
+    }
+}
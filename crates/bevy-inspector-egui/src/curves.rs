@@ -0,0 +1,207 @@
+//! Keyed values over normalized time, like the size or color of a particle over its lifetime.
+//!
+//! [`Curve`] and [`ColorGradient`] are registered with an editor by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin),
+//! which shows them as a plot and as a preview strip with draggable keys. The editors are also available for plain values
+//! as [`widgets::curve`](crate::widgets::curve) and [`widgets::gradient`](crate::widgets::gradient).
+//!
+//! ```rust
+//! use bevy_inspector_egui::curves::{Curve, CurveKey};
+//!
+//! let size = Curve::new(vec![CurveKey::new(0.0, 1.0), CurveKey::new(1.0, 0.0)]);
+//! assert_eq!(size.sample(0.25), 0.75);
+//! ```
+
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(feature = "bevy_render")]
+use bevy_render::color::Color;
+
+/// A value at a position of a [`Curve`]
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Default)]
+pub struct CurveKey {
+    /// The position of the key between `0.0` and `1.0`
+    pub position: f32,
+    pub value: f32,
+}
+
+impl CurveKey {
+    pub fn new(position: f32, value: f32) -> Self {
+        CurveKey { position, value }
+    }
+}
+
+/// A number linearly interpolated between keys, see [`Curve::sample`]
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct Curve {
+    /// The keys of the curve, sorted by their position
+    pub keys: Vec<CurveKey>,
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Curve::constant(1.0)
+    }
+}
+
+impl Curve {
+    /// A curve through `keys`, which are sorted by their position
+    pub fn new(keys: Vec<CurveKey>) -> Self {
+        let mut curve = Curve { keys };
+        curve.sort();
+        curve
+    }
+
+    /// A curve with the same value everywhere
+    pub fn constant(value: f32) -> Self {
+        Curve::new(vec![CurveKey::new(0.0, value), CurveKey::new(1.0, value)])
+    }
+
+    /// Sort the keys by their position, after moving them
+    pub fn sort(&mut self) {
+        self.keys.sort_by(|a, b| a.position.total_cmp(&b.position));
+    }
+
+    /// The value at `position`, interpolated between the surrounding keys.
+    ///
+    /// Positions before the first or after the last key have the value of that key, a curve without keys is `0.0`.
+    pub fn sample(&self, position: f32) -> f32 {
+        sample(
+            &self.keys,
+            position,
+            |key| key.position,
+            |key| key.value,
+            |a, b, t| a + (b - a) * t,
+        )
+        .unwrap_or(0.0)
+    }
+}
+
+/// A color at a position of a [`ColorGradient`]
+#[cfg(feature = "bevy_render")]
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Default)]
+pub struct GradientStop {
+    /// The position of the stop between `0.0` and `1.0`
+    pub position: f32,
+    pub color: Color,
+}
+
+#[cfg(feature = "bevy_render")]
+impl GradientStop {
+    pub fn new(position: f32, color: Color) -> Self {
+        GradientStop { position, color }
+    }
+}
+
+/// A color interpolated between stops, see [`ColorGradient::sample`]
+#[cfg(feature = "bevy_render")]
+#[derive(Reflect, Clone, Debug, PartialEq)]
+#[reflect(Default)]
+pub struct ColorGradient {
+    /// The stops of the gradient, sorted by their position
+    pub stops: Vec<GradientStop>,
+}
+
+#[cfg(feature = "bevy_render")]
+impl Default for ColorGradient {
+    fn default() -> Self {
+        ColorGradient::new(vec![
+            GradientStop::new(0.0, Color::WHITE),
+            GradientStop::new(1.0, Color::BLACK),
+        ])
+    }
+}
+
+#[cfg(feature = "bevy_render")]
+impl ColorGradient {
+    /// A gradient through `stops`, which are sorted by their position
+    pub fn new(stops: Vec<GradientStop>) -> Self {
+        let mut gradient = ColorGradient { stops };
+        gradient.sort();
+        gradient
+    }
+
+    /// Sort the stops by their position, after moving them
+    pub fn sort(&mut self) {
+        self.stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+    }
+
+    /// The color at `position`, interpolated in linear RGBA between the surrounding stops.
+    ///
+    /// Positions before the first or after the last stop have the color of that stop, a gradient without stops is transparent.
+    pub fn sample(&self, position: f32) -> Color {
+        sample(
+            &self.stops,
+            position,
+            |stop| stop.position,
+            |stop| stop.color,
+            |a, b, t| {
+                let [a, b] = [a.as_linear_rgba_f32(), b.as_linear_rgba_f32()];
+                Color::rgba_linear_from_array(std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t))
+            },
+        )
+        .unwrap_or(Color::NONE)
+    }
+}
+
+fn sample<K, V: Copy>(
+    keys: &[K],
+    position: f32,
+    key_position: impl Fn(&K) -> f32,
+    key_value: impl Fn(&K) -> V,
+    lerp: impl Fn(V, V, f32) -> V,
+) -> Option<V> {
+    let first = keys.first()?;
+    let last = keys.last()?;
+    if position <= key_position(first) {
+        return Some(key_value(first));
+    }
+    if position >= key_position(last) {
+        return Some(key_value(last));
+    }
+    keys.windows(2).find_map(|pair| {
+        let (start, end) = (key_position(&pair[0]), key_position(&pair[1]));
+        (position >= start && position <= end).then(|| {
+            let t = match end - start {
+                length if length > 0.0 => (position - start) / length,
+                _ => 0.0,
+            };
+            lerp(key_value(&pair[0]), key_value(&pair[1]), t)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Curve, CurveKey};
+
+    #[test]
+    fn curves_interpolate_between_keys_and_clamp_outside() {
+        let curve = Curve::new(vec![
+            CurveKey::new(1.0, 4.0),
+            CurveKey::new(0.0, 0.0),
+            CurveKey::new(0.5, 2.0),
+        ]);
+        assert_eq!(curve.keys[1].position, 0.5);
+        assert_eq!(curve.sample(-1.0), 0.0);
+        assert_eq!(curve.sample(0.25), 1.0);
+        assert_eq!(curve.sample(0.75), 3.0);
+        assert_eq!(curve.sample(2.0), 4.0);
+        assert_eq!(Curve::new(Vec::new()).sample(0.5), 0.0);
+    }
+
+    #[cfg(feature = "bevy_render")]
+    #[test]
+    fn gradients_interpolate_in_linear_rgba() {
+        use super::{ColorGradient, GradientStop};
+        use bevy_render::color::Color;
+
+        let gradient = ColorGradient::new(vec![
+            GradientStop::new(0.0, Color::rgba_linear(0.0, 0.0, 0.0, 1.0)),
+            GradientStop::new(1.0, Color::rgba_linear(1.0, 0.5, 0.0, 0.0)),
+        ]);
+        let color = gradient.sample(0.5).as_linear_rgba_f32();
+        assert_eq!(color, [0.5, 0.25, 0.0, 0.5]);
+    }
+}
@@ -0,0 +1,221 @@
+//! No-op versions of the plugins, used when the `enabled` feature is turned off.
+//!
+//! They keep the names and builder methods of the real plugins, so the calls setting up the inspector
+//! can stay in place in builds shipped without it. Builder methods taking types of the UI, like
+//! `with_edit_mode` or `with_tour`, aren't available.
+
+/// No-op versions of the plugins in `quick`
+pub mod quick {
+    use std::marker::PhantomData;
+
+    use bevy_app::{App, Plugin};
+    use bevy_ecs::prelude::*;
+    use bevy_input::keyboard::KeyCode;
+    use bevy_reflect::{GetTypeRegistration, Reflect, TypePath};
+
+    /// The builder methods of the real plugins, ignoring their arguments
+    macro_rules! disabled_builder_methods {
+        () => {
+            disabled_builder_methods!(hud);
+
+            pub fn with_default_pos(self, _pos: impl Into<[f32; 2]>) -> Self {
+                self
+            }
+
+            pub fn with_default_open(self, _open: bool) -> Self {
+                self
+            }
+        };
+        (hud) => {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            pub fn run_if<M>(self, _condition: impl Condition<M>) -> Self {
+                self
+            }
+
+            pub fn run_in_state<S: States>(self, _state: S) -> Self {
+                self
+            }
+
+            pub fn with_title(self, _title: impl Into<String>) -> Self {
+                self
+            }
+
+            pub fn with_hotkey(self, _key: KeyCode) -> Self {
+                self
+            }
+        };
+    }
+
+    /// Defines `$name` as a plugin adding nothing, with the builder methods of `disabled_builder_methods!($($methods)?)`
+    macro_rules! disabled_plugin {
+        ($name:ident $(($($methods:tt)*))?) => {
+            // not a unit struct, since the real plugins are created with `default()`
+            #[derive(Default)]
+            pub struct $name {
+                _private: (),
+            }
+
+            impl $name {
+                disabled_builder_methods!($($($methods)*)?);
+            }
+
+            impl Plugin for $name {
+                fn build(&self, _app: &mut App) {}
+
+                fn is_unique(&self) -> bool {
+                    false
+                }
+            }
+        };
+        ($name:ident < $($param:ident),* >: $($bounds:tt)*) => {
+            pub struct $name<$($param),*>(PhantomData<fn() -> ($($param,)*)>);
+
+            impl<$($param),*> Default for $name<$($param),*> {
+                fn default() -> Self {
+                    Self(PhantomData)
+                }
+            }
+
+            impl<$($param),*> $name<$($param),*> {
+                disabled_builder_methods!();
+            }
+
+            impl<$($param: 'static),*> Plugin for $name<$($param),*>
+            where
+                $($bounds)*
+            {
+                fn build(&self, _app: &mut App) {}
+
+                fn is_unique(&self) -> bool {
+                    false
+                }
+            }
+        };
+    }
+
+    /// System set of the UI systems, which stays empty
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct InspectorSet;
+
+    disabled_plugin!(WorldInspectorPlugin);
+    disabled_plugin!(ResourceInspectorPlugin<T>: T: Resource + Reflect);
+    disabled_plugin!(StateInspectorPlugin<T>: T: States + Reflect);
+    disabled_plugin!(AssetInspectorPlugin<A>: A: bevy_asset::Asset + Reflect);
+    disabled_plugin!(FilterQueryInspectorPlugin<F>: F: bevy_ecs::query::QueryFilter);
+    disabled_plugin!(AssetsBrowserPlugin);
+    disabled_plugin!(ArchetypesInspectorPlugin);
+    disabled_plugin!(SystemsInspectorPlugin);
+    disabled_plugin!(InputStateInspectorPlugin);
+    disabled_plugin!(TasksInspectorPlugin);
+    disabled_plugin!(TimeControlsPlugin);
+    disabled_plugin!(InspectorConsolePlugin);
+    disabled_plugin!(PinnedInspectorPlugin(hud));
+    disabled_plugin!(DiagnosticsInspectorPlugin);
+    disabled_plugin!(EventInspectorPlugin);
+
+    impl WorldInspectorPlugin {
+        pub fn ignore<T: 'static>(self) -> Self {
+            self
+        }
+
+        pub fn embedded(self) -> Self {
+            self
+        }
+    }
+
+    impl<T> ResourceInspectorPlugin<T> {
+        pub fn with_ab_toggle(self, _key: KeyCode) -> Self {
+            self
+        }
+    }
+
+    impl PinnedInspectorPlugin {
+        pub fn with_default_pos(self, _pos: impl Into<[f32; 2]>) -> Self {
+            self
+        }
+    }
+
+    impl DiagnosticsInspectorPlugin {
+        pub fn with_history_length(self, _history_length: usize) -> Self {
+            self
+        }
+    }
+
+    impl EventInspectorPlugin {
+        pub fn with_capacity(self, _capacity: usize) -> Self {
+            self
+        }
+    }
+
+    pub struct QueryInspectorPlugin<Q, F = ()>(PhantomData<fn() -> (Q, F)>);
+
+    impl<Q, F> Default for QueryInspectorPlugin<Q, F> {
+        fn default() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<Q, F> QueryInspectorPlugin<Q, F> {
+        disabled_builder_methods!();
+    }
+
+    impl<Q: 'static, F: 'static> Plugin for QueryInspectorPlugin<Q, F>
+    where
+        Q: bevy_ecs::query::QueryData,
+        F: bevy_ecs::query::QueryFilter,
+    {
+        fn build(&self, _app: &mut App) {}
+
+        fn is_unique(&self) -> bool {
+            false
+        }
+    }
+
+    #[derive(Default)]
+    pub struct InspectorProfilerPlugin;
+
+    impl Plugin for InspectorProfilerPlugin {
+        fn build(&self, _app: &mut App) {}
+    }
+
+    /// Extension trait adding events like the real one, without logging them
+    pub trait RegisterInspectableEvent {
+        fn register_inspectable_event<E: Event + Reflect + TypePath + GetTypeRegistration + Clone>(
+            &mut self,
+        ) -> &mut Self;
+    }
+
+    impl RegisterInspectableEvent for App {
+        fn register_inspectable_event<
+            E: Event + Reflect + TypePath + GetTypeRegistration + Clone,
+        >(
+            &mut self,
+        ) -> &mut Self {
+            // the event is still added, since the game sends it
+            self.add_event::<E>().register_type::<E>()
+        }
+    }
+}
+
+/// No-op versions of the extension traits in `bevy_inspector`
+pub mod bevy_inspector {
+    /// Extension trait not tracking anything
+    pub mod history {
+        use bevy_app::App;
+        use bevy_ecs::prelude::*;
+        use bevy_reflect::Reflect;
+
+        pub trait TrackComponentHistory {
+            fn track_component_history<C: Component + Reflect>(&mut self) -> &mut Self;
+        }
+
+        impl TrackComponentHistory for App {
+            fn track_component_history<C: Component + Reflect>(&mut self) -> &mut Self {
+                self
+            }
+        }
+    }
+}
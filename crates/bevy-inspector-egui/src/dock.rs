@@ -0,0 +1,295 @@
+//! A side panel showing the world inspector, resources, assets and diagnostics as dockable tabs instead of floating windows.
+//!
+//! Enabled by the `dock` feature. Tabs can be rearranged, split and closed by dragging them around,
+//! and the layout is written to a file so that it is restored on the next run:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::dock::InspectorDockPlugin;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(InspectorDockPlugin::new().with_layout_file("target/inspector-layout.ron"))
+//!         .run();
+//! }
+//! ```
+
+use std::path::PathBuf;
+
+use bevy_app::{Last, Plugin, Update};
+use bevy_diagnostic::{EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiPlugin;
+use egui_dock::{DockArea, DockState, NodeIndex, Style, TabViewer};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bevy_inspector::{self, asset_usage, assets_browser, locks},
+    quick::{self, DiagnosticsHistory, InspectorSet, InspectorWindows},
+    theme::InspectorTheme,
+    DefaultInspectorConfigPlugin,
+};
+
+/// A tab of the [`InspectorDock`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InspectorTab {
+    World,
+    Resources,
+    Assets,
+    AssetUsage,
+    Diagnostics,
+}
+
+impl InspectorTab {
+    pub const ALL: [InspectorTab; 5] = [
+        InspectorTab::World,
+        InspectorTab::Resources,
+        InspectorTab::Assets,
+        InspectorTab::AssetUsage,
+        InspectorTab::Diagnostics,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            InspectorTab::World => "World",
+            InspectorTab::Resources => "Resources",
+            InspectorTab::Assets => "Assets",
+            InspectorTab::AssetUsage => "Asset usage",
+            InspectorTab::Diagnostics => "Diagnostics",
+        }
+    }
+}
+
+/// The layout of the tabs in the side panel of the [`InspectorDockPlugin`]
+#[derive(Resource)]
+pub struct InspectorDock {
+    pub state: DockState<InspectorTab>,
+    layout_file: Option<PathBuf>,
+    saved_layout: Option<String>,
+}
+
+impl Default for InspectorDock {
+    fn default() -> Self {
+        InspectorDock::new(default_layout())
+    }
+}
+
+impl InspectorDock {
+    pub fn new(state: DockState<InspectorTab>) -> Self {
+        InspectorDock {
+            state,
+            layout_file: None,
+            saved_layout: None,
+        }
+    }
+
+    /// Load the layout from `path` if that file exists, and write the layout to it whenever it changes.
+    ///
+    /// The layout isn't persisted on `wasm32`.
+    pub fn with_layout_file(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if let Some(layout) = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| ron::from_str(&text).ok())
+        {
+            self.state = layout;
+        }
+        self.saved_layout = ron::to_string(&self.state).ok();
+        self.layout_file = Some(path);
+        self
+    }
+
+    /// Reset to the default layout with all tabs
+    pub fn reset(&mut self) {
+        self.state = default_layout();
+    }
+
+    fn save_if_changed(&mut self) {
+        // there is no file system in the browser
+        if cfg!(target_arch = "wasm32") {
+            return;
+        }
+        let Some(path) = &self.layout_file else {
+            return;
+        };
+        let Ok(layout) = ron::to_string(&self.state) else {
+            return;
+        };
+        if self.saved_layout.as_ref() != Some(&layout) {
+            if let Err(error) = std::fs::write(path, &layout) {
+                bevy_log::warn!(
+                    "failed to save inspector layout to {}: {error}",
+                    path.display()
+                );
+            }
+            self.saved_layout = Some(layout);
+        }
+    }
+}
+
+/// The world inspector on top, with resources, assets and diagnostics as tabs below it
+fn default_layout() -> DockState<InspectorTab> {
+    let mut state = DockState::new(vec![InspectorTab::World]);
+    state.main_surface_mut().split_below(
+        NodeIndex::root(),
+        0.6,
+        vec![
+            InspectorTab::Resources,
+            InspectorTab::Assets,
+            InspectorTab::AssetUsage,
+            InspectorTab::Diagnostics,
+        ],
+    );
+    state
+}
+
+/// Plugin showing the [`InspectorDock`] in a side panel of the primary window.
+///
+/// The panel can be hidden like the windows of the [`quick`] plugins using the title `"Inspector Dock"`,
+/// see [`InspectorWindows`].
+#[derive(Default)]
+pub struct InspectorDockPlugin {
+    layout_file: Option<PathBuf>,
+    left: bool,
+}
+
+impl InspectorDockPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Persist the layout in the file at `path`, see [`InspectorDock::with_layout_file`]
+    pub fn with_layout_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.layout_file = Some(path.into());
+        self
+    }
+
+    /// Show the panel on the left side of the window instead of the right
+    pub fn on_left(mut self) -> Self {
+        self.left = true;
+        self
+    }
+}
+
+/// Title of the panel, for use with [`InspectorWindows`] and [`InspectorLocks`](locks::InspectorLocks)
+pub const DOCK_TITLE: &str = "Inspector Dock";
+
+impl Plugin for InspectorDockPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+        if !app.world.contains_resource::<DiagnosticsHistory>() {
+            app.insert_resource(DiagnosticsHistory::new(
+                bevy_diagnostic::DEFAULT_MAX_HISTORY_LENGTH,
+            ))
+            .add_systems(Last, quick::record_diagnostics);
+        }
+        app.init_resource::<InspectorWindows>();
+
+        let mut dock = InspectorDock::default();
+        if let Some(path) = &self.layout_file {
+            dock = dock.with_layout_file(path);
+        }
+        let left = self.left;
+        app.insert_resource(dock).add_systems(
+            Update,
+            (move |world: &mut World| inspector_dock_ui(world, left)).in_set(InspectorSet),
+        );
+    }
+}
+
+fn inspector_dock_ui(world: &mut World, left: bool) {
+    if !world
+        .get_resource::<InspectorWindows>()
+        .map_or(true, |windows| windows.is_visible(DOCK_TITLE))
+    {
+        return;
+    }
+    let Some(mut egui_context) = quick::inspector_egui_context(world) else {
+        return;
+    };
+    let ctx = egui_context.get_mut();
+    let lock = locks::window_lock_reason(world, DOCK_TITLE);
+
+    let panel = match left {
+        true => egui::SidePanel::left(DOCK_TITLE),
+        false => egui::SidePanel::right(DOCK_TITLE),
+    };
+    world.resource_scope(|world, mut dock: Mut<InspectorDock>| {
+        panel.default_width(320.0).show(ctx, |ui| {
+            InspectorTheme::apply_selected(ui);
+            locks::ui_locked(ui, lock.as_deref(), |ui| {
+                let mut tab_viewer = InspectorTabViewer { world };
+                DockArea::new(&mut dock.state)
+                    .id(egui::Id::new(DOCK_TITLE))
+                    .style(Style::from_egui(ui.style()))
+                    .show_inside(ui, &mut tab_viewer);
+            });
+        });
+        dock.bypass_change_detection().save_if_changed();
+    });
+}
+
+struct InspectorTabViewer<'a> {
+    world: &'a mut World,
+}
+
+impl TabViewer for InspectorTabViewer<'_> {
+    type Tab = InspectorTab;
+
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
+        tab.title().into()
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
+        match tab {
+            InspectorTab::World => quick::ui_for_world_inspector(self.world, ui),
+            InspectorTab::Resources => {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    bevy_inspector::ui_for_resources(self.world, ui);
+                });
+            }
+            InspectorTab::Assets => {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    assets_browser::ui_for_assets_browser(self.world, ui);
+                });
+            }
+            InspectorTab::AssetUsage => {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    asset_usage::ui_for_asset_usage(self.world, ui);
+                });
+            }
+            InspectorTab::Diagnostics => quick::ui_for_diagnostics(self.world, ui),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InspectorDock, InspectorTab};
+
+    #[test]
+    fn layout_is_restored_from_file() {
+        let path = std::env::temp_dir().join("bevy-inspector-egui-dock-layout-test.ron");
+        let _ = std::fs::remove_file(&path);
+
+        let mut dock = InspectorDock::default().with_layout_file(&path);
+        dock.state = egui_dock::DockState::new(vec![InspectorTab::Assets]);
+        dock.save_if_changed();
+
+        let dock = InspectorDock::default().with_layout_file(&path);
+        let tabs: Vec<_> = dock.state.iter_all_tabs().map(|(_, tab)| *tab).collect();
+        assert_eq!(tabs, [InspectorTab::Assets]);
+    }
+}
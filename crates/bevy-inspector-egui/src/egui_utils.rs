@@ -96,3 +96,142 @@ pub fn up_button(ui: &mut egui::Ui) -> egui::Response {
 pub fn down_button(ui: &mut egui::Ui) -> egui::Response {
     IconButton::new(ui).down_button()
 }
+
+/// Whether the [`egui::CollapsingHeader`] with the `id_source` added to `ui` is open, with headers closed by default
+pub fn is_header_open(ui: &egui::Ui, id_source: impl std::hash::Hash) -> bool {
+    egui::collapsing_header::CollapsingState::load(ui.ctx(), ui.make_persistent_id(id_source))
+        .is_some_and(|state| state.is_open())
+}
+
+/// Display the `rows`, skipping collapsed rows which are outside of the visible part of the `ui`.
+///
+/// Works like [`egui::ScrollArea::show_rows`], but inside of an outer scroll area and with rows of different heights:
+/// rows for which `is_expanded` returns `true` are always added, while skipped rows are replaced by empty space
+/// of the height measured for collapsed rows in the previous frame.
+pub fn lazy_rows<T>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    rows: &[T],
+    mut is_expanded: impl FnMut(&egui::Ui, &T) -> bool,
+    mut add_row: impl FnMut(&mut egui::Ui, &T),
+) {
+    let spacing = ui.spacing().item_spacing.y;
+    let row_height = ui
+        .data(|data| data.get_temp::<f32>(id))
+        .unwrap_or(ui.spacing().interact_size.y);
+    let visible = ui.clip_rect().y_range();
+
+    let mut skipped = 0.0;
+    let mut measured: Option<f32> = None;
+    for row in rows {
+        let expanded = is_expanded(ui, row);
+        if !expanded {
+            let top = ui.cursor().top() + skipped;
+            if top > visible.max || top + row_height < visible.min {
+                skipped += row_height + spacing;
+                continue;
+            }
+        }
+        allocate_skipped(ui, &mut skipped, spacing);
+
+        let top = ui.cursor().top();
+        add_row(ui, row);
+        if !expanded {
+            // the smallest height, so that headers which are still closing don't count
+            let height = ui.cursor().top() - top - spacing;
+            measured = Some(measured.map_or(height, |measured| measured.min(height)));
+        }
+    }
+    allocate_skipped(ui, &mut skipped, spacing);
+
+    if let Some(measured) = measured {
+        ui.data_mut(|data| data.insert_temp(id, measured));
+    }
+}
+
+fn allocate_skipped(ui: &mut egui::Ui, skipped: &mut f32, spacing: f32) {
+    if *skipped > 0.0 {
+        // `allocate_space` adds the spacing after the last skipped row itself
+        ui.allocate_space(egui::vec2(0.0, *skipped - spacing));
+        *skipped = 0.0;
+    }
+}
+
+/// Draw `values` as a line scaled to fill the allocated rect
+pub fn sparkline(ui: &mut egui::Ui, values: &[f64]) -> egui::Response {
+    let size = egui::vec2(160.0, ui.spacing().interact_size.y * 1.5);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    if !ui.is_rect_visible(rect) || values.len() < 2 {
+        return response;
+    }
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = (max - min).max(f64::EPSILON);
+    let step = rect.width() / (values.len() - 1) as f32;
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let y = ((value - min) / range) as f32;
+            egui::pos2(
+                rect.left() + i as f32 * step,
+                rect.bottom() - y * rect.height(),
+            )
+        })
+        .collect();
+
+    let visuals = ui.visuals();
+    ui.painter()
+        .rect_filled(rect, 2.0, visuals.extreme_bg_color);
+    ui.painter().add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.5, visuals.selection.bg_fill),
+    ));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lazy_rows;
+
+    /// Height of the content and number of rows added in the second frame
+    fn show_rows(lazy: bool) -> (f32, usize) {
+        let ctx = egui::Context::default();
+        let rows: Vec<usize> = (0..1000).collect();
+        let mut result = (0.0, 0);
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            let mut added = 0;
+                            let top = ui.cursor().top();
+                            let mut add_row = |ui: &mut egui::Ui, row: &usize| {
+                                added += 1;
+                                ui.collapsing(format!("row {row}"), |_| {});
+                            };
+                            if lazy {
+                                let id = egui::Id::new("rows");
+                                lazy_rows(ui, id, &rows, |_, _| false, add_row);
+                            } else {
+                                rows.iter().for_each(|row| add_row(ui, row));
+                            }
+                            result = (ui.cursor().top() - top, added);
+                        });
+                });
+            });
+        }
+        result
+    }
+
+    #[test]
+    fn lazy_rows_skip_hidden_rows() {
+        let (eager_height, eager_added) = show_rows(false);
+        let (lazy_height, lazy_added) = show_rows(true);
+        assert_eq!(eager_added, 1000);
+        assert!(lazy_added < 50, "added {lazy_added} rows");
+        assert!((eager_height - lazy_height).abs() < 1.0);
+    }
+}
@@ -0,0 +1,85 @@
+//! Events sent when values are edited, entities are selected or windows are toggled in the inspector.
+//!
+//! The [`InspectorEvent`]s are added by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin),
+//! so game and editor code can react to inspector interactions, e.g. to autosave edited values:
+//!
+//! ```rust
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::events::InspectorEvent;
+//!
+//! fn autosave(mut events: EventReader<InspectorEvent>) {
+//!     for event in events.read() {
+//!         if let InspectorEvent::ValueChanged { path, .. } = event {
+//!             println!("{path} was edited");
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy_ecs::{prelude::*, system::CommandQueue};
+
+/// An interaction with the inspector
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub enum InspectorEvent {
+    /// A component, resource or asset was edited
+    ValueChanged {
+        /// The entity of the edited component
+        entity: Option<Entity>,
+        /// Type path of the edited component, resource or asset
+        path: String,
+    },
+    /// An entity was selected in the hierarchy or by box selection
+    EntitySelected { entity: Entity },
+    /// A command queued in the [`InspectorCommands`](crate::bevy_inspector::commands::InspectorCommands) was applied
+    CommandApplied {
+        name: String,
+        /// The entity targeted by the command
+        entity: Option<Entity>,
+    },
+    /// A window of the [`quick`](crate::quick) plugins was shown or hidden
+    WindowToggled {
+        /// Title of the window, or `None` if all windows were toggled at once
+        title: Option<String>,
+        visible: bool,
+    },
+}
+
+/// Send `event`, if the [`InspectorEvent`]s were added to the world
+pub fn send(world: &mut World, event: InspectorEvent) {
+    if let Some(mut events) = world.get_resource_mut::<Events<InspectorEvent>>() {
+        events.send(event);
+    }
+}
+
+/// Send `event` once `queue` is applied
+pub(crate) fn send_deferred(queue: &mut CommandQueue, event: InspectorEvent) {
+    queue.push(move |world: &mut World| send(world, event));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{event::Events, system::CommandQueue, world::World};
+
+    use super::InspectorEvent;
+
+    #[test]
+    fn deferred_events_are_sent() {
+        let mut world = World::new();
+        let event = InspectorEvent::WindowToggled {
+            title: None,
+            visible: false,
+        };
+
+        // without registered events, sending does nothing
+        super::send(&mut world, event.clone());
+
+        world.init_resource::<Events<InspectorEvent>>();
+        let mut queue = CommandQueue::default();
+        super::send_deferred(&mut queue, event.clone());
+        queue.apply(&mut world);
+
+        let events = world.resource::<Events<InspectorEvent>>();
+        let sent: Vec<_> = events.get_reader().read(events).cloned().collect();
+        assert_eq!(sent, [event]);
+    }
+}
@@ -0,0 +1,342 @@
+//! A window showing a live instance of every widget and `#[inspector(..)]` attribute of the crate.
+//!
+//! Enabled by the `gallery` feature. Each field of the [`WidgetGallery`] is named after the attribute it uses,
+//! so you can see what an attribute does with your bevy version and [`InspectorTheme`] before using it in your own types:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::gallery::WidgetGalleryPlugin;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(WidgetGalleryPlugin)
+//!         .run();
+//! }
+//! ```
+
+use std::{
+    borrow::Cow,
+    num::NonZeroU32,
+    ops::{Range, RangeInclusive},
+    path::PathBuf,
+    time::Duration,
+};
+
+use bevy_app::{Plugin, Update};
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiPlugin;
+use bevy_math::{Quat, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    color::Color,
+    view::{RenderLayers, Visibility},
+};
+use bevy_utils::HashMap;
+
+use crate::{
+    bevy_inspector,
+    curves::{ColorGradient, Curve, CurveKey, GradientStop},
+    inspector_options::std_options::{
+        CodeLanguage, ColorChannels, ColorOptions, NumberCommit, NumberDisplay, NumberFormat,
+        NumberOptions, QuatDisplay, StringFormat,
+    },
+    prelude::*,
+    quick::{self, InspectorSet, InspectorWindows},
+    theme::{self, InspectorTheme},
+    widgets, DefaultInspectorConfigPlugin,
+};
+
+/// Title of the gallery window, for use with [`InspectorWindows`]
+pub const GALLERY_TITLE: &str = "Widget Gallery";
+
+/// One field for every widget and attribute, named after what it shows
+#[derive(Resource, Reflect, InspectorOptions, Debug, Clone)]
+#[reflect(Resource, Default, InspectorOptions)]
+#[inspector(button = "reset")]
+pub struct WidgetGallery {
+    drag: f32,
+    #[inspector(min = 0.0, max = 1.0, speed = 0.01)]
+    min_max_speed: f32,
+    #[inspector(min = 0.0, max = 100.0, display = NumberDisplay::Slider)]
+    display_slider: f32,
+    #[inspector(min = 1.0, max = 1e6, display = NumberDisplay::LogSlider, suffix = " lm")]
+    display_log_slider_suffix: f32,
+    #[inspector(min = 0.0, soft_max = 10.0, display = NumberDisplay::Slider)]
+    soft_max: f32,
+    #[inspector(min = 0.0, max = 100.0, display = NumberDisplay::Bar { show_value: true })]
+    display_bar: f32,
+    #[inspector(angle)]
+    angle: f32,
+    #[inspector(commit = NumberCommit::OnRelease)]
+    commit_on_release: f32,
+    #[inspector(decimals = 3)]
+    decimals: f64,
+    #[inspector(default = 1.0)]
+    reset_to_default: f32,
+    #[inspector(validate = "check_even")]
+    validate: u32,
+    #[inspector(prefix = "x")]
+    prefix: u32,
+    big_int: u128,
+    #[inspector(format = NumberFormat::Bytes)]
+    memory: u64,
+    #[inspector(format = NumberFormat::Hex)]
+    mask: u32,
+    #[inspector(max = 16)]
+    non_zero: NonZeroU32,
+    transparent: GalleryMeters,
+    toggle: bool,
+    character: char,
+    text: String,
+    borrowed_text: Cow<'static, str>,
+    #[inspector(code = CodeLanguage::Wgsl)]
+    code_wgsl: String,
+    #[inspector(format = StringFormat::SocketAddr)]
+    socket_addr: String,
+    #[inspector(extensions = ["png", "jpg"])]
+    path_extensions: PathBuf,
+    duration: Duration,
+    linkable_vec3: Vec3,
+    quat_euler: Quat,
+    #[inspector(display = QuatDisplay::AxisAngle)]
+    quat_axis_angle: Quat,
+    color: Color,
+    #[inspector(alpha = false, channels = ColorChannels::Hsv)]
+    color_hsv_no_alpha: Color,
+    curve: Curve,
+    gradient: ColorGradient,
+    visibility: Visibility,
+    render_layers: RenderLayers,
+    range: Range<f32>,
+    range_inclusive: RangeInclusive<f32>,
+    #[inspector(min = 0.0)]
+    option: Option<f32>,
+    list: Vec<f32>,
+    #[inspector(fixed_size)]
+    fixed_size_list: Vec<f32>,
+    #[inspector(labels = ["X", "Y", "Z"])]
+    labels: [f32; 3],
+    map: HashMap<String, u32>,
+    variant: GalleryShape,
+}
+
+/// A newtype shown like the `f32` it wraps
+#[derive(Reflect, InspectorOptions, Debug, Clone, Copy, Default)]
+#[reflect(Default, InspectorOptions)]
+#[inspector(transparent)]
+pub struct GalleryMeters(#[inspector(min = 0.0, suffix = " m")] f32);
+
+/// An enum with struct, tuple and unit variants
+#[derive(Reflect, InspectorOptions, Debug, Clone, Default)]
+#[reflect(Default, InspectorOptions)]
+pub enum GalleryShape {
+    Circle {
+        #[inspector(min = 0.0)]
+        radius: f32,
+    },
+    Rectangle(Vec2),
+    #[default]
+    Empty,
+}
+
+impl Default for WidgetGallery {
+    fn default() -> Self {
+        WidgetGallery {
+            drag: 1.0,
+            min_max_speed: 0.5,
+            display_slider: 50.0,
+            display_log_slider_suffix: 800.0,
+            soft_max: 2.0,
+            display_bar: 75.0,
+            angle: std::f32::consts::FRAC_PI_4,
+            commit_on_release: 0.0,
+            decimals: 0.5,
+            reset_to_default: 2.5,
+            validate: 3,
+            prefix: 2,
+            big_int: u128::MAX,
+            memory: 3 * 1024 * 1024 / 2,
+            mask: 0xFF00,
+            non_zero: NonZeroU32::MIN,
+            transparent: GalleryMeters(1.5),
+            toggle: true,
+            character: 'λ',
+            text: "Hello".to_owned(),
+            borrowed_text: Cow::Borrowed("static"),
+            code_wgsl:
+                "// tint\nfn tint(color: vec4<f32>) -> vec4<f32> {\n    return color * 0.5;\n}"
+                    .to_owned(),
+            socket_addr: "127.0.0.1:8080".to_owned(),
+            path_extensions: PathBuf::from("assets/icon.png"),
+            duration: Duration::from_millis(1500),
+            linkable_vec3: Vec3::ONE,
+            quat_euler: Quat::IDENTITY,
+            quat_axis_angle: Quat::IDENTITY,
+            color: Color::ORANGE,
+            color_hsv_no_alpha: Color::SEA_GREEN,
+            curve: Curve::new(vec![
+                CurveKey::new(0.0, 0.0),
+                CurveKey::new(0.3, 1.0),
+                CurveKey::new(1.0, 0.2),
+            ]),
+            gradient: ColorGradient::new(vec![
+                GradientStop::new(0.0, Color::YELLOW),
+                GradientStop::new(0.5, Color::ORANGE_RED),
+                GradientStop::new(1.0, Color::rgba(0.2, 0.2, 0.2, 0.0)),
+            ]),
+            visibility: Visibility::Hidden,
+            render_layers: RenderLayers::from_layers(&[0, 2]),
+            range: 0.5..2.0,
+            range_inclusive: 1.0..=3.0,
+            option: Some(1.0),
+            list: vec![1.0, 2.0],
+            fixed_size_list: vec![0.0; 3],
+            labels: [1.0, 0.0, -1.0],
+            map: HashMap::from([("apples".to_owned(), 3)]),
+            variant: GalleryShape::Circle { radius: 1.0 },
+        }
+    }
+}
+
+impl WidgetGallery {
+    /// Restore the initial values
+    pub fn reset(&mut self) {
+        *self = WidgetGallery::default();
+    }
+
+    fn check_even(&self) -> Result<(), String> {
+        match self.validate % 2 {
+            0 => Ok(()),
+            _ => Err(format!("{} is odd", self.validate)),
+        }
+    }
+}
+
+/// Values of the standalone [`widgets`], which don't need [`Reflect`]
+#[derive(Resource)]
+struct StandaloneWidgets {
+    number: f32,
+    linked: [f32; 3],
+    color: Color,
+    canvas: Vec2,
+    list: Vec<f32>,
+}
+
+impl Default for StandaloneWidgets {
+    fn default() -> Self {
+        StandaloneWidgets {
+            number: 0.5,
+            linked: [1.0, 2.0, 4.0],
+            color: Color::TEAL,
+            canvas: Vec2::ZERO,
+            list: vec![0.25, 0.75],
+        }
+    }
+}
+
+/// Plugin showing the [`WidgetGallery`] and the standalone [`widgets`] in a window titled [`GALLERY_TITLE`]
+pub struct WidgetGalleryPlugin;
+
+impl Plugin for WidgetGalleryPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        app.register_type::<WidgetGallery>()
+            .register_type::<GalleryMeters>()
+            .register_type::<GalleryShape>()
+            .init_resource::<WidgetGallery>()
+            .init_resource::<StandaloneWidgets>()
+            .init_resource::<InspectorWindows>()
+            .add_systems(Update, widget_gallery_ui.in_set(InspectorSet));
+    }
+}
+
+fn widget_gallery_ui(world: &mut World) {
+    if !world
+        .get_resource::<InspectorWindows>()
+        .map_or(true, |windows| windows.is_visible(GALLERY_TITLE))
+    {
+        return;
+    }
+    let Some(mut egui_context) = quick::inspector_egui_context(world) else {
+        return;
+    };
+
+    egui::Window::new(GALLERY_TITLE)
+        .default_size((360.0, 560.0))
+        .show(egui_context.get_mut(), |ui| {
+            InspectorTheme::apply_selected(ui);
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                theme::ui_for_theme(ui);
+
+                ui.separator();
+                ui.heading("Attributes");
+                ui.label("Fields are named after the `#[inspector(..)]` attributes they use.");
+                bevy_inspector::ui_for_resource::<WidgetGallery>(world, ui);
+
+                ui.separator();
+                ui.heading("Standalone widgets");
+                let mut values = world.resource_mut::<StandaloneWidgets>();
+                ui_for_standalone_widgets(ui, values.bypass_change_detection());
+            });
+        });
+}
+
+fn ui_for_standalone_widgets(ui: &mut egui::Ui, values: &mut StandaloneWidgets) {
+    egui::Grid::new("widget gallery standalone").show(ui, |ui| {
+        ui.label("number");
+        widgets::number(ui, &mut values.number, &NumberOptions::normalized());
+        ui.end_row();
+
+        ui.label("linked_numbers");
+        let id = egui::Id::new("widget gallery linked");
+        widgets::linked_numbers(ui, id, &mut values.linked, &NumberOptions::default());
+        ui.end_row();
+
+        ui.label("color");
+        widgets::color(ui, &mut values.color, &ColorOptions::default());
+        ui.end_row();
+
+        ui.label("vec2_canvas");
+        widgets::vec2_canvas(ui, &mut values.canvas, Vec2::splat(-1.0), Vec2::splat(1.0));
+        ui.end_row();
+
+        ui.label("list");
+        let id = egui::Id::new("widget gallery list");
+        widgets::list(ui, id, &mut values.list, |ui, _, value| {
+            widgets::number(ui, value, &NumberOptions::normalized())
+        });
+        ui.end_row();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_reflect::TypeRegistry;
+
+    use super::WidgetGallery;
+    use crate::inspector_options::{std_options::NumberOptions, ReflectInspectorOptions, Target};
+
+    #[test]
+    fn attributes_are_registered() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<WidgetGallery>();
+        let options = type_registry
+            .get_type_data::<ReflectInspectorOptions>(TypeId::of::<WidgetGallery>())
+            .unwrap();
+        let min_max_speed = options
+            .0
+            .get(Target::Field(1))
+            .and_then(|options| options.downcast_ref::<NumberOptions<f32>>())
+            .unwrap();
+        assert_eq!(min_max_speed.max, Some(1.0));
+        assert_eq!(min_max_speed.speed, 0.01);
+    }
+}
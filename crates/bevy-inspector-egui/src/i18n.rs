@@ -0,0 +1,209 @@
+//! Display names for the fields and types shown by the inspector, see [`InspectorI18n`].
+//!
+//! Like the [`InspectorStyle`](crate::theme::InspectorStyle), the labels are stored in the egui [`Context`](egui::Context)'s
+//! memory, so they apply to all inspector UI drawn with that context. The [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin)
+//! copies the [`InspectorI18n`] resource there whenever it changes.
+//!
+//! ```rust
+//! use bevy_inspector_egui::i18n::InspectorI18n;
+//!
+//! fn setup(ctx: &egui::Context) {
+//!     InspectorI18n::default()
+//!         .with_label("max_hp", "Max HP")
+//!         .with_label("my_game::Player.max_hp", "Maximum health")
+//!         .with_label("Transform", "Position & Rotation")
+//!         .set(ctx);
+//! }
+//! ```
+
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
+
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiContext;
+
+use crate::notes;
+
+type TranslateFn = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+/// Labels replacing the names of fields and types in the inspector, e.g. to localize or prettify them.
+///
+/// A field is labeled by the first of
+/// 1. the label at `type_path.field`, e.g. `my_game::Player.max_hp`,
+/// 2. the label at the field name, e.g. `max_hp`,
+/// 3. the [`translator`](InspectorI18n::with_translator) called with the `#[inspector(label = "..")]` of the field, or its name,
+/// 4. the `#[inspector(label = "..")]` of the field,
+/// 5. the name, [`prettified`](prettify) if [`prettify_names`](InspectorI18n::prettify_names) is set.
+///
+/// Components and resources are labeled by the label at their full or short type path, or the translator called with
+/// the short type path.
+#[derive(Resource, Default, Clone)]
+pub struct InspectorI18n {
+    labels: HashMap<String, String>,
+    translator: Option<Arc<TranslateFn>>,
+    /// Display `max_hp` as `Max hp` when there is no other label
+    pub prettify_names: bool,
+}
+
+impl InspectorI18n {
+    fn id() -> egui::Id {
+        egui::Id::new("bevy-inspector-egui i18n")
+    }
+
+    /// Display `label` for the field or type at `key`
+    pub fn with_label(mut self, key: impl Into<String>, label: impl Into<String>) -> Self {
+        self.insert(key, label);
+        self
+    }
+
+    /// Look up labels without their own entry with `translator`, e.g. in a localization catalog
+    pub fn with_translator(
+        mut self,
+        translator: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.translator = Some(Arc::new(translator));
+        self
+    }
+
+    /// Display `max_hp` as `Max hp` when there is no other label
+    pub fn with_prettified_names(mut self) -> Self {
+        self.prettify_names = true;
+        self
+    }
+
+    /// Display `label` for the field or type at `key`
+    pub fn insert(&mut self, key: impl Into<String>, label: impl Into<String>) {
+        self.labels.insert(key.into(), label.into());
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.labels.remove(key)
+    }
+
+    /// The labels stored for `ctx`
+    pub fn get(ctx: &egui::Context) -> Arc<InspectorI18n> {
+        ctx.data(|data| data.get_temp(Self::id()))
+            .unwrap_or_default()
+    }
+
+    /// Use these labels for all inspector UI drawn with `ctx`
+    pub fn set(&self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(), Arc::new(self.clone())));
+    }
+
+    fn translate(&self, key: &str) -> Option<String> {
+        self.translator
+            .as_ref()
+            .and_then(|translator| translator(key))
+    }
+
+    /// The label of the field `field` of the type at `type_path`, with the `#[inspector(label = "..")]` `derived` from its options
+    pub fn field_label<'a>(
+        &'a self,
+        type_path: &str,
+        field: &'a str,
+        derived: Option<&'a str>,
+    ) -> Cow<'a, str> {
+        if let Some(label) = self
+            .labels
+            .get(&notes::field_path(type_path, field))
+            .or_else(|| self.labels.get(field))
+        {
+            return Cow::Borrowed(label);
+        }
+        if let Some(label) = self.translate(derived.unwrap_or(field)) {
+            return Cow::Owned(label);
+        }
+        match derived {
+            Some(derived) => Cow::Borrowed(derived),
+            None if self.prettify_names => Cow::Owned(prettify(field)),
+            None => Cow::Borrowed(field),
+        }
+    }
+
+    /// The label of the component or resource at `type_path`, displayed as `short_path` by default
+    pub fn type_label<'a>(&'a self, type_path: &str, short_path: &'a str) -> Cow<'a, str> {
+        if let Some(label) = self
+            .labels
+            .get(type_path)
+            .or_else(|| self.labels.get(short_path))
+        {
+            return Cow::Borrowed(label);
+        }
+        match self.translate(short_path) {
+            Some(label) => Cow::Owned(label),
+            None => Cow::Borrowed(short_path),
+        }
+    }
+}
+
+/// `max_hp` as `Max hp`, i.e. with spaces instead of underscores and the first letter capitalized
+pub fn prettify(name: &str) -> String {
+    let mut words = name.split('_').filter(|word| !word.is_empty());
+    let mut prettified = String::with_capacity(name.len());
+    if let Some(first) = words.next() {
+        let mut chars = first.chars();
+        prettified.extend(chars.next().into_iter().flat_map(char::to_uppercase));
+        prettified.push_str(chars.as_str());
+    }
+    for word in words {
+        prettified.push(' ');
+        prettified.push_str(word);
+    }
+    prettified
+}
+
+/// Copy the [`InspectorI18n`] resource into the memory of the egui contexts, when it changed or a context was added
+pub fn sync_inspector_i18n(
+    i18n: Option<Res<InspectorI18n>>,
+    mut contexts: Query<&mut EguiContext>,
+) {
+    let Some(i18n) = i18n else {
+        return;
+    };
+    for mut context in &mut contexts {
+        if i18n.is_changed() || context.is_added() {
+            i18n.set(context.get_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prettify, InspectorI18n};
+
+    #[test]
+    fn labels_are_looked_up_by_path_then_name() {
+        let i18n = InspectorI18n::default()
+            .with_label("max_hp", "Max HP")
+            .with_label("game::Boss.max_hp", "Maximum health")
+            .with_label("Transform", "Placement")
+            .with_translator(|key| (key == "speed").then(|| "Geschwindigkeit".to_owned()))
+            .with_prettified_names();
+
+        assert_eq!(i18n.field_label("game::Player", "max_hp", None), "Max HP");
+        assert_eq!(
+            i18n.field_label("game::Boss", "max_hp", Some("HP")),
+            "Maximum health"
+        );
+        assert_eq!(
+            i18n.field_label("game::Player", "speed", None),
+            "Geschwindigkeit"
+        );
+        assert_eq!(
+            i18n.field_label("game::Player", "jump_height", Some("Jump")),
+            "Jump"
+        );
+        assert_eq!(
+            i18n.field_label("game::Player", "jump_height", None),
+            "Jump height"
+        );
+        assert_eq!(
+            i18n.type_label(
+                "bevy_transform::components::transform::Transform",
+                "Transform"
+            ),
+            "Placement"
+        );
+        assert_eq!(prettify("__private_value"), "Private value");
+    }
+}
@@ -0,0 +1,209 @@
+//! Keyboard editing of numbers in the inspector, configured by the [`InspectorInputConfig`].
+//!
+//! Fields are focused with `Tab` and `Shift+Tab` like all egui widgets. While a number is focused, `ArrowUp` and
+//! `ArrowDown` change it by its [`speed`](crate::inspector_options::std_options::NumberOptions::speed), and holding
+//! the [`coarse`](InspectorInputConfig::coarse_modifier) or [`fine`](InspectorInputConfig::fine_modifier) modifier
+//! scales the step.
+//!
+//! Like the [`InspectorStyle`](crate::theme::InspectorStyle), the config is stored in the egui [`Context`](egui::Context)'s
+//! memory. The [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin) copies the [`InspectorInputConfig`]
+//! resource there whenever it changes.
+
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiContext;
+use egui::{emath::Numeric, Key, Modifiers};
+
+/// How the arrow keys change focused numbers in the inspector, see the [module docs](self).
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct InspectorInputConfig {
+    /// Change focused numbers with the modified arrow keys, and focused bars with the plain ones.
+    ///
+    /// Plain arrow keys always step drag values, that is built into egui.
+    pub arrow_keys: bool,
+    /// The modifier multiplying the step by [`coarse_factor`](InspectorInputConfig::coarse_factor)
+    pub coarse_modifier: Modifiers,
+    pub coarse_factor: f64,
+    /// The modifier multiplying the step by [`fine_factor`](InspectorInputConfig::fine_factor)
+    pub fine_modifier: Modifiers,
+    pub fine_factor: f64,
+}
+
+impl Default for InspectorInputConfig {
+    fn default() -> Self {
+        Self {
+            arrow_keys: true,
+            coarse_modifier: Modifiers::SHIFT,
+            coarse_factor: 10.0,
+            fine_modifier: Modifiers::CTRL,
+            fine_factor: 0.1,
+        }
+    }
+}
+
+impl InspectorInputConfig {
+    fn id() -> egui::Id {
+        egui::Id::new("bevy-inspector-egui input config")
+    }
+
+    /// The config stored for `ctx`
+    pub fn get(ctx: &egui::Context) -> Arc<InspectorInputConfig> {
+        ctx.data(|data| data.get_temp(Self::id()))
+            .unwrap_or_default()
+    }
+
+    /// Use this config for all inspector UI drawn with `ctx`
+    pub fn set(&self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(), Arc::new(self.clone())));
+    }
+
+    /// Consume the arrow keys pressed this frame, returning the change they request in multiples of the speed.
+    ///
+    /// Plain arrow keys are only consumed if `plain` is set.
+    fn consume_steps(&self, input: &mut egui::InputState, plain: bool) -> f64 {
+        let mut count = |modifiers| {
+            input.count_and_consume_key(modifiers, Key::ArrowUp) as f64
+                - input.count_and_consume_key(modifiers, Key::ArrowDown) as f64
+        };
+        // plain keys match shifted presses too, so they are counted last
+        let mut steps = count(self.coarse_modifier) * self.coarse_factor
+            + count(self.fine_modifier) * self.fine_factor;
+        if plain {
+            steps += count(Modifiers::NONE);
+        }
+        steps
+    }
+}
+
+/// Step `value` by `speed` for the arrow keys pressed while the widget added next to `ui` is focused.
+///
+/// Must be called right before adding the widget, so that the keys are consumed before an [`egui::DragValue`] handles
+/// them. Set `plain` for widgets that don't react to the plain arrow keys themselves.
+/// Returns whether `value` changed.
+pub(crate) fn step_focused_number<T: Numeric>(
+    ui: &egui::Ui,
+    value: &mut T,
+    speed: f64,
+    plain: bool,
+) -> bool {
+    let id = ui.next_auto_id();
+    if !ui.memory(|memory| memory.has_focus(id)) {
+        return false;
+    }
+    let config = InspectorInputConfig::get(ui.ctx());
+    if !config.arrow_keys {
+        return false;
+    }
+    if plain {
+        // keep the arrow keys from moving the focus to the next widget
+        let filter = egui::EventFilter {
+            vertical_arrows: true,
+            ..Default::default()
+        };
+        ui.memory_mut(|memory| memory.set_focus_lock_filter(id, filter));
+    }
+
+    let steps = ui.input_mut(|input| config.consume_steps(input, plain));
+    let new = stepped(*value, steps, speed);
+    if new == *value {
+        return false;
+    }
+    *value = new;
+    // a drag value edited with the keyboard displays its text buffer instead of the value
+    ui.data_mut(|data| data.remove::<String>(id));
+    true
+}
+
+/// `value` changed by `steps` times `speed`, rounded to the precision of the step or speed like egui does for dragging.
+///
+/// Integers are changed by at least one.
+fn stepped<T: Numeric>(value: T, steps: f64, speed: f64) -> T {
+    if steps == 0.0 {
+        return value;
+    }
+    let mut step = steps * speed;
+    if T::INTEGRAL {
+        step = step.signum() * step.abs().round().max(1.0);
+    }
+    let decimals = |step: f64| (-step.abs().log10()).ceil().clamp(0.0, 15.0) as usize;
+    let decimals = decimals(step).max(decimals(speed));
+    let new = egui::emath::round_to_decimals(value.to_f64() + step, decimals);
+    T::from_f64(new.clamp(T::MIN.to_f64(), T::MAX.to_f64()))
+}
+
+/// Copy the [`InspectorInputConfig`] resource into the memory of the egui contexts, when it changed or a context was added
+pub fn sync_inspector_input_config(
+    config: Option<Res<InspectorInputConfig>>,
+    mut contexts: Query<&mut EguiContext>,
+) {
+    let Some(config) = config else {
+        return;
+    };
+    for mut context in &mut contexts {
+        if config.is_changed() || context.is_added() {
+            config.set(context.get_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{inspector_options::std_options::NumberOptions, widgets};
+
+    fn press(key: egui::Key, modifiers: egui::Modifiers) -> egui::RawInput {
+        egui::RawInput {
+            events: vec![egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn focused_number<T: egui::emath::Numeric>(
+        ctx: &egui::Context,
+        input: egui::RawInput,
+        value: &mut T,
+    ) {
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let id = ui.next_auto_id();
+                ui.memory_mut(|memory| memory.request_focus(id));
+                widgets::number(ui, value, &NumberOptions::default());
+            });
+        });
+    }
+
+    #[test]
+    fn modifiers_scale_arrow_key_steps() {
+        let ctx = egui::Context::default();
+        let mut value = 0.5f32;
+        focused_number(&ctx, egui::RawInput::default(), &mut value);
+
+        focused_number(
+            &ctx,
+            press(egui::Key::ArrowUp, egui::Modifiers::SHIFT),
+            &mut value,
+        );
+        assert_eq!(value, 1.5);
+        focused_number(
+            &ctx,
+            press(egui::Key::ArrowDown, egui::Modifiers::CTRL),
+            &mut value,
+        );
+        assert_eq!(value, 1.49);
+
+        let mut integer = 3u8;
+        focused_number(&ctx, egui::RawInput::default(), &mut integer);
+        focused_number(
+            &ctx,
+            press(egui::Key::ArrowUp, egui::Modifiers::CTRL),
+            &mut integer,
+        );
+        assert_eq!(integer, 4);
+    }
+}
@@ -1,16 +1,20 @@
-use bevy_asset::{Assets, Handle};
-use bevy_ecs::world::World;
+use bevy_asset::{Asset, AssetServer, Assets, Handle, LoadState};
+use bevy_core::Name;
 use bevy_ecs::{entity::Entity, system::CommandQueue};
-use bevy_render::mesh::Mesh;
-use bevy_render::{color::Color, view::RenderLayers};
-use egui::{ecolor::Hsva, Color32};
-use std::any::Any;
+use bevy_scene::Scene;
+use pretty_type_name::pretty_type_name;
+use std::any::{Any, TypeId};
 
 use crate::{
-    bevy_inspector::errors::{dead_asset_handle, no_world_in_context, show_error},
+    bevy_inspector::{
+        commands, dnd,
+        errors::{no_world_in_context, show_error},
+        handle_name,
+    },
     egui_utils,
     inspector_options::std_options::{EntityDisplay, EntityOptions},
     reflect_inspector::{Context, InspectorUi},
+    restricted_world_view::RestrictedWorldView,
 };
 
 use super::InspectorPrimitive;
@@ -30,9 +34,15 @@ impl InspectorPrimitive for Entity {
             .cloned()
             .unwrap_or_default();
 
+        let mut changed = false;
         match options.display {
             EntityDisplay::Id => {
-                ui.label(format!("{entity:?}"));
+                ui.horizontal(|ui| {
+                    ui.label(format!("{entity:?}"));
+                    if let Some(world) = &mut env.context.world {
+                        changed |= entity_reference_buttons(ui, self, id, world);
+                    }
+                });
             }
             EntityDisplay::Components => {
                 let Context {
@@ -46,9 +56,17 @@ impl InspectorPrimitive for Entity {
 
                 let entity_name =
                     crate::utils::guess_entity_name::guess_entity_name_restricted(world, entity);
-                egui::CollapsingHeader::new(entity_name)
-                    .id_source(id)
-                    .show(ui, |ui| {
+                let state = egui::collapsing_header::CollapsingState::load_with_default_open(
+                    ui.ctx(),
+                    ui.make_persistent_id(id),
+                    false,
+                );
+                state
+                    .show_header(ui, |ui| {
+                        ui.label(entity_name);
+                        changed |= entity_reference_buttons(ui, self, id, world);
+                    })
+                    .body(|ui| {
                         let _queue = CommandQueue::default();
                         crate::bevy_inspector::ui_for_entity_components(
                             world,
@@ -61,16 +79,21 @@ impl InspectorPrimitive for Entity {
                         if options.despawnable && world.contains_entity(entity) {
                             if let Some(queue) = queue {
                                 if egui_utils::label_button(ui, "✖ Despawn", egui::Color32::RED) {
-                                    queue.push(move |world: &mut World| {
-                                        world.entity_mut(entity).despawn();
-                                    });
+                                    commands::push_deferred(
+                                        queue,
+                                        "Despawn",
+                                        Some(entity),
+                                        move |world| {
+                                            world.despawn(entity);
+                                        },
+                                    );
                                 }
                             }
                         }
                     });
             }
         }
-        false
+        changed
     }
 
     fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
@@ -78,177 +101,365 @@ impl InspectorPrimitive for Entity {
     }
 }
 
-impl InspectorPrimitive for Handle<Mesh> {
+/// Maximum number of entities listed in the [`entity_picker`] at once
+const ENTITY_PICKER_LIMIT: usize = 100;
+
+/// Buttons for jumping to `entity` in the world inspector and for reassigning it to another entity.
+///
+/// Returns `true` if `entity` was reassigned.
+fn entity_reference_buttons(
+    ui: &mut egui::Ui,
+    entity: &mut Entity,
+    id: egui::Id,
+    world: &mut RestrictedWorldView<'_>,
+) -> bool {
+    let alive = world.contains_entity(*entity);
+    let go_to = ui
+        .add_enabled(alive, egui::Button::new("➡").small())
+        .on_hover_text("Go to entity in the world inspector");
+    if go_to.clicked() {
+        crate::bevy_inspector::focus_entity(ui.ctx(), *entity);
+    }
+
+    let pick = ui
+        .add(egui::Button::new("🔍").small())
+        .on_hover_text("Reassign to another entity");
+    match entity_picker(ui, id.with("entity picker"), &pick, *entity, world) {
+        Some(picked) if picked != *entity => {
+            *entity = picked;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// A popup below `button` with a searchable list of all living entities, toggled by clicking `button`.
+///
+/// Returns the entity that was clicked in the list.
+fn entity_picker(
+    ui: &egui::Ui,
+    popup_id: egui::Id,
+    button: &egui::Response,
+    current: Entity,
+    world: &mut RestrictedWorldView<'_>,
+) -> Option<Entity> {
+    let ctx = ui.ctx().clone();
+    let search_id = popup_id.with("search");
+
+    let mut open = ctx.data(|data| data.get_temp::<bool>(popup_id).unwrap_or(false));
+    if button.clicked() {
+        open = !open;
+    }
+    if !open {
+        ctx.data_mut(|data| {
+            data.remove::<bool>(popup_id);
+            data.remove::<String>(search_id);
+        });
+        return None;
+    }
+
+    let mut search = ctx.data(|data| data.get_temp::<String>(search_id).unwrap_or_default());
+    let search_lowercase = search.to_lowercase();
+
+    let mut entities: Vec<Entity> = world
+        .world()
+        .archetypes()
+        .iter()
+        .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+        .collect();
+    entities.sort();
+    let mut matches = entities
+        .into_iter()
+        .map(|entity| {
+            let name = crate::utils::guess_entity_name::guess_entity_name_restricted(world, entity);
+            (entity, name)
+        })
+        .filter(|(_, name)| name.to_lowercase().contains(&search_lowercase));
+
+    let mut picked = None;
+    let area = egui::Area::new(popup_id)
+        .order(egui::Order::Foreground)
+        .fixed_pos(button.rect.left_bottom())
+        .show(&ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                let search_response =
+                    ui.add(egui::TextEdit::singleline(&mut search).hint_text("Search entities"));
+                if button.clicked() {
+                    search_response.request_focus();
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(250.0)
+                    .show(ui, |ui| {
+                        for (entity, name) in matches.by_ref().take(ENTITY_PICKER_LIMIT) {
+                            if ui.selectable_label(entity == current, name).clicked() {
+                                picked = Some(entity);
+                            }
+                        }
+                        let remaining = matches.count();
+                        if remaining > 0 {
+                            ui.weak(format!("{remaining} more, refine the search"));
+                        }
+                    });
+            });
+        });
+
+    let clicked_outside = ui.input(|input| {
+        input.pointer.any_click()
+            && input
+                .pointer
+                .interact_pos()
+                .is_some_and(|pos| !area.response.rect.contains(pos))
+    });
+    let escape = ui.input(|input| input.key_pressed(egui::Key::Escape));
+    if picked.is_some() || escape || (clicked_outside && !button.clicked()) {
+        open = false;
+    }
+
+    ctx.data_mut(|data| {
+        data.insert_temp(popup_id, open);
+        data.insert_temp(search_id, search);
+    });
+    picked
+}
+
+/// The instantiated entities are listed by the world inspector, which knows the root entity of the scene
+impl InspectorPrimitive for Handle<Scene> {
     fn ui(
         &mut self,
         ui: &mut egui::Ui,
         _: &dyn Any,
-        _: egui::Id,
+        id: egui::Id,
         env: InspectorUi<'_, '_>,
     ) -> bool {
-        let handle = &*self;
-        let Some(world) = &mut env.context.world else {
-            no_world_in_context(ui, "Handle<Mesh>");
-            return false;
-        };
-        let mut meshes = match world.get_resource_mut::<Assets<Mesh>>() {
-            Ok(meshes) => meshes,
-            Err(error) => {
-                show_error(error, ui, "Assets<Mesh>");
-                return false;
-            }
-        };
-        let Some(mesh) = meshes.get_mut(handle) else {
-            dead_asset_handle(ui, handle.into());
-            return false;
-        };
+        let changed = asset_path_picker(
+            self,
+            ui,
+            id,
+            InspectorUi::new_no_short_circuit(env.type_registry, &mut *env.context),
+        );
+        let Context { world, queue } = env.context;
+        crate::bevy_inspector::scenes::ui_for_scene_spawn(
+            ui,
+            world.as_mut(),
+            queue.as_deref_mut(),
+            self,
+        );
+        changed
+    }
 
-        mesh_ui_inner(mesh, ui);
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, env: InspectorUi<'_, '_>) {
+        asset_path_readonly(self, ui, env);
+    }
+}
 
-        ui.add_enabled_ui(mesh.indices().is_some(), |ui| {
-            if ui.button("Duplicate vertices").clicked() {
-                mesh.duplicate_vertices();
-            }
-        });
-        ui.add_enabled_ui(mesh.indices().is_none(), |ui| {
-            if ui.button("Compute flat normals").clicked() {
-                mesh.compute_flat_normals();
-            }
-        });
-        if ui.button("Generate tangents").clicked() {
-            let _ = mesh.generate_tangents();
-        }
+/// Sink controls are shown by the world inspector, which knows the entity playing the sound
+#[cfg(feature = "bevy_audio")]
+impl InspectorPrimitive for Handle<bevy_audio::AudioSource> {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let changed = asset_path_picker(
+            self,
+            ui,
+            id,
+            InspectorUi::new_no_short_circuit(env.type_registry, &mut *env.context),
+        );
+        let Context { world, queue } = env.context;
+        crate::bevy_inspector::audio::ui_for_audio_source(
+            ui,
+            world.as_mut(),
+            queue.as_deref_mut(),
+            self,
+        );
+        changed
+    }
 
-        false
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, env: InspectorUi<'_, '_>) {
+        asset_path_readonly(
+            self,
+            ui,
+            InspectorUi::new_no_short_circuit(env.type_registry, &mut *env.context),
+        );
+        crate::bevy_inspector::audio::ui_for_audio_source(
+            ui,
+            env.context.world.as_mut(),
+            None,
+            self,
+        );
+    }
+}
+
+#[cfg(feature = "bevy_gltf")]
+impl InspectorPrimitive for Handle<bevy_gltf::Gltf> {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        asset_path_picker(self, ui, id, env)
     }
 
     fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, env: InspectorUi<'_, '_>) {
-        let Some(world) = &mut env.context.world else {
-            no_world_in_context(ui, "Handle<Mesh>");
-            return;
-        };
-        let meshes = match world.get_resource_mut::<Assets<Mesh>>() {
-            Ok(meshes) => meshes,
-            Err(error) => return show_error(error, ui, "Assets<Mesh>"),
-        };
-        let Some(mesh) = meshes.get(self) else {
-            return dead_asset_handle(ui, self.into());
-        };
-
-        mesh_ui_inner(mesh, ui);
+        asset_path_readonly(self, ui, env);
     }
 }
 
-fn mesh_ui_inner(mesh: &Mesh, ui: &mut egui::Ui) {
-    egui::Grid::new("mesh").show(ui, |ui| {
-        ui.label("primitive_topology");
-        ui.label(format!("{:?}", mesh.primitive_topology()));
-        ui.end_row();
-
-        ui.label("Vertices");
-        ui.label(mesh.count_vertices().to_string());
-        ui.end_row();
-
-        if let Some(indices) = mesh.indices() {
-            ui.label("Indices");
-            let len = match indices {
-                bevy_render::mesh::Indices::U16(vec) => vec.len(),
-                bevy_render::mesh::Indices::U32(vec) => vec.len(),
-            };
-            ui.label(len.to_string());
-            ui.end_row();
-        }
+#[cfg(feature = "bevy_text")]
+impl InspectorPrimitive for Handle<bevy_text::Font> {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        asset_path_picker(self, ui, id, env)
+    }
 
-        ui.label("Vertex Attributes");
-
-        let builtin_attributes = &[
-            Mesh::ATTRIBUTE_POSITION,
-            Mesh::ATTRIBUTE_COLOR,
-            Mesh::ATTRIBUTE_UV_0,
-            Mesh::ATTRIBUTE_NORMAL,
-            Mesh::ATTRIBUTE_TANGENT,
-            Mesh::ATTRIBUTE_COLOR,
-            Mesh::ATTRIBUTE_JOINT_INDEX,
-            Mesh::ATTRIBUTE_JOINT_WEIGHT,
-        ];
-
-        ui.vertical(|ui| {
-            for attribute in builtin_attributes {
-                if mesh.attribute(attribute.id).is_some() {
-                    ui.label(attribute.name);
-                }
-            }
-        });
-    });
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, env: InspectorUi<'_, '_>) {
+        asset_path_readonly(self, ui, env);
+    }
 }
 
-impl InspectorPrimitive for Color {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        match self {
-            Color::Rgba {
-                red,
-                green,
-                blue,
-                alpha,
-            } => {
-                let mut color = Color32::from_rgba_premultiplied(
-                    (*red * 255.) as u8,
-                    (*green * 255.) as u8,
-                    (*blue * 255.) as u8,
-                    (*alpha * 255.) as u8,
-                );
-                if ui.color_edit_button_srgba(&mut color).changed() {
-                    let [r, g, b, a] = color.to_array();
-                    *red = r as f32 / 255.;
-                    *green = g as f32 / 255.;
-                    *blue = b as f32 / 255.;
-                    *alpha = a as f32 / 255.;
-                    return true;
-                }
+/// Display a handle to an asset which can't be inspected itself by its path and load state,
+/// with a dropdown to switch to any other loaded asset of the same type.
+fn asset_path_picker<A: Asset>(
+    handle: &mut Handle<A>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+) -> bool {
+    let Some(world) = &mut env.context.world else {
+        no_world_in_context(ui, &pretty_type_name::<Handle<A>>());
+        return false;
+    };
+    let (asset_server, assets) = match world.get_two_resources_mut::<AssetServer, Assets<A>>() {
+        (Ok(a), Ok(b)) => (a, b),
+        (a, b) => {
+            if let Err(e) = a {
+                show_error(e, ui, &pretty_type_name::<AssetServer>());
             }
-            Color::RgbaLinear {
-                red,
-                green,
-                blue,
-                alpha,
-            } => {
-                let mut color = [*red, *green, *blue, *alpha];
-                if ui
-                    .color_edit_button_rgba_premultiplied(&mut color)
-                    .changed()
-                {
-                    *red = color[0];
-                    *green = color[1];
-                    *blue = color[2];
-                    *alpha = color[3];
-                    return true;
-                }
-            }
-            Color::Hsla {
-                hue,
-                saturation,
-                lightness,
-                alpha,
-            } => {
-                let mut hsva = Hsva::new(*hue, *saturation, *lightness, *alpha);
-                if ui.color_edit_button_hsva(&mut hsva).changed() {
-                    *hue = hsva.h;
-                    *saturation = hsva.s;
-                    *lightness = hsva.v;
-                    *alpha = hsva.a;
-                    return true;
-                }
+            if let Err(e) = b {
+                show_error(e, ui, &pretty_type_name::<Assets<A>>());
             }
-            Color::Lcha { .. } => {
-                let [hue, saturation, lightness, alpha] = self.as_hsla_f32();
-                let mut hsva = Hsva::new(hue, saturation, lightness, alpha);
-                if ui.color_edit_button_hsva(&mut hsva).changed() {
-                    *self = Color::hsla(hue, saturation, lightness, alpha).as_lcha();
-                    return true;
+            return false;
+        }
+    };
+
+    let mut paths: Vec<String> = assets
+        .ids()
+        .filter_map(|id| asset_server.get_path(id))
+        .map(|path| path.to_string())
+        .collect();
+    paths.sort();
+
+    let current = handle_name(handle.id().untyped(), Some(&asset_server));
+    let mut selected_path = None;
+    let row = ui.horizontal(|ui| {
+        egui::ComboBox::from_id_source(id)
+            .selected_text(&current)
+            .show_ui(ui, |ui| {
+                for path in &paths {
+                    if ui.selectable_label(*path == current, path).clicked() {
+                        selected_path = Some(path.clone());
+                    }
                 }
-            }
+            });
+        load_state_label(handle, &asset_server, ui);
+    });
+    if let Some(dropped) = dnd::dropped_asset(ui, &row.response, TypeId::of::<A>()) {
+        *handle = dropped.typed();
+        return true;
+    }
+
+    match selected_path {
+        Some(path) => {
+            *handle = asset_server.load(path);
+            true
+        }
+        None => false,
+    }
+}
+
+fn asset_path_readonly<A: Asset>(handle: &Handle<A>, ui: &mut egui::Ui, env: InspectorUi<'_, '_>) {
+    let Some(world) = &mut env.context.world else {
+        no_world_in_context(ui, &pretty_type_name::<Handle<A>>());
+        return;
+    };
+    let asset_server = match world.get_resource_mut::<AssetServer>() {
+        Ok(asset_server) => asset_server,
+        Err(error) => return show_error(error, ui, &pretty_type_name::<AssetServer>()),
+    };
+
+    ui.horizontal(|ui| {
+        ui.label(handle_name(handle.id().untyped(), Some(&asset_server)));
+        load_state_label(handle, &asset_server, ui);
+    });
+}
+
+fn load_state_label<A: Asset>(handle: &Handle<A>, asset_server: &AssetServer, ui: &mut egui::Ui) {
+    match asset_server.get_load_state(handle) {
+        Some(LoadState::Loading) => {
+            ui.label("loading");
         }
-        false
+        Some(LoadState::Failed) => {
+            ui.colored_label(ui.visuals().error_fg_color, "failed");
+        }
+        _ => {}
+    }
+}
+
+#[cfg(feature = "bevy_sprite")]
+impl InspectorPrimitive for bevy_sprite::Sprite {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let mut changed = false;
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            ui.label("color");
+            changed |= super::color_ui(
+                &mut self.color,
+                &crate::inspector_options::std_options::ColorOptions::default(),
+                ui,
+            );
+            ui.end_row();
+
+            ui.label("flip");
+            ui.horizontal(|ui| {
+                changed |= ui.checkbox(&mut self.flip_x, "x").changed();
+                changed |= ui.checkbox(&mut self.flip_y, "y").changed();
+            });
+            ui.end_row();
+
+            ui.label("custom_size");
+            changed |= env.ui_for_reflect_with_options(
+                &mut self.custom_size,
+                ui,
+                id.with("custom_size"),
+                &(),
+            );
+            ui.end_row();
+
+            ui.label("rect");
+            changed |= env.ui_for_reflect_with_options(&mut self.rect, ui, id.with("rect"), &());
+            ui.end_row();
+
+            ui.label("anchor");
+            changed |=
+                env.ui_for_reflect_with_options(&mut self.anchor, ui, id.with("anchor"), &());
+            ui.end_row();
+        });
+        changed
     }
 
     fn ui_readonly(
@@ -258,52 +469,207 @@ impl InspectorPrimitive for Color {
         id: egui::Id,
         env: InspectorUi<'_, '_>,
     ) {
-        let mut copy = *self;
+        let mut copy = self.clone();
         ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
     }
 }
 
-impl InspectorPrimitive for RenderLayers {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        let mut new_value = None;
+/// The index is scrubbed with a slider over the frames of the layout. The selected frame is previewed by the world inspector,
+/// which knows the image of the entity.
+#[cfg(feature = "bevy_sprite")]
+impl InspectorPrimitive for bevy_sprite::TextureAtlas {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let frames = env.context.world.as_mut().and_then(|world| {
+            let layouts = world
+                .get_resource_mut::<Assets<bevy_sprite::TextureAtlasLayout>>()
+                .ok()?;
+            layouts.get(&self.layout).map(|layout| layout.len())
+        });
+
+        let mut changed = false;
         egui::Grid::new(id).num_columns(2).show(ui, |ui| {
-            for layer in self.iter() {
-                let mut layer_copy = layer;
-                if ui
-                    .add(
-                        egui::DragValue::new(&mut layer_copy)
-                            .clamp_range(0..=RenderLayers::TOTAL_LAYERS - 1),
-                    )
-                    .changed()
-                {
-                    new_value = Some(self.without(layer).with(layer_copy));
-                }
+            ui.label("index");
+            changed |= match frames {
+                Some(frames) if frames > 0 => ui
+                    .add(egui::Slider::new(&mut self.index, 0..=frames - 1))
+                    .changed(),
+                _ => ui.add(egui::DragValue::new(&mut self.index)).changed(),
+            };
+            ui.end_row();
+
+            ui.label("layout");
+            changed |=
+                env.ui_for_reflect_with_options(&mut self.layout, ui, id.with("layout"), &());
+            ui.end_row();
+        });
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
 
-                if ui.button("-").clicked() {
-                    new_value = Some(self.without(layer));
+/// Playback controls and a scrub bar over the time of the current clip, seeking the animation when dragged
+#[cfg(feature = "bevy_animation")]
+impl InspectorPrimitive for bevy_animation::AnimationPlayer {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let duration = env.context.world.as_mut().and_then(|world| {
+            let clips = world
+                .get_resource_mut::<Assets<bevy_animation::AnimationClip>>()
+                .ok()?;
+            clips.get(self.animation_clip()).map(|clip| clip.duration())
+        });
+
+        let mut changed = false;
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            ui.label("clip");
+            let mut clip = self.animation_clip().clone();
+            if env.ui_for_reflect_with_options(&mut clip, ui, id.with("clip"), &()) {
+                self.start(clip);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("playback");
+            ui.horizontal(|ui| {
+                let (icon, hover) = match self.is_paused() {
+                    true => ("▶", "Resume"),
+                    false => ("⏸", "Pause"),
+                };
+                if ui.button(icon).on_hover_text(hover).clicked() {
+                    match self.is_paused() {
+                        true => self.resume(),
+                        false => self.pause(),
+                    }
+                    changed = true;
+                }
+                if ui.button("⟲").on_hover_text("Replay").clicked() {
+                    self.replay();
+                    changed = true;
+                }
+                if self.is_finished() {
+                    ui.weak("finished");
                 }
-                ui.end_row();
+            });
+            ui.end_row();
+
+            ui.label("time");
+            let mut seek_time = self.seek_time();
+            let response = match duration {
+                Some(duration) if duration > 0.0 => ui.add(
+                    egui::Slider::new(&mut seek_time, 0.0..=duration)
+                        .suffix(" s")
+                        .max_decimals(2),
+                ),
+                _ => ui.add(
+                    egui::DragValue::new(&mut seek_time)
+                        .speed(0.01)
+                        .suffix(" s"),
+                ),
+            };
+            if response
+                .on_hover_text(format!(
+                    "elapsed {:.2} s, completed {} times",
+                    self.elapsed(),
+                    self.completions()
+                ))
+                .changed()
+            {
+                self.seek_to(seek_time);
+                changed = true;
             }
-        });
+            ui.end_row();
+
+            ui.label("speed");
+            let mut speed = self.speed();
+            if ui
+                .add(egui::DragValue::new(&mut speed).speed(0.01))
+                .changed()
+            {
+                self.set_speed(speed);
+                changed = true;
+            }
+            ui.end_row();
 
-        ui.horizontal(|ui| {
-            if ui.button("Add").clicked() {
-                let new_layer = self.iter().last().map_or(0, |last| last + 1);
-                new_value = Some(self.with(new_layer));
+            ui.label("repeat");
+            let mut repeat = self.repeat_mode();
+            if env.ui_for_reflect_with_options(&mut repeat, ui, id.with("repeat"), &()) {
+                self.set_repeat(repeat);
+                changed = true;
             }
+            ui.end_row();
         });
+        changed
+    }
 
-        if let Some(new_value) = new_value {
-            *self = new_value;
-            true
-        } else {
-            false
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            ui.label("clip");
+            env.ui_for_reflect_readonly_with_options(
+                self.animation_clip(),
+                ui,
+                id.with("clip"),
+                &(),
+            );
+            ui.end_row();
+
+            ui.label("time");
+            ui.label(format!("{:.2} s", self.seek_time()));
+            ui.end_row();
+
+            ui.label("speed");
+            ui.label(format!("{:.2}", self.speed()));
+            ui.end_row();
+
+            ui.label("repeat");
+            ui.label(format!("{:?}", self.repeat_mode()));
+            ui.end_row();
+
+            ui.label("paused");
+            ui.label(self.is_paused().to_string());
+            ui.end_row();
+        });
+    }
+}
+
+impl InspectorPrimitive for Name {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        let mut name = self.as_str().to_owned();
+        let changed = ui.text_edit_singleline(&mut name).changed();
+        if changed {
+            // `set` keeps the precomputed hash up to date
+            self.set(name);
         }
+        changed
     }
 
     fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        for layer in self.iter() {
-            ui.label(format!("- {layer}"));
-        }
+        ui.text_edit_singleline(&mut self.as_str());
     }
 }
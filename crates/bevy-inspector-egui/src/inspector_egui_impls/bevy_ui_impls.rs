@@ -0,0 +1,202 @@
+use bevy_text::TextSection;
+use bevy_ui::{Node, UiRect, Val};
+use std::any::Any;
+
+use crate::{inspector_options::std_options::ColorOptions, reflect_inspector::InspectorUi};
+
+use super::{color_ui, InspectorPrimitive};
+
+/// The units of [`Val`] in the order they are offered in the unit picker
+const VAL_UNITS: [&str; 7] = ["auto", "px", "%", "vw", "vh", "vmin", "vmax"];
+
+/// The unit and number of `val`, with `0.0` for [`Val::Auto`]
+fn val_parts(val: Val) -> (&'static str, f32) {
+    match val {
+        Val::Auto => ("auto", 0.0),
+        Val::Px(value) => ("px", value),
+        Val::Percent(value) => ("%", value),
+        Val::Vw(value) => ("vw", value),
+        Val::Vh(value) => ("vh", value),
+        Val::VMin(value) => ("vmin", value),
+        Val::VMax(value) => ("vmax", value),
+    }
+}
+
+/// The [`Val`] with one of the [`VAL_UNITS`], keeping `value` when switching between units
+fn val_from_parts(unit: &str, value: f32) -> Val {
+    match unit {
+        "px" => Val::Px(value),
+        "%" => Val::Percent(value),
+        "vw" => Val::Vw(value),
+        "vh" => Val::Vh(value),
+        "vmin" => Val::VMin(value),
+        "vmax" => Val::VMax(value),
+        _ => Val::Auto,
+    }
+}
+
+fn val_ui(val: &mut Val, ui: &mut egui::Ui, id: egui::Id) -> bool {
+    let (mut unit, mut value) = val_parts(*val);
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        changed |= ui
+            .add_enabled(unit != "auto", egui::DragValue::new(&mut value))
+            .changed();
+        egui::ComboBox::from_id_source(id.with("unit"))
+            .width(56.0)
+            .selected_text(unit)
+            .show_ui(ui, |ui| {
+                for option in VAL_UNITS {
+                    changed |= ui.selectable_value(&mut unit, option, option).changed();
+                }
+            });
+    });
+    if changed {
+        *val = val_from_parts(unit, value);
+    }
+    changed
+}
+
+fn val_label(val: Val, ui: &mut egui::Ui) {
+    match val_parts(val) {
+        ("auto", _) => ui.label("auto"),
+        (unit, value) => ui.label(format!("{value} {unit}")),
+    };
+}
+
+impl InspectorPrimitive for Val {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        val_ui(self, ui, id)
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+        val_label(*self, ui);
+    }
+}
+
+impl InspectorPrimitive for UiRect {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        let mut changed = false;
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            for (name, side) in [
+                ("left", &mut self.left),
+                ("right", &mut self.right),
+                ("top", &mut self.top),
+                ("bottom", &mut self.bottom),
+            ] {
+                ui.label(name);
+                changed |= val_ui(side, ui, id.with(name));
+                ui.end_row();
+            }
+        });
+        changed
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) {
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            for (name, side) in [
+                ("left", self.left),
+                ("right", self.right),
+                ("top", self.top),
+                ("bottom", self.bottom),
+            ] {
+                ui.label(name);
+                val_label(side, ui);
+                ui.end_row();
+            }
+        });
+    }
+}
+
+/// The layout of a node is computed by bevy, so it is only displayed
+impl InspectorPrimitive for Node {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        self.ui_readonly(ui, options, id, env);
+        false
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) {
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            let size = self.size();
+            ui.label("size");
+            ui.label(format!("{} × {}", size.x, size.y));
+            ui.end_row();
+
+            ui.label("stack_index");
+            ui.label(self.stack_index().to_string());
+            ui.end_row();
+
+            ui.label("outline_width");
+            ui.label(self.outline_width().to_string());
+            ui.end_row();
+        });
+    }
+}
+
+impl InspectorPrimitive for TextSection {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let mut changed = ui
+            .add(egui::TextEdit::multiline(&mut self.value).desired_rows(1))
+            .changed();
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            ui.label("font_size");
+            changed |= ui
+                .add(egui::DragValue::new(&mut self.style.font_size).clamp_range(0.0..=f32::MAX))
+                .changed();
+            ui.end_row();
+
+            ui.label("color");
+            changed |= color_ui(&mut self.style.color, &ColorOptions::default(), ui);
+            ui.end_row();
+
+            ui.label("font");
+            changed |=
+                env.ui_for_reflect_with_options(&mut self.style.font, ui, id.with("font"), &());
+            ui.end_row();
+        });
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ui::Val;
+
+    use super::{val_from_parts, val_parts, VAL_UNITS};
+
+    #[test]
+    fn val_keeps_number_across_units() {
+        for unit in VAL_UNITS {
+            let val = val_from_parts(unit, 25.0);
+            assert_eq!(val_parts(val).0, unit);
+        }
+        assert_eq!(
+            val_from_parts("%", val_parts(Val::Px(12.0)).1),
+            Val::Percent(12.0)
+        );
+        assert_eq!(val_parts(Val::Auto), ("auto", 0.0));
+    }
+}
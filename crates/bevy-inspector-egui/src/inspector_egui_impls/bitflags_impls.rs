@@ -0,0 +1,139 @@
+use bitflags::{
+    parser::{ParseHex, WriteHex},
+    Flags,
+};
+
+/// Implement [`InspectorPrimitive`](crate::inspector_egui_impls::InspectorPrimitive) for a type defined with
+/// [`bitflags::bitflags!`], displaying a checkbox for every named flag and a field for the raw bits in hex.
+///
+/// The type still needs to be reflected as a value and have its [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl) registered:
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::{impl_inspector_bitflags, inspector_egui_impls::InspectorEguiImpl};
+/// use bevy_reflect::Reflect;
+///
+/// bitflags::bitflags! {
+///     #[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+///     #[reflect_value(Component, PartialEq, Hash)]
+///     pub struct CollisionGroups: u32 {
+///         const PLAYER = 1 << 0;
+///         const ENEMY = 1 << 1;
+///         const TERRAIN = 1 << 2;
+///     }
+/// }
+///
+/// impl_inspector_bitflags!(CollisionGroups);
+///
+/// # fn register(app: &mut bevy_app::App) {
+/// app.register_type::<CollisionGroups>()
+///     .register_type_data::<CollisionGroups, InspectorEguiImpl>();
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_inspector_bitflags {
+    ($ty:ty) => {
+        impl $crate::inspector_egui_impls::InspectorPrimitive for $ty {
+            fn ui(
+                &mut self,
+                ui: &mut $crate::egui::Ui,
+                _: &dyn ::std::any::Any,
+                id: $crate::egui::Id,
+                _: $crate::reflect_inspector::InspectorUi<'_, '_>,
+            ) -> bool {
+                $crate::inspector_egui_impls::ui_for_bitflags(self, ui, id)
+            }
+
+            fn ui_readonly(
+                &self,
+                ui: &mut $crate::egui::Ui,
+                _: &dyn ::std::any::Any,
+                id: $crate::egui::Id,
+                _: $crate::reflect_inspector::InspectorUi<'_, '_>,
+            ) {
+                $crate::inspector_egui_impls::ui_for_bitflags_readonly(self, ui, id)
+            }
+        }
+    };
+}
+
+fn write_hex<B: WriteHex>(bits: &B) -> String {
+    let mut text = String::new();
+    let _ = bits.write_hex(&mut text);
+    text
+}
+
+fn parse_hex<B: ParseHex>(text: &str) -> Option<B> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .unwrap_or(text);
+    B::parse_hex(text).ok()
+}
+
+/// A checkbox for every named flag of `flags`, and a text field for editing all bits in hex
+pub fn ui_for_bitflags<T>(flags: &mut T, ui: &mut egui::Ui, id: egui::Id) -> bool
+where
+    T: Flags + Copy,
+    T::Bits: WriteHex + ParseHex,
+{
+    let mut changed = false;
+    ui.vertical(|ui| {
+        for flag in T::FLAGS.iter().filter(|flag| flag.is_named()) {
+            let mut enabled = flags.contains(*flag.value());
+            if ui.checkbox(&mut enabled, flag.name()).changed() {
+                flags.set(*flag.value(), enabled);
+                changed = true;
+            }
+        }
+
+        // keep the text while it is edited, so that it can be invalid in between
+        let text_id = id.with("bitflags hex");
+        let mut text = ui
+            .data_mut(|data| data.get_temp::<String>(text_id))
+            .unwrap_or_else(|| write_hex(&flags.bits()));
+        let parsed = parse_hex::<T::Bits>(&text);
+        ui.horizontal(|ui| {
+            ui.label("0x");
+            let mut text_edit = egui::TextEdit::singleline(&mut text).desired_width(80.0);
+            if parsed.is_none() {
+                text_edit = text_edit.text_color(ui.visuals().error_fg_color);
+            }
+            let response = ui.add(text_edit);
+            if response.changed() {
+                if let Some(bits) = parse_hex::<T::Bits>(&text) {
+                    *flags = T::from_bits_retain(bits);
+                    changed = true;
+                }
+            }
+            match response.has_focus() {
+                true => ui.data_mut(|data| data.insert_temp(text_id, text)),
+                false => ui.data_mut(|data| data.remove::<String>(text_id)),
+            }
+        });
+    });
+    changed
+}
+
+pub fn ui_for_bitflags_readonly<T>(flags: &T, ui: &mut egui::Ui, id: egui::Id)
+where
+    T: Flags + Copy,
+    T::Bits: WriteHex + ParseHex,
+{
+    let mut copy = *flags;
+    ui.add_enabled_ui(false, |ui| ui_for_bitflags(&mut copy, ui, id));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_hex, write_hex};
+
+    #[test]
+    fn hex_bits_roundtrip() {
+        assert_eq!(write_hex(&0x2au32), "2a");
+        assert_eq!(parse_hex::<u32>("0x2A"), Some(0x2a));
+        assert_eq!(parse_hex::<u8>(" ff "), Some(0xff));
+        assert_eq!(parse_hex::<u8>("100"), None);
+    }
+}
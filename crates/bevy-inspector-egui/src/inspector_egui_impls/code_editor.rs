@@ -0,0 +1,254 @@
+use std::ops::Range;
+
+use egui::{text::LayoutJob, Color32, TextFormat};
+
+use crate::inspector_options::std_options::CodeLanguage;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TokenKind {
+    Comment,
+    Keyword,
+    Literal,
+    Text,
+}
+
+impl CodeLanguage {
+    fn line_comment(self) -> Option<&'static str> {
+        match self {
+            CodeLanguage::Wgsl | CodeLanguage::Glsl | CodeLanguage::Rust | CodeLanguage::Ron => {
+                Some("//")
+            }
+            CodeLanguage::Lua => Some("--"),
+            CodeLanguage::Json => None,
+        }
+    }
+
+    fn block_comment(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            CodeLanguage::Wgsl | CodeLanguage::Glsl | CodeLanguage::Rust | CodeLanguage::Ron => {
+                Some(("/*", "*/"))
+            }
+            CodeLanguage::Lua => Some(("--[[", "]]")),
+            CodeLanguage::Json => None,
+        }
+    }
+
+    fn keywords(self) -> &'static [&'static str] {
+        match self {
+            CodeLanguage::Wgsl => &[
+                "alias",
+                "array",
+                "bool",
+                "break",
+                "case",
+                "const",
+                "continue",
+                "default",
+                "discard",
+                "else",
+                "enable",
+                "f16",
+                "f32",
+                "false",
+                "fn",
+                "for",
+                "i32",
+                "if",
+                "let",
+                "loop",
+                "mat2x2",
+                "mat3x3",
+                "mat4x4",
+                "override",
+                "ptr",
+                "return",
+                "sampler",
+                "struct",
+                "switch",
+                "texture_2d",
+                "true",
+                "u32",
+                "uniform",
+                "var",
+                "vec2",
+                "vec3",
+                "vec4",
+                "while",
+            ],
+            CodeLanguage::Glsl => &[
+                "bool",
+                "break",
+                "const",
+                "continue",
+                "discard",
+                "else",
+                "false",
+                "float",
+                "for",
+                "if",
+                "in",
+                "inout",
+                "int",
+                "layout",
+                "mat2",
+                "mat3",
+                "mat4",
+                "out",
+                "return",
+                "sampler2D",
+                "struct",
+                "true",
+                "uint",
+                "uniform",
+                "vec2",
+                "vec3",
+                "vec4",
+                "void",
+                "while",
+            ],
+            CodeLanguage::Rust => &[
+                "as", "break", "const", "continue", "else", "enum", "false", "fn", "for", "if",
+                "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+                "self", "Self", "static", "struct", "trait", "true", "use", "where", "while",
+            ],
+            CodeLanguage::Lua => &[
+                "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "if",
+                "in", "local", "nil", "not", "or", "repeat", "return", "then", "true", "until",
+                "while",
+            ],
+            CodeLanguage::Json => &["false", "null", "true"],
+            CodeLanguage::Ron => &["false", "None", "Some", "true"],
+        }
+    }
+}
+
+/// Split `code` into ranges of comments, keywords, string and number literals and the remaining text
+fn tokenize(code: &str, language: CodeLanguage) -> Vec<(TokenKind, Range<usize>)> {
+    let mut tokens: Vec<(TokenKind, Range<usize>)> = Vec::new();
+    let mut push = |kind: TokenKind, range: Range<usize>| match tokens.last_mut() {
+        Some((last_kind, last)) if *last_kind == kind && last.end == range.start => {
+            last.end = range.end
+        }
+        _ => tokens.push((kind, range)),
+    };
+
+    let mut start = 0;
+    while let Some(c) = code[start..].chars().next() {
+        let rest = &code[start..];
+        let (kind, len) = if let Some((open, close)) = language
+            .block_comment()
+            .filter(|(open, _)| rest.starts_with(open))
+        {
+            let len = rest[open.len()..]
+                .find(close)
+                .map_or(rest.len(), |end| open.len() + end + close.len());
+            (TokenKind::Comment, len)
+        } else if language
+            .line_comment()
+            .is_some_and(|comment| rest.starts_with(comment))
+        {
+            (TokenKind::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else if c == '"' {
+            let mut escaped = false;
+            let end = rest[1..].find(|c| {
+                let end = c == '"' && !escaped;
+                escaped = c == '\\' && !escaped;
+                end
+            });
+            (TokenKind::Literal, end.map_or(rest.len(), |end| end + 2))
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                .unwrap_or(rest.len());
+            (TokenKind::Literal, len)
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let kind = match language.keywords().contains(&&rest[..len]) {
+                true => TokenKind::Keyword,
+                false => TokenKind::Text,
+            };
+            (kind, len)
+        } else {
+            (TokenKind::Text, c.len_utf8())
+        };
+        push(kind, start..start + len);
+        start += len;
+    }
+    tokens
+}
+
+/// Lay out `code` in a monospace font, colored by a simple highlighter for `language`
+pub(crate) fn highlight(ui: &egui::Ui, code: &str, language: CodeLanguage) -> LayoutJob {
+    let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+    let dark_mode = ui.visuals().dark_mode;
+    let color = |kind| match (kind, dark_mode) {
+        (TokenKind::Comment, _) => ui.visuals().weak_text_color(),
+        (TokenKind::Keyword, true) => Color32::from_rgb(86, 156, 214),
+        (TokenKind::Keyword, false) => Color32::from_rgb(0, 80, 190),
+        (TokenKind::Literal, true) => Color32::from_rgb(206, 145, 120),
+        (TokenKind::Literal, false) => Color32::from_rgb(160, 40, 20),
+        (TokenKind::Text, _) => ui.visuals().text_color(),
+    };
+
+    let mut job = LayoutJob::default();
+    for (kind, range) in tokenize(code, language) {
+        job.append(
+            &code[range],
+            0.0,
+            TextFormat::simple(font_id.clone(), color(kind)),
+        );
+    }
+    job
+}
+
+/// A multiline monospace editor for `code`, highlighted for `language`
+pub(crate) fn code_editor(
+    ui: &mut egui::Ui,
+    code: &mut dyn egui::TextBuffer,
+    language: CodeLanguage,
+) -> egui::Response {
+    let mut layouter = |ui: &egui::Ui, code: &str, wrap_width: f32| {
+        let mut job = highlight(ui, code, language);
+        job.wrap.max_width = wrap_width;
+        ui.fonts(|fonts| fonts.layout_job(job))
+    };
+    ui.add(
+        egui::TextEdit::multiline(code)
+            .code_editor()
+            .desired_rows(4)
+            .lock_focus(true)
+            .layouter(&mut layouter),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, TokenKind};
+    use crate::inspector_options::std_options::CodeLanguage;
+
+    #[test]
+    fn tokenize_wgsl() {
+        let code = "let x = 1.5; // \"no string\"\nvar s = \"a\\\"b\";";
+        let tokens: Vec<_> = tokenize(code, CodeLanguage::Wgsl)
+            .into_iter()
+            .filter(|(kind, _)| *kind != TokenKind::Text)
+            .map(|(kind, range)| (kind, &code[range]))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::Keyword, "let"),
+                (TokenKind::Literal, "1.5"),
+                (TokenKind::Comment, "// \"no string\""),
+                (TokenKind::Keyword, "var"),
+                (TokenKind::Literal, "\"a\\\"b\""),
+            ]
+        );
+
+        let tokens = tokenize(code, CodeLanguage::Wgsl);
+        assert_eq!(tokens.first().unwrap().1.start, 0);
+        assert_eq!(tokens.last().unwrap().1.end, code.len());
+    }
+}
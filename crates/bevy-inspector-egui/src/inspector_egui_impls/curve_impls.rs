@@ -0,0 +1,42 @@
+use std::any::Any;
+
+#[cfg(feature = "bevy_render")]
+use crate::curves::ColorGradient;
+use crate::{curves::Curve, reflect_inspector::InspectorUi, widgets};
+
+use super::InspectorPrimitive;
+
+impl InspectorPrimitive for Curve {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        ui.vertical(|ui| widgets::curve(ui, id, self)).inner
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+#[cfg(feature = "bevy_render")]
+impl InspectorPrimitive for ColorGradient {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        ui.vertical(|ui| widgets::gradient(ui, id, self)).inner
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
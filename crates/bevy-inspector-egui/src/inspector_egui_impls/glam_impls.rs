@@ -83,6 +83,49 @@ macro_rules! vec_ui {
             changed
         }
 
+        vec_ui!(@readonly $name_readonly $ty: $count $($component)*);
+    };
+    // float vectors, with a toggle for editing the components proportionally
+    ($name:ident $name_readonly:ident $ty:ty > $elem_ty:ty: $count:literal $($component:ident)*) => {
+        pub fn $name(
+            value: &mut dyn Any,
+            ui: &mut egui::Ui,
+            options: &dyn Any,
+            id: egui::Id,
+            mut env: InspectorUi<'_, '_>,
+        ) -> bool {
+            let value = value.downcast_mut::<$ty>().unwrap();
+
+            let options = options
+                .downcast_ref::<NumberOptions<$ty>>()
+                .cloned()
+                .unwrap_or_default();
+
+            let before = *value;
+            let mut changed = false;
+            let mut linked = false;
+            ui.horizontal(|ui| {
+                linked = crate::inspector_egui_impls::ui_for_link_toggle(ui, id);
+                ui.style_mut().spacing.item_spacing = egui::Vec2::new(4.0, 0.);
+
+                ui.columns($count, |ui| match ui {
+                    [$($component),*] => {
+                        $(changed |= env.ui_for_reflect_with_options(&mut value.$component, $component, id.with(stringify!($component)), &options.map(|vec| vec.$component));)*
+                    }
+                    _ => unreachable!(),
+                });
+            });
+            if changed && linked {
+                let mut components: [$elem_ty; $count] = value.to_array();
+                crate::inspector_egui_impls::scale_linked(&mut components, &before.to_array());
+                *value = <$ty>::from_array(components);
+            }
+            changed
+        }
+
+        vec_ui!(@readonly $name_readonly $ty: $count $($component)*);
+    };
+    (@readonly $name_readonly:ident $ty:ty: $count:literal $($component:ident)*) => {
         pub fn $name_readonly(
             value: &dyn Any,
             ui: &mut egui::Ui,
@@ -140,19 +183,19 @@ macro_rules! mat_ui {
     };
 }
 
-vec_ui!(vec2_ui vec2_ui_readonly Vec2: 2 x y);
-vec_ui!(vec3_ui vec3_ui_readonly Vec3: 3 x y z);
-vec_ui!(vec3a_ui vec3a_ui_readonly Vec3A: 3 x y z);
-vec_ui!(vec4_ui vec4_ui_readonly Vec4: 4 x y z w);
+vec_ui!(vec2_ui vec2_ui_readonly Vec2 > f32: 2 x y);
+vec_ui!(vec3_ui vec3_ui_readonly Vec3 > f32: 3 x y z);
+vec_ui!(vec3a_ui vec3a_ui_readonly Vec3A > f32: 3 x y z);
+vec_ui!(vec4_ui vec4_ui_readonly Vec4 > f32: 4 x y z w);
 vec_ui!(uvec2_ui uvec2_ui_readonly UVec2: 2 x y);
 vec_ui!(uvec3_ui uvec3_ui_readonly UVec3: 3 x y z);
 vec_ui!(uvec4_ui uvec4_ui_readonly UVec4: 4 x y z w);
 vec_ui!(ivec2_ui ivec2_ui_readonly IVec2: 2 x y);
 vec_ui!(ivec3_ui ivec3_ui_readonly IVec3: 3 x y z);
 vec_ui!(ivec4_ui ivec4_ui_readonly IVec4: 4 x y z w);
-vec_ui!(dvec2_ui dvec2_ui_readonly DVec2: 2 x y);
-vec_ui!(dvec3_ui dvec3_ui_readonly DVec3: 3 x y z);
-vec_ui!(dvec4_ui dvec4_ui_readonly DVec4: 4 x y z w);
+vec_ui!(dvec2_ui dvec2_ui_readonly DVec2 > f64: 2 x y);
+vec_ui!(dvec3_ui dvec3_ui_readonly DVec3 > f64: 3 x y z);
+vec_ui!(dvec4_ui dvec4_ui_readonly DVec4 > f64: 4 x y z w);
 vec_ui!(bvec2_ui bvec2_ui_readonly BVec2: 2 x y);
 vec_ui!(bvec3_ui bvec3_ui_readonly BVec3: 3 x y z);
 vec_ui!(bvec4_ui bvec4_ui_readonly BVec4: 4 x y z w);
@@ -293,7 +336,7 @@ pub mod quat {
                 .get_temp_mut_or_insert_with(id, || T::from_quat(*val))
         });
 
-        let externally_changed = !intermediate.to_quat().abs_diff_eq(*val, std::f32::EPSILON);
+        let externally_changed = !intermediate.to_quat().abs_diff_eq(*val, f32::EPSILON);
         if externally_changed {
             intermediate = T::from_quat(*val);
         }
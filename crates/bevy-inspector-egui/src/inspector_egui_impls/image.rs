@@ -1,5 +1,5 @@
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     collections::{hash_map::Entry, HashMap, HashSet},
     sync::Mutex,
 };
@@ -13,7 +13,10 @@ use once_cell::sync::Lazy;
 use pretty_type_name::pretty_type_name;
 
 use crate::{
-    bevy_inspector::errors::{no_world_in_context, show_error},
+    bevy_inspector::{
+        dnd,
+        errors::{no_world_in_context, show_error},
+    },
     reflect_inspector::InspectorUi,
     restricted_world_view::RestrictedWorldView,
 };
@@ -81,7 +84,11 @@ impl InspectorPrimitive for Handle<Image> {
                 response
             },
         );
-        ui.add(dropdown);
+        let response = ui.add(dropdown);
+        if let Some(dropped) = dnd::dropped_asset(ui, &response, TypeId::of::<Image>()) {
+            *self = dropped.typed();
+            return true;
+        }
 
         // update the typed search text
         ui.data_mut(|data| {
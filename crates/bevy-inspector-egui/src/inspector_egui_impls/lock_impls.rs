@@ -0,0 +1,141 @@
+use std::{
+    any::Any,
+    sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+};
+
+use bevy_reflect::Reflect;
+
+use crate::reflect_inspector::InspectorUi;
+
+/// `bevy_reflect` doesn't implement [`Reflect`] for locks, so shared values have to be wrapped in a type reflected as
+/// a value which displays them with these functions:
+///
+/// ```rust
+/// use std::{any::Any, sync::{Arc, Mutex}};
+/// use bevy_inspector_egui::{inspector_egui_impls::{self, InspectorPrimitive}, reflect_inspector::InspectorUi};
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Clone, Default)]
+/// #[reflect_value]
+/// struct SharedScore(Arc<Mutex<u32>>);
+///
+/// impl InspectorPrimitive for SharedScore {
+///     fn ui(&mut self, ui: &mut egui::Ui, options: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) -> bool {
+///         inspector_egui_impls::ui_for_mutex(&self.0, ui, options, id, env)
+///     }
+///
+///     fn ui_readonly(&self, ui: &mut egui::Ui, options: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) {
+///         inspector_egui_impls::ui_for_mutex_readonly(&self.0, ui, options, id, env)
+///     }
+/// }
+/// ```
+///
+/// The locks are never waited for, a value locked by another thread is shown as "locked" until it is released.
+pub fn ui_for_mutex<T: Reflect>(
+    mutex: &Mutex<T>,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    match try_lock::<MutexGuard<T>>(mutex.try_lock()) {
+        Some(mut value) => env.ui_for_reflect_with_options(&mut *value, ui, id, options),
+        None => locked_ui(ui),
+    }
+}
+
+/// Display the value in a [`Mutex`] which isn't locked by another thread, see [`ui_for_mutex`]
+pub fn ui_for_mutex_readonly<T: Reflect>(
+    mutex: &Mutex<T>,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) {
+    match try_lock::<MutexGuard<T>>(mutex.try_lock()) {
+        Some(value) => env.ui_for_reflect_readonly_with_options(&*value, ui, id, options),
+        None => {
+            locked_ui(ui);
+        }
+    }
+}
+
+/// Edit the value in a [`RwLock`] which isn't read or written by another thread, see [`ui_for_mutex`]
+pub fn ui_for_rw_lock<T: Reflect>(
+    lock: &RwLock<T>,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    match try_lock::<RwLockWriteGuard<T>>(lock.try_write()) {
+        Some(mut value) => env.ui_for_reflect_with_options(&mut *value, ui, id, options),
+        None => locked_ui(ui),
+    }
+}
+
+/// Display the value in a [`RwLock`] which isn't written by another thread, see [`ui_for_mutex`]
+pub fn ui_for_rw_lock_readonly<T: Reflect>(
+    lock: &RwLock<T>,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) {
+    match try_lock::<RwLockReadGuard<T>>(lock.try_read()) {
+        Some(value) => env.ui_for_reflect_readonly_with_options(&*value, ui, id, options),
+        None => {
+            locked_ui(ui);
+        }
+    }
+}
+
+/// The guard of a lock which isn't held by another thread, even if a thread panicked while holding it
+fn try_lock<G>(result: Result<G, TryLockError<G>>) -> Option<G> {
+    match result {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(error)) => Some(error.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+fn locked_ui(ui: &mut egui::Ui) -> bool {
+    ui.weak("locked")
+        .on_hover_text("The value is locked by another thread");
+    // try again next frame, since nothing else causes a repaint once the lock is released
+    ui.ctx().request_repaint();
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, RwLock};
+
+    use super::try_lock;
+
+    #[test]
+    fn held_locks_are_not_waited_for() {
+        let mutex = Mutex::new(1);
+        let guard = mutex.lock().unwrap();
+        assert!(try_lock(mutex.try_lock()).is_none());
+        drop(guard);
+        assert_eq!(try_lock(mutex.try_lock()).as_deref(), Some(&1));
+
+        let lock = RwLock::new(2);
+        let read = lock.read().unwrap();
+        assert!(try_lock(lock.try_read()).is_some());
+        assert!(try_lock(lock.try_write()).is_none());
+        drop(read);
+
+        let _ = std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let _guard = mutex.lock().unwrap();
+                    panic!("poison the mutex");
+                })
+                .join()
+        });
+        assert!(mutex.is_poisoned());
+        assert_eq!(try_lock(mutex.try_lock()).as_deref(), Some(&1));
+    }
+}
@@ -1,7 +1,10 @@
 //! Custom UI implementations for specific types. Check [`InspectorPrimitive`] for an example.
 
-use crate::reflect_inspector::{errors::no_multiedit, InspectorUi};
-use bevy_reflect::{FromType, Reflect, TypePath, TypeRegistry};
+use crate::{
+    inspector_options::CustomWidget,
+    reflect_inspector::{errors::no_multiedit, Context, InspectorUi},
+};
+use bevy_reflect::{FromType, GetTypeRegistration, Reflect, TypePath, TypeRegistry};
 use bevy_utils::Instant;
 use std::{
     any::{Any, TypeId},
@@ -10,10 +13,34 @@ use std::{
 };
 
 mod bevy_impls;
+#[cfg(feature = "bevy_ui")]
+mod bevy_ui_impls;
+#[cfg(feature = "bitflags")]
+mod bitflags_impls;
+mod code_editor;
+mod curve_impls;
+#[cfg(feature = "glam")]
 mod glam_impls;
+#[cfg(feature = "bevy_render")]
 mod image;
+mod lock_impls;
+mod number_format;
+mod parse_impls;
+#[cfg(feature = "bevy_render")]
+mod render_impls;
 mod std_impls;
 
+#[cfg(feature = "bitflags")]
+pub use bitflags_impls::{ui_for_bitflags, ui_for_bitflags_readonly};
+pub(crate) use code_editor::{code_editor, highlight};
+pub use lock_impls::{
+    ui_for_mutex, ui_for_mutex_readonly, ui_for_rw_lock, ui_for_rw_lock_readonly,
+};
+pub(crate) use parse_impls::parsed_text;
+#[cfg(feature = "bevy_render")]
+pub(crate) use render_impls::color_ui;
+pub(crate) use std_impls::{display_number, scale_linked, ui_for_link_toggle};
+
 type InspectorEguiImplFn =
     fn(&mut dyn Any, &mut egui::Ui, &dyn Any, egui::Id, InspectorUi<'_, '_>) -> bool;
 type InspectorEguiImplFnReadonly =
@@ -95,7 +122,17 @@ fn ui_many_vtable<T: Reflect + PartialEq + Clone + Default + InspectorPrimitive>
     );
 
     let mut temp = same.cloned().unwrap_or_default();
-    if T::ui(&mut temp, ui, options, id, env) {
+    let changed = match same {
+        Some(_) => T::ui(&mut temp, ui, options, id, env),
+        None => {
+            ui.horizontal(|ui| {
+                ui_for_differing_values(ui);
+                T::ui(&mut temp, ui, options, id, env)
+            })
+            .inner
+        }
+    };
+    if changed {
         for value in values.iter_mut() {
             let value = projector(*value).downcast_mut::<T>().unwrap();
             *value = temp.clone();
@@ -106,6 +143,12 @@ fn ui_many_vtable<T: Reflect + PartialEq + Clone + Default + InspectorPrimitive>
     false
 }
 
+/// Shown in front of the widget editing many values which aren't all the same, which starts out at the default value
+pub(crate) fn ui_for_differing_values(ui: &mut egui::Ui) {
+    ui.weak("—")
+        .on_hover_text("The values differ, editing sets all of them");
+}
+
 fn ui_vtable<T: InspectorPrimitive>(
     val: &mut dyn Any,
     ui: &mut egui::Ui,
@@ -206,7 +249,41 @@ impl InspectorEguiImpl {
     }
 }
 
-fn many_unimplemented<T: Any>(
+/// Replace the UI of `T` everywhere in the inspector with `ui`, including types without an [`InspectorPrimitive`]
+/// implementation like components of other crates, which would otherwise need a newtype wrapper.
+///
+/// The widget takes precedence over the [`InspectorEguiImpl`] and the default UI of `T`, but not over
+/// `#[inspector(with = ..)]` on a field. Like a [`CustomWidget`], it is only used for editing.
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::{inspector_egui_impls::register_raw, reflect_inspector::Context};
+///
+/// fn translation_only(transform: &mut Transform, ui: &mut egui::Ui, _: &mut Context) -> bool {
+///     let mut changed = false;
+///     ui.horizontal(|ui| {
+///         changed |= ui.add(egui::DragValue::new(&mut transform.translation.x)).changed();
+///         changed |= ui.add(egui::DragValue::new(&mut transform.translation.y)).changed();
+///     });
+///     changed
+/// }
+///
+/// # fn register(app: &mut App) {
+/// register_raw::<Transform>(&mut app.world.resource::<AppTypeRegistry>().write(), translation_only);
+/// # }
+/// ```
+pub fn register_raw<T: Reflect + GetTypeRegistration>(
+    type_registry: &mut TypeRegistry,
+    ui: fn(&mut T, &mut egui::Ui, &mut Context<'_>) -> bool,
+) {
+    type_registry.register::<T>();
+    type_registry
+        .get_mut(TypeId::of::<T>())
+        .unwrap()
+        .insert(CustomWidget::new(ui));
+}
+
+pub(crate) fn many_unimplemented<T: Any>(
     ui: &mut egui::Ui,
     _options: &dyn Any,
     _id: egui::Id,
@@ -233,6 +310,7 @@ fn add_of_with_many<T: InspectorPrimitive>(
         .insert(InspectorEguiImpl::of_with_many::<T>(fn_many));
 }
 
+#[cfg(feature = "glam")]
 fn add_raw<T: 'static>(
     type_registry: &mut TypeRegistry,
     fn_mut: InspectorEguiImplFn,
@@ -245,6 +323,27 @@ fn add_raw<T: 'static>(
         .insert(InspectorEguiImpl::new(fn_mut, fn_readonly, fn_many));
 }
 
+/// Sets are reflected as opaque values, so they need an [`InspectorEguiImpl`] for every element type
+fn add_hash_set<T>(type_registry: &mut TypeRegistry)
+where
+    T: Reflect + TypePath + std::hash::Hash + Eq + Clone + Default,
+{
+    type_registry.register::<bevy_utils::HashSet<T>>();
+    add_of_with_many::<bevy_utils::HashSet<T>>(
+        type_registry,
+        many_unimplemented::<bevy_utils::HashSet<T>>,
+    );
+}
+
+/// Non-zero integers aren't registered by default and can't be edited together, since they have no [`Default`]
+fn add_non_zero<T>(type_registry: &mut TypeRegistry)
+where
+    T: InspectorPrimitive + bevy_reflect::GetTypeRegistration,
+{
+    type_registry.register::<T>();
+    add_of_with_many::<T>(type_registry, many_unimplemented::<T>);
+}
+
 /// Register [`InspectorEguiImpl`]s for primitive rust types as well as standard library types
 #[rustfmt::skip]
 pub fn register_std_impls(type_registry: &mut TypeRegistry) {
@@ -260,7 +359,21 @@ pub fn register_std_impls(type_registry: &mut TypeRegistry) {
     add_of_with_many::<u32>(type_registry, std_impls::number_ui_many::<u32>);
     add_of_with_many::<u64>(type_registry, std_impls::number_ui_many::<u64>);
     add_of_with_many::<usize>(type_registry, std_impls::number_ui_many::<usize>);
+    add_of_with_many::<i128>(type_registry, many_unimplemented::<i128>);
+    add_of_with_many::<u128>(type_registry, many_unimplemented::<u128>);
+    add_non_zero::<std::num::NonZeroI8>(type_registry);
+    add_non_zero::<std::num::NonZeroI16>(type_registry);
+    add_non_zero::<std::num::NonZeroI32>(type_registry);
+    add_non_zero::<std::num::NonZeroI64>(type_registry);
+    add_non_zero::<std::num::NonZeroIsize>(type_registry);
+    add_non_zero::<std::num::NonZeroU8>(type_registry);
+    add_non_zero::<std::num::NonZeroU16>(type_registry);
+    add_non_zero::<std::num::NonZeroU32>(type_registry);
+    add_non_zero::<std::num::NonZeroU64>(type_registry);
+    add_non_zero::<std::num::NonZeroUsize>(type_registry);
     add::<bool>(type_registry);
+    type_registry.register::<char>();
+    add::<char>(type_registry);
     add::<String>(type_registry);
     add::<Cow<str>>(type_registry);
     add::<PathBuf>(type_registry);
@@ -268,12 +381,42 @@ pub fn register_std_impls(type_registry: &mut TypeRegistry) {
     type_registry.register::<std::ops::Range<f64>>();
     add::<std::ops::Range<f32>>(type_registry);
     add::<std::ops::Range<f64>>(type_registry);
+    type_registry.register::<std::ops::RangeInclusive<f32>>();
+    type_registry.register::<std::ops::RangeInclusive<f64>>();
+    add_of_with_many::<std::ops::RangeInclusive<f32>>(
+        type_registry,
+        many_unimplemented::<std::ops::RangeInclusive<f32>>,
+    );
+    add_of_with_many::<std::ops::RangeInclusive<f64>>(
+        type_registry,
+        many_unimplemented::<std::ops::RangeInclusive<f64>>,
+    );
+
+    #[cfg(feature = "uuid")]
+    {
+        type_registry.register::<bevy_utils::Uuid>();
+        add::<bevy_utils::Uuid>(type_registry);
+    }
 
     add::<std::time::Duration>(type_registry);
     add_of_with_many::<Instant>(type_registry, many_unimplemented::<Instant>);
+
+    add_hash_set::<String>(type_registry);
+    add_hash_set::<bool>(type_registry);
+    add_hash_set::<u8>(type_registry);
+    add_hash_set::<u16>(type_registry);
+    add_hash_set::<u32>(type_registry);
+    add_hash_set::<u64>(type_registry);
+    add_hash_set::<usize>(type_registry);
+    add_hash_set::<i8>(type_registry);
+    add_hash_set::<i16>(type_registry);
+    add_hash_set::<i32>(type_registry);
+    add_hash_set::<i64>(type_registry);
+    add_hash_set::<isize>(type_registry);
 }
 
 /// Register [`InspectorEguiImpl`]s for [`bevy_math`]/`glam` types
+#[cfg(feature = "glam")]
 #[rustfmt::skip]
 pub fn register_glam_impls(type_registry: &mut TypeRegistry) {
     add_raw::<bevy_math::Vec2>(type_registry, glam_impls::vec2_ui, glam_impls::vec2_ui_readonly, glam_impls::vec2_ui_many);
@@ -306,11 +449,65 @@ pub fn register_glam_impls(type_registry: &mut TypeRegistry) {
 /// Register [`InspectorEguiImpl`]s for `bevy` types
 #[rustfmt::skip]
 pub fn register_bevy_impls(type_registry: &mut TypeRegistry) {
-    add_of_with_many::<bevy_asset::Handle<bevy_render::texture::Image>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_render::texture::Image>>);
-    add_of_with_many::<bevy_asset::Handle<bevy_render::mesh::Mesh>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_render::mesh::Mesh>>);
+    #[cfg(feature = "bevy_render")]
+    {
+        add_of_with_many::<bevy_asset::Handle<bevy_render::texture::Image>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_render::texture::Image>>);
+        add_of_with_many::<bevy_asset::Handle<bevy_render::mesh::Mesh>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_render::mesh::Mesh>>);
+        type_registry.register::<crate::bevy_inspector::mesh_shape::MeshShape>();
+        add::<bevy_render::color::Color>(type_registry);
+        add::<bevy_render::view::RenderLayers>(type_registry);
+        add::<bevy_render::view::Visibility>(type_registry);
+        type_registry.register::<crate::curves::ColorGradient>();
+        add::<crate::curves::ColorGradient>(type_registry);
+    }
+    type_registry.register::<crate::curves::Curve>();
+    add::<crate::curves::Curve>(type_registry);
+    // these assets don't implement `Reflect`, so their handles are displayed by path
+    type_registry.register::<bevy_asset::Handle<bevy_scene::Scene>>();
+    add_of_with_many::<bevy_asset::Handle<bevy_scene::Scene>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_scene::Scene>>);
+    #[cfg(feature = "bevy_gltf")]
+    {
+        type_registry.register::<bevy_asset::Handle<bevy_gltf::Gltf>>();
+        add_of_with_many::<bevy_asset::Handle<bevy_gltf::Gltf>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_gltf::Gltf>>);
+    }
+    #[cfg(feature = "bevy_text")]
+    {
+        type_registry.register::<bevy_asset::Handle<bevy_text::Font>>();
+        add_of_with_many::<bevy_asset::Handle<bevy_text::Font>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_text::Font>>);
+    }
     add_of_with_many::<bevy_ecs::entity::Entity>(type_registry, many_unimplemented::<bevy_ecs::entity::Entity>);
-    add::<bevy_render::color::Color>(type_registry);
-    add::<bevy_render::view::RenderLayers>(type_registry);
+    add::<bevy_core::Name>(type_registry);
+    #[cfg(feature = "bevy_sprite")]
+    {
+        // registered here too, so that apps without the `SpritePlugin` don't panic
+        type_registry.register::<bevy_sprite::Sprite>();
+        type_registry.register::<bevy_sprite::TextureAtlas>();
+        add_of_with_many::<bevy_sprite::Sprite>(type_registry, many_unimplemented::<bevy_sprite::Sprite>);
+        add_of_with_many::<bevy_sprite::TextureAtlas>(type_registry, many_unimplemented::<bevy_sprite::TextureAtlas>);
+    }
+    #[cfg(feature = "bevy_animation")]
+    {
+        // registered here too, so that apps without the `AnimationPlugin` don't panic
+        type_registry.register::<bevy_animation::AnimationPlayer>();
+        add_of_with_many::<bevy_animation::AnimationPlayer>(type_registry, many_unimplemented::<bevy_animation::AnimationPlayer>);
+    }
+    #[cfg(feature = "bevy_audio")]
+    {
+        type_registry.register::<bevy_asset::Handle<bevy_audio::AudioSource>>();
+        add_of_with_many::<bevy_asset::Handle<bevy_audio::AudioSource>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_audio::AudioSource>>);
+    }
+    #[cfg(feature = "bevy_ui")]
+    {
+        // registered here too, so that apps without the `UiPlugin` don't panic
+        type_registry.register::<bevy_ui::Val>();
+        type_registry.register::<bevy_ui::UiRect>();
+        type_registry.register::<bevy_ui::Node>();
+        type_registry.register::<bevy_text::TextSection>();
+        add::<bevy_ui::Val>(type_registry);
+        add::<bevy_ui::UiRect>(type_registry);
+        add_of_with_many::<bevy_ui::Node>(type_registry, many_unimplemented::<bevy_ui::Node>);
+        add_of_with_many::<bevy_text::TextSection>(type_registry, many_unimplemented::<bevy_text::TextSection>);
+    }
 }
 
 pub(crate) fn change_slider<T>(
@@ -326,9 +523,10 @@ where
 
     match same {
         Some(mut same) => {
+            let stepped = crate::input::step_focused_number(ui, &mut same, speed, false);
             let widget = egui::DragValue::new(&mut same).speed(speed);
 
-            let changed = ui.add(widget).changed();
+            let changed = ui.add(widget).changed() || stepped;
             if changed {
                 f(same, true);
             }
@@ -338,12 +536,13 @@ where
         None => {
             let old_change = ui.memory_mut(|memory| *memory.data.get_temp_mut_or_default::<T>(id));
             let mut change = old_change;
+            let stepped = crate::input::step_focused_number(ui, &mut change, speed, false);
 
             let widget = egui::DragValue::new(&mut change)
                 .speed(speed)
-                .custom_formatter(|_, _| "-".to_string());
+                .custom_formatter(|_, _| "—".to_string());
 
-            let changed = ui.add(widget).changed();
+            let changed = ui.add(widget).changed() || stepped;
             if changed {
                 f(change - old_change, false);
             }
@@ -378,7 +577,17 @@ macro_rules! many_ui {
             );
 
             let mut temp = same.cloned().unwrap_or_default();
-            if $inner(&mut temp, ui, options, id, env) {
+            let changed = match same {
+                Some(_) => $inner(&mut temp, ui, options, id, env),
+                None => {
+                    ui.horizontal(|ui| {
+                        $crate::inspector_egui_impls::ui_for_differing_values(ui);
+                        $inner(&mut temp, ui, options, id, env)
+                    })
+                    .inner
+                }
+            };
+            if changed {
                 for value in values.iter_mut() {
                     let value = projector(*value).downcast_mut::<$ty>().unwrap();
                     *value = temp.clone();
@@ -390,3 +599,36 @@ macro_rules! many_ui {
         }
     };
 }
+
+#[cfg(all(test, feature = "glam"))]
+mod tests {
+    use bevy_math::*;
+    use bevy_reflect::TypeRegistry;
+
+    use super::{register_glam_impls, InspectorEguiImpl};
+    use crate::reflect_inspector;
+
+    #[test]
+    fn glam_impls_work_without_a_world() {
+        let mut type_registry = TypeRegistry::new();
+        macro_rules! register {
+            ($($ty:ty),*) => { $(type_registry.register::<$ty>();)* };
+        }
+        register!(Vec2, Vec3, Vec3A, Vec4, UVec2, UVec3, UVec4, IVec2, IVec3, IVec4);
+        register!(DVec2, DVec3, DVec4, BVec2, BVec3, BVec4, Quat);
+        register!(Mat2, Mat3, Mat3A, Mat4, DMat2, DMat3, DMat4);
+        register_glam_impls(&mut type_registry);
+        assert!(type_registry
+            .get_type_data::<InspectorEguiImpl>(std::any::TypeId::of::<Vec3>())
+            .is_some());
+
+        let mut value = Vec3::new(1.0, 2.0, 3.0);
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                reflect_inspector::ui_for_value(&mut value, ui, &type_registry);
+            });
+        });
+        assert_eq!(value, Vec3::new(1.0, 2.0, 3.0));
+    }
+}
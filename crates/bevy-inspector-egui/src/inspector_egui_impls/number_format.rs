@@ -0,0 +1,228 @@
+//! Writing and parsing numbers in a [`NumberFormat`]
+
+use std::ops::RangeInclusive;
+
+use egui::emath::format_with_decimals_in_range;
+
+use crate::inspector_options::std_options::NumberFormat;
+
+const SI_PREFIXES: [(f64, &str); 11] = [
+    (1e18, "E"),
+    (1e15, "P"),
+    (1e12, "T"),
+    (1e9, "G"),
+    (1e6, "M"),
+    (1e3, "k"),
+    (1.0, ""),
+    (1e-3, "m"),
+    (1e-6, "µ"),
+    (1e-9, "n"),
+    (1e-12, "p"),
+];
+const BYTE_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Write `value` in `format`, with decimals in `decimals` where the format doesn't choose them itself
+pub(crate) fn format_number(
+    format: NumberFormat,
+    value: f64,
+    decimals: RangeInclusive<usize>,
+) -> String {
+    match format {
+        NumberFormat::Decimal => format_with_decimals_in_range(value, decimals),
+        NumberFormat::Thousands => group_thousands(&format_with_decimals_in_range(value, decimals)),
+        NumberFormat::Hex | NumberFormat::Binary => {
+            let rounded = value.round();
+            format_integer(format, rounded < 0.0, rounded.abs() as u128)
+        }
+        NumberFormat::Si => {
+            let (scale, prefix) = SI_PREFIXES
+                .into_iter()
+                .find(|(scale, _)| value.abs() >= *scale)
+                .unwrap_or((1.0, ""));
+            format!(
+                "{}{prefix}",
+                format_with_decimals_in_range(value / scale, 0..=2)
+            )
+        }
+        NumberFormat::Bytes => {
+            let mut scaled = value;
+            let mut unit = 0;
+            while scaled.abs() >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+                scaled /= 1024.0;
+                unit += 1;
+            }
+            let decimals = match unit {
+                0 => decimals,
+                _ => 0..=2,
+            };
+            format!(
+                "{} {}",
+                format_with_decimals_in_range(scaled, decimals),
+                BYTE_UNITS[unit]
+            )
+        }
+    }
+}
+
+/// Write the integer `-magnitude` or `magnitude` in `format`, exactly unless the format scales it
+pub(crate) fn format_integer(format: NumberFormat, negative: bool, magnitude: u128) -> String {
+    let sign = match negative && magnitude != 0 {
+        true => "-",
+        false => "",
+    };
+    match format {
+        NumberFormat::Decimal => format!("{sign}{magnitude}"),
+        NumberFormat::Thousands => format!("{sign}{}", group_thousands(&magnitude.to_string())),
+        NumberFormat::Hex => format!("{sign}0x{magnitude:X}"),
+        NumberFormat::Binary => format!("{sign}0b{magnitude:b}"),
+        NumberFormat::Si | NumberFormat::Bytes => {
+            let value = magnitude as f64;
+            format_number(format, if negative { -value } else { value }, 0..=0)
+        }
+    }
+}
+
+/// Parse a number written in any [`NumberFormat`]
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let (negative, text) = split_sign(text);
+    let value = match parse_radix(&text) {
+        Some(magnitude) => magnitude? as f64,
+        None => {
+            // the longest start of the text which is a number, followed by a unit
+            let split = (1..=text.len())
+                .rev()
+                .filter(|&i| text.is_char_boundary(i))
+                .find(|&i| text[..i].parse::<f64>().is_ok())?;
+            let (number, unit) = text.split_at(split);
+            number.parse::<f64>().ok()? * unit_scale(unit)?
+        }
+    };
+    Some(if negative { -value } else { value })
+}
+
+/// Parse an integer written in any [`NumberFormat`] into its sign and magnitude, exactly unless it has a unit
+pub(crate) fn parse_integer(text: &str) -> Option<(bool, u128)> {
+    let (negative, text) = split_sign(text);
+    let magnitude = match parse_radix(&text) {
+        Some(magnitude) => magnitude?,
+        None => match text.parse::<u128>() {
+            Ok(magnitude) => magnitude,
+            Err(_) => {
+                let value = parse_number(&text)?;
+                if !value.is_finite() || value < 0.0 {
+                    return None;
+                }
+                value.round() as u128
+            }
+        },
+    };
+    Some((negative, magnitude))
+}
+
+/// Whether the text starts with a minus, and the text after the sign without whitespace and separators
+fn split_sign(text: &str) -> (bool, String) {
+    let text: String = text
+        .chars()
+        .filter(|char| !char.is_whitespace() && !matches!(char, ',' | '_'))
+        .collect();
+    match text.strip_prefix('-') {
+        Some(rest) => (true, rest.to_owned()),
+        None => (false, text.strip_prefix('+').unwrap_or(&text).to_owned()),
+    }
+}
+
+/// The value of a hexadecimal or binary integer, or `None` if the text has neither prefix
+fn parse_radix(text: &str) -> Option<Option<u128>> {
+    let (digits, radix) = if let Some(hex) = text.strip_prefix("0x").or(text.strip_prefix("0X")) {
+        (hex, 16)
+    } else if let Some(binary) = text.strip_prefix("0b").or(text.strip_prefix("0B")) {
+        (binary, 2)
+    } else {
+        return None;
+    };
+    // `0B` is zero bytes
+    if digits.is_empty() {
+        return None;
+    }
+    Some(u128::from_str_radix(digits, radix).ok())
+}
+
+fn unit_scale(unit: &str) -> Option<f64> {
+    if let Some((scale, _)) = SI_PREFIXES.iter().find(|(_, prefix)| *prefix == unit) {
+        return Some(*scale);
+    }
+    let binary = |power: i32| 1024f64.powi(power);
+    Some(match unit.to_lowercase().as_str() {
+        "b" => 1.0,
+        "k" | "kb" => 1e3,
+        "u" => 1e-6,
+        "kib" => binary(1),
+        "mb" => 1e6,
+        "mib" => binary(2),
+        "gb" => 1e9,
+        "gib" => binary(3),
+        "tb" => 1e12,
+        "tib" => binary(4),
+        "pb" => 1e15,
+        "pib" => binary(5),
+        "eb" => 1e18,
+        "eib" => binary(6),
+        _ => return None,
+    })
+}
+
+/// Insert a `,` between every three digits of the integer part of `number`
+fn group_thousands(number: &str) -> String {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", number),
+    };
+    let integer_end = unsigned
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(unsigned.len());
+    let (integer, rest) = unsigned.split_at(integer_end);
+
+    let mut grouped = String::with_capacity(number.len() + integer.len() / 3);
+    grouped.push_str(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(rest);
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_integer, format_number, parse_integer, parse_number};
+    use crate::inspector_options::std_options::NumberFormat;
+
+    #[test]
+    fn numbers_roundtrip_through_their_format() {
+        assert_eq!(
+            format_number(NumberFormat::Thousands, -1234567.5, 1..=1),
+            "-1,234,567.5"
+        );
+        assert_eq!(format_number(NumberFormat::Si, 4700.0, 0..=3), "4.7k");
+        assert_eq!(format_number(NumberFormat::Si, 0.012, 0..=3), "12m");
+        assert_eq!(format_number(NumberFormat::Bytes, 1536.0, 0..=0), "1.5 KiB");
+        assert_eq!(format_number(NumberFormat::Bytes, 512.0, 0..=0), "512 B");
+        assert_eq!(format_integer(NumberFormat::Hex, false, 255), "0xFF");
+        assert_eq!(format_integer(NumberFormat::Binary, true, 5), "-0b101");
+
+        assert_eq!(parse_number("-1,234,567.5"), Some(-1234567.5));
+        assert_eq!(parse_number("4.7k"), Some(4700.0));
+        assert_eq!(parse_number("1.5 KiB"), Some(1536.0));
+        assert_eq!(parse_number("0xff"), Some(255.0));
+        assert_eq!(parse_number("12 parsecs"), None);
+        assert_eq!(parse_number("0 B"), Some(0.0));
+        assert_eq!(parse_integer("0b1010_1010"), Some((false, 0xAA)));
+        assert_eq!(
+            parse_integer("340282366920938463463374607431768211455"),
+            Some((false, u128::MAX))
+        );
+        assert_eq!(parse_integer("-2 MiB"), Some((true, 2 * 1024 * 1024)));
+    }
+}
@@ -0,0 +1,150 @@
+//! Text fields for values which are written and parsed as text, like network addresses and UUIDs
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::inspector_options::std_options::StringFormat;
+
+#[cfg(feature = "uuid")]
+use super::InspectorPrimitive;
+#[cfg(feature = "uuid")]
+use crate::reflect_inspector::InspectorUi;
+#[cfg(feature = "uuid")]
+use std::any::Any;
+
+/// A text field for `value`, which is only written once the text parses.
+///
+/// Until then, the typed text is kept under `id` and the field is outlined in red, with the parse error on hover.
+pub(crate) fn parsed_text<T>(ui: &mut egui::Ui, id: egui::Id, value: &mut T) -> bool
+where
+    T: FromStr + Display + PartialEq,
+    T::Err: Display,
+{
+    let text_id = id.with("parsed text");
+    let mut text = ui
+        .data_mut(|data| data.get_temp::<String>(text_id))
+        .unwrap_or_else(|| value.to_string());
+
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        let error = text
+            .trim()
+            .parse::<T>()
+            .err()
+            .map(|error| error.to_string());
+        let response = text_edit_with_error(ui, &mut text, error.as_deref());
+        if response.changed() {
+            if let Ok(new) = text.trim().parse::<T>() {
+                if new != *value {
+                    *value = new;
+                    changed = true;
+                }
+            }
+        }
+        match response.has_focus() {
+            true => ui.data_mut(|data| data.insert_temp(text_id, text)),
+            false => ui.data_mut(|data| data.remove::<String>(text_id)),
+        }
+    });
+    changed
+}
+
+/// A text field for a string which is expected to parse as `format`, outlined in red with the parse error on hover
+/// while it doesn't. Invalid text is still written to the string.
+pub(crate) fn string_with_format(
+    ui: &mut egui::Ui,
+    value: &mut String,
+    format: StringFormat,
+) -> bool {
+    ui.horizontal(|ui| {
+        let error = format_error(value.trim(), format);
+        text_edit_with_error(ui, value, error.as_deref()).changed()
+    })
+    .inner
+}
+
+/// Why `text` doesn't parse as `format`, or `None` if it does
+pub(crate) fn format_error(text: &str, format: StringFormat) -> Option<String> {
+    fn error<T: FromStr>(text: &str) -> Option<String>
+    where
+        T::Err: Display,
+    {
+        text.parse::<T>().err().map(|error| error.to_string())
+    }
+    match format {
+        StringFormat::IpAddr => error::<std::net::IpAddr>(text),
+        StringFormat::Ipv4Addr => error::<std::net::Ipv4Addr>(text),
+        StringFormat::Ipv6Addr => error::<std::net::Ipv6Addr>(text),
+        StringFormat::SocketAddr => error::<std::net::SocketAddr>(text),
+        #[cfg(feature = "uuid")]
+        StringFormat::Uuid => error::<bevy_utils::Uuid>(text),
+    }
+}
+
+/// A monospace text field, which is outlined in red with `error` on hover if there is one
+fn text_edit_with_error(
+    ui: &mut egui::Ui,
+    text: &mut dyn egui::TextBuffer,
+    error: Option<&str>,
+) -> egui::Response {
+    let error_color = ui.visuals().error_fg_color;
+    let response = ui.scope(|ui| {
+        if error.is_some() {
+            let visuals = &mut ui.visuals_mut().widgets;
+            for widget in [
+                &mut visuals.inactive,
+                &mut visuals.hovered,
+                &mut visuals.active,
+            ] {
+                widget.bg_stroke = egui::Stroke::new(1.0, error_color);
+            }
+            ui.visuals_mut().selection.stroke = egui::Stroke::new(1.0, error_color);
+        }
+        ui.add(egui::TextEdit::singleline(text).font(egui::TextStyle::Monospace))
+    });
+    let response = response.inner;
+    match error {
+        Some(error) => response.on_hover_text(egui::RichText::new(error).color(error_color)),
+        None => response,
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl InspectorPrimitive for bevy_utils::Uuid {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        ui.horizontal(|ui| {
+            let mut changed = parsed_text(ui, id, self);
+            if ui
+                .button("🎲")
+                .on_hover_text("Generate a random UUID")
+                .clicked()
+            {
+                *self = bevy_utils::Uuid::new_v4();
+                changed = true;
+            }
+            changed
+        })
+        .inner
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+        ui.add(
+            egui::TextEdit::singleline(&mut self.to_string().as_str())
+                .font(egui::TextStyle::Monospace),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_error;
+    use crate::inspector_options::std_options::StringFormat;
+
+    #[test]
+    fn strings_are_checked_against_their_format() {
+        assert_eq!(format_error("127.0.0.1", StringFormat::IpAddr), None);
+        assert_eq!(format_error("::1", StringFormat::IpAddr), None);
+        assert!(format_error("::1", StringFormat::Ipv4Addr).is_some());
+        assert_eq!(format_error("[::1]:8080", StringFormat::SocketAddr), None);
+        assert!(format_error("localhost:8080", StringFormat::SocketAddr).is_some());
+    }
+}
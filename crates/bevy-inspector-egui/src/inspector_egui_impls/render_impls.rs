@@ -0,0 +1,475 @@
+use bevy_asset::{Assets, Handle};
+use bevy_render::{
+    color::Color,
+    mesh::Mesh,
+    view::{RenderLayers, Visibility},
+};
+use egui::{color_picker, color_picker::Alpha, ecolor::Hsva};
+use std::any::Any;
+
+use crate::{
+    bevy_inspector::{
+        dnd,
+        errors::{dead_asset_handle, no_world_in_context, show_error},
+        mesh_shape::MeshShapes,
+    },
+    inspector_options::std_options::{ColorChannels, ColorOptions},
+    reflect_inspector::InspectorUi,
+};
+
+use super::InspectorPrimitive;
+
+impl InspectorPrimitive for Handle<Mesh> {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        dnd::ui_with_asset_drop(ui, self, |ui, handle| mesh_handle_ui(handle, ui, id, env))
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        let shape = {
+            let Some(world) = &mut env.context.world else {
+                no_world_in_context(ui, "Handle<Mesh>");
+                return;
+            };
+            let shape = world
+                .get_resource_mut::<MeshShapes>()
+                .ok()
+                .and_then(|shapes| shapes.get(self).copied());
+            let meshes = match world.get_resource_mut::<Assets<Mesh>>() {
+                Ok(meshes) => meshes,
+                Err(error) => return show_error(error, ui, "Assets<Mesh>"),
+            };
+            let Some(mesh) = meshes.get(self) else {
+                return dead_asset_handle(ui, self.into());
+            };
+
+            mesh_ui_inner(mesh, ui);
+            shape
+        };
+
+        if let Some(shape) = shape {
+            let shape_id = id.with("mesh shape");
+            egui::CollapsingHeader::new("Shape")
+                .id_source(shape_id)
+                .show(ui, |ui| {
+                    env.ui_for_reflect_readonly_with_options(&shape, ui, shape_id, &())
+                });
+        }
+    }
+}
+
+/// Edit the mesh of `handle`, and the shape it was generated from if there is one
+fn mesh_handle_ui(
+    handle: &Handle<Mesh>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let shape = {
+        let Some(world) = &mut env.context.world else {
+            no_world_in_context(ui, "Handle<Mesh>");
+            return false;
+        };
+        let shape = world
+            .get_resource_mut::<MeshShapes>()
+            .ok()
+            .and_then(|shapes| shapes.get(handle).copied());
+        let mut meshes = match world.get_resource_mut::<Assets<Mesh>>() {
+            Ok(meshes) => meshes,
+            Err(error) => {
+                show_error(error, ui, "Assets<Mesh>");
+                return false;
+            }
+        };
+        let Some(mesh) = meshes.get_mut(handle) else {
+            dead_asset_handle(ui, handle.into());
+            return false;
+        };
+
+        mesh_ui_inner(mesh, ui);
+
+        ui.add_enabled_ui(mesh.indices().is_some(), |ui| {
+            if ui.button("Duplicate vertices").clicked() {
+                mesh.duplicate_vertices();
+            }
+        });
+        ui.add_enabled_ui(mesh.indices().is_none(), |ui| {
+            if ui.button("Compute flat normals").clicked() {
+                mesh.compute_flat_normals();
+            }
+        });
+        if ui.button("Generate tangents").clicked() {
+            let _ = mesh.generate_tangents();
+        }
+        shape
+    };
+
+    let Some(mut shape) = shape else {
+        return false;
+    };
+    let shape_id = id.with("mesh shape");
+    let changed = egui::CollapsingHeader::new("Shape")
+        .id_source(shape_id)
+        .show(ui, |ui| {
+            env.ui_for_reflect_with_options(&mut shape, ui, shape_id, &())
+        })
+        .body_returned
+        .unwrap_or(false);
+    if !changed {
+        return false;
+    }
+
+    let Some(world) = &mut env.context.world else {
+        return false;
+    };
+    match shape.mesh() {
+        Ok(new_mesh) => {
+            let (Ok(mut shapes), Ok(mut meshes)) =
+                world.get_two_resources_mut::<MeshShapes, Assets<Mesh>>()
+            else {
+                return false;
+            };
+            shapes.insert(handle, shape);
+            meshes.insert(handle, new_mesh);
+        }
+        Err(error) => {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+    }
+    false
+}
+
+fn mesh_ui_inner(mesh: &Mesh, ui: &mut egui::Ui) {
+    egui::Grid::new("mesh").show(ui, |ui| {
+        ui.label("primitive_topology");
+        ui.label(format!("{:?}", mesh.primitive_topology()));
+        ui.end_row();
+
+        ui.label("Vertices");
+        ui.label(mesh.count_vertices().to_string());
+        ui.end_row();
+
+        if let Some(indices) = mesh.indices() {
+            ui.label("Indices");
+            let len = match indices {
+                bevy_render::mesh::Indices::U16(vec) => vec.len(),
+                bevy_render::mesh::Indices::U32(vec) => vec.len(),
+            };
+            ui.label(len.to_string());
+            ui.end_row();
+        }
+
+        ui.label("Vertex Attributes");
+
+        let builtin_attributes = &[
+            Mesh::ATTRIBUTE_POSITION,
+            Mesh::ATTRIBUTE_COLOR,
+            Mesh::ATTRIBUTE_UV_0,
+            Mesh::ATTRIBUTE_UV_1,
+            Mesh::ATTRIBUTE_NORMAL,
+            Mesh::ATTRIBUTE_TANGENT,
+            Mesh::ATTRIBUTE_JOINT_INDEX,
+            Mesh::ATTRIBUTE_JOINT_WEIGHT,
+        ];
+
+        ui.vertical(|ui| {
+            for attribute in builtin_attributes {
+                if mesh.attribute(attribute.id).is_some() {
+                    ui.label(attribute.name);
+                }
+            }
+            let custom = mesh
+                .attributes()
+                .filter(|(id, _)| !builtin_attributes.iter().any(|builtin| builtin.id == *id))
+                .count();
+            if custom > 0 {
+                ui.label(format!("{custom} custom"));
+            }
+        });
+    });
+}
+
+impl InspectorPrimitive for Color {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<ColorOptions>()
+            .cloned()
+            .unwrap_or_default();
+        color_ui(self, &options, ui)
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+pub(crate) fn color_ui(color: &mut Color, options: &ColorOptions, ui: &mut egui::Ui) -> bool {
+    let alpha = match options.alpha {
+        true => Alpha::OnlyBlend,
+        false => Alpha::Opaque,
+    };
+
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        let [r, g, b, a] = color.as_linear_rgba_f32();
+        let mut hsva = Hsva::from_rgba_unmultiplied(r, g, b, a);
+        if color_picker::color_edit_button_hsva(ui, &mut hsva, alpha).changed() {
+            // the opaque picker resets alpha to 1.0, but it should stay untouched instead
+            if !options.alpha {
+                hsva.a = a;
+            }
+            set_color_keep_space(
+                color,
+                Color::rgba_linear_from_array(hsva.to_rgba_unmultiplied()),
+            );
+            changed = true;
+        }
+
+        changed |= color_channels_ui(color, options.channels, options.alpha, ui);
+    });
+    changed
+}
+
+/// Replace `color` with `new` without changing the color space `color` is stored in
+fn set_color_keep_space(color: &mut Color, new: Color) {
+    *color = match color {
+        Color::Rgba { .. } => new.as_rgba(),
+        Color::RgbaLinear { .. } => new.as_rgba_linear(),
+        Color::Hsla { .. } => new.as_hsla(),
+        Color::Lcha { .. } => new.as_lcha(),
+    };
+}
+
+fn color_channels_ui(
+    color: &mut Color,
+    channels: ColorChannels,
+    alpha: bool,
+    ui: &mut egui::Ui,
+) -> bool {
+    const UNIT: (f32, f32) = (0.0, 1.0);
+    const HUE: (f32, f32) = (0.0, 360.0);
+    const LCH: (f32, f32) = (0.0, 1.5);
+
+    let hsv_id = ui.make_persistent_id("hsv channels");
+    let (mut values, labels, ranges, from_array): (_, _, _, fn([f32; 4]) -> Color) =
+        match (channels, *color) {
+            (ColorChannels::Hidden, _) => return false,
+            (ColorChannels::Srgb, _) | (ColorChannels::Stored, Color::Rgba { .. }) => (
+                color.as_rgba_f32(),
+                ["r", "g", "b"],
+                [UNIT; 3],
+                Color::rgba_from_array,
+            ),
+            (ColorChannels::Linear, _) | (ColorChannels::Stored, Color::RgbaLinear { .. }) => (
+                color.as_linear_rgba_f32(),
+                ["r", "g", "b"],
+                [UNIT; 3],
+                Color::rgba_linear_from_array,
+            ),
+            (ColorChannels::Hsv, _) => (
+                remembered_hsva(ui, hsv_id, color.as_linear_rgba_f32()),
+                ["h", "s", "v"],
+                [UNIT; 3],
+                hsva_to_color,
+            ),
+            (ColorChannels::Stored, Color::Hsla { .. }) => (
+                color.as_hsla_f32(),
+                ["h", "s", "l"],
+                [HUE, UNIT, UNIT],
+                Color::hsla_from_array,
+            ),
+            (ColorChannels::Stored, Color::Lcha { .. }) => (
+                color.as_lcha_f32(),
+                ["l", "c", "h"],
+                [LCH, LCH, HUE],
+                Color::lcha_from_array,
+            ),
+        };
+
+    let mut changed = false;
+    for ((value, label), (min, max)) in values.iter_mut().zip(labels).zip(ranges) {
+        let speed = (max - min) / 200.0;
+        changed |= ui
+            .add(
+                egui::DragValue::new(value)
+                    .prefix(format!("{label}: "))
+                    .speed(speed)
+                    .clamp_range(min..=max),
+            )
+            .changed();
+    }
+    if alpha {
+        changed |= ui
+            .add(
+                egui::DragValue::new(&mut values[3])
+                    .prefix("a: ")
+                    .speed(0.005)
+                    .clamp_range(0.0..=1.0),
+            )
+            .changed();
+    }
+
+    if changed {
+        if channels == ColorChannels::Hsv {
+            ui.data_mut(|data| data.insert_temp(hsv_id, values));
+        }
+        set_color_keep_space(color, from_array(values));
+    }
+    changed
+}
+
+fn hsva_to_color([h, s, v, a]: [f32; 4]) -> Color {
+    Color::rgba_linear_from_array(Hsva::new(h, s, v, a).to_rgba_unmultiplied())
+}
+
+/// The HSV values last edited at `id` if they still describe `rgba`, otherwise `rgba` converted to HSV.
+///
+/// Converting back and forth loses the hue of grays and the saturation of black, so they would snap to 0 while dragging.
+fn remembered_hsva(ui: &egui::Ui, id: egui::Id, rgba: [f32; 4]) -> [f32; 4] {
+    ui.data(|data| data.get_temp::<[f32; 4]>(id))
+        .filter(|&hsva| same_color(hsva_to_color(hsva).as_linear_rgba_f32(), rgba))
+        .unwrap_or_else(|| {
+            let [r, g, b, a] = rgba;
+            let hsva = Hsva::from_rgba_unmultiplied(r, g, b, a);
+            [hsva.h, hsva.s, hsva.v, hsva.a]
+        })
+}
+
+fn same_color(a: [f32; 4], b: [f32; 4]) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-4)
+}
+
+impl InspectorPrimitive for Visibility {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            for (visibility, label) in [
+                (Visibility::Inherited, "Inherited"),
+                (Visibility::Visible, "Visible"),
+                (Visibility::Hidden, "Hidden"),
+            ] {
+                changed |= ui.selectable_value(self, visibility, label).changed();
+            }
+        });
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+/// Render layers shown per row of checkboxes
+const LAYERS_PER_ROW: usize = 8;
+
+/// `layers` with `layer` added or removed
+fn set_layer(layers: RenderLayers, layer: u8, enabled: bool) -> RenderLayers {
+    match enabled {
+        true => layers.with(layer),
+        false => layers.without(layer),
+    }
+}
+
+impl InspectorPrimitive for RenderLayers {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        let mut changed = false;
+        egui::Grid::new(id)
+            .num_columns(LAYERS_PER_ROW)
+            .show(ui, |ui| {
+                for layer in 0..RenderLayers::TOTAL_LAYERS {
+                    let layer = layer as u8;
+                    let mut enabled = self.intersects(&RenderLayers::layer(layer));
+                    if ui.checkbox(&mut enabled, layer.to_string()).changed() {
+                        *self = set_layer(*self, layer, enabled);
+                        changed = true;
+                    }
+                    if usize::from(layer) % LAYERS_PER_ROW == LAYERS_PER_ROW - 1 {
+                        ui.end_row();
+                    }
+                }
+            });
+
+        ui.horizontal(|ui| {
+            if ui.button("All").clicked() {
+                *self = RenderLayers::all();
+                changed = true;
+            }
+            if ui.button("None").clicked() {
+                *self = RenderLayers::none();
+                changed = true;
+            }
+        });
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_render::view::RenderLayers;
+
+    use bevy_render::color::Color;
+
+    use super::{hsva_to_color, same_color, set_layer};
+
+    #[test]
+    fn layers_are_toggled_individually() {
+        let layers = set_layer(RenderLayers::layer(0), 5, true);
+        assert_eq!(layers.iter().collect::<Vec<_>>(), [0, 5]);
+        let layers = set_layer(layers, 0, false);
+        assert_eq!(layers, RenderLayers::layer(5));
+        assert_eq!(set_layer(layers, 5, true), layers);
+    }
+
+    #[test]
+    fn hue_of_grays_is_not_stored_in_the_color() {
+        let gray = hsva_to_color([0.5, 0.0, 0.5, 1.0]).as_linear_rgba_f32();
+        // the remembered hue still describes the same gray, so it can be kept while editing
+        assert!(same_color(
+            hsva_to_color([0.25, 0.0, 0.5, 1.0]).as_linear_rgba_f32(),
+            gray
+        ));
+        assert!(!same_color(Color::RED.as_linear_rgba_f32(), gray));
+    }
+}
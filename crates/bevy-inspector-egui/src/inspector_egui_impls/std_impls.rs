@@ -1,12 +1,28 @@
-use std::{borrow::Cow, ops::AddAssign, path::PathBuf};
+use std::{
+    borrow::Cow,
+    hash::Hash,
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::{AddAssign, RangeInclusive},
+    path::PathBuf,
+};
 
 use bevy_reflect::{Reflect, TypePath};
-use bevy_utils::Instant;
+use bevy_utils::{HashSet, Instant};
 use egui::{DragValue, RichText, TextBuffer};
 
-use super::{change_slider, iter_all_eq, InspectorPrimitive, InspectorUi};
+use super::{
+    change_slider, iter_all_eq, number_format, parse_impls, InspectorPrimitive, InspectorUi,
+};
+use crate::egui_utils::{add_button, remove_button};
+use crate::input;
 use crate::inspector_options::{
-    std_options::{NumberDisplay, NumberOptions, RangeOptions},
+    std_options::{
+        NumberCommit, NumberDisplay, NumberFormat, NumberOptions, PathOptions, RangeOptions,
+        StringOptions,
+    },
     InspectorOptionsType,
 };
 use std::{any::Any, time::Duration};
@@ -22,7 +38,7 @@ macro_rules! impl_num {
     };
 }
 
-impl_num!(f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+impl_num!(f32, f64, i8, u8, i16, u16, i32, u32);
 
 impl<T: Reflect + Num> InspectorPrimitive for T {
     fn ui(
@@ -50,20 +66,7 @@ impl<T: Reflect + Num> InspectorPrimitive for T {
             .downcast_ref::<NumberOptions<T>>()
             .cloned()
             .unwrap_or_default();
-        let decimal_range = 0..=1usize;
-        ui.add(
-            egui::Button::new(
-                RichText::new(format!(
-                    "{}{}{}",
-                    options.prefix,
-                    egui::emath::format_with_decimals_in_range(self.to_f64(), decimal_range),
-                    options.suffix
-                ))
-                .monospace(),
-            )
-            .wrap(false)
-            .sense(egui::Sense::hover()),
-        );
+        display_number_readonly(self, &options, ui);
     }
 }
 
@@ -93,13 +96,339 @@ pub fn number_ui_readonly<T: egui::emath::Numeric>(
         .downcast_ref::<NumberOptions<T>>()
         .cloned()
         .unwrap_or_default();
-    let decimal_range = 0..=1usize;
+    display_number_readonly(value, &options, ui);
+}
+
+/// Non-zero integers, which are edited like their primitive type using its [`NumberOptions`]
+trait NonZero: Copy + 'static {
+    type Primitive: egui::emath::Numeric;
+
+    fn get(self) -> Self::Primitive;
+
+    /// The edited `value`, or the non-zero value closest to zero on the side of `previous` if it is zero
+    fn away_from_zero(previous: Self, value: Self::Primitive) -> Self;
+}
+
+macro_rules! impl_non_zero {
+    (unsigned: $($non_zero:ty => $ty:ty),*) => {
+        $(
+            impl NonZero for $non_zero {
+                type Primitive = $ty;
+
+                fn get(self) -> $ty {
+                    <$non_zero>::get(self)
+                }
+
+                fn away_from_zero(_: Self, value: $ty) -> Self {
+                    <$non_zero>::new(value).unwrap_or(<$non_zero>::MIN)
+                }
+            }
+        )*
+        impl_non_zero!($($non_zero),*);
+    };
+    (signed: $($non_zero:ty => $ty:ty),*) => {
+        $(
+            impl NonZero for $non_zero {
+                type Primitive = $ty;
+
+                fn get(self) -> $ty {
+                    <$non_zero>::get(self)
+                }
+
+                fn away_from_zero(previous: Self, value: $ty) -> Self {
+                    <$non_zero>::new(value)
+                        .or(<$non_zero>::new(previous.get().signum()))
+                        .unwrap_or(previous)
+                }
+            }
+        )*
+        impl_non_zero!($($non_zero),*);
+    };
+    ($($non_zero:ty),*) => {
+        $(
+            impl InspectorPrimitive for $non_zero {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) -> bool {
+                    non_zero_ui(self, ui, options)
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) {
+                    non_zero_ui_readonly(self, ui, options);
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(unsigned:
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroUsize => usize
+);
+impl_non_zero!(signed:
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroIsize => isize
+);
+
+fn non_zero_ui<T: NonZero>(value: &mut T, ui: &mut egui::Ui, options: &dyn Any) -> bool {
+    let options = options
+        .downcast_ref::<NumberOptions<T::Primitive>>()
+        .cloned()
+        .unwrap_or_default();
+    let mut primitive = value.get();
+    if !display_number(&mut primitive, &options, ui, 0.1) {
+        return false;
+    }
+    let previous = *value;
+    *value = T::away_from_zero(previous, primitive);
+    value.get() != previous.get()
+}
+
+fn non_zero_ui_readonly<T: NonZero>(value: &T, ui: &mut egui::Ui, options: &dyn Any) {
+    let options = options
+        .downcast_ref::<NumberOptions<T::Primitive>>()
+        .cloned()
+        .unwrap_or_default();
+    display_number_readonly(&value.get(), &options, ui);
+}
+
+/// Integers which don't fit into an `f64` without losing precision, so they are parsed and formatted as text
+/// instead of being edited as `f64`s like the other numbers
+trait BigInt: Copy + Ord + Send + Sync + 'static {
+    /// `self + delta`, saturating at the bounds of the type
+    fn saturating_add_i64(self, delta: i64) -> Self;
+
+    /// Whether the value is negative, and its absolute value
+    fn to_sign_magnitude(self) -> (bool, u128);
+
+    /// The value with the sign and absolute value, if it fits into the type
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self>;
+
+    fn format(self, format: NumberFormat) -> String {
+        let (negative, magnitude) = self.to_sign_magnitude();
+        number_format::format_integer(format, negative, magnitude)
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let (negative, magnitude) = number_format::parse_integer(text)?;
+        Self::from_sign_magnitude(negative, magnitude)
+    }
+}
+
+macro_rules! impl_big_int {
+    (unsigned: $($ty:ty => $signed:ty),*) => {
+        $(
+            impl BigInt for $ty {
+                fn saturating_add_i64(self, delta: i64) -> Self {
+                    let delta = <$signed>::try_from(delta)
+                        .unwrap_or(if delta < 0 { <$signed>::MIN } else { <$signed>::MAX });
+                    self.saturating_add_signed(delta)
+                }
+
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+
+                fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    match negative && magnitude != 0 {
+                        true => None,
+                        false => <$ty>::try_from(magnitude).ok(),
+                    }
+                }
+            }
+        )*
+    };
+    (signed: $($ty:ty),*) => {
+        $(
+            impl BigInt for $ty {
+                fn saturating_add_i64(self, delta: i64) -> Self {
+                    let delta = <$ty>::try_from(delta)
+                        .unwrap_or(if delta < 0 { <$ty>::MIN } else { <$ty>::MAX });
+                    self.saturating_add(delta)
+                }
+
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (self < 0, self.unsigned_abs() as u128)
+                }
+
+                fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    let value = match negative {
+                        // the magnitude of the minimum doesn't fit into the positive range
+                        true if magnitude == i128::MIN.unsigned_abs() => i128::MIN,
+                        true => -i128::try_from(magnitude).ok()?,
+                        false => i128::try_from(magnitude).ok()?,
+                    };
+                    <$ty>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+    // 64-bit integers can still be shown as sliders and bars, which are as precise as the screen anyways
+    (numeric: $($ty:ty),*) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    id: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) -> bool {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$ty>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    match (options.display, options.angle) {
+                        (NumberDisplay::Drag, false) => big_int_widget(self, &options, ui, id),
+                        _ => display_number(self, &options, ui, 0.1),
+                    }
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$ty>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    match (options.display, options.angle) {
+                        (NumberDisplay::Drag, false) => big_int_readonly(self, &options, ui),
+                        _ => display_number_readonly(self, &options, ui),
+                    }
+                }
+            }
+        )*
+    };
+    (text: $($ty:ty),*) => {
+        $(
+            impl InspectorPrimitive for $ty {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    id: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) -> bool {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$ty>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    big_int_widget(self, &options, ui, id)
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$ty>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    big_int_readonly(self, &options, ui);
+                }
+            }
+        )*
+    };
+}
+
+impl_big_int!(unsigned: u64 => i64, usize => isize, u128 => i128);
+impl_big_int!(signed: i64, isize, i128);
+impl_big_int!(numeric: i64, u64, isize, usize);
+impl_big_int!(text: i128, u128);
+
+/// A text field for the exact value of `value` with a handle next to it for dragging the value,
+/// changing it by [`NumberOptions::speed`] per point
+fn big_int_widget<T: BigInt>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> bool {
+    let clamp = |value: T| {
+        let value = options.min.map_or(value, |min| value.max(min));
+        options.max.map_or(value, |max| value.min(max))
+    };
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        let handle = ui
+            .add(egui::Button::new("↔").sense(egui::Sense::drag()))
+            .on_hover_text("Drag to change the value");
+        if handle.dragged() {
+            // the fractions of steps dragged so far, which are added up until they make a whole step
+            let remainder_id = id.with("big int drag");
+            let speed = match options.speed {
+                0.0 => 1.0,
+                speed => speed as f64,
+            };
+            let dragged = ui.data(|data| data.get_temp::<f64>(remainder_id).unwrap_or(0.0))
+                + handle.drag_delta().x as f64 * speed;
+            let steps = dragged.trunc();
+            ui.data_mut(|data| data.insert_temp(remainder_id, dragged - steps));
+            let new = clamp(value.saturating_add_i64(steps as i64));
+            if new != *value {
+                *value = new;
+                changed = true;
+            }
+        }
+
+        if !options.prefix.is_empty() {
+            ui.label(&options.prefix);
+        }
+        // keep the text while it is edited, so that it can be invalid in between
+        let text_id = id.with("big int text");
+        let mut text = ui
+            .data_mut(|data| data.get_temp::<String>(text_id))
+            .unwrap_or_else(|| value.format(options.format));
+        let is_valid = T::parse(&text).is_some();
+        let mut text_edit = egui::TextEdit::singleline(&mut text)
+            .font(egui::TextStyle::Monospace)
+            .desired_width(ui.spacing().interact_size.x * 3.0);
+        if !is_valid {
+            text_edit = text_edit.text_color(ui.visuals().error_fg_color);
+        }
+        let response = ui.add(text_edit);
+        if response.changed() {
+            if let Some(new) = T::parse(&text) {
+                let new = clamp(new);
+                if new != *value {
+                    *value = new;
+                    changed = true;
+                }
+            }
+        }
+        match response.has_focus() {
+            true => ui.data_mut(|data| data.insert_temp(text_id, text)),
+            false => ui.data_mut(|data| data.remove::<String>(text_id)),
+        }
+        if !options.suffix.is_empty() {
+            ui.label(&options.suffix);
+        }
+    });
+    changed
+}
+
+fn big_int_readonly<T: BigInt>(value: &T, options: &NumberOptions<T>, ui: &mut egui::Ui) {
     ui.add(
         egui::Button::new(
             RichText::new(format!(
                 "{}{}{}",
                 options.prefix,
-                egui::emath::format_with_decimals_in_range(value.to_f64(), decimal_range),
+                value.format(options.format),
                 options.suffix
             ))
             .monospace(),
@@ -109,42 +438,407 @@ pub fn number_ui_readonly<T: egui::emath::Numeric>(
     );
 }
 
-fn display_number<T: egui::emath::Numeric>(
+fn display_number_readonly<T: egui::emath::Numeric>(
+    value: &T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+) {
+    if let NumberDisplay::Bar { show_value } = options.display {
+        let mut value = *value;
+        bar_widget(&mut value, options, show_value, egui::Sense::hover(), ui);
+        return;
+    }
+
+    let decimal_range = decimal_range(options, 0..=1);
+    ui.add(
+        egui::Button::new(
+            RichText::new(format!(
+                "{}{}{}",
+                options.prefix,
+                number_format::format_number(options.format, value.to_f64(), decimal_range),
+                options.suffix
+            ))
+            .monospace(),
+        )
+        .wrap(false)
+        .sense(egui::Sense::hover()),
+    );
+}
+
+/// The range of decimals to show values with, `default` unless [`NumberOptions::decimals`] is set for floats
+fn decimal_range<T: egui::emath::Numeric>(
+    options: &NumberOptions<T>,
+    default: RangeInclusive<usize>,
+) -> RangeInclusive<usize> {
+    match options.decimals {
+        Some(decimals) if !T::INTEGRAL => decimals..=decimals,
+        _ => default,
+    }
+}
+
+/// A value shown in a number field which isn't written yet, see [`NumberCommit`]
+#[derive(Clone, Copy)]
+struct UncommittedNumber {
+    value: f64,
+    since: Instant,
+}
+
+pub(crate) fn display_number<T: egui::emath::Numeric>(
     value: &mut T,
     options: &NumberOptions<T>,
     ui: &mut egui::Ui,
     default_speed: f32,
 ) -> bool {
-    let mut changed = match options.display {
+    if options.commit == NumberCommit::Continuous {
+        return number_widget(value, options, ui, default_speed).0;
+    }
+
+    let id = ui.next_auto_id().with("uncommitted number");
+    let uncommitted = ui.data(|data| data.get_temp::<UncommittedNumber>(id));
+    let mut shown = uncommitted.map_or(*value, |uncommitted| T::from_f64(uncommitted.value));
+    let (changed, response) = number_widget(&mut shown, options, ui, default_speed);
+    if !changed && uncommitted.is_none() {
+        return false;
+    }
+
+    let interacting = response.dragged() || response.has_focus();
+    let interval_elapsed = match options.commit {
+        NumberCommit::Interval(interval) => {
+            uncommitted.is_some_and(|uncommitted| uncommitted.since.elapsed() >= interval)
+        }
+        _ => false,
+    };
+    if interacting && !interval_elapsed {
+        let since = uncommitted.map_or_else(Instant::now, |uncommitted| uncommitted.since);
+        let uncommitted = UncommittedNumber {
+            value: shown.to_f64(),
+            since,
+        };
+        ui.data_mut(|data| data.insert_temp(id, uncommitted));
+        return false;
+    }
+
+    ui.data_mut(|data| data.remove::<UncommittedNumber>(id));
+    let changed = *value != shown;
+    *value = shown;
+    changed
+}
+
+/// A slider whose range starts at the soft limits and grows to include values dragged or typed past them,
+/// see [`NumberOptions::soft_min`]
+fn soft_slider_widget<T: egui::emath::Numeric>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+) -> egui::Response {
+    let id = ui.next_auto_id().with("discovered range");
+    let hard_min = options.min.map_or(f64::NEG_INFINITY, |min| min.to_f64());
+    let hard_max = options.max.map_or(f64::INFINITY, |max| max.to_f64());
+    let (mut lo, mut hi) = ui
+        .data(|data| data.get_temp::<(f64, f64)>(id))
+        .unwrap_or_else(|| {
+            let lo = options
+                .soft_min
+                .or(options.min)
+                .map_or(0.0, |min| min.to_f64());
+            let hi = options
+                .soft_max
+                .or(options.max)
+                .map_or(1.0, |max| max.to_f64());
+            (lo, hi)
+        });
+    let current = value.to_f64();
+    lo = lo.min(current);
+    hi = hi.max(current);
+
+    let mut widget =
+        egui::Slider::new(value, T::from_f64(lo)..=T::from_f64(hi)).clamp_to_range(false);
+    if let (Some(decimals), false) = (options.decimals, T::INTEGRAL) {
+        widget = widget.fixed_decimals(decimals);
+    }
+    if let NumberDisplay::LogSlider = options.display {
+        widget = widget
+            .logarithmic(true)
+            .prefix(&options.prefix)
+            .suffix(&options.suffix);
+    }
+    let mut response = ui.add(slider_format(widget, options.format));
+
+    // dragging past an end of the slider extends the range by the distance to the end, relative to the slider width
+    if let (true, Some(pointer)) = (response.dragged(), response.interact_pointer_pos()) {
+        let rect = response.rect;
+        let overshoot = match pointer.x {
+            x if x > rect.right() => (x - rect.right()) as f64,
+            x if x < rect.left() => (x - rect.left()) as f64,
+            _ => 0.0,
+        };
+        if overshoot != 0.0 {
+            let end = if overshoot > 0.0 { hi } else { lo };
+            let span = (hi - lo).max(f64::EPSILON);
+            let width = ui.spacing().slider_width.max(1.0) as f64;
+            let extended = (end + span * overshoot / width).clamp(hard_min, hard_max);
+            *value = T::from_f64(extended);
+            lo = lo.min(extended);
+            hi = hi.max(extended);
+            response.mark_changed();
+        }
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, (lo.max(hard_min), hi.min(hard_max))));
+    response
+}
+
+/// A progress bar between `min` and `max`, which sets the value where it is clicked or dragged if `sense` allows it.
+///
+/// See [`NumberDisplay::Bar`].
+fn bar_widget<T: egui::emath::Numeric>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    show_value: bool,
+    sense: egui::Sense,
+    ui: &mut egui::Ui,
+) -> egui::Response {
+    let min = options.min.map_or(0.0, |min| min.to_f64());
+    let max = options.max.map_or(1.0, |max| max.to_f64());
+    let desired_size = egui::vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
+    let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
+
+    if let Some(pointer) = response.interact_pointer_pos() {
+        let t = ((pointer.x - rect.left()) / rect.width()).clamp(0.0, 1.0) as f64;
+        let new = T::from_f64(min + t * (max - min));
+        if new != *value {
+            *value = new;
+            response.mark_changed();
+        }
+    }
+
+    if ui.is_rect_visible(rect) {
+        let fraction = bar_fraction(value.to_f64(), min, max);
+        let visuals = ui.style().interact(&response);
+        let rounding = visuals.rounding;
+        let painter = ui.painter();
+        painter.rect(
+            rect,
+            rounding,
+            ui.visuals().extreme_bg_color,
+            visuals.bg_stroke,
+        );
+        let mut filled = rect;
+        filled.set_width(rect.width() * fraction);
+        painter.rect_filled(filled, rounding, ui.visuals().selection.bg_fill);
+
+        if show_value {
+            let text = format!(
+                "{}{}{}",
+                options.prefix,
+                number_format::format_number(
+                    options.format,
+                    value.to_f64(),
+                    decimal_range(options, 0..=2)
+                ),
+                options.suffix
+            );
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                text,
+                egui::TextStyle::Button.resolve(ui.style()),
+                visuals.text_color(),
+            );
+        }
+    }
+    response
+}
+
+/// How much of a bar from `min` to `max` is filled by `value`, between 0 and 1
+fn bar_fraction(value: f64, min: f64, max: f64) -> f32 {
+    if max <= min {
+        return 1.0;
+    }
+    ((value - min) / (max - min)).clamp(0.0, 1.0) as f32
+}
+
+/// Display the radians `value` in degrees, see [`NumberOptions::angle`]
+fn angle_widget<T: egui::emath::Numeric>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+) -> (bool, egui::Response) {
+    let to_degrees = |radians: &T| radians.to_f64().to_degrees();
+    let mut degrees = wrap_degrees(to_degrees(value));
+    let options = NumberOptions {
+        suffix: match options.suffix.is_empty() {
+            true => "°".to_owned(),
+            false => options.suffix.clone(),
+        },
+        prefix: options.prefix.clone(),
+        display: options.display,
+        angle: false,
+        ..options.map(to_degrees)
+    }
+    .with_commit(NumberCommit::Continuous);
+
+    let (changed, response) = number_widget(&mut degrees, &options, ui, 0.5);
+    if changed {
+        *value = T::from_f64(wrap_degrees(degrees).to_radians());
+    }
+    (changed, response)
+}
+
+/// `degrees` wrapped to the range `(-180, 180]`
+fn wrap_degrees(degrees: f64) -> f64 {
+    let wrapped = (degrees + 180.0).rem_euclid(360.0) - 180.0;
+    if wrapped == -180.0 {
+        180.0
+    } else {
+        wrapped
+    }
+}
+
+/// Display a toggle for linking the values of a field, whose state is kept in the memory of `ui` under `id`.
+///
+/// Returns whether the values are linked.
+pub(crate) fn ui_for_link_toggle(ui: &mut egui::Ui, id: egui::Id) -> bool {
+    let id = id.with("linked");
+    let mut linked = ui.data_mut(|data| data.get_persisted::<bool>(id).unwrap_or(false));
+    let response = ui
+        .selectable_label(linked, "🔗")
+        .on_hover_text("Keep the values proportional when editing one of them");
+    if response.clicked() {
+        linked = !linked;
+        ui.data_mut(|data| data.insert_persisted(id, linked));
+    }
+    linked
+}
+
+/// Scale the other `values` by the same factor as the one which differs from `before`.
+///
+/// If that value was zero before, the others are offset by its change instead.
+pub(crate) fn scale_linked<T: egui::emath::Numeric>(values: &mut [T], before: &[T]) {
+    let Some(edited) = (0..values.len()).find(|&i| values[i] != before[i]) else {
+        return;
+    };
+    let old = before[edited].to_f64();
+    let new = values[edited].to_f64();
+    for (i, value) in values.iter_mut().enumerate() {
+        if i == edited {
+            continue;
+        }
+        let other = before[i].to_f64();
+        *value = T::from_f64(match old != 0.0 {
+            true => other * new / old,
+            false => other + new - old,
+        });
+    }
+}
+
+fn drag_value_format(widget: egui::DragValue<'_>, format: NumberFormat) -> egui::DragValue<'_> {
+    match format {
+        // egui's own formatting, which picks the decimals
+        NumberFormat::Decimal => widget,
+        _ => widget
+            .custom_formatter(move |value, decimals| {
+                number_format::format_number(format, value, decimals)
+            })
+            .custom_parser(number_format::parse_number),
+    }
+}
+
+fn slider_format(widget: egui::Slider<'_>, format: NumberFormat) -> egui::Slider<'_> {
+    match format {
+        NumberFormat::Decimal => widget,
+        _ => widget
+            .custom_formatter(move |value, decimals| {
+                number_format::format_number(format, value, decimals)
+            })
+            .custom_parser(number_format::parse_number),
+    }
+}
+
+/// Display the widget for `value`, returning whether it changed and the response of the widget
+fn number_widget<T: egui::emath::Numeric>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+    default_speed: f32,
+) -> (bool, egui::Response) {
+    if options.angle {
+        return angle_widget(value, options, ui);
+    }
+
+    let speed = match options.speed {
+        0.0 => default_speed,
+        speed => speed,
+    };
+    let response = match options.display {
         NumberDisplay::Drag => {
-            let mut widget = egui::DragValue::new(value);
+            let stepped = input::step_focused_number(ui, value, speed as f64, false);
+            let mut widget = egui::DragValue::new(value).speed(speed);
             if !options.prefix.is_empty() {
                 widget = widget.prefix(&options.prefix);
             }
             if !options.suffix.is_empty() {
                 widget = widget.suffix(&options.suffix);
             }
+            if let (Some(decimals), false) = (options.decimals, T::INTEGRAL) {
+                widget = widget.fixed_decimals(decimals);
+            }
+            widget = drag_value_format(widget, options.format);
             match (options.min, options.max) {
                 (Some(min), Some(max)) => widget = widget.clamp_range(min.to_f64()..=max.to_f64()),
                 (Some(min), None) => widget = widget.clamp_range(min.to_f64()..=f64::MAX),
                 (None, Some(max)) => widget = widget.clamp_range(f64::MIN..=max.to_f64()),
                 (None, None) => {}
             }
-            if options.speed != 0.0 {
-                widget = widget.speed(options.speed);
-            } else {
-                widget = widget.speed(default_speed);
+            let mut response = ui.add(widget);
+            if stepped {
+                response.mark_changed();
+            }
+            response
+        }
+        NumberDisplay::Bar { show_value } => {
+            let stepped = input::step_focused_number(ui, value, speed as f64, true);
+            let mut response = bar_widget(
+                value,
+                options,
+                show_value,
+                egui::Sense::click_and_drag(),
+                ui,
+            );
+            if stepped {
+                response.mark_changed();
             }
-            ui.add(widget).changed()
+            response
+        }
+        NumberDisplay::Slider | NumberDisplay::LogSlider
+            if options.soft_min.is_some() || options.soft_max.is_some() =>
+        {
+            soft_slider_widget(value, options, ui)
         }
         NumberDisplay::Slider => {
             let min = options.min.unwrap_or_else(|| T::from_f64(0.0));
             let max = options.max.unwrap_or_else(|| T::from_f64(1.0));
             let range = min..=max;
-            let widget = egui::Slider::new(value, range);
-            ui.add(widget).changed()
+            let mut widget = egui::Slider::new(value, range);
+            if let (Some(decimals), false) = (options.decimals, T::INTEGRAL) {
+                widget = widget.fixed_decimals(decimals);
+            }
+            ui.add(slider_format(widget, options.format))
+        }
+        NumberDisplay::LogSlider => {
+            let min = options.min.unwrap_or_else(|| T::from_f64(0.0));
+            let max = options.max.unwrap_or_else(|| T::from_f64(1.0));
+            let mut widget = egui::Slider::new(value, min..=max)
+                .logarithmic(true)
+                .prefix(&options.prefix)
+                .suffix(&options.suffix);
+            if let (Some(decimals), false) = (options.decimals, T::INTEGRAL) {
+                widget = widget.fixed_decimals(decimals);
+            }
+            ui.add(slider_format(widget, options.format))
         }
     };
+    let mut changed = response.changed();
 
     if let Some(min) = options.min {
         let as_f64 = value.to_f64();
@@ -162,7 +856,7 @@ fn display_number<T: egui::emath::Numeric>(
             changed = true;
         }
     }
-    changed
+    (changed, response)
 }
 
 pub fn number_ui_many<T>(
@@ -216,16 +910,40 @@ impl InspectorPrimitive for bool {
 }
 
 impl InspectorPrimitive for String {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        if self.contains('\n') {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options.downcast_ref::<StringOptions>();
+        let code = options.and_then(|options| options.code);
+        let format = options.and_then(|options| options.format);
+        if let Some(language) = code {
+            super::code_editor(ui, self, language).changed()
+        } else if let Some(format) = format {
+            parse_impls::string_with_format(ui, self, format)
+        } else if self.contains('\n') {
             ui.text_edit_multiline(self).changed()
         } else {
             ui.text_edit_singleline(self).changed()
         }
     }
 
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        if self.contains('\n') {
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let code = options
+            .downcast_ref::<StringOptions>()
+            .and_then(|options| options.code);
+        if let Some(language) = code {
+            super::code_editor(ui, &mut self.as_str(), language);
+        } else if self.contains('\n') {
             ui.text_edit_multiline(&mut self.as_str());
         } else {
             ui.text_edit_singleline(&mut self.as_str());
@@ -234,27 +952,79 @@ impl InspectorPrimitive for String {
 }
 
 impl InspectorPrimitive for Cow<'static, str> {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        let mut clone = self.to_string();
-        let changed = if self.contains('\n') {
-            ui.text_edit_multiline(&mut clone).changed()
-        } else {
-            ui.text_edit_singleline(&mut clone).changed()
-        };
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        match self {
+            Cow::Owned(value) => value.ui(ui, options, id, env),
+            // borrowed strings are only copied into an owned one once they are edited
+            Cow::Borrowed(value) => {
+                let mut clone = value.to_owned();
+                let changed = clone.ui(ui, options, id, env);
+                if changed {
+                    *self = Cow::Owned(clone);
+                }
+                changed
+            }
+        }
+    }
 
-        if changed {
-            *self = Cow::Owned(clone);
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let code = options
+            .downcast_ref::<StringOptions>()
+            .and_then(|options| options.code);
+        if let Some(language) = code {
+            super::code_editor(ui, &mut &**self, language);
+        } else if self.contains('\n') {
+            ui.text_edit_multiline(&mut &**self);
+        } else {
+            ui.text_edit_singleline(&mut &**self);
         }
+    }
+}
+
+/// The character typed into `text` next to the `previous` one, so that a new character can be typed without deleting
+/// the old one first
+fn char_from_text(text: &str, previous: char) -> Option<char> {
+    text.chars()
+        .find(|&char| char != previous)
+        .or_else(|| text.chars().next())
+}
 
+impl InspectorPrimitive for char {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        let mut text = self.to_string();
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let response = ui.add(egui::TextEdit::singleline(&mut text).desired_width(24.0));
+            if response.changed() {
+                if let Some(char) = char_from_text(&text, *self) {
+                    changed = char != *self;
+                    *self = char;
+                }
+            }
+            ui.weak(format!("U+{:04X}", *self as u32))
+                .on_hover_text(format!("{self:?}"));
+        });
         changed
     }
 
     fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        if self.contains('\n') {
-            ui.text_edit_multiline(&mut self.as_str());
-        } else {
-            ui.text_edit_singleline(&mut self.as_str());
-        }
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(&mut self.to_string().as_str()).desired_width(24.0));
+            ui.weak(format!("U+{:04X}", *self as u32))
+                .on_hover_text(format!("{self:?}"));
+        });
     }
 }
 
@@ -329,7 +1099,7 @@ impl<T: Reflect + TypePath + egui::emath::Numeric + InspectorOptionsType> Inspec
         env: InspectorUi<'_, '_>,
     ) -> bool {
         let std::ops::Range { start, end } = self;
-        display_range::<T>(ui, options, id, env, "..", Some(start), Some(end))
+        display_range::<T>(ui, options, id, env, "..", start, end)
     }
 
     fn ui_readonly(
@@ -343,34 +1113,70 @@ impl<T: Reflect + TypePath + egui::emath::Numeric + InspectorOptionsType> Inspec
         display_range_readonly::<T>(ui, options, id, env, "..", Some(start), Some(end));
     }
 }
+
+impl<T: Reflect + TypePath + egui::emath::Numeric + InspectorOptionsType> InspectorPrimitive
+    for std::ops::RangeInclusive<T>
+{
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        // `RangeInclusive` doesn't hand out mutable references to its bounds
+        let (mut start, mut end) = (*self.start(), *self.end());
+        let changed = display_range::<T>(ui, options, id, env, "..=", &mut start, &mut end);
+        if changed {
+            *self = start..=end;
+        }
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let (start, end) = (self.start(), self.end());
+        display_range_readonly::<T>(ui, options, id, env, "..=", Some(start), Some(end));
+    }
+}
+
+/// Display the bounds of a range using the [`RangeOptions`], keeping `start` from going past `end`
 fn display_range<T: egui::emath::Numeric + InspectorOptionsType>(
     ui: &mut egui::Ui,
     options: &dyn Any,
     id: egui::Id,
     mut env: InspectorUi<'_, '_>,
-
-    // this is made to be generic but I'm currently just using it for a..b, not a..=b, ..a, a.., .., etc., because these types don't hand out mutable references
     symbol: &'static str,
-    start: Option<&mut T>,
-    end: Option<&mut T>,
+    start: &mut T,
+    end: &mut T,
 ) -> bool {
     let options = options.downcast_ref::<RangeOptions<T>>();
 
     let start_options = options.map(|a| &a.start as &dyn Any).unwrap_or(&());
     let end_options = options.map(|a| &a.end as &dyn Any).unwrap_or(&());
 
-    let mut changed = false;
+    let mut start_changed = false;
+    let mut end_changed = false;
     ui.horizontal(|ui| {
-        if let Some(start) = start {
-            changed |= number_ui::<T>(start, ui, start_options, id, env.reborrow());
-        }
+        start_changed = number_ui::<T>(start, ui, start_options, id, env.reborrow());
         ui.label(symbol);
-        if let Some(end) = end {
-            changed |= number_ui::<T>(end, ui, end_options, id, env.reborrow());
-        }
+        end_changed = number_ui::<T>(end, ui, end_options, id, env.reborrow());
     });
 
-    changed
+    if *start > *end {
+        if end_changed {
+            *end = *start;
+        } else {
+            *start = *end;
+        }
+    }
+
+    start_changed || end_changed
 }
 
 fn display_range_readonly<T: egui::emath::Numeric + InspectorOptionsType>(
@@ -400,18 +1206,214 @@ fn display_range_readonly<T: egui::emath::Numeric + InspectorOptionsType>(
 }
 
 impl InspectorPrimitive for PathBuf {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        let mut str = self.to_string_lossy();
-        let changed = ui.text_edit_singleline(&mut str).changed();
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<PathOptions>()
+            .cloned()
+            .unwrap_or_default();
+        ui.horizontal(|ui| {
+            let mut str = self.to_string_lossy();
+            let mut changed = ui.text_edit_singleline(&mut str).changed();
 
-        if changed {
-            *self = PathBuf::from(str.as_str());
-        }
+            if changed {
+                *self = PathBuf::from(str.as_str());
+            }
+            changed |= ui_for_browse_button(ui, self, &options);
 
-        changed
+            changed
+        })
+        .inner
     }
 
     fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
         ui.text_edit_singleline(&mut self.to_string_lossy());
     }
 }
+
+/// Display a button opening a native file dialog for picking `path`, see [`PathOptions`]
+#[cfg(all(feature = "file-dialog", not(target_arch = "wasm32")))]
+fn ui_for_browse_button(ui: &mut egui::Ui, path: &mut PathBuf, options: &PathOptions) -> bool {
+    let clicked = ui
+        .button("Browse…")
+        .on_hover_text("Open a file dialog. The app is paused while it is open.")
+        .clicked();
+    if !clicked {
+        return false;
+    }
+
+    let mut dialog = rfd::FileDialog::new();
+    let directory = match path.is_dir() {
+        true => Some(path.as_path()),
+        false => path.parent().filter(|parent| parent.is_dir()),
+    };
+    if let Some(directory) = directory {
+        dialog = dialog.set_directory(directory);
+    }
+    if !options.extensions.is_empty() {
+        dialog = dialog.add_filter(options.extensions.join(", "), &options.extensions);
+    }
+
+    let picked = match options.directory {
+        true => dialog.pick_folder(),
+        false => dialog.pick_file(),
+    };
+    match picked {
+        Some(picked) => {
+            *path = picked;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(not(all(feature = "file-dialog", not(target_arch = "wasm32"))))]
+fn ui_for_browse_button(_: &mut egui::Ui, _: &mut PathBuf, _: &PathOptions) -> bool {
+    false
+}
+
+impl<T> InspectorPrimitive for HashSet<T>
+where
+    T: Reflect + TypePath + Hash + Eq + Clone + Default,
+{
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let mut changed = false;
+
+        ui.vertical(|ui| {
+            if self.is_empty() {
+                ui.label("(Empty Set)");
+            }
+
+            let mut remove = None;
+            for (i, element) in self.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    if remove_button(ui).on_hover_text("Remove element").clicked() {
+                        remove = Some(element.clone());
+                    }
+                    env.ui_for_reflect_readonly_with_options(element, ui, id.with(i), options);
+                });
+            }
+            if let Some(element) = remove {
+                self.remove(&element);
+                changed = true;
+            }
+
+            // elements of a set are immutable, so new ones are edited in a draft before insertion
+            let draft_id = id.with("set_draft");
+            let mut draft = ui
+                .data_mut(|data| data.get_temp::<T>(draft_id))
+                .unwrap_or_default();
+            ui.separator();
+            ui.horizontal(|ui| {
+                let duplicate = self.contains(&draft);
+                let add = ui
+                    .add_enabled_ui(!duplicate, |ui| add_button(ui).on_hover_text("Add element"))
+                    .inner
+                    .on_disabled_hover_text("Element is already in the set");
+                env.ui_for_reflect_with_options(&mut draft, ui, draft_id, options);
+
+                if add.clicked() {
+                    self.insert(std::mem::take(&mut draft));
+                    changed = true;
+                }
+            });
+            ui.data_mut(|data| data.insert_temp(draft_id, draft));
+        });
+
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        ui.vertical(|ui| {
+            if self.is_empty() {
+                ui.label("(Empty Set)");
+            }
+            for (i, element) in self.iter().enumerate() {
+                env.ui_for_reflect_readonly_with_options(element, ui, id.with(i), options);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZeroI32, NonZeroU8};
+
+    use super::{bar_fraction, char_from_text, scale_linked, wrap_degrees, BigInt, NonZero};
+
+    #[test]
+    fn angles_wrap_around() {
+        assert_eq!(wrap_degrees(90.0), 90.0);
+        assert_eq!(wrap_degrees(190.0), -170.0);
+        assert_eq!(wrap_degrees(-180.0), 180.0);
+        assert_eq!(wrap_degrees(-540.0), 180.0);
+        assert_eq!(wrap_degrees(720.0), 0.0);
+    }
+
+    #[test]
+    fn big_ints_are_changed_exactly() {
+        assert_eq!(u64::MAX.saturating_add_i64(-1), u64::MAX - 1);
+        assert_eq!(0u128.saturating_add_i64(-5), 0);
+        assert_eq!(i128::MAX.saturating_add_i64(1), i128::MAX);
+        assert_eq!((i64::MIN + 3).saturating_add_i64(-2), i64::MIN + 1);
+    }
+
+    #[test]
+    fn typed_chars_replace_the_previous_one() {
+        assert_eq!(char_from_text("ab", 'a'), Some('b'));
+        assert_eq!(char_from_text("ba", 'a'), Some('b'));
+        assert_eq!(char_from_text("aa", 'a'), Some('a'));
+        assert_eq!(char_from_text("", 'a'), None);
+    }
+
+    #[test]
+    fn bar_fraction_is_clamped() {
+        assert_eq!(bar_fraction(25.0, 0.0, 100.0), 0.25);
+        assert_eq!(bar_fraction(-5.0, 0.0, 100.0), 0.0);
+        assert_eq!(bar_fraction(150.0, 0.0, 100.0), 1.0);
+        assert_eq!(bar_fraction(3.0, 3.0, 3.0), 1.0);
+    }
+
+    #[test]
+    fn linked_values_scale_proportionally() {
+        let before = [1.0f32, 2.0, 4.0];
+        let mut values = [1.0f32, 3.0, 4.0];
+        scale_linked(&mut values, &before);
+        assert_eq!(values, [1.5, 3.0, 6.0]);
+
+        let before = [0.0f32, 1.0];
+        let mut values = [0.5f32, 1.0];
+        scale_linked(&mut values, &before);
+        assert_eq!(values, [0.5, 1.5]);
+    }
+
+    #[test]
+    fn non_zero_edits_skip_zero() {
+        let one = NonZeroU8::MIN;
+        assert_eq!(NonZeroU8::away_from_zero(one, 0), one);
+        assert_eq!(NonZeroU8::away_from_zero(one, 5).get(), 5);
+
+        let negative = NonZeroI32::new(-3).unwrap();
+        assert_eq!(NonZeroI32::away_from_zero(negative, 0).get(), -1);
+        let positive = NonZeroI32::new(2).unwrap();
+        assert_eq!(NonZeroI32::away_from_zero(positive, 0).get(), 1);
+        assert_eq!(NonZeroI32::away_from_zero(positive, -4).get(), -4);
+    }
+}
@@ -29,6 +29,7 @@ fn insert_options_struct<T: 'static>(
     }
 }
 
+#[allow(dead_code)]
 fn insert_options_enum<T: 'static>(
     type_registry: &mut TypeRegistry,
     fields: &[(&'static str, &'static str, &dyn TypeData)],
@@ -63,60 +64,100 @@ fn insert_options_enum<T: 'static>(
 }
 
 pub fn register_default_options(type_registry: &mut TypeRegistry) {
-    insert_options_enum::<bevy_render::color::Color>(
-        type_registry,
-        &[
-            ("Rgba", "red", &NumberOptions::<f32>::normalized()),
-            ("Rgba", "green", &NumberOptions::<f32>::normalized()),
-            ("Rgba", "blue", &NumberOptions::<f32>::normalized()),
-            ("Rgba", "alpha", &NumberOptions::<f32>::normalized()),
-            ("RgbaLinear", "red", &NumberOptions::<f32>::normalized()),
-            ("RgbaLinear", "green", &NumberOptions::<f32>::normalized()),
-            ("RgbaLinear", "blue", &NumberOptions::<f32>::normalized()),
-            ("RgbaLinear", "alpha", &NumberOptions::<f32>::normalized()),
-            ("Hsla", "hue", &NumberOptions::<f32>::between(0.0, 360.0)),
-            ("Hsla", "saturation", &NumberOptions::<f32>::normalized()),
-            ("Hsla", "lightness", &NumberOptions::<f32>::normalized()),
-            ("Hsla", "alpha", &NumberOptions::<f32>::normalized()),
-        ],
-    );
+    #[cfg(feature = "bevy_render")]
+    {
+        insert_options_enum::<bevy_render::color::Color>(
+            type_registry,
+            &[
+                ("Rgba", "red", &NumberOptions::<f32>::normalized()),
+                ("Rgba", "green", &NumberOptions::<f32>::normalized()),
+                ("Rgba", "blue", &NumberOptions::<f32>::normalized()),
+                ("Rgba", "alpha", &NumberOptions::<f32>::normalized()),
+                ("RgbaLinear", "red", &NumberOptions::<f32>::normalized()),
+                ("RgbaLinear", "green", &NumberOptions::<f32>::normalized()),
+                ("RgbaLinear", "blue", &NumberOptions::<f32>::normalized()),
+                ("RgbaLinear", "alpha", &NumberOptions::<f32>::normalized()),
+                ("Hsla", "hue", &NumberOptions::<f32>::between(0.0, 360.0)),
+                ("Hsla", "saturation", &NumberOptions::<f32>::normalized()),
+                ("Hsla", "lightness", &NumberOptions::<f32>::normalized()),
+                ("Hsla", "alpha", &NumberOptions::<f32>::normalized()),
+            ],
+        );
 
-    insert_options_struct::<bevy_render::view::ColorGrading>(
-        type_registry,
-        &[
-            (
-                "exposure",
-                &NumberOptions::<f32>::positive().with_speed(0.01),
-            ),
-            ("gamma", &NumberOptions::<f32>::positive().with_speed(0.01)),
-            (
-                "pre_saturation",
-                &NumberOptions::<f32>::positive().with_speed(0.01),
-            ),
-            (
-                "post_saturation",
-                &NumberOptions::<f32>::positive().with_speed(0.01),
-            ),
-        ],
-    );
+        insert_options_struct::<bevy_render::view::ColorGrading>(
+            type_registry,
+            &[
+                (
+                    "exposure",
+                    &NumberOptions::<f32>::positive().with_speed(0.01),
+                ),
+                ("gamma", &NumberOptions::<f32>::positive().with_speed(0.01)),
+                (
+                    "pre_saturation",
+                    &NumberOptions::<f32>::positive().with_speed(0.01),
+                ),
+                (
+                    "post_saturation",
+                    &NumberOptions::<f32>::positive().with_speed(0.01),
+                ),
+            ],
+        );
+    }
 
     #[cfg(feature = "bevy_pbr")]
     {
+        use crate::inspector_options::std_options::NumberDisplay;
+
+        let angle = NumberOptions::<f32>::between(0.0, std::f32::consts::FRAC_PI_2).as_angle();
+        let depth_bias = NumberOptions::<f32>::positive().with_speed(0.001);
+        let normal_bias = NumberOptions::<f32>::positive().with_speed(0.01);
+        let lumens = NumberOptions::<f32>::between(0.0, 1e9)
+            .with_display(NumberDisplay::LogSlider)
+            .with_suffix(" lm");
+
         insert_options_struct::<bevy_pbr::AmbientLight>(
             type_registry,
-            &[("brightness", &NumberOptions::<f32>::positive())],
+            &[(
+                "brightness",
+                &NumberOptions::<f32>::between(0.0, 100_000.0)
+                    .with_display(NumberDisplay::LogSlider)
+                    .with_suffix(" cd/m²"),
+            )],
         );
         insert_options_struct::<bevy_pbr::PointLight>(
             type_registry,
             &[
-                ("intensity", &NumberOptions::<f32>::positive()),
-                ("range", &NumberOptions::<f32>::positive()),
-                ("radius", &NumberOptions::<f32>::positive()),
+                ("intensity", &lumens),
+                ("range", &NumberOptions::<f32>::positive().with_speed(0.1)),
+                ("radius", &NumberOptions::<f32>::positive().with_speed(0.01)),
+                ("shadow_depth_bias", &depth_bias),
+                ("shadow_normal_bias", &normal_bias),
+            ],
+        );
+        insert_options_struct::<bevy_pbr::SpotLight>(
+            type_registry,
+            &[
+                ("intensity", &lumens),
+                ("range", &NumberOptions::<f32>::positive().with_speed(0.1)),
+                ("radius", &NumberOptions::<f32>::positive().with_speed(0.01)),
+                ("shadow_depth_bias", &depth_bias),
+                ("shadow_normal_bias", &normal_bias),
+                ("outer_angle", &angle),
+                ("inner_angle", &angle),
             ],
         );
         insert_options_struct::<bevy_pbr::DirectionalLight>(
             type_registry,
-            &[("illuminance", &NumberOptions::<f32>::positive())],
+            &[
+                (
+                    "illuminance",
+                    &NumberOptions::<f32>::between(0.0, 200_000.0)
+                        .with_display(NumberDisplay::LogSlider)
+                        .with_suffix(" lx"),
+                ),
+                ("shadow_depth_bias", &depth_bias),
+                ("shadow_normal_bias", &normal_bias),
+            ],
         );
         insert_options_struct::<bevy_pbr::StandardMaterial>(
             type_registry,
@@ -143,11 +184,137 @@ pub fn register_default_options(type_registry: &mut TypeRegistry) {
         );
     }
 
-    insert_options_enum::<bevy_core_pipeline::core_3d::Camera3dDepthLoadOp>(
+    #[cfg(feature = "bevy_ui")]
+    insert_options_struct::<bevy_ui::Style>(
         type_registry,
-        &[("Clear", "0", &NumberOptions::<f32>::normalized())],
+        &[
+            (
+                "flex_grow",
+                &NumberOptions::<f32>::positive().with_speed(0.01),
+            ),
+            (
+                "flex_shrink",
+                &NumberOptions::<f32>::positive().with_speed(0.01),
+            ),
+        ],
     );
 
+    #[cfg(feature = "bevy_render")]
+    {
+        insert_options_struct::<bevy_render::camera::PerspectiveProjection>(
+            type_registry,
+            &[
+                (
+                    "fov",
+                    &NumberOptions::<f32>::between(0.01, std::f32::consts::PI - 0.01)
+                        .with_speed(0.01),
+                ),
+                (
+                    "aspect_ratio",
+                    &NumberOptions::<f32>::positive().with_speed(0.01),
+                ),
+                ("near", &NumberOptions::<f32>::positive().with_speed(0.01)),
+                ("far", &NumberOptions::<f32>::positive()),
+            ],
+        );
+        insert_options_struct::<bevy_render::camera::OrthographicProjection>(
+            type_registry,
+            &[
+                (
+                    "viewport_origin",
+                    &NumberOptions::<bevy_math::Vec2>::between(
+                        bevy_math::Vec2::ZERO,
+                        bevy_math::Vec2::ONE,
+                    )
+                    .with_speed(0.01),
+                ),
+                ("scale", &NumberOptions::<f32>::positive().with_speed(0.01)),
+            ],
+        );
+        insert_options_enum::<bevy_render::camera::ScalingMode>(
+            type_registry,
+            &[
+                ("Fixed", "width", &NumberOptions::<f32>::positive()),
+                ("Fixed", "height", &NumberOptions::<f32>::positive()),
+                (
+                    "WindowSize",
+                    "0",
+                    &NumberOptions::<f32>::positive().with_speed(0.01),
+                ),
+                ("AutoMin", "min_width", &NumberOptions::<f32>::positive()),
+                ("AutoMin", "min_height", &NumberOptions::<f32>::positive()),
+                ("AutoMax", "max_width", &NumberOptions::<f32>::positive()),
+                ("AutoMax", "max_height", &NumberOptions::<f32>::positive()),
+                ("FixedVertical", "0", &NumberOptions::<f32>::positive()),
+                ("FixedHorizontal", "0", &NumberOptions::<f32>::positive()),
+            ],
+        );
+
+        insert_options_enum::<bevy_core_pipeline::core_3d::Camera3dDepthLoadOp>(
+            type_registry,
+            &[("Clear", "0", &NumberOptions::<f32>::normalized())],
+        );
+    }
+
+    #[cfg(feature = "rapier")]
+    {
+        use bevy_rapier3d::{
+            dynamics::{AdditionalMassProperties, Damping, MassProperties, RigidBody, Velocity},
+            geometry::{Friction, Restitution},
+        };
+
+        // the rapier plugin registers these as well, but might be added after the inspector
+        type_registry.register::<RigidBody>();
+        type_registry.register::<Velocity>();
+        type_registry.register::<Damping>();
+        type_registry.register::<AdditionalMassProperties>();
+        type_registry.register::<MassProperties>();
+        type_registry.register::<Friction>();
+        type_registry.register::<Restitution>();
+
+        let speed = NumberOptions::<bevy_math::Vec3>::default().with_speed(0.01);
+        insert_options_struct::<Velocity>(type_registry, &[("linvel", &speed), ("angvel", &speed)]);
+        insert_options_struct::<Damping>(
+            type_registry,
+            &[
+                (
+                    "linear_damping",
+                    &NumberOptions::<f32>::positive().with_speed(0.01),
+                ),
+                (
+                    "angular_damping",
+                    &NumberOptions::<f32>::positive().with_speed(0.01),
+                ),
+            ],
+        );
+        insert_options_enum::<AdditionalMassProperties>(
+            type_registry,
+            &[(
+                "Mass",
+                "0",
+                &NumberOptions::<f32>::positive().with_speed(0.01),
+            )],
+        );
+        insert_options_struct::<MassProperties>(
+            type_registry,
+            &[("mass", &NumberOptions::<f32>::positive().with_speed(0.01))],
+        );
+        insert_options_struct::<Friction>(
+            type_registry,
+            &[(
+                "coefficient",
+                &NumberOptions::<f32>::positive().with_speed(0.01),
+            )],
+        );
+        insert_options_struct::<Restitution>(
+            type_registry,
+            &[(
+                "coefficient",
+                &NumberOptions::<f32>::positive().with_speed(0.01),
+            )],
+        );
+    }
+
     type_registry.register::<bevy_time::Virtual>();
 
     insert_options_struct::<bevy_time::Virtual>(
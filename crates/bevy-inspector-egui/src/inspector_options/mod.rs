@@ -1,9 +1,15 @@
 //! Way of associating options to fields using [`struct@InspectorOptions`]
 
+#[cfg(feature = "enabled")]
+use std::sync::Arc;
 use std::{any::Any, collections::HashMap};
 
-use bevy_reflect::{FromType, TypeData};
+use bevy_reflect::{FromType, Reflect, TypeData};
 
+#[cfg(feature = "enabled")]
+use crate::reflect_inspector::Context;
+
+#[cfg(feature = "enabled")]
 pub(crate) mod default_options;
 
 /// Options for dealing with common types such as numbers or quaternions
@@ -46,9 +52,237 @@ pub use bevy_inspector_egui_derive::InspectorOptions;
 /// field_options.max = 70.0.into();
 /// options.insert(Target::Field(0usize), field_options);
 /// ```
+///
+/// Parameterless methods can be shown as buttons below the value using `#[inspector(button = "method")]` on the type,
+/// and `#[inspector(on_change = "method")]` calls a method whenever the value was edited:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// #[inspector(button = "respawn", on_change = "update_alive")]
+/// struct Player {
+///     health: f32,
+///     #[reflect(ignore)]
+///     alive: bool,
+/// }
+///
+/// impl Player {
+///     fn respawn(&mut self) {
+///         self.health = 100.0;
+///     }
+///
+///     fn update_alive(&mut self) {
+///         self.alive = self.health > 0.0;
+///     }
+/// }
+/// ```
+///
+/// Fields can be hidden depending on the rest of the value with `#[inspector(visible_if = "expr")]`,
+/// where `self` refers to the value containing the field, or `#[inspector(visible_if = function)]` with a `fn(&Self) -> bool`:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, PartialEq)]
+/// enum Mode {
+///     #[default]
+///     Simple,
+///     Advanced,
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Settings {
+///     mode: Mode,
+///     #[inspector(visible_if = "self.mode == Mode::Advanced", min = 0.0)]
+///     tolerance: f32,
+///     #[inspector(visible_if = Settings::has_tolerance)]
+///     iterations: u32,
+/// }
+///
+/// impl Settings {
+///     fn has_tolerance(&self) -> bool {
+///         self.tolerance > 0.0
+///     }
+/// }
+/// ```
+///
+/// Options can depend on other fields by giving an expression as a string, where `self` refers to the value containing
+/// the field. The expression is evaluated every time the field is displayed, e.g. to keep a value below a maximum:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Movement {
+///     #[inspector(min = 0.0)]
+///     max_speed: f32,
+///     #[inspector(min = 0.0, max = "self.max_speed")]
+///     speed: f32,
+/// }
+/// ```
+///
+/// Fields whose invariants are maintained by accessor methods can be edited through them with
+/// `#[inspector(getter = "method", setter = "method")]`, where the getter returns the field type
+/// and the setter takes it by value. Without a setter, the field is read-only:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_math::Vec3;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Light {
+///     #[inspector(getter = "direction", setter = "set_direction")]
+///     direction: Vec3,
+///     #[inspector(getter = "intensity")]
+///     intensity: f32,
+/// }
+///
+/// impl Light {
+///     fn direction(&self) -> Vec3 {
+///         self.direction
+///     }
+///
+///     fn set_direction(&mut self, direction: Vec3) {
+///         self.direction = direction.normalize_or_zero();
+///     }
+///
+///     fn intensity(&self) -> f32 {
+///         self.intensity
+///     }
+/// }
+/// ```
+///
+/// Fields with `#[inspector(reset)]` get a button setting them back to the `Default` of their type, or to the value of
+/// `#[inspector(default = ..)]`. The button can be shown for all fields whose types implement `Default` with
+/// [`InspectorStyle::reset_buttons`](crate::theme::InspectorStyle::reset_buttons):
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(Default, InspectorOptions)]
+/// struct Camera {
+///     #[inspector(default = 1.5)]
+///     zoom: f32,
+///     #[inspector(reset)]
+///     offset: f32,
+/// }
+/// ```
+///
+/// Fields violating an invariant can be shown with `#[inspector(validate = "method")]`, where the method takes `&self`
+/// and returns a `Result` whose error implements `Display`. The field is outlined in red with the error on hover, but can
+/// still be edited. `#[inspector(validate = function)]` takes a `fn(&Self)` instead:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Spawner {
+///     min_delay: f32,
+///     #[inspector(validate = "check_max_delay")]
+///     max_delay: f32,
+/// }
+///
+/// impl Spawner {
+///     fn check_max_delay(&self) -> Result<(), String> {
+///         match self.max_delay >= self.min_delay {
+///             true => Ok(()),
+///             false => Err(format!("must be at least the min delay of {}", self.min_delay)),
+///         }
+///     }
+/// }
+/// ```
+///
+/// Fields can be displayed under a different name with `#[inspector(label = "Max HP")]`. To rename fields of types you
+/// don't own, or to translate all names at once, use the [`InspectorI18n`](crate::i18n::InspectorI18n) instead.
+///
+/// Newtypes with `#[inspector(transparent)]` are displayed like their only field.
+/// When used as a field, they take the options of the inner type, which replace the options of the inner field:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// #[inspector(transparent)]
+/// struct Meters(#[inspector(min = 0.0)] f32);
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Sensor {
+///     range: Meters,
+///     #[inspector(min = 1.0, max = 10.0)]
+///     precision_range: Meters,
+/// }
+/// ```
+///
+/// On generic types, fields whose types contain a type parameter get the bounds their attributes need, like
+/// `T: InspectorOptionsType`. Options like `max` need to know the concrete options of the field, which can be required
+/// with `#[inspector(bound = "..")]` on the field, replacing its inferred bounds, or on the type, replacing all of them.
+/// `#[reflect(InspectorOptions)]` registers the options within the `Reflect` impl, which needs the same bounds:
+/// ```rust
+/// use bevy_inspector_egui::inspector_options::{std_options::NumberOptions, InspectorOptionsType};
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::{Reflect, TypePath};
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions, where T: InspectorOptionsType<DeriveOptions = NumberOptions<T>>)]
+/// struct Limited<T: Reflect + TypePath + Copy> {
+///     limit: T,
+///     #[inspector(
+///         bound = "T: InspectorOptionsType<DeriveOptions = NumberOptions<T>>",
+///         max = "self.limit"
+///     )]
+///     value: T,
+/// }
+/// ```
 #[derive(Default)]
 pub struct InspectorOptions {
     options: HashMap<Target, Box<dyn TypeData>>,
+    buttons: Vec<InspectorButton>,
+    on_change: Option<fn(&mut dyn Reflect)>,
+    visible_if: HashMap<Target, fn(&dyn Reflect) -> bool>,
+    dynamic_options: HashMap<Target, DynamicOptionsFn>,
+    accessors: HashMap<Target, FieldAccessor>,
+    labels: HashMap<Target, &'static str>,
+    resets: HashMap<Target, FieldReset>,
+    validators: HashMap<Target, ValidateFn>,
+    transparent: bool,
+}
+
+type DynamicOptionsFn = fn(&dyn Reflect) -> Option<Box<dyn TypeData>>;
+type ValidateFn = fn(&dyn Reflect) -> Result<(), String>;
+
+/// Accessor methods displaying a field instead of accessing it directly, see [`InspectorOptions::set_accessor`]
+#[derive(Clone, Copy)]
+pub struct FieldAccessor {
+    /// Returns a copy of the field from the value containing it
+    pub get: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+    /// Writes the edited copy back into the value containing it. Fields without a setter are read-only
+    pub set: Option<fn(&mut dyn Reflect, Box<dyn Reflect>)>,
+}
+
+/// What the reset button of a field sets it to, see [`InspectorOptions::set_reset`]
+#[derive(Clone, Copy)]
+pub enum FieldReset {
+    /// The `Default` of the field type, which needs to be registered with `#[reflect(Default)]`
+    Default,
+    /// The value returned by the function
+    Value(fn() -> Box<dyn Reflect>),
+}
+
+/// A button calling a method of the inspected value, see [`InspectorOptions::add_button`]
+#[derive(Clone, Copy)]
+pub struct InspectorButton {
+    pub name: &'static str,
+    /// Called with the inspected value when the button is clicked
+    pub call: fn(&mut dyn Reflect),
 }
 
 impl std::fmt::Debug for InspectorOptions {
@@ -57,6 +291,33 @@ impl std::fmt::Debug for InspectorOptions {
         for entry in self.options.keys() {
             options.field(&format!("{entry:?}"), &"..");
         }
+        for button in &self.buttons {
+            options.field(button.name, &"button");
+        }
+        if self.on_change.is_some() {
+            options.field("on_change", &"..");
+        }
+        for target in self.visible_if.keys() {
+            options.field(&format!("{target:?}"), &"visible_if");
+        }
+        for target in self.dynamic_options.keys() {
+            options.field(&format!("{target:?}"), &"dynamic options");
+        }
+        for target in self.accessors.keys() {
+            options.field(&format!("{target:?}"), &"accessor");
+        }
+        for (target, label) in &self.labels {
+            options.field(&format!("{target:?}"), label);
+        }
+        for target in self.resets.keys() {
+            options.field(&format!("{target:?}"), &"reset");
+        }
+        for target in self.validators.keys() {
+            options.field(&format!("{target:?}"), &"validator");
+        }
+        if self.transparent {
+            options.field("transparent", &true);
+        }
         options.finish()
     }
 }
@@ -69,6 +330,15 @@ impl Clone for InspectorOptions {
                 .iter()
                 .map(|(target, data)| (*target, TypeData::clone_type_data(&**data)))
                 .collect(),
+            buttons: self.buttons.clone(),
+            on_change: self.on_change,
+            visible_if: self.visible_if.clone(),
+            dynamic_options: self.dynamic_options.clone(),
+            accessors: self.accessors.clone(),
+            labels: self.labels.clone(),
+            resets: self.resets.clone(),
+            validators: self.validators.clone(),
+            transparent: self.transparent,
         }
     }
 }
@@ -90,6 +360,167 @@ impl InspectorOptions {
     pub fn iter(&self) -> impl Iterator<Item = (Target, &dyn TypeData)> + '_ {
         self.options.iter().map(|(target, data)| (*target, &**data))
     }
+
+    /// Show a button labeled `name` below the value, which calls `call` with the value when clicked
+    pub fn add_button(&mut self, name: &'static str, call: fn(&mut dyn Reflect)) {
+        self.buttons.push(InspectorButton { name, call });
+    }
+
+    pub fn buttons(&self) -> &[InspectorButton] {
+        &self.buttons
+    }
+
+    /// Call `on_change` with the value after it was edited in the inspector, e.g. to rebuild cached data.
+    /// Generated by `#[inspector(on_change = "method")]` on the type.
+    pub fn set_on_change(&mut self, on_change: fn(&mut dyn Reflect)) {
+        self.on_change = Some(on_change);
+    }
+
+    pub fn on_change(&self) -> Option<fn(&mut dyn Reflect)> {
+        self.on_change
+    }
+
+    /// Only show the field at `target` while `visible_if` returns `true` for the value containing it.
+    /// Generated by `#[inspector(visible_if = "expr")]` on the field.
+    pub fn set_visible_if(&mut self, target: Target, visible_if: fn(&dyn Reflect) -> bool) {
+        self.visible_if.insert(target, visible_if);
+    }
+
+    /// Whether the field at `target` of `value` should be shown, see [`InspectorOptions::set_visible_if`]
+    pub fn is_visible(&self, target: Target, value: &dyn Reflect) -> bool {
+        self.visible_if
+            .get(&target)
+            .map_or(true, |visible_if| visible_if(value))
+    }
+
+    /// Display the field at `target` with the options returned by `options` for the value containing it, instead of the
+    /// options inserted for it. Generated by attributes referring to other fields like `#[inspector(max = "self.max_speed")]`.
+    ///
+    /// The inserted options are still used where there is no single value containing the field, like when editing many values.
+    pub fn set_dynamic_options(&mut self, target: Target, options: DynamicOptionsFn) {
+        self.dynamic_options.insert(target, options);
+    }
+
+    /// The options of the field at `target` of `value`, see [`InspectorOptions::set_dynamic_options`]
+    pub fn dynamic_options(
+        &self,
+        target: Target,
+        value: &dyn Reflect,
+    ) -> Option<Box<dyn TypeData>> {
+        self.dynamic_options
+            .get(&target)
+            .and_then(|options| options(value))
+    }
+
+    /// Display the field at `target` using `accessor` instead of accessing it directly,
+    /// e.g. to maintain invariants. Generated by `#[inspector(getter = "method", setter = "method")]` on the field.
+    pub fn set_accessor(&mut self, target: Target, accessor: FieldAccessor) {
+        self.accessors.insert(target, accessor);
+    }
+
+    pub fn accessor(&self, target: Target) -> Option<&FieldAccessor> {
+        self.accessors.get(&target)
+    }
+
+    /// Display `label` instead of the name of the field at `target`, unless the [`InspectorI18n`](crate::i18n::InspectorI18n)
+    /// has a label for it. Generated by `#[inspector(label = "..")]` on the field.
+    pub fn set_label(&mut self, target: Target, label: &'static str) {
+        self.labels.insert(target, label);
+    }
+
+    pub fn label(&self, target: Target) -> Option<&'static str> {
+        self.labels.get(&target).copied()
+    }
+
+    /// Show a button next to the field at `target` which sets it to the value of `reset`.
+    /// Generated by `#[inspector(reset)]` or `#[inspector(default = ..)]` on the field.
+    pub fn set_reset(&mut self, target: Target, reset: FieldReset) {
+        self.resets.insert(target, reset);
+    }
+
+    pub fn reset(&self, target: Target) -> Option<FieldReset> {
+        self.resets.get(&target).copied()
+    }
+
+    /// Outline the field at `target` in red while `validate` returns an error for the value containing it, without
+    /// preventing edits. Generated by `#[inspector(validate = "method")]` on the field.
+    pub fn set_validator(&mut self, target: Target, validate: ValidateFn) {
+        self.validators.insert(target, validate);
+    }
+
+    /// Validate the field at `target` of `value`, see [`InspectorOptions::set_validator`]
+    pub fn validate(&self, target: Target, value: &dyn Reflect) -> Result<(), String> {
+        match self.validators.get(&target) {
+            Some(validate) => validate(value),
+            None => Ok(()),
+        }
+    }
+
+    /// Display a struct with a single field as just that field, using its widget and options.
+    /// Generated by `#[inspector(transparent)]` on the type.
+    pub fn set_transparent(&mut self) {
+        self.transparent = true;
+    }
+
+    pub fn is_transparent(&self) -> bool {
+        self.transparent
+    }
+}
+
+#[cfg(feature = "enabled")]
+type CustomWidgetFn =
+    dyn Fn(&mut dyn Reflect, &mut egui::Ui, &mut Context<'_>) -> bool + Send + Sync;
+
+/// Options replacing the UI of a field with a custom function, generated by `#[inspector(with = my_widget)]`.
+/// Registered as type data by [`register_raw`](crate::inspector_egui_impls::register_raw), it replaces the UI of a whole type.
+///
+/// The function is only used for editing; read-only and multi-value UI still use the default widgets.
+/// ```rust
+/// use bevy_inspector_egui::{prelude::*, reflect_inspector::Context};
+/// use bevy_reflect::Reflect;
+///
+/// fn percentage(value: &mut f32, ui: &mut egui::Ui, _: &mut Context) -> bool {
+///     ui.add(egui::Slider::new(value, 0.0..=1.0).custom_formatter(|v, _| format!("{:.0}%", v * 100.0)))
+///         .changed()
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Config {
+///     #[inspector(with = percentage)]
+///     volume: f32,
+/// }
+/// ```
+#[cfg(feature = "enabled")]
+#[derive(Clone)]
+pub struct CustomWidget {
+    ui: Arc<CustomWidgetFn>,
+}
+
+#[cfg(feature = "enabled")]
+impl CustomWidget {
+    pub fn new<T: Reflect>(ui: fn(&mut T, &mut egui::Ui, &mut Context<'_>) -> bool) -> Self {
+        CustomWidget {
+            ui: Arc::new(
+                move |value, egui_ui, context| match value.downcast_mut::<T>() {
+                    Some(value) => ui(value, egui_ui, context),
+                    None => false,
+                },
+            ),
+        }
+    }
+
+    /// Display the widget for `value`, returning `true` if it was changed.
+    ///
+    /// Does nothing if `value` is not of the type the widget was created for.
+    pub fn ui(
+        &self,
+        value: &mut dyn Reflect,
+        ui: &mut egui::Ui,
+        context: &mut Context<'_>,
+    ) -> bool {
+        (self.ui)(value, ui, context)
+    }
 }
 
 /// Wrapper of [`struct@InspectorOptions`] to be stored in the [`TypeRegistry`](bevy_reflect::TypeRegistry)
@@ -123,6 +554,11 @@ where
 ///     Target::Field(0usize),
 ///     <f32 as InspectorOptionsType>::options_from_derive(field_options),
 /// );
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not support `#[inspector(..)]` attributes",
+    label = "no inspector options for this type",
+    note = "remove the `#[inspector(..)]` attribute from this field, or implement `InspectorOptionsType` for `{Self}`"
+)]
 pub trait InspectorOptionsType {
     type DeriveOptions: Default;
     /// Can be arbitrary types which will be passed to [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl) like [`NumberOptions`](crate::inspector_options::std_options::NumberOptions),
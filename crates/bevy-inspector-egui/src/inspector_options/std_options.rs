@@ -1,5 +1,6 @@
 use bevy_ecs::entity::Entity;
-use std::collections::VecDeque;
+use bevy_reflect::TypeData;
+use std::{collections::VecDeque, sync::Arc};
 
 use crate::InspectorOptions;
 
@@ -17,14 +18,18 @@ macro_rules! impl_options {
         }
     };
 }
-macro_rules! impl_options_defer_generic {
+macro_rules! impl_options_list {
     ($name:ident < $generic:ident >) => {
         impl<$generic: InspectorOptionsType> InspectorOptionsType for $name<$generic> {
-            type DeriveOptions = <$generic as InspectorOptionsType>::DeriveOptions;
-            type Options = <$generic as InspectorOptionsType>::Options;
+            type DeriveOptions = ListOptions<<$generic as InspectorOptionsType>::DeriveOptions>;
+            type Options = ListOptions<Box<dyn TypeData>>;
 
             fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
-                $generic::options_from_derive(options)
+                ListOptions {
+                    fixed_size: options.fixed_size,
+                    labels: options.labels,
+                    element: Box::new($generic::options_from_derive(options.element)),
+                }
             }
         }
     };
@@ -33,12 +38,25 @@ macro_rules! impl_options_defer_generic {
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct NumberOptions<T> {
+    /// Hard lower bound, the value is clamped to it
     pub min: Option<T>,
+    /// Hard upper bound, the value is clamped to it
     pub max: Option<T>,
+    /// Initial start of the range of sliders, which grows when dragging past it or typing a smaller value
+    pub soft_min: Option<T>,
+    /// Initial end of the range of sliders, which grows when dragging past it or typing a larger value
+    pub soft_max: Option<T>,
     pub speed: f32,
     pub prefix: String,
     pub suffix: String,
     pub display: NumberDisplay,
+    pub commit: NumberCommit,
+    /// Show the value, stored in radians, in degrees wrapped to ±180°. `speed`, `prefix` and `suffix` apply to the degrees.
+    pub angle: bool,
+    /// Show floats with exactly this many decimals, instead of as many as the value and `speed` need
+    pub decimals: Option<usize>,
+    /// How the value is written in text fields and labels, and how typed text is parsed
+    pub format: NumberFormat,
 }
 
 impl<T> Default for NumberOptions<T> {
@@ -46,10 +64,16 @@ impl<T> Default for NumberOptions<T> {
         Self {
             min: None,
             max: None,
+            soft_min: None,
+            soft_max: None,
             speed: 0.0,
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            commit: NumberCommit::default(),
+            angle: false,
+            decimals: None,
+            format: NumberFormat::default(),
         }
     }
 }
@@ -60,6 +84,59 @@ pub enum NumberDisplay {
     #[default]
     Drag,
     Slider,
+    /// Slider with a logarithmic scale, for values spanning several orders of magnitude like light intensities
+    LogSlider,
+    /// Progress bar filled from `min` to `max`, like for health or cooldowns. It can be dragged to set the value unless read-only.
+    Bar {
+        /// Show the value, with `prefix` and `suffix`, on the bar
+        show_value: bool,
+    },
+}
+
+/// How a number is written and parsed, see [`NumberOptions::format`].
+///
+/// Text typed into a number field is parsed leniently in every format: separators like `,` and `_` are ignored,
+/// `0x` and `0b` prefixes are understood and unit suffixes like `k`, `M` or `KiB` multiply the value.
+/// ```rust,ignore
+/// #[inspector(format = NumberFormat::Bytes)]
+/// texture_memory: u64,
+/// #[inspector(format = NumberFormat::Hex)]
+/// layer_mask: u32,
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NumberFormat {
+    #[default]
+    Decimal,
+    /// Decimal with the digits grouped in thousands, like `1,234,567`
+    Thousands,
+    /// Hexadecimal like `0xFF`, for bitmasks and ids. Floats are rounded to integers.
+    Hex,
+    /// Binary like `0b1010`, for bitmasks. Floats are rounded to integers.
+    Binary,
+    /// Scaled to a metric prefix, like `4.7k` or `12µ`
+    Si,
+    /// A size in bytes scaled to binary units, like `1.5 MiB`
+    Bytes,
+}
+
+/// When the value of a number field being dragged or typed into is written.
+///
+/// Use [`NumberCommit::OnRelease`] or [`NumberCommit::Interval`] for values whose changes trigger heavy recomputation:
+/// ```rust,ignore
+/// #[inspector(commit = NumberCommit::Interval(Duration::from_millis(200)))]
+/// subdivisions: u32,
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NumberCommit {
+    /// In every frame the value changes
+    #[default]
+    Continuous,
+    /// Once the drag is released or the text field loses focus
+    OnRelease,
+    /// At most once per interval while dragging or typing, and once it is released
+    Interval(std::time::Duration),
 }
 
 impl<T> NumberOptions<T> {
@@ -67,20 +144,32 @@ impl<T> NumberOptions<T> {
         NumberOptions {
             min: Some(min),
             max: Some(max),
+            soft_min: None,
+            soft_max: None,
             speed: 0.0,
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            commit: NumberCommit::default(),
+            angle: false,
+            decimals: None,
+            format: NumberFormat::default(),
         }
     }
     pub fn at_least(min: T) -> NumberOptions<T> {
         NumberOptions {
             min: Some(min),
             max: None,
+            soft_min: None,
+            soft_max: None,
             speed: 0.0,
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            commit: NumberCommit::default(),
+            angle: false,
+            decimals: None,
+            format: NumberFormat::default(),
         }
     }
 
@@ -88,27 +177,84 @@ impl<T> NumberOptions<T> {
         NumberOptions { speed, ..self }
     }
 
+    pub fn with_display(self, display: NumberDisplay) -> NumberOptions<T> {
+        NumberOptions { display, ..self }
+    }
+
+    /// Start sliders with the range `min..=max`, which grows while dragging past its ends, see [`NumberOptions::soft_min`]
+    pub fn with_soft_range(self, min: T, max: T) -> NumberOptions<T> {
+        NumberOptions {
+            soft_min: Some(min),
+            soft_max: Some(max),
+            ..self
+        }
+    }
+
+    /// Edit the value in degrees, see [`NumberOptions::angle`]
+    pub fn as_angle(self) -> NumberOptions<T> {
+        NumberOptions {
+            angle: true,
+            ..self
+        }
+    }
+
+    /// Show floats with `decimals` decimals, see [`NumberOptions::decimals`]
+    pub fn with_decimals(self, decimals: usize) -> NumberOptions<T> {
+        NumberOptions {
+            decimals: Some(decimals),
+            ..self
+        }
+    }
+
+    pub fn with_format(self, format: NumberFormat) -> NumberOptions<T> {
+        NumberOptions { format, ..self }
+    }
+
+    pub fn with_commit(self, commit: NumberCommit) -> NumberOptions<T> {
+        NumberOptions { commit, ..self }
+    }
+
+    pub fn with_suffix(self, suffix: impl Into<String>) -> NumberOptions<T> {
+        NumberOptions {
+            suffix: suffix.into(),
+            ..self
+        }
+    }
+
     pub fn map<U>(&self, f: impl Fn(&T) -> U) -> NumberOptions<U> {
         NumberOptions {
             #[allow(clippy::redundant_closure)] // false positive
             min: self.min.as_ref().map(|min| f(min)),
-            max: self.max.as_ref().map(f),
+            max: self.max.as_ref().map(&f),
+            soft_min: self.soft_min.as_ref().map(&f),
+            soft_max: self.soft_max.as_ref().map(f),
             speed: self.speed,
             prefix: self.prefix.clone(),
             suffix: self.suffix.clone(),
             display: NumberDisplay::default(),
+            commit: self.commit,
+            angle: self.angle,
+            decimals: self.decimals,
+            format: self.format,
         }
     }
 }
+#[cfg(feature = "enabled")]
 impl<T: egui::emath::Numeric> NumberOptions<T> {
     pub fn positive() -> NumberOptions<T> {
         NumberOptions {
             min: Some(T::from_f64(0.0)),
             max: None,
+            soft_min: None,
+            soft_max: None,
             speed: 0.0,
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            commit: NumberCommit::default(),
+            angle: false,
+            decimals: None,
+            format: NumberFormat::default(),
         }
     }
 
@@ -116,10 +262,16 @@ impl<T: egui::emath::Numeric> NumberOptions<T> {
         NumberOptions {
             min: Some(T::from_f64(0.0)),
             max: Some(T::from_f64(1.0)),
+            soft_min: None,
+            soft_max: None,
             speed: 0.01,
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            commit: NumberCommit::default(),
+            angle: false,
+            decimals: None,
+            format: NumberFormat::default(),
         }
     }
 }
@@ -138,6 +290,17 @@ impl_options!(u32 => NumberOptions<u32>);
 impl_options!(u64 => NumberOptions<u64>);
 impl_options!(u128 => NumberOptions<u128>);
 impl_options!(usize => NumberOptions<usize>);
+// non-zero integers use the options of their primitive, e.g. `#[inspector(max = 16)]`
+impl_options!(std::num::NonZeroI8 => NumberOptions<i8>);
+impl_options!(std::num::NonZeroI16 => NumberOptions<i16>);
+impl_options!(std::num::NonZeroI32 => NumberOptions<i32>);
+impl_options!(std::num::NonZeroI64 => NumberOptions<i64>);
+impl_options!(std::num::NonZeroIsize => NumberOptions<isize>);
+impl_options!(std::num::NonZeroU8 => NumberOptions<u8>);
+impl_options!(std::num::NonZeroU16 => NumberOptions<u16>);
+impl_options!(std::num::NonZeroU32 => NumberOptions<u32>);
+impl_options!(std::num::NonZeroU64 => NumberOptions<u64>);
+impl_options!(std::num::NonZeroUsize => NumberOptions<usize>);
 
 #[non_exhaustive]
 pub struct RangeOptions<T: InspectorOptionsType> {
@@ -172,6 +335,15 @@ impl<T: InspectorOptionsType + 'static> InspectorOptionsType for std::ops::Range
     }
 }
 
+impl<T: InspectorOptionsType + 'static> InspectorOptionsType for std::ops::RangeInclusive<T> {
+    type DeriveOptions = RangeOptions<T>;
+    type Options = RangeOptions<T>;
+
+    fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+        options
+    }
+}
+
 #[derive(Default, Clone)]
 #[non_exhaustive]
 pub struct QuatOptions {
@@ -215,6 +387,97 @@ pub enum EntityDisplay {
 
 impl_options!(Entity => EntityOptions);
 
+/// Options for `Color`s
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct ColorOptions {
+    /// Whether the alpha channel can be edited
+    pub alpha: bool,
+    /// Show the numeric channels in the given color space next to the color button
+    pub channels: ColorChannels,
+}
+
+impl Default for ColorOptions {
+    fn default() -> Self {
+        Self {
+            alpha: true,
+            channels: ColorChannels::default(),
+        }
+    }
+}
+
+/// Which numeric channels are shown next to the color button, see [`ColorOptions::channels`]
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColorChannels {
+    /// Only show the color button
+    #[default]
+    Hidden,
+    /// Channels of the space the color is stored in
+    Stored,
+    /// Red, green and blue in the sRGB color space
+    Srgb,
+    /// Red, green and blue in linear RGB
+    Linear,
+    /// Hue, saturation and value. The edited values are kept while editing, so the hue of a gray isn't lost.
+    Hsv,
+}
+
+#[cfg(feature = "bevy_render")]
+impl_options!(bevy_render::color::Color => ColorOptions);
+
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct StringOptions {
+    /// Edit the string in a multiline monospace editor highlighting the syntax of the language,
+    /// e.g. `#[inspector(code = CodeLanguage::Wgsl)]` for shader snippets
+    pub code: Option<CodeLanguage>,
+    /// Outline the string in red with the parse error on hover while it doesn't parse as this format,
+    /// e.g. `#[inspector(format = StringFormat::SocketAddr)]` for server addresses
+    pub format: Option<StringFormat>,
+}
+
+/// What the text of a string is expected to parse as, see [`StringOptions::format`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StringFormat {
+    /// An IPv4 or IPv6 address like `127.0.0.1` or `::1`
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+    /// An IP address with a port like `127.0.0.1:8080` or `[::1]:8080`
+    SocketAddr,
+    /// A UUID like `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    #[cfg(feature = "uuid")]
+    Uuid,
+}
+
+/// Languages which the code editor of [`StringOptions::code`] can highlight
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CodeLanguage {
+    Wgsl,
+    Glsl,
+    Rust,
+    Lua,
+    Json,
+    /// The format of Bevy's scene files
+    Ron,
+}
+
+impl_options!(String => StringOptions);
+
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct PathOptions {
+    /// Pick a directory instead of a file with the "Browse…" button of the `file-dialog` feature
+    pub directory: bool,
+    /// File extensions the dialog of the "Browse…" button is filtered to, e.g. `#[inspector(extensions = ["png", "jpg"])]`
+    pub extensions: Vec<&'static str>,
+}
+
+impl_options!(std::path::PathBuf => PathOptions);
+
 impl<T: InspectorOptionsType> InspectorOptionsType for Option<T> {
     type DeriveOptions = T::DeriveOptions;
     type Options = InspectorOptions;
@@ -235,14 +498,159 @@ impl<T: InspectorOptionsType> InspectorOptionsType for Option<T> {
     }
 }
 
-impl_options_defer_generic!(Vec<T>);
-impl_options_defer_generic!(VecDeque<T>);
+/// Options for lists like [`Vec`], [`VecDeque`] and, with the `smallvec` feature, `SmallVec`, and for arrays.
+///
+/// Options of the elements can be specified right next to the list options, e.g. `#[inspector(fixed_size, min = 0.0)]`.
+#[derive(Default)]
+#[non_exhaustive]
+pub struct ListOptions<T> {
+    /// Disable adding, removing, reordering and clearing elements. Arrays always have a fixed size
+    pub fixed_size: bool,
+    /// Labels of the elements, e.g. `#[inspector(labels = ["Primary", "Secondary"])]`
+    pub labels: ElementLabels,
+    pub element: T,
+}
+
+impl Clone for ListOptions<Box<dyn TypeData>> {
+    fn clone(&self) -> Self {
+        Self {
+            fixed_size: self.fixed_size,
+            labels: self.labels.clone(),
+            element: self.element.clone_type_data(),
+        }
+    }
+}
+
+/// Labels of the elements of a list or array, see [`ListOptions::labels`]
+///
+/// Created from an array of names, where elements past its end fall back to their index,
+/// or any function from the index to the label, e.g. `#[inspector(labels = track_name)]` or `labels = |index: usize| format!("#{index}")`:
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// fn track_name(index: usize) -> String {
+///     format!("Track {}", index + 1)
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Loadout {
+///     #[inspector(labels = ["Primary", "Secondary", "Melee"])]
+///     weapons: [String; 3],
+///     #[inspector(labels = track_name)]
+///     tracks: Vec<f32>,
+/// }
+/// ```
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub enum ElementLabels {
+    /// Label the elements with their index
+    #[default]
+    Index,
+    Names(Vec<&'static str>),
+    Function(Arc<dyn Fn(usize) -> String + Send + Sync>),
+}
+
+impl ElementLabels {
+    pub fn label(&self, index: usize) -> String {
+        match self {
+            ElementLabels::Names(names) if index < names.len() => names[index].to_owned(),
+            ElementLabels::Function(label) => label(index),
+            _ => index.to_string(),
+        }
+    }
+}
+
+impl<const N: usize> From<[&'static str; N]> for ElementLabels {
+    fn from(names: [&'static str; N]) -> Self {
+        ElementLabels::Names(names.to_vec())
+    }
+}
+
+impl From<Vec<&'static str>> for ElementLabels {
+    fn from(names: Vec<&'static str>) -> Self {
+        ElementLabels::Names(names)
+    }
+}
+
+impl<F: Fn(usize) -> String + Send + Sync + 'static> From<F> for ElementLabels {
+    fn from(label: F) -> Self {
+        ElementLabels::Function(Arc::new(label))
+    }
+}
+
+impl<T> std::ops::Deref for ListOptions<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+impl<T> std::ops::DerefMut for ListOptions<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl_options_list!(Vec<T>);
+impl_options_list!(VecDeque<T>);
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> InspectorOptionsType for smallvec::SmallVec<A>
+where
+    A::Item: InspectorOptionsType,
+{
+    type DeriveOptions = ListOptions<<A::Item as InspectorOptionsType>::DeriveOptions>;
+    type Options = ListOptions<Box<dyn TypeData>>;
+
+    fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+        ListOptions {
+            fixed_size: options.fixed_size,
+            labels: options.labels,
+            element: Box::new(A::Item::options_from_derive(options.element)),
+        }
+    }
+}
 
 impl<T: InspectorOptionsType, const N: usize> InspectorOptionsType for [T; N] {
-    type DeriveOptions = T::DeriveOptions;
-    type Options = T::Options;
+    type DeriveOptions = ListOptions<T::DeriveOptions>;
+    type Options = ListOptions<Box<dyn TypeData>>;
 
     fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
-        T::options_from_derive(options)
+        ListOptions {
+            fixed_size: true,
+            labels: options.labels,
+            element: Box::new(T::options_from_derive(options.element)),
+        }
     }
 }
+
+/// Options for the individual elements of a tuple, e.g. `#[inspector(0 = NumberOptions::positive(), 1 = NumberOptions::normalized())]`
+macro_rules! impl_options_tuple {
+    ($($index:tt $ty:ident),*) => {
+        impl<$($ty: InspectorOptionsType),*> InspectorOptionsType for ($($ty,)*) {
+            type DeriveOptions = ($($ty::DeriveOptions,)*);
+            type Options = InspectorOptions;
+
+            fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+                let mut inspector_options = InspectorOptions::new();
+                $(inspector_options.insert(Target::Field($index), $ty::options_from_derive(options.$index));)*
+                inspector_options
+            }
+        }
+    };
+}
+
+impl_options_tuple!(0 A);
+impl_options_tuple!(0 A, 1 B);
+impl_options_tuple!(0 A, 1 B, 2 C);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+impl_options_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
@@ -26,7 +26,7 @@
 //!
 //! ```no_run
 //! use bevy::prelude::*;
-//! use bevy_inspector_egui::quick::WorldInspectorPlugin;
+//! use bevy_inspector_egui::prelude::*;
 //!
 //! fn main() {
 //!     App::new()
@@ -44,7 +44,6 @@
 //! ```no_run
 //! use bevy::prelude::*;
 //! use bevy_inspector_egui::prelude::*;
-//! use bevy_inspector_egui::quick::ResourceInspectorPlugin;
 //!
 //! // `InspectorOptions` are completely optional
 //! #[derive(Reflect, Resource, Default, InspectorOptions)]
@@ -133,35 +132,119 @@
 //! **Q:** Can I change how exactly my type is displayed?
 //!
 //! **A:** Implement [`InspectorPrimitive`](crate::inspector_egui_impls::InspectorPrimitive) and call `app.register_type_data::<T, InspectorEguiImpl>`.
+//! For types of other crates like `Transform`, use [`register_raw`](crate::inspector_egui_impls::register_raw) instead.
+//!
+//! **Q: How do I ship release builds without the inspector?**
+//!
+//! **A:** Turn off the default `enabled` feature in release builds, for example by depending on the crate with
+//! `default-features = false` and enabling `bevy-inspector-egui/enabled` through a default feature of your game.
+//! The `quick` plugins, `register_inspectable_event` and `track_component_history` then don't add anything to the app,
+//! and the `InspectorOptions` derive only checks its attributes, so the inspector calls can stay in place.
+//! `egui` and `bevy_egui` aren't compiled, so your own code using them, the [`bevy_inspector`] functions or
+//! resources like [`InspectorWindows`](crate::quick::InspectorWindows) needs a `#[cfg(feature = "..")]` on your feature.
 
+// lets the derive macros, which use absolute paths, be used inside this crate
+extern crate self as bevy_inspector_egui;
+
+#[cfg(feature = "auto_register")]
+pub mod auto_register;
+#[cfg(feature = "enabled")]
 pub mod bevy_inspector;
+#[cfg(feature = "enabled")]
+pub mod curves;
+#[cfg(feature = "dock")]
+pub mod dock;
+#[cfg(feature = "enabled")]
+pub mod events;
+#[cfg(feature = "gallery")]
+pub mod gallery;
+#[cfg(feature = "enabled")]
+pub mod i18n;
+#[cfg(feature = "enabled")]
+pub mod input;
+#[cfg(feature = "enabled")]
 pub mod inspector_egui_impls;
 pub mod inspector_options;
+#[cfg(feature = "enabled")]
+pub mod notes;
+#[cfg(feature = "persistence")]
+pub mod persistence;
+#[cfg(feature = "enabled")]
 pub mod quick;
+#[cfg(feature = "enabled")]
 pub mod reflect_inspector;
+#[cfg(all(feature = "remote", not(target_arch = "wasm32")))]
+pub mod remote;
+#[cfg(feature = "enabled")]
 pub mod restricted_world_view;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "enabled")]
+pub mod theme;
+#[cfg(feature = "enabled")]
+pub mod tour;
+#[cfg(feature = "enabled")]
+pub mod widgets;
 
+#[cfg(not(feature = "enabled"))]
+mod disabled;
+#[cfg(feature = "enabled")]
 mod egui_utils;
+#[cfg(feature = "enabled")]
 mod utils;
 
+#[cfg(not(feature = "enabled"))]
+pub use disabled::{bevy_inspector, quick};
+
+#[cfg(feature = "enabled")]
 pub use bevy_egui;
+#[cfg(feature = "enabled")]
 pub use egui;
 
 /// [`bevy_app::Plugin`] used to register default [`struct@InspectorOptions`] and [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl)s
 pub struct DefaultInspectorConfigPlugin;
 impl bevy_app::Plugin for DefaultInspectorConfigPlugin {
+    #[cfg(not(feature = "enabled"))]
+    fn build(&self, _app: &mut bevy_app::App) {}
+
+    #[cfg(feature = "enabled")]
     fn build(&self, app: &mut bevy_app::App) {
         if app.is_plugin_added::<Self>() {
             return;
         }
 
+        app.add_event::<events::InspectorEvent>()
+            .init_resource::<bevy_inspector::commands::InspectorCommands>()
+            .add_systems(
+                bevy_app::Last,
+                bevy_inspector::commands::apply_inspector_commands,
+            )
+            .init_resource::<bevy_inspector::tags::EntityTags>()
+            .init_resource::<bevy_inspector::locks::InspectorLocks>()
+            .init_resource::<bevy_inspector::params::WorldInspectorParams>()
+            .init_resource::<bevy_inspector::selection::InspectorSelection>()
+            .init_resource::<bevy_inspector::component_actions::ComponentActions>()
+            .add_systems(
+                bevy_app::PreUpdate,
+                (
+                    i18n::sync_inspector_i18n,
+                    input::sync_inspector_input_config,
+                ),
+            );
+
+        #[cfg(feature = "bevy_render")]
+        app.init_resource::<bevy_inspector::mesh_shape::MeshShapes>();
+
         let type_registry = app.world.resource::<bevy_ecs::prelude::AppTypeRegistry>();
         let mut type_registry = type_registry.write();
 
         inspector_options::default_options::register_default_options(&mut type_registry);
         inspector_egui_impls::register_std_impls(&mut type_registry);
+        #[cfg(feature = "glam")]
         inspector_egui_impls::register_glam_impls(&mut type_registry);
         inspector_egui_impls::register_bevy_impls(&mut type_registry);
+        #[cfg(feature = "auto_register")]
+        auto_register::register_all(&mut type_registry);
     }
 }
 
@@ -171,11 +254,68 @@ pub use inspector_options::InspectorOptions;
 #[doc(hidden)]
 pub mod __macro_exports {
     pub use bevy_reflect;
+    #[cfg(feature = "auto_register")]
+    pub use inventory;
+
+    /// Ignores the return value of methods called by `#[inspector(button = "..")]`
+    pub fn discard<T>(_: T) {}
 }
 
 /// Reexports of commonly used types
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::prelude::*;
+///
+/// #[derive(Reflect, Resource, Default, InspectorOptions)]
+/// #[reflect(Resource, InspectorOptions)]
+/// struct Configuration {
+///     #[inspector(min = 0.0, max = 1.0, display = NumberDisplay::Slider)]
+///     volume: f32,
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .init_resource::<Configuration>()
+///         .register_type::<Configuration>()
+///         .add_plugins(WorldInspectorPlugin::new().ignore::<Visibility>().with_hotkey(KeyCode::F1))
+///         .add_plugins(ResourceInspectorPlugin::<Configuration>::new())
+///         .run();
+/// }
+/// ```
 pub mod prelude {
     // for `#[derive(Reflect)] #[reflect(InspectorOptions)]
     pub use crate::inspector_options::InspectorOptions;
     pub use crate::inspector_options::ReflectInspectorOptions;
+
+    // values of `#[inspector(..)]` attributes
+    pub use crate::inspector_options::std_options::{
+        CodeLanguage, ColorChannels, EntityDisplay, NumberCommit, NumberDisplay, NumberFormat,
+        QuatDisplay, StringFormat,
+    };
+
+    #[cfg(feature = "enabled")]
+    pub use crate::bevy_inspector::params::WorldInspectorParams;
+    #[cfg(feature = "enabled")]
+    pub use crate::inspector_egui_impls::{InspectorEguiImpl, InspectorPrimitive};
+    #[cfg(feature = "enabled")]
+    pub use crate::quick::InspectorWindows;
+    pub use crate::quick::{
+        ArchetypesInspectorPlugin, AssetInspectorPlugin, AssetsBrowserPlugin,
+        DiagnosticsInspectorPlugin, EventInspectorPlugin, FilterQueryInspectorPlugin,
+        InputStateInspectorPlugin, InspectorConsolePlugin, InspectorSet, PinnedInspectorPlugin,
+        QueryInspectorPlugin, ResourceInspectorPlugin, StateInspectorPlugin,
+        SystemsInspectorPlugin, TasksInspectorPlugin, TimeControlsPlugin, WorldInspectorPlugin,
+    };
+    pub use crate::DefaultInspectorConfigPlugin;
+
+    #[cfg(feature = "picking")]
+    pub use crate::bevy_inspector::picking::InspectorPickingPlugin;
+    #[cfg(all(feature = "enabled", feature = "bevy_gizmos"))]
+    pub use crate::bevy_inspector::visibility::VisibilityDebugPlugin;
+    #[cfg(feature = "dock")]
+    pub use crate::dock::InspectorDockPlugin;
+    #[cfg(feature = "gallery")]
+    pub use crate::gallery::WidgetGalleryPlugin;
 }
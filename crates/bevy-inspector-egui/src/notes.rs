@@ -0,0 +1,160 @@
+//! Free-text notes attached to struct fields, for keeping track of findings while tuning values.
+//!
+//! Notes are keyed by the type path of the struct and the field name, so a note on `Player::max_speed`
+//! shows up on every `Player`. Right-click a field name to edit its note, fields with a note display a 📝 icon.
+//! The notes are kept in the persisted memory of the egui [`Context`](egui::Context) and can be written to
+//! and read from a file using [`save`] and [`load`].
+//!
+//! ```rust,no_run
+//! fn setup(ctx: &egui::Context) {
+//!     bevy_inspector_egui::notes::load(ctx, "inspector_notes.ron").ok();
+//! }
+//! ```
+
+use std::{collections::BTreeMap, io, path::Path};
+
+fn id() -> egui::Id {
+    egui::Id::new("bevy-inspector-egui notes")
+}
+
+/// The path used as the key for notes on `field` of the struct with the type path `type_path`
+pub fn field_path(type_path: &str, field: &str) -> String {
+    format!("{type_path}.{field}")
+}
+
+/// The note at `path`
+pub fn note(ctx: &egui::Context, path: &str) -> Option<String> {
+    ctx.data_mut(|data| {
+        data.get_persisted_mut_or_default::<BTreeMap<String, String>>(id())
+            .get(path)
+            .cloned()
+    })
+}
+
+/// Set the note at `path`, removing it if `text` is empty
+pub fn set_note(ctx: &egui::Context, path: &str, text: String) {
+    ctx.data_mut(|data| {
+        let notes = data.get_persisted_mut_or_default::<BTreeMap<String, String>>(id());
+        if text.is_empty() {
+            notes.remove(path);
+        } else {
+            notes.insert(path.to_owned(), text);
+        }
+    });
+}
+
+/// All notes by their path
+pub fn notes(ctx: &egui::Context) -> BTreeMap<String, String> {
+    ctx.data_mut(|data| {
+        data.get_persisted_mut_or_default::<BTreeMap<String, String>>(id())
+            .clone()
+    })
+}
+
+/// Write all notes to the file at `path` as RON
+pub fn save(ctx: &egui::Context, path: impl AsRef<Path>) -> io::Result<()> {
+    let text = ron::ser::to_string_pretty(&notes(ctx), ron::ser::PrettyConfig::default())
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    std::fs::write(path, text)
+}
+
+/// Replace all notes with the ones in the file at `path` written by [`save`]
+pub fn load(ctx: &egui::Context, path: impl AsRef<Path>) -> io::Result<()> {
+    let text = std::fs::read_to_string(path)?;
+    let notes: BTreeMap<String, String> =
+        ron::from_str(&text).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    ctx.data_mut(|data| data.insert_persisted(id(), notes));
+    Ok(())
+}
+
+/// Labels are clickable for the context menu, but skipped when moving the focus with `Tab`
+const FIELD_LABEL_SENSE: egui::Sense = egui::Sense {
+    click: true,
+    drag: false,
+    focusable: false,
+};
+
+/// Display the label of a field, with a 📝 icon showing the note at `path` and a context menu for editing it
+pub fn ui_for_field_label(ui: &mut egui::Ui, label: &str, path: &str) {
+    ui_for_field_label_with_menu(ui, label, path, |_| {});
+}
+
+/// Like [`ui_for_field_label`], with `add_menu` adding more entries below the note in the context menu.
+///
+/// Returns the response of the whole label, including the icon.
+pub fn ui_for_field_label_with_menu(
+    ui: &mut egui::Ui,
+    label: &str,
+    path: &str,
+    add_menu: impl FnOnce(&mut egui::Ui),
+) -> egui::Response {
+    let note = note(ui.ctx(), path);
+    ui.horizontal(|ui| {
+        let response = ui.add(egui::Label::new(label).sense(FIELD_LABEL_SENSE));
+        if let Some(note) = &note {
+            ui.label("📝").on_hover_text(note);
+        }
+        response.context_menu(|ui| {
+            let mut text = note.clone().unwrap_or_default();
+            ui.label("Note");
+            if ui
+                .add(egui::TextEdit::multiline(&mut text).desired_rows(3))
+                .changed()
+            {
+                set_note(ui.ctx(), path, text);
+            }
+            add_menu(ui);
+        });
+    })
+    .response
+}
+
+/// Display all notes with their path, for editing and removing them
+pub fn ui_for_notes(ui: &mut egui::Ui) {
+    let notes = notes(ui.ctx());
+    if notes.is_empty() {
+        ui.weak("No notes yet, right-click a field name to add one");
+        return;
+    }
+
+    egui::Grid::new("inspector notes")
+        .striped(true)
+        .show(ui, |ui| {
+            for (path, mut text) in notes {
+                ui.label(&path);
+                if ui
+                    .add(egui::TextEdit::multiline(&mut text).desired_rows(1))
+                    .changed()
+                {
+                    set_note(ui.ctx(), &path, text);
+                }
+                if crate::egui_utils::remove_button(ui)
+                    .on_hover_text("Remove note")
+                    .clicked()
+                {
+                    set_note(ui.ctx(), &path, String::new());
+                }
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn notes_round_trip_through_file() {
+        let path = std::env::temp_dir().join("bevy_inspector_egui_notes_test.ron");
+        let ctx = egui::Context::default();
+        let field = super::field_path("game::Player", "max_speed");
+        super::set_note(&ctx, &field, "0.3 felt too floaty".into());
+        super::save(&ctx, &path).unwrap();
+
+        let other = egui::Context::default();
+        super::load(&other, &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(super::note(&other, &field).unwrap(), "0.3 felt too floaty");
+
+        super::set_note(&other, &field, String::new());
+        assert!(super::notes(&other).is_empty());
+    }
+}
@@ -0,0 +1,303 @@
+//! Restoring the inspector's egui state, like window positions and sizes or open headers, on the next run.
+//!
+//! Enabled by the `persistence` feature, which turns on egui's `persistence` feature, so every value stored with
+//! `insert_persisted` in egui's memory must implement `serde`'s traits. The [`InspectorPersistencePlugin`] writes the
+//! egui memory, the user-facing [`WorldInspectorParams`] and the names of the selected entities to a file while the
+//! app runs and when it exits, and restores them on startup:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::{persistence::InspectorPersistencePlugin, quick::WorldInspectorPlugin};
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(WorldInspectorPlugin::new())
+//!         .add_plugins(InspectorPersistencePlugin::new("target/inspector-state.ron"))
+//!         .run();
+//! }
+//! ```
+//!
+//! Entities get different ids on every run, so only selected entities with a [`Name`] are selected again,
+//! once an entity with that name exists. Nothing is written in the browser.
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy_app::{AppExit, Last, Plugin, PreUpdate};
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use bevy_egui::{EguiPlugin, EguiSet};
+use bevy_utils::Instant;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bevy_inspector::{
+        params::{EntitySort, WorldInspectorParams},
+        selection::InspectorSelection,
+    },
+    quick,
+};
+
+/// How often the state is compared with the one in the file, and written if it changed
+pub const SAVE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The fields of [`WorldInspectorParams`] which are changed from the inspector's UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PersistedParams {
+    despawnable_entities: bool,
+    sort: EntitySort,
+    highlight_changed_fields: bool,
+}
+
+impl PersistedParams {
+    fn new(params: &WorldInspectorParams) -> Self {
+        PersistedParams {
+            despawnable_entities: params.despawnable_entities,
+            sort: params.sort,
+            highlight_changed_fields: params.highlight_changed_fields,
+        }
+    }
+
+    fn apply(&self, params: &mut WorldInspectorParams) {
+        params.despawnable_entities = self.despawnable_entities;
+        params.sort = self.sort;
+        params.highlight_changed_fields = self.highlight_changed_fields;
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct PersistedState {
+    memory: egui::Memory,
+    params: Option<PersistedParams>,
+    /// Names of the selected entities
+    selected: Vec<String>,
+}
+
+/// The file the inspector state is persisted in, see the [module docs](self)
+#[derive(Resource)]
+pub struct InspectorPersistence {
+    path: PathBuf,
+    /// The state read from the file, until it is restored
+    loaded: Option<PersistedState>,
+    /// Names of selected entities which haven't been spawned yet
+    pending_selection: Vec<String>,
+    saved: Option<String>,
+    last_check: Option<Instant>,
+}
+
+impl InspectorPersistence {
+    /// Persist the state in the file at `path`, reading the state of the previous run from it if it exists
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let saved = std::fs::read_to_string(&path).ok();
+        let loaded = saved.as_deref().and_then(|text| match ron::from_str(text) {
+            Ok(state) => Some(state),
+            Err(error) => {
+                bevy_log::warn!(
+                    "failed to read inspector state from {}: {error}",
+                    path.display()
+                );
+                None
+            }
+        });
+        InspectorPersistence {
+            path,
+            loaded,
+            pending_selection: Vec::new(),
+            saved,
+            last_check: None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Replace the egui memory of `ctx` and the `params` with the state read from the file, if it isn't restored yet
+    fn restore(&mut self, ctx: &egui::Context, params: Option<&mut WorldInspectorParams>) {
+        let Some(state) = self.loaded.take() else {
+            return;
+        };
+        ctx.memory_mut(|memory| *memory = state.memory);
+        if let (Some(persisted), Some(params)) = (state.params, params) {
+            persisted.apply(params);
+        }
+        self.pending_selection = state.selected;
+    }
+
+    /// Write the state to the file if it changed since it was last written
+    fn save_if_changed(&mut self, state: &PersistedState) {
+        // there is no file system in the browser
+        if cfg!(target_arch = "wasm32") {
+            return;
+        }
+        let text = match ron::to_string(state) {
+            Ok(text) => text,
+            Err(error) => {
+                bevy_log::warn!("failed to serialize inspector state: {error}");
+                return;
+            }
+        };
+        if self.saved.as_ref() != Some(&text) {
+            if let Err(error) = std::fs::write(&self.path, &text) {
+                bevy_log::warn!(
+                    "failed to save inspector state to {}: {error}",
+                    self.path.display()
+                );
+            }
+            self.saved = Some(text);
+        }
+    }
+}
+
+/// Plugin persisting the inspector state in a file, see the [module docs](self)
+pub struct InspectorPersistencePlugin {
+    path: PathBuf,
+}
+
+impl InspectorPersistencePlugin {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        InspectorPersistencePlugin { path: path.into() }
+    }
+}
+
+impl Plugin for InspectorPersistencePlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+
+        app.insert_resource(InspectorPersistence::new(&self.path))
+            .add_systems(
+                PreUpdate,
+                (restore_state, restore_selection)
+                    .chain()
+                    .after(EguiSet::InitContexts)
+                    .before(EguiSet::BeginFrame),
+            )
+            .add_systems(Last, save_state);
+    }
+}
+
+fn restore_state(world: &mut World) {
+    let Some(mut egui_context) = quick::inspector_egui_context(world) else {
+        return;
+    };
+    world.resource_scope(|world, mut persistence: Mut<InspectorPersistence>| {
+        let params = world
+            .get_resource_mut::<WorldInspectorParams>()
+            .map(Mut::into_inner);
+        persistence.restore(egui_context.get_mut(), params);
+    });
+}
+
+/// Select the entities named like the ones selected in the previous run, unless something was already selected
+fn restore_selection(
+    mut persistence: ResMut<InspectorPersistence>,
+    selection: Option<ResMut<InspectorSelection>>,
+    names: Query<(Entity, &Name)>,
+) {
+    if persistence.pending_selection.is_empty() {
+        return;
+    }
+    let Some(mut selection) = selection else {
+        return;
+    };
+    if !selection.is_empty() {
+        persistence.pending_selection.clear();
+        return;
+    }
+    let entities: Vec<Entity> = names
+        .iter()
+        .filter(|(_, name)| persistence.pending_selection.contains(&name.to_string()))
+        .map(|(entity, _)| entity)
+        .collect();
+    if !entities.is_empty() {
+        selection.extend(entities);
+        persistence.pending_selection.clear();
+    }
+}
+
+fn save_state(world: &mut World) {
+    let is_exiting = world
+        .get_resource::<Events<AppExit>>()
+        .is_some_and(|events| !events.is_empty());
+    let now = Instant::now();
+    let Some(persistence) = world.get_resource::<InspectorPersistence>() else {
+        return;
+    };
+    // don't overwrite the previous state before it is restored
+    if persistence.loaded.is_some() {
+        return;
+    }
+    let is_due = persistence.last_check.map_or(true, |last_check| {
+        now.duration_since(last_check) >= SAVE_INTERVAL
+    });
+    if !is_due && !is_exiting {
+        return;
+    }
+    let Some(mut egui_context) = quick::inspector_egui_context(world) else {
+        return;
+    };
+
+    let mut selected: Vec<String> = Vec::new();
+    if let Some(selection) = world.get_resource::<InspectorSelection>() {
+        selected.extend(
+            selection
+                .iter()
+                .filter_map(|entity| world.get::<Name>(entity))
+                .map(|name| name.to_string()),
+        );
+    }
+    let state = PersistedState {
+        memory: egui_context.get_mut().memory(|memory| memory.clone()),
+        params: world
+            .get_resource::<WorldInspectorParams>()
+            .map(PersistedParams::new),
+        selected,
+    };
+
+    let mut persistence = world.resource_mut::<InspectorPersistence>();
+    persistence.last_check = Some(now);
+    persistence.save_if_changed(&state);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bevy_inspector::params::{EntitySort, WorldInspectorParams};
+
+    use super::{InspectorPersistence, PersistedParams, PersistedState};
+
+    #[test]
+    fn state_is_restored_from_file() {
+        let path = std::env::temp_dir().join("bevy-inspector-egui-persistence-test.ron");
+        let _ = std::fs::remove_file(&path);
+        let id = egui::Id::new("header");
+
+        let ctx = egui::Context::default();
+        ctx.data_mut(|data| data.insert_persisted(id, true));
+        let mut params = WorldInspectorParams::default();
+        params.sort = EntitySort::Name;
+        let state = PersistedState {
+            memory: ctx.memory(|memory| memory.clone()),
+            params: Some(PersistedParams::new(&params)),
+            selected: vec!["Player".to_owned()],
+        };
+        InspectorPersistence::new(&path).save_if_changed(&state);
+
+        let ctx = egui::Context::default();
+        let mut params = WorldInspectorParams::default();
+        let mut persistence = InspectorPersistence::new(&path);
+        persistence.restore(&ctx, Some(&mut params));
+        assert_eq!(
+            ctx.data_mut(|data| data.get_persisted::<bool>(id)),
+            Some(true)
+        );
+        assert_eq!(params.sort, EntitySort::Name);
+        assert_eq!(persistence.pending_selection, ["Player"]);
+    }
+}
@@ -2,98 +2,285 @@
 //!
 //! **Pros:** no manual code required
 //!
-//! **Cons:** not configurable
+//! **Cons:** barely configurable, apart from a run condition and the window title and position
 //!
 //! When you want something more custom, you can use these plugins as a starting point.
+//!
+//! ## Integrating with your own egui code
+//!
+//! The windows are drawn in the [`Update`] schedule, in the [`InspectorSet`] system set, so your own egui systems
+//! can be ordered before or after them. They are drawn to the egui context of the primary window,
+//! unless a different window is set in the [`InspectorEguiContext`] resource.
+//! To show the world inspector inside your own layout instead of its own window, use [`WorldInspectorPlugin::embedded`]
+//! and call [`ui_for_world_inspector`] with your [`egui::Ui`].
 
-use std::{marker::PhantomData, sync::Mutex};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet, VecDeque},
+    marker::PhantomData,
+    sync::Mutex,
+    time::Duration,
+};
 
-use bevy_app::{Plugin, Update};
+use bevy_app::{First, Last, Plugin, PreUpdate, Update};
 use bevy_asset::Asset;
 use bevy_core::TypeRegistrationPlugin;
-use bevy_ecs::{prelude::*, query::QueryFilter, schedule::BoxedCondition};
+use bevy_diagnostic::{
+    DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy_ecs::{
+    prelude::*,
+    query::{QueryData, QueryFilter},
+    schedule::BoxedCondition,
+};
 use bevy_egui::{EguiContext, EguiPlugin};
-use bevy_reflect::Reflect;
+use bevy_input::{keyboard::KeyCode, ButtonInput, InputSystem};
+use bevy_reflect::{GetTypeRegistration, Reflect, TypePath};
+use bevy_time::{Real, Time, TimeSystem, Virtual};
+use bevy_utils::Instant;
 use bevy_window::PrimaryWindow;
 use pretty_type_name::pretty_type_name;
 
-use crate::{bevy_inspector, DefaultInspectorConfigPlugin};
+use crate::{
+    bevy_inspector::{
+        self,
+        ab_comparison::{self, AbComparison},
+        archetypes, asset_usage, assets_browser, console,
+        edit_mode::{self, EditMode, InspectorUpdateMode},
+        input_state, locks,
+        params::WorldInspectorParams,
+        pins, systems, tasks, time_controls,
+    },
+    egui_utils,
+    events::InspectorEvent,
+    notes,
+    theme::{self, InspectorTheme},
+    tour::{self, Tour},
+    DefaultInspectorConfigPlugin,
+};
 
 const DEFAULT_SIZE: (f32, f32) = (320., 160.);
 
+/// The builder methods of the window plugins in this module, setting their `window: QuickWindow` field.
+///
+/// `edit_mode` adds [`with_edit_mode`](WorldInspectorPlugin::with_edit_mode) and [`with_update_mode`](WorldInspectorPlugin::with_update_mode)
+/// for windows editing values, `hud` leaves out the position and open state for windows without a title bar.
+macro_rules! window_builder_methods {
+    () => {
+        window_builder_methods!(hud);
+
+        /// Set the position the window is shown at initially
+        pub fn with_default_pos(mut self, pos: impl Into<egui::Pos2>) -> Self {
+            self.window.options.default_pos = Some(pos.into());
+            self
+        }
+
+        /// Whether the window starts out expanded or collapsed. Defaults to `true`.
+        pub fn with_default_open(mut self, open: bool) -> Self {
+            self.window.options.default_open = open;
+            self
+        }
+    };
+    (edit_mode) => {
+        window_builder_methods!();
+
+        /// Whether edits are written immediately or only when pressing "Apply". Defaults to [`EditMode::Immediate`].
+        pub fn with_edit_mode(mut self, edit_mode: EditMode) -> Self {
+            self.window.options.edit_mode = edit_mode;
+            self
+        }
+
+        /// How often immediate edits mark the values as changed. Defaults to [`InspectorUpdateMode::EveryFrame`].
+        pub fn with_update_mode(mut self, update_mode: InspectorUpdateMode) -> Self {
+            self.window.options.update_mode = update_mode;
+            self
+        }
+    };
+    (hud) => {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Only show the UI of the specified condition is active
+        pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+            let condition_system = IntoSystem::into_system(condition);
+            self.window.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+            self
+        }
+
+        /// Only show the UI while the [`State<S>`] is `state`. Like [`run_if`](Self::run_if), this replaces any previous condition.
+        pub fn run_in_state<S: States>(self, state: S) -> Self {
+            self.run_if(in_state(state))
+        }
+
+        /// Set the title of the window. The title is also used as the window's id, so every plugin instance needs a distinct one.
+        pub fn with_title(mut self, title: impl Into<String>) -> Self {
+            self.window.options.title = Some(title.into());
+            self
+        }
+
+        /// Show and hide the window by pressing `key`, in addition to the key toggling all windows in [`InspectorWindows`]
+        pub fn with_hotkey(mut self, key: KeyCode) -> Self {
+            self.window.options.hotkey = Some(key);
+            self
+        }
+    };
+}
+
+/// System set containing the UI systems of all plugins in this module, which run in [`Update`]
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InspectorSet;
+
+/// The window whose [`EguiContext`](bevy_egui::EguiContext) the plugins in this module draw to.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::InspectorEguiContext;
+///
+/// fn draw_to_second_window(mut commands: Commands) {
+///     let window = commands.spawn(Window::default()).id();
+///     commands.insert_resource(InspectorEguiContext { window: Some(window) });
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InspectorEguiContext {
+    /// The window entity, or `None` for the primary window
+    pub window: Option<Entity>,
+}
+
+/// The egui context of the window configured in [`InspectorEguiContext`], or of the primary window by default
+pub(crate) fn inspector_egui_context(world: &mut World) -> Option<EguiContext> {
+    let window = world
+        .get_resource::<InspectorEguiContext>()
+        .and_then(|context| context.window);
+    match window {
+        Some(window) => world.get::<EguiContext>(window).cloned(),
+        None => world
+            .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+            .get_single(world)
+            .ok()
+            .cloned(),
+    }
+}
+
 /// Plugin displaying a egui window with an entity list, resources and assets
 ///
 /// You can use [`WorldInspectorPlugin::run_if`] to control when the window is shown, for example
-/// in combination with `input_toggle_active`.
+/// in combination with `input_toggle_active`, or [`WorldInspectorPlugin::run_in_state`] to only show it in some
+/// development state of your game.
 ///
 /// ```no_run
 /// use bevy::prelude::*;
 /// use bevy_inspector_egui::prelude::*;
-/// use bevy_inspector_egui::quick::WorldInspectorPlugin;
 ///
 /// fn main() {
 ///     App::new()
 ///         .add_plugins(DefaultPlugins)
-///         .add_plugins(WorldInspectorPlugin::new())
+///         .add_plugins(
+///             WorldInspectorPlugin::new()
+///                 .ignore::<ViewVisibility>()
+///                 .with_hotkey(KeyCode::F1),
+///         )
 ///         .run();
 /// }
 /// ```
 #[derive(Default)]
 pub struct WorldInspectorPlugin {
-    condition: Mutex<Option<BoxedCondition>>,
+    window: QuickWindow,
+    tour: Option<Tour>,
+    embedded: bool,
+    ignored_components: Vec<fn(&mut WorldInspectorParams) -> &mut WorldInspectorParams>,
 }
 
 impl WorldInspectorPlugin {
-    pub fn new() -> Self {
-        Self::default()
+    window_builder_methods!(edit_mode);
+
+    /// Show a guided [`Tour`] next to the window until it is dismissed
+    pub fn with_tour(mut self, tour: Tour) -> Self {
+        self.tour = Some(tour);
+        self
+    }
+
+    /// Don't show the component `T` in the list of components of an entity, see [`WorldInspectorParams::ignore_component`]
+    pub fn ignore<T: 'static>(mut self) -> Self {
+        self.ignored_components
+            .push(WorldInspectorParams::ignore_component::<T>);
+        self
     }
 
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+    /// Don't open a window, but only set up the inspector so that [`ui_for_world_inspector`]
+    /// can be called from your own UI, e.g. inside a dock layout.
+    pub fn embedded(mut self) -> Self {
+        self.embedded = true;
         self
     }
 }
 
 impl Plugin for WorldInspectorPlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
+
     fn build(&self, app: &mut bevy_app::App) {
-        check_default_plugins(app, "WorldInspectorPlugin");
+        add_window_plugins(app, "WorldInspectorPlugin");
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
+        let mut params = app
+            .world
+            .get_resource_or_insert_with(WorldInspectorParams::default);
+        for ignore in &self.ignored_components {
+            ignore(&mut params);
         }
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = world_inspector_ui.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
+        if self.embedded {
+            return;
         }
-        app.add_systems(Update, system);
+
+        let tour = self.tour.clone();
+        self.window
+            .add_system(app, "World Inspector", move |world, options, title| {
+                world_inspector_ui(world, options, title, tour.as_ref());
+            });
     }
 }
 
-fn world_inspector_ui(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
+fn world_inspector_ui(
+    world: &mut World,
+    options: &WindowOptions,
+    title: &str,
+    tour: Option<&Tour>,
+) {
+    let configure = |window: egui::Window<'static>| window.default_size(DEFAULT_SIZE);
+    show_window(world, options, title, configure, |world, ui| {
+        world_inspector_contents(world, ui, tour);
+    });
 
-    let Ok(egui_context) = egui_context else {
+    let Some(tour) = tour else {
         return;
     };
-    let mut egui_context = egui_context.clone();
-
-    egui::Window::new("World Inspector")
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
-            egui::ScrollArea::both().show(ui, |ui| {
-                bevy_inspector::ui_for_world(world, ui);
-                ui.allocate_space(ui.available_size());
-            });
+    if InspectorWindows::is_visible_in(world, title) {
+        if let Some(mut egui_context) = inspector_egui_context(world) {
+            tour.show(egui_context.get_mut(), Some(title));
+        }
+    }
+}
+
+/// Display the contents of the [`WorldInspectorPlugin`] window in `ui`, for showing it inside your own layout.
+pub fn ui_for_world_inspector(world: &mut World, ui: &mut egui::Ui) {
+    world_inspector_contents(world, ui, None);
+}
+
+fn world_inspector_contents(world: &mut World, ui: &mut egui::Ui, tour: Option<&Tour>) {
+    InspectorTheme::apply_selected(ui);
+    egui::ScrollArea::both().show(ui, |ui| {
+        egui::CollapsingHeader::new("Settings").show(ui, |ui| {
+            theme::ui_for_theme(ui);
+            if let Some(tour) = tour {
+                tour::ui_for_restart(ui, tour);
+            }
         });
+        egui::CollapsingHeader::new("Notes").show(ui, notes::ui_for_notes);
+        bevy_inspector::ui_for_world(world, ui);
+        ui.allocate_space(ui.available_size());
+    });
 }
 
 /// Plugin displaying an egui window for a single resource.
@@ -124,11 +311,19 @@ fn world_inspector_ui(world: &mut World) {
 ///         .add_plugins(ResourceInspectorPlugin::<Configuration>::default())
 ///         // also works with built-in resources, as long as they implement `Reflect`
 ///         .add_plugins(ResourceInspectorPlugin::<Time>::default())
+///         // the window title, position and initial state can be configured
+///         .add_plugins(
+///             ResourceInspectorPlugin::<Time<Virtual>>::new()
+///                 .with_title("Virtual Time")
+///                 .with_default_pos((400., 0.))
+///                 .with_default_open(false),
+///         )
 ///         .run();
 /// }
 /// ```
 pub struct ResourceInspectorPlugin<T> {
-    condition: Mutex<Option<BoxedCondition>>,
+    window: QuickWindow,
+    ab_toggle_key: Option<KeyCode>,
     marker: PhantomData<fn() -> T>,
 }
 
@@ -136,63 +331,66 @@ impl<T> Default for ResourceInspectorPlugin<T> {
     fn default() -> Self {
         Self {
             marker: PhantomData,
-            condition: Mutex::new(None),
+            window: QuickWindow::default(),
+            ab_toggle_key: None,
         }
     }
 }
 
 impl<T> ResourceInspectorPlugin<T> {
-    pub fn new() -> Self {
-        Self::default()
-    }
+    window_builder_methods!(edit_mode);
 
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+    /// Show controls for storing two sets of values of the resource, and flip between them by pressing `key`.
+    ///
+    /// See [`AbComparison`](bevy_inspector::ab_comparison::AbComparison) for details.
+    pub fn with_ab_toggle(mut self, key: KeyCode) -> Self {
+        self.ab_toggle_key = Some(key);
         self
     }
 }
 
 impl<T: Resource + Reflect> Plugin for ResourceInspectorPlugin<T> {
-    fn build(&self, app: &mut bevy_app::App) {
-        check_default_plugins(app, "ResourceInspectorPlugin");
-
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = inspector_ui::<T>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Update, system);
+    fn is_unique(&self) -> bool {
+        false
     }
-}
 
-fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "ResourceInspectorPlugin");
 
-    let Ok(egui_context) = egui_context else {
-        return;
-    };
-    let mut egui_context = egui_context.clone();
+        if let Some(key) = self.ab_toggle_key {
+            app.init_resource::<AbComparison<T>>().add_systems(
+                PreUpdate,
+                move |world: &mut World| {
+                    let pressed = world
+                        .get_resource::<ButtonInput<KeyCode>>()
+                        .is_some_and(|keys| keys.just_pressed(key));
+                    if pressed {
+                        ab_comparison::toggle_ab_comparison::<T>(world);
+                    }
+                },
+            );
+        }
 
-    egui::Window::new(pretty_type_name::<T>())
-        .default_size((0., 0.))
-        .show(egui_context.get_mut(), |ui| {
-            egui::ScrollArea::both().show(ui, |ui| {
-                bevy_inspector::ui_for_resource::<T>(world, ui);
+        let ab_comparison = self.ab_toggle_key.is_some();
+        self.window.add_system(
+            app,
+            pretty_type_name::<T>(),
+            move |world, options, title| {
+                let configure = |window: egui::Window<'static>| window.default_size((0., 0.));
+                show_window(world, options, title, configure, |world, ui| {
+                    egui::ScrollArea::both().show(ui, |ui| {
+                        if ab_comparison {
+                            ab_comparison::ui_for_ab_comparison::<T>(world, ui);
+                            ui.separator();
+                        }
+                        bevy_inspector::ui_for_resource::<T>(world, ui);
 
-                ui.allocate_space(ui.available_size());
-            });
-        });
+                        ui.allocate_space(ui.available_size());
+                    });
+                });
+            },
+        );
+    }
 }
 
 /// Plugin displaying an egui window for an app state.
@@ -224,70 +422,42 @@ fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct StateInspectorPlugin<T> {
-    condition: Mutex<Option<BoxedCondition>>,
+    window: QuickWindow,
     marker: PhantomData<fn() -> T>,
 }
 
 impl<T> Default for StateInspectorPlugin<T> {
     fn default() -> Self {
         StateInspectorPlugin {
-            condition: Mutex::new(None),
+            window: QuickWindow::default(),
             marker: PhantomData,
         }
     }
 }
 impl<T> StateInspectorPlugin<T> {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
+    window_builder_methods!();
 }
 
 impl<T: States + Reflect> Plugin for StateInspectorPlugin<T> {
-    fn build(&self, app: &mut bevy_app::App) {
-        check_default_plugins(app, "StateInspectorPlugin");
-
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = state_ui::<T>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Update, system);
+    fn is_unique(&self) -> bool {
+        false
     }
-}
 
-fn state_ui<T: States + Reflect>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "StateInspectorPlugin");
 
-    let Ok(egui_context) = egui_context else {
-        return;
-    };
-    let mut egui_context = egui_context.clone();
-
-    egui::Window::new(std::any::type_name::<T>())
-        .resizable(false)
-        .title_bar(false)
-        .show(egui_context.get_mut(), |ui| {
-            egui::ScrollArea::both().show(ui, |ui| {
-                ui.heading(pretty_type_name::<T>());
-                bevy_inspector::ui_for_state::<T>(world, ui);
+        self.window
+            .add_system(app, pretty_type_name::<T>(), |world, options, title| {
+                let configure =
+                    |window: egui::Window<'static>| window.resizable(false).title_bar(false);
+                show_window(world, options, title, configure, |world, ui| {
+                    egui::ScrollArea::both().show(ui, |ui| {
+                        ui.heading(title);
+                        bevy_inspector::ui_for_state::<T>(world, ui);
+                    });
+                });
             });
-        });
+    }
 }
 
 /// Plugin displaying an egui window for all assets of type `A`.
@@ -308,162 +478,1532 @@ fn state_ui<T: States + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct AssetInspectorPlugin<A> {
-    condition: Mutex<Option<BoxedCondition>>,
+    window: QuickWindow,
     marker: PhantomData<fn() -> A>,
 }
 
 impl<A> Default for AssetInspectorPlugin<A> {
     fn default() -> Self {
         Self {
-            condition: Mutex::new(None),
+            window: QuickWindow::default(),
             marker: PhantomData,
         }
     }
 }
 impl<A> AssetInspectorPlugin<A> {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
+    window_builder_methods!();
 }
 
 impl<A: Asset + Reflect> Plugin for AssetInspectorPlugin<A> {
-    fn build(&self, app: &mut bevy_app::App) {
-        check_default_plugins(app, "AssetInspectorPlugin");
+    fn is_unique(&self) -> bool {
+        false
+    }
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "AssetInspectorPlugin");
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = asset_inspector_ui::<A>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Update, system);
+        self.window
+            .add_window(app, pretty_type_name::<A>(), |world, ui| {
+                bevy_inspector::ui_for_assets::<A>(world, ui);
+            });
     }
 }
 
-fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
+/// Plugin displaying an egui window listing the assets of every type registered using
+/// [`App::register_asset_reflect`](bevy_asset::AssetApp::register_asset_reflect), with their load state
+/// and handle counts, see [`ui_for_assets_browser`](bevy_inspector::assets_browser::ui_for_assets_browser).
+/// The "Usage" tab of the window lists leaked and missing assets, see [`ui_for_asset_usage`](bevy_inspector::asset_usage::ui_for_asset_usage).
+///
+/// You can use [`AssetsBrowserPlugin::run_if`] to control when the window is shown, for example
+/// in combination with `input_toggle_active`.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::AssetsBrowserPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(AssetsBrowserPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct AssetsBrowserPlugin {
+    window: QuickWindow,
+}
+
+impl AssetsBrowserPlugin {
+    window_builder_methods!();
+}
 
-    let Ok(egui_context) = egui_context else {
-        return;
-    };
-    let mut egui_context = egui_context.clone();
+impl Plugin for AssetsBrowserPlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
 
-    egui::Window::new(pretty_type_name::<A>())
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
-            egui::ScrollArea::both().show(ui, |ui| {
-                bevy_inspector::ui_for_assets::<A>(world, ui);
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "AssetsBrowserPlugin");
 
-                ui.allocate_space(ui.available_size());
+        let usage_id = egui::Id::new(self.window.title("Assets")).with("usage tab");
+        self.window.add_window(app, "Assets", move |world, ui| {
+            let mut usage = ui.data_mut(|data| data.get_temp::<bool>(usage_id).unwrap_or(false));
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut usage, false, "Browse");
+                ui.selectable_value(&mut usage, true, "Usage");
             });
+            ui.data_mut(|data| data.insert_temp(usage_id, usage));
+            ui.separator();
+            match usage {
+                true => asset_usage::ui_for_asset_usage(world, ui),
+                false => assets_browser::ui_for_assets_browser(world, ui),
+            }
         });
+    }
 }
 
-/// Plugin displaying an egui window for all entities matching the filter `F`.
+/// Plugin displaying an egui window listing the archetypes of the world with their components, entity counts
+/// and estimated memory usage, see [`ui_for_archetypes`](bevy_inspector::archetypes::ui_for_archetypes).
+/// Filtering an archetype in the window limits the [`WorldInspectorPlugin`] to its entities.
+///
+/// You can use [`ArchetypesInspectorPlugin::run_if`] to control when the window is shown, for example
+/// in combination with `input_toggle_active`.
+///
 /// ```no_run
 /// use bevy::prelude::*;
-/// use bevy_inspector_egui::quick::FilterQueryInspectorPlugin;
+/// use bevy_inspector_egui::quick::ArchetypesInspectorPlugin;
 ///
 /// fn main() {
 ///     App::new()
 ///         .add_plugins(DefaultPlugins)
-///         .add_plugins(FilterQueryInspectorPlugin::<With<Transform>>::default())
+///         .add_plugins(ArchetypesInspectorPlugin::new())
 ///         .run();
 /// }
 /// ```
-pub struct FilterQueryInspectorPlugin<F> {
-    condition: Mutex<Option<BoxedCondition>>,
-    marker: PhantomData<fn() -> F>,
+#[derive(Default)]
+pub struct ArchetypesInspectorPlugin {
+    window: QuickWindow,
 }
 
-impl<F> Default for FilterQueryInspectorPlugin<F> {
-    fn default() -> Self {
-        Self {
-            condition: Mutex::new(None),
-            marker: PhantomData,
-        }
+impl ArchetypesInspectorPlugin {
+    window_builder_methods!();
+}
+
+impl Plugin for ArchetypesInspectorPlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "ArchetypesInspectorPlugin");
+
+        self.window.add_window(app, "Archetypes", |world, ui| {
+            archetypes::ui_for_archetypes(world, ui);
+        });
     }
 }
-impl<A> FilterQueryInspectorPlugin<A> {
-    pub fn new() -> Self {
-        Self::default()
+
+/// Plugin displaying an egui window listing the systems of every schedule, see [`ui_for_systems`](bevy_inspector::systems::ui_for_systems).
+/// Systems added with [`toggleable`](bevy_inspector::systems::toggleable) can be disabled from the window.
+///
+/// You can use [`SystemsInspectorPlugin::run_if`] to control when the window is shown, for example
+/// in combination with `input_toggle_active`.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::SystemsInspectorPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(SystemsInspectorPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct SystemsInspectorPlugin {
+    window: QuickWindow,
+}
+
+impl SystemsInspectorPlugin {
+    window_builder_methods!();
+}
+
+impl Plugin for SystemsInspectorPlugin {
+    fn is_unique(&self) -> bool {
+        false
     }
 
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "SystemsInspectorPlugin");
+
+        app.init_resource::<systems::ScheduleOverview>()
+            .init_resource::<systems::SystemToggles>()
+            // every schedule is missing from `Schedules` while it runs, so capture them from two schedules
+            .add_systems(First, systems::update_schedule_overview)
+            .add_systems(Last, systems::update_schedule_overview);
+
+        self.window.add_window(app, "Systems", |world, ui| {
+            systems::ui_for_systems(world, ui);
+        });
     }
 }
 
-impl<F: 'static> Plugin for FilterQueryInspectorPlugin<F>
-where
-    F: QueryFilter,
-{
+/// Plugin displaying an egui window with the pressed keys, the cursor position and mouse buttons, and the sticks and
+/// buttons of every gamepad, see [`ui_for_input_state`](bevy_inspector::input_state::ui_for_input_state).
+///
+/// You can use [`InputStateInspectorPlugin::run_if`] to control when the window is shown, for example
+/// in combination with `input_toggle_active`.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::InputStateInspectorPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(InputStateInspectorPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct InputStateInspectorPlugin {
+    window: QuickWindow,
+}
+
+impl InputStateInspectorPlugin {
+    window_builder_methods!();
+}
+
+impl Plugin for InputStateInspectorPlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
+
     fn build(&self, app: &mut bevy_app::App) {
-        check_default_plugins(app, "FilterQueryInspectorPlugin");
+        add_window_plugins(app, "InputStateInspectorPlugin");
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
+        app.init_resource::<input_state::RecentInputs>()
+            .add_systems(
+                PreUpdate,
+                input_state::record_recent_inputs.after(InputSystem),
+            );
 
-        let condition: Option<Box<dyn ReadOnlySystem<In = (), Out = bool>>> =
-            self.condition.lock().unwrap().take();
-        let mut system = entity_query_ui::<F>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Update, system);
+        self.window.add_window(app, "Input", |world, ui| {
+            input_state::ui_for_input_state(world, ui);
+        });
     }
 }
 
-fn entity_query_ui<F: QueryFilter>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
+/// Plugin displaying an egui window with the threads of Bevy's task pools and the background tasks listed in the
+/// [`TaskProgress`](bevy_inspector::tasks::TaskProgress) resource with their progress, see [`ui_for_tasks`](bevy_inspector::tasks::ui_for_tasks).
+///
+/// You can use [`TasksInspectorPlugin::run_if`] to control when the window is shown, for example
+/// in combination with `input_toggle_active`.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::TasksInspectorPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(TasksInspectorPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct TasksInspectorPlugin {
+    window: QuickWindow,
+}
+
+impl TasksInspectorPlugin {
+    window_builder_methods!();
+}
 
-    let Ok(egui_context) = egui_context else {
-        return;
-    };
-    let mut egui_context = egui_context.clone();
+impl Plugin for TasksInspectorPlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
 
-    egui::Window::new(pretty_type_name::<F>())
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
-            egui::ScrollArea::both().show(ui, |ui| {
-                bevy_inspector::ui_for_world_entities_filtered::<F>(world, ui, false);
-                ui.allocate_space(ui.available_size());
-            });
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "TasksInspectorPlugin");
+
+        app.init_resource::<tasks::TaskProgress>();
+
+        self.window.add_window(app, "Tasks", |world, ui| {
+            tasks::ui_for_tasks(world, ui);
         });
+    }
 }
 
-fn check_default_plugins(app: &bevy_app::App, name: &str) {
-    if !app.is_plugin_added::<TypeRegistrationPlugin>() {
-        panic!(
-            r#"`{name}` should be added after the default plugins:
-        .add_plugins(DefaultPlugins)
-        .add_plugins({name}::default())
-            "#,
-            name = name,
-        );
+/// Plugin displaying an egui window for pausing, single-stepping and slowing down [`Time<Virtual>`](bevy_time::Virtual),
+/// see [`ui_for_time_controls`](bevy_inspector::time_controls::ui_for_time_controls).
+///
+/// You can use [`TimeControlsPlugin::run_if`] to control when the window is shown, for example
+/// in combination with `input_toggle_active`.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::TimeControlsPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(TimeControlsPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct TimeControlsPlugin {
+    window: QuickWindow,
+}
+
+impl TimeControlsPlugin {
+    window_builder_methods!();
+}
+
+impl Plugin for TimeControlsPlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "TimeControlsPlugin");
+
+        // shared by all instances of the plugin, so that a step isn't applied twice
+        if !app.world.contains_resource::<time_controls::TimeControls>() {
+            app.init_resource::<time_controls::TimeControls>()
+                .add_systems(
+                    First,
+                    (
+                        time_controls::step_virtual_time.before(TimeSystem),
+                        time_controls::finish_virtual_time_step.after(TimeSystem),
+                    )
+                        .run_if(resource_exists::<Time<Virtual>>),
+                );
+        }
+
+        self.window.add_window(app, "Time", |world, ui| {
+            time_controls::ui_for_time_controls(world, ui);
+        });
+    }
+}
+
+/// Plugin displaying an egui window with a console, in which resources and components can be read and assigned by their path,
+/// see [`execute`](bevy_inspector::console::execute).
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::InspectorConsolePlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(InspectorConsolePlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct InspectorConsolePlugin {
+    window: QuickWindow,
+}
+
+impl InspectorConsolePlugin {
+    window_builder_methods!();
+}
+
+impl Plugin for InspectorConsolePlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "InspectorConsolePlugin");
+
+        self.window
+            .add_system(app, "Console", |world, options, title| {
+                let configure = |window: egui::Window<'static>| window.default_size(DEFAULT_SIZE);
+                show_window(world, options, title, configure, console::ui_for_console);
+            });
+    }
+}
+
+/// Plugin displaying a compact egui window with the components and fields pinned by right-clicking them in the world inspector,
+/// see [`ui_for_pins`](bevy_inspector::pins::ui_for_pins).
+///
+/// The window is only shown while something is pinned, and sits in the top-right corner unless a position is set.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{PinnedInspectorPlugin, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(WorldInspectorPlugin::new())
+///         .add_plugins(PinnedInspectorPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct PinnedInspectorPlugin {
+    window: QuickWindow,
+}
+
+impl PinnedInspectorPlugin {
+    window_builder_methods!(hud);
+
+    /// Set the position the window is shown at initially, instead of the top-right corner
+    pub fn with_default_pos(mut self, pos: impl Into<egui::Pos2>) -> Self {
+        self.window.options.default_pos = Some(pos.into());
+        self
+    }
+}
+
+impl Plugin for PinnedInspectorPlugin {
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "PinnedInspectorPlugin");
+
+        self.window.add_system(app, "Pinned", pins_ui);
+    }
+}
+
+fn pins_ui(world: &mut World, options: &WindowOptions, title: &str) {
+    let has_pins = inspector_egui_context(world)
+        .is_some_and(|mut egui_context| !pins::pins(egui_context.get_mut()).is_empty());
+    if !has_pins {
+        return;
+    }
+
+    let configure = |window: egui::Window<'static>| {
+        let hud = window.title_bar(false).resizable(false);
+        match options.default_pos {
+            Some(_) => hud,
+            None => hud.anchor(egui::Align2::RIGHT_TOP, [-8.0, 8.0]),
+        }
+    };
+    show_window(world, options, title, configure, pins::ui_for_pins);
+}
+
+/// Plugin displaying an egui window for all entities matching the filter `F`.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::FilterQueryInspectorPlugin;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(FilterQueryInspectorPlugin::<With<Transform>>::default())
+///         .run();
+/// }
+/// ```
+pub struct FilterQueryInspectorPlugin<F> {
+    window: QuickWindow,
+    marker: PhantomData<fn() -> F>,
+}
+
+impl<F> Default for FilterQueryInspectorPlugin<F> {
+    fn default() -> Self {
+        Self {
+            window: QuickWindow::default(),
+            marker: PhantomData,
+        }
+    }
+}
+impl<A> FilterQueryInspectorPlugin<A> {
+    window_builder_methods!(edit_mode);
+}
+
+impl<F: 'static> Plugin for FilterQueryInspectorPlugin<F>
+where
+    F: QueryFilter,
+{
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "FilterQueryInspectorPlugin");
+
+        self.window
+            .add_window(app, pretty_type_name::<F>(), |world, ui| {
+                bevy_inspector::ui_for_world_entities_filtered::<F>(world, ui, false);
+            });
+    }
+}
+
+/// Plugin displaying an egui window for all entities matching the query data `Q` and the filter `F`,
+/// with only the components accessed by `Q`, see [`ui_for_query`](bevy_inspector::ui_for_query).
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::QueryInspectorPlugin;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(QueryInspectorPlugin::<(&mut Transform, &Name), With<Player>>::default())
+///         .run();
+/// }
+/// ```
+pub struct QueryInspectorPlugin<Q, F = ()> {
+    window: QuickWindow,
+    marker: PhantomData<fn() -> (Q, F)>,
+}
+
+impl<Q, F> Default for QueryInspectorPlugin<Q, F> {
+    fn default() -> Self {
+        Self {
+            window: QuickWindow::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Q, F> QueryInspectorPlugin<Q, F> {
+    window_builder_methods!(edit_mode);
+}
+
+impl<Q: 'static, F: 'static> Plugin for QueryInspectorPlugin<Q, F>
+where
+    Q: QueryData,
+    F: QueryFilter,
+{
+    fn is_unique(&self) -> bool {
+        false
+    }
+
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "QueryInspectorPlugin");
+
+        self.window
+            .add_window(app, query_title::<Q, F>(), |world, ui| {
+                bevy_inspector::ui_for_query::<Q, F>(world, ui);
+            });
+    }
+}
+
+/// `Query<Q, F>`, leaving out an empty filter
+fn query_title<Q: 'static, F: 'static>() -> String {
+    match std::any::TypeId::of::<F>() == std::any::TypeId::of::<()>() {
+        true => format!("Query<{}>", pretty_type_name::<Q>()),
+        false => format!(
+            "Query<{}, {}>",
+            pretty_type_name::<Q>(),
+            pretty_type_name::<F>()
+        ),
+    }
+}
+
+/// Time spent drawing each window of the plugins in this module, to find out which ones are expensive.
+///
+/// Measuring only happens while [`InspectorProfiler::enabled`] is set.
+/// Use [`InspectorProfilerPlugin`] to display the timings, and [`InspectorProfiler::record`] to add your own sections.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct InspectorProfiler {
+    pub enabled: bool,
+    timings: BTreeMap<String, InspectorTiming>,
+}
+
+/// Duration of a single window or section
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InspectorTiming {
+    /// Duration in the last frame it was drawn
+    pub last: Duration,
+    /// Exponential moving average over the recent frames
+    pub average: Duration,
+}
+
+impl InspectorProfiler {
+    /// Record the time it took to draw `name` this frame
+    pub fn record(&mut self, name: &str, duration: Duration) {
+        match self.timings.get_mut(name) {
+            Some(timing) => {
+                timing.last = duration;
+                timing.average = timing.average.mul_f32(0.9) + duration.mul_f32(0.1);
+            }
+            None => {
+                let timing = InspectorTiming {
+                    last: duration,
+                    average: duration,
+                };
+                self.timings.insert(name.to_owned(), timing);
+            }
+        }
+    }
+
+    pub fn timings(&self) -> impl Iterator<Item = (&str, &InspectorTiming)> {
+        self.timings
+            .iter()
+            .map(|(name, timing)| (name.as_str(), timing))
+    }
+
+    pub fn clear(&mut self) {
+        self.timings.clear();
+    }
+
+    fn record_in(world: &mut World, name: &str, duration: Duration) {
+        if let Some(mut profiler) = world.get_resource_mut::<InspectorProfiler>() {
+            if profiler.enabled {
+                profiler.record(name, duration);
+            }
+        }
+    }
+}
+
+/// Plugin displaying a window with the timings measured by the [`InspectorProfiler`].
+///
+/// Profiling is enabled when adding this plugin and can be paused from the window.
+#[derive(Default)]
+pub struct InspectorProfilerPlugin;
+
+impl Plugin for InspectorProfilerPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        if !app.is_plugin_added::<InspectorWindowsPlugin>() {
+            app.add_plugins(InspectorWindowsPlugin);
+        }
+
+        app.insert_resource(InspectorProfiler {
+            enabled: true,
+            ..Default::default()
+        })
+        .add_systems(Update, inspector_profiler_ui.in_set(InspectorSet));
+    }
+}
+
+fn inspector_profiler_ui(world: &mut World) {
+    let configure = |window: egui::Window<'static>| window.default_size(DEFAULT_SIZE);
+    let options = WindowOptions::default();
+    show_window(
+        world,
+        &options,
+        "Inspector Profiler",
+        configure,
+        |world, ui| {
+            let mut profiler = world.resource_mut::<InspectorProfiler>();
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut profiler.enabled, "Enabled");
+                if ui.button("Clear").clicked() {
+                    profiler.clear();
+                }
+            });
+
+            let mut timings: Vec<_> = profiler.timings().collect();
+            timings.sort_by_key(|(_, timing)| std::cmp::Reverse(timing.average));
+            let max = timings.first().map_or(Duration::ZERO, |(_, t)| t.average);
+
+            egui::Grid::new("inspector profiler")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Window");
+                    ui.strong("Last");
+                    ui.strong("Average");
+                    ui.end_row();
+                    for (name, timing) in timings {
+                        ui.label(name);
+                        ui.label(format!("{:.2} ms", timing.last.as_secs_f64() * 1000.0));
+                        let fraction =
+                            timing.average.as_secs_f32() / max.as_secs_f32().max(f32::EPSILON);
+                        ui.add(
+                            egui::ProgressBar::new(fraction)
+                                .desired_width(100.0)
+                                .text(format!("{:.2} ms", timing.average.as_secs_f64() * 1000.0)),
+                        );
+                        ui.end_row();
+                    }
+                });
+        },
+    );
+}
+
+/// Plugin displaying a window with a plot of every [`Diagnostic`](bevy_diagnostic::Diagnostic) in the [`DiagnosticsStore`].
+///
+/// Adds the [`FrameTimeDiagnosticsPlugin`] and [`EntityCountDiagnosticsPlugin`] if they are missing,
+/// custom diagnostics registered by the app are shown as well.
+/// The recorded values are kept in the [`DiagnosticsHistory`] resource, which can be paused from the window.
+pub struct DiagnosticsInspectorPlugin {
+    window: QuickWindow,
+    history_length: usize,
+}
+
+impl Default for DiagnosticsInspectorPlugin {
+    fn default() -> Self {
+        Self {
+            window: QuickWindow::default(),
+            history_length: bevy_diagnostic::DEFAULT_MAX_HISTORY_LENGTH,
+        }
+    }
+}
+
+impl DiagnosticsInspectorPlugin {
+    window_builder_methods!();
+
+    /// Set how many measurements of each diagnostic are plotted. Defaults to [`DEFAULT_MAX_HISTORY_LENGTH`](bevy_diagnostic::DEFAULT_MAX_HISTORY_LENGTH).
+    pub fn with_history_length(mut self, history_length: usize) -> Self {
+        self.history_length = history_length;
+        self
+    }
+}
+
+impl Plugin for DiagnosticsInspectorPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "DiagnosticsInspectorPlugin");
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+
+        app.insert_resource(DiagnosticsHistory::new(self.history_length))
+            .add_systems(Last, record_diagnostics);
+
+        self.window
+            .add_system(app, "Diagnostics", |world, options, title| {
+                let configure = |window: egui::Window<'static>| window.default_size(DEFAULT_SIZE);
+                show_window(world, options, title, configure, ui_for_diagnostics);
+            });
+    }
+}
+
+/// Display the contents of the [`DiagnosticsInspectorPlugin`] window in `ui`
+pub fn ui_for_diagnostics(world: &mut World, ui: &mut egui::Ui) {
+    if !world.contains_resource::<DiagnosticsHistory>() {
+        world.insert_resource(DiagnosticsHistory::new(
+            bevy_diagnostic::DEFAULT_MAX_HISTORY_LENGTH,
+        ));
+    }
+    world.resource_scope(|world, mut history: Mut<DiagnosticsHistory>| {
+        let Some(store) = world.get_resource::<DiagnosticsStore>() else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut history.paused, "Paused");
+            if ui.button("Clear").clicked() {
+                history.clear();
+            }
+        });
+
+        let mut diagnostics: Vec<_> = store
+            .iter()
+            .filter(|diagnostic| diagnostic.is_enabled)
+            .collect();
+        diagnostics.sort_by_key(|diagnostic| diagnostic.path().as_str());
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("diagnostics inspector")
+                .striped(true)
+                .show(ui, |ui| {
+                    for diagnostic in diagnostics {
+                        let values: Vec<f64> = history.values(diagnostic.path()).collect();
+                        let suffix = &diagnostic.suffix;
+                        ui.label(diagnostic.path().as_str());
+                        match diagnostic.smoothed() {
+                            Some(value) => ui.label(format!("{value:.2}{suffix}")),
+                            None => ui.weak("-"),
+                        };
+                        egui_utils::sparkline(ui, &values).on_hover_ui(|ui| {
+                            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+                            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                            if min <= max {
+                                ui.label(format!("min {min:.2}{suffix}"));
+                                ui.label(format!("max {max:.2}{suffix}"));
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+    });
+}
+
+/// The recent measurements of every diagnostic, recorded by the [`DiagnosticsInspectorPlugin`]
+#[derive(Resource, Debug, Clone)]
+pub struct DiagnosticsHistory {
+    /// Stop recording new measurements, so that the plots can be looked at in detail
+    pub paused: bool,
+    /// Number of measurements kept per diagnostic
+    pub history_length: usize,
+    histories: BTreeMap<String, DiagnosticHistory>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct DiagnosticHistory {
+    values: VecDeque<f64>,
+    last_measurement: Option<Instant>,
+}
+
+impl DiagnosticsHistory {
+    pub fn new(history_length: usize) -> Self {
+        DiagnosticsHistory {
+            paused: false,
+            history_length,
+            histories: BTreeMap::new(),
+        }
+    }
+
+    /// The recorded values of the diagnostic at `path`, oldest first
+    pub fn values(&self, path: &DiagnosticPath) -> impl Iterator<Item = f64> + '_ {
+        self.histories
+            .get(path.as_str())
+            .into_iter()
+            .flat_map(|history| history.values.iter().copied())
+    }
+
+    pub fn clear(&mut self) {
+        self.histories.clear();
+    }
+
+    /// Append the latest measurement of every enabled diagnostic in `store` which wasn't recorded yet
+    pub fn record(&mut self, store: &DiagnosticsStore) {
+        for diagnostic in store.iter().filter(|diagnostic| diagnostic.is_enabled) {
+            let Some(measurement) = diagnostic.measurement() else {
+                continue;
+            };
+            let history = self
+                .histories
+                .entry(diagnostic.path().as_str().to_owned())
+                .or_default();
+            if history.last_measurement == Some(measurement.time) {
+                continue;
+            }
+            history.last_measurement = Some(measurement.time);
+            history.values.push_back(measurement.value);
+            while history.values.len() > self.history_length {
+                history.values.pop_front();
+            }
+        }
+    }
+}
+
+pub(crate) fn record_diagnostics(
+    store: Res<DiagnosticsStore>,
+    mut history: ResMut<DiagnosticsHistory>,
+) {
+    if !history.paused {
+        history.record(&store);
+    }
+}
+
+/// Extension trait for logging events in the [`EventLog`], to be displayed by the [`EventInspectorPlugin`]
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{EventInspectorPlugin, RegisterInspectableEvent};
+///
+/// #[derive(Event, Reflect, Clone)]
+/// struct Damage {
+///     target: Entity,
+///     amount: f32,
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(EventInspectorPlugin::default())
+///         .register_inspectable_event::<Damage>()
+///         .run();
+/// }
+/// ```
+pub trait RegisterInspectableEvent {
+    /// Add the event `E` and log every `E` sent from now on
+    fn register_inspectable_event<E: Event + Reflect + TypePath + GetTypeRegistration + Clone>(
+        &mut self,
+    ) -> &mut Self;
+}
+
+impl RegisterInspectableEvent for bevy_app::App {
+    fn register_inspectable_event<E: Event + Reflect + TypePath + GetTypeRegistration + Clone>(
+        &mut self,
+    ) -> &mut Self {
+        self.add_event::<E>()
+            .register_type::<E>()
+            .init_resource::<EventLog>()
+            .add_systems(Last, log_events::<E>);
+        self.world
+            .resource_mut::<EventLog>()
+            .types
+            .insert(E::short_type_path());
+        self
+    }
+}
+
+/// Rolling log of the events registered using [`RegisterInspectableEvent::register_inspectable_event`]
+#[derive(Resource, Debug)]
+pub struct EventLog {
+    /// Stop logging events, so that the logged ones can be looked at in detail
+    pub paused: bool,
+    /// Maximum number of logged events, older ones are dropped first
+    pub capacity: usize,
+    events: VecDeque<LoggedEvent>,
+    next_id: u64,
+    types: BTreeSet<&'static str>,
+    hidden: HashSet<&'static str>,
+}
+
+/// A single event in the [`EventLog`]
+#[derive(Debug)]
+pub struct LoggedEvent {
+    id: u64,
+    /// Time since startup at which the event was read
+    pub time: Duration,
+    /// Short type path of the event type
+    pub type_name: &'static str,
+    pub event: Box<dyn Reflect>,
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        EventLog {
+            paused: false,
+            capacity: 200,
+            events: VecDeque::new(),
+            next_id: 0,
+            types: BTreeSet::new(),
+            hidden: HashSet::new(),
+        }
+    }
+}
+
+impl EventLog {
+    /// Add `event` to the log, dropping the oldest event if the log is full
+    pub fn push(&mut self, time: Duration, type_name: &'static str, event: Box<dyn Reflect>) {
+        self.events.push_back(LoggedEvent {
+            id: self.next_id,
+            time,
+            type_name,
+            event,
+        });
+        self.next_id += 1;
+        while self.events.len() > self.capacity {
+            self.events.pop_front();
+        }
+    }
+
+    /// The logged events, oldest first
+    pub fn events(&self) -> impl DoubleEndedIterator<Item = &LoggedEvent> {
+        self.events.iter()
+    }
+
+    /// The short type paths of all registered event types
+    pub fn types(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.types.iter().copied()
+    }
+
+    /// Whether the events of type `type_name` are shown in the [`EventInspectorPlugin`] window
+    pub fn is_shown(&self, type_name: &str) -> bool {
+        !self.hidden.contains(type_name)
+    }
+
+    pub fn set_shown(&mut self, type_name: &'static str, shown: bool) {
+        if shown {
+            self.hidden.remove(type_name);
+        } else {
+            self.hidden.insert(type_name);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+fn log_events<E: Event + Reflect + TypePath + Clone>(
+    mut reader: EventReader<E>,
+    time: Option<Res<Time<Real>>>,
+    mut log: ResMut<EventLog>,
+) {
+    if log.paused {
+        reader.clear();
+        return;
+    }
+    let time = time.map_or(Duration::ZERO, |time| time.elapsed());
+    for event in reader.read() {
+        log.push(time, E::short_type_path(), Box::new(event.clone()));
+    }
+}
+
+/// Plugin displaying a window with the [`EventLog`] of events registered using [`RegisterInspectableEvent::register_inspectable_event`].
+///
+/// Events are shown newest first, with a filter per event type and a button for pausing the log.
+pub struct EventInspectorPlugin {
+    window: QuickWindow,
+    capacity: usize,
+}
+
+impl Default for EventInspectorPlugin {
+    fn default() -> Self {
+        Self {
+            window: QuickWindow::default(),
+            capacity: EventLog::default().capacity,
+        }
+    }
+}
+
+impl EventInspectorPlugin {
+    window_builder_methods!();
+
+    /// Set how many events are kept in the [`EventLog`]. Defaults to 200.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+}
+
+impl Plugin for EventInspectorPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        add_window_plugins(app, "EventInspectorPlugin");
+
+        app.init_resource::<EventLog>();
+        app.world.resource_mut::<EventLog>().capacity = self.capacity;
+
+        self.window
+            .add_system(app, "Events", |world, options, title| {
+                let configure = |window: egui::Window<'static>| window.default_size(DEFAULT_SIZE);
+                show_window(world, options, title, configure, event_log_ui);
+            });
+    }
+}
+
+fn event_log_ui(world: &mut World, ui: &mut egui::Ui) {
+    world.resource_scope(|world, mut log: Mut<EventLog>| {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut log.paused, "Paused");
+            if ui.button("Clear").clicked() {
+                log.clear();
+            }
+        });
+
+        egui::CollapsingHeader::new("Filter")
+            .id_source("event inspector filter")
+            .show(ui, |ui| {
+                let types: Vec<_> = log.types().collect();
+                for type_name in types {
+                    let mut shown = log.is_shown(type_name);
+                    if ui.checkbox(&mut shown, type_name).changed() {
+                        log.set_shown(type_name, shown);
+                    }
+                }
+            });
+        ui.separator();
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            let events = log
+                .events()
+                .rev()
+                .filter(|event| log.is_shown(event.type_name));
+            for event in events {
+                let header = format!("{:.3}s {}", event.time.as_secs_f64(), event.type_name);
+                egui::CollapsingHeader::new(header)
+                    .id_source(("logged event", event.id))
+                    .show(ui, |ui| {
+                        crate::reflect_inspector::ui_for_value_readonly(
+                            event.event.as_ref(),
+                            ui,
+                            &type_registry,
+                        );
+                    });
+            }
+        });
+    });
+}
+
+/// Controls which windows of the plugins in this module are visible.
+///
+/// Windows are identified by their title. By default, pressing [`KeyCode::F12`] toggles all windows at once.
+/// Insert this resource before adding the plugins to change the key binding or start out hidden:
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{InspectorWindows, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .insert_resource(InspectorWindows::hidden().with_toggle_key(Some(KeyCode::Backquote)))
+///         .add_plugins(WorldInspectorPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct InspectorWindows {
+    all_visible: bool,
+    /// Windows hidden using [`InspectorWindows::hide`], which stay hidden when all windows are shown
+    hidden: HashSet<String>,
+    /// Windows shown using [`InspectorWindows::show`] while all windows are hidden
+    shown: HashSet<String>,
+    toggle_key: Option<KeyCode>,
+    /// Toggles not yet sent as [`InspectorEvent::WindowToggled`]
+    toggled: Vec<(Option<String>, bool)>,
+}
+
+impl Default for InspectorWindows {
+    fn default() -> Self {
+        InspectorWindows {
+            all_visible: true,
+            hidden: HashSet::new(),
+            shown: HashSet::new(),
+            toggle_key: Some(KeyCode::F12),
+            toggled: Vec::new(),
+        }
+    }
+}
+
+impl InspectorWindows {
+    /// All windows start out hidden, until they are shown one by one or all at once
+    pub fn hidden() -> Self {
+        InspectorWindows {
+            all_visible: false,
+            ..Default::default()
+        }
+    }
+
+    /// Set the key toggling all windows, or `None` to disable the key binding
+    pub fn with_toggle_key(mut self, key: Option<KeyCode>) -> Self {
+        self.toggle_key = key;
+        self
+    }
+
+    /// The key toggling all windows, [`KeyCode::F12`] by default
+    pub fn toggle_key(&self) -> Option<KeyCode> {
+        self.toggle_key
+    }
+
+    /// Whether the window with the given title is shown
+    pub fn is_visible(&self, title: &str) -> bool {
+        match self.all_visible {
+            true => !self.hidden.contains(title),
+            false => self.shown.contains(title),
+        }
+    }
+
+    /// Show the window with the given title, also while all other windows are hidden
+    pub fn show(&mut self, title: &str) {
+        let was_visible = self.is_visible(title);
+        self.hidden.remove(title);
+        if !self.all_visible {
+            self.shown.insert(title.to_owned());
+        }
+        if !was_visible {
+            self.toggled.push((Some(title.to_owned()), true));
+        }
+    }
+
+    /// Hide the window with the given title. It stays hidden when all windows are shown, until it is shown itself.
+    pub fn hide(&mut self, title: &str) {
+        let was_visible = self.is_visible(title);
+        self.shown.remove(title);
+        self.hidden.insert(title.to_owned());
+        if was_visible {
+            self.toggled.push((Some(title.to_owned()), false));
+        }
+    }
+
+    /// [`show`](Self::show) the window with the given title if it is hidden, or [`hide`](Self::hide) it otherwise
+    pub fn toggle(&mut self, title: &str) {
+        if self.is_visible(title) {
+            self.hide(title);
+        } else {
+            self.show(title);
+        }
+    }
+
+    /// Whether windows are shown at all. Windows hidden using [`InspectorWindows::hide`] stay hidden either way,
+    /// windows shown using [`InspectorWindows::show`] are visible either way.
+    pub fn all_visible(&self) -> bool {
+        self.all_visible
+    }
+
+    /// Show all windows, except for the ones hidden using [`InspectorWindows::hide`]
+    pub fn show_all(&mut self) {
+        self.set_all_visible(true);
+    }
+
+    /// Hide all windows, including the ones shown using [`InspectorWindows::show`]
+    pub fn hide_all(&mut self) {
+        self.set_all_visible(false);
+    }
+
+    /// [`show_all`](Self::show_all) if windows are hidden, or [`hide_all`](Self::hide_all) otherwise.
+    /// This is what pressing the [`toggle_key`](Self::toggle_key) does.
+    pub fn toggle_all(&mut self) {
+        self.set_all_visible(!self.all_visible);
+    }
+
+    fn set_all_visible(&mut self, visible: bool) {
+        if self.all_visible != visible {
+            self.all_visible = visible;
+            self.shown.clear();
+            self.toggled.push((None, visible));
+        }
+    }
+
+    fn is_visible_in(world: &World, title: &str) -> bool {
+        world
+            .get_resource::<InspectorWindows>()
+            .map_or(true, |windows| windows.is_visible(title))
+    }
+}
+
+pub(crate) struct InspectorWindowsPlugin;
+
+impl Plugin for InspectorWindowsPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.init_resource::<InspectorWindows>()
+            .init_resource::<theme::InspectorStyle>()
+            .add_systems(
+                PreUpdate,
+                (
+                    (toggle_inspector_windows, send_window_toggled_events).chain(),
+                    theme::sync_inspector_style,
+                ),
+            );
+    }
+}
+
+fn toggle_inspector_windows(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut windows: ResMut<InspectorWindows>,
+) {
+    let (Some(keys), Some(key)) = (keys, windows.toggle_key) else {
+        return;
+    };
+    if keys.just_pressed(key) {
+        windows.toggle_all();
+    }
+}
+
+fn send_window_toggled_events(
+    mut windows: ResMut<InspectorWindows>,
+    events: Option<ResMut<Events<InspectorEvent>>>,
+) {
+    if windows.toggled.is_empty() {
+        return;
+    }
+    let toggled = std::mem::take(&mut windows.bypass_change_detection().toggled);
+    if let Some(mut events) = events {
+        events.send_batch(
+            toggled
+                .into_iter()
+                .map(|(title, visible)| InspectorEvent::WindowToggled { title, visible }),
+        );
+    }
+}
+
+/// The run condition and window settings of a plugin in this module, set by its builder methods
+#[derive(Default)]
+struct QuickWindow {
+    condition: Mutex<Option<BoxedCondition>>,
+    options: WindowOptions,
+}
+
+impl QuickWindow {
+    fn title(&self, default_title: impl Into<String>) -> String {
+        self.options.title(default_title)
+    }
+
+    /// Run `ui` in the [`InspectorSet`] under the run condition, passing the window settings and title,
+    /// and toggle the window with its hotkey
+    fn add_system(
+        &self,
+        app: &mut bevy_app::App,
+        default_title: impl Into<String>,
+        ui: impl Fn(&mut World, &WindowOptions, &str) + Send + Sync + 'static,
+    ) {
+        let title = self.title(default_title);
+        self.options.add_hotkey(app, title.clone());
+        let options = self.options.clone();
+        let mut system = (move |world: &mut World| ui(world, &options, &title)).into_configs();
+        if let Some(condition) = self.condition.lock().unwrap().take() {
+            system.run_if_dyn(condition);
+        }
+        app.add_systems(Update, system.in_set(InspectorSet));
+    }
+
+    /// Like [`add_system`](Self::add_system), drawing a scrollable window of the default size with `contents`
+    fn add_window(
+        &self,
+        app: &mut bevy_app::App,
+        default_title: impl Into<String>,
+        contents: impl Fn(&mut World, &mut egui::Ui) + Send + Sync + 'static,
+    ) {
+        self.add_system(app, default_title, move |world, options, title| {
+            let configure = |window: egui::Window<'static>| window.default_size(DEFAULT_SIZE);
+            show_window(world, options, title, configure, |world, ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    contents(world, ui);
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+        });
+    }
+}
+
+/// Draw the window `title` with `contents` if it is visible in [`InspectorWindows`], applying the selected theme and
+/// the lock of the window, and record the time it took in the [`InspectorProfiler`].
+///
+/// `configure` can change the window built from the `options`, e.g. its size.
+fn show_window(
+    world: &mut World,
+    options: &WindowOptions,
+    title: &str,
+    configure: impl FnOnce(egui::Window<'static>) -> egui::Window<'static>,
+    contents: impl FnOnce(&mut World, &mut egui::Ui),
+) {
+    if !InspectorWindows::is_visible_in(world, title) {
+        return;
+    }
+    let start = Instant::now();
+
+    let Some(mut egui_context) = inspector_egui_context(world) else {
+        return;
+    };
+    let lock = locks::window_lock_reason(world, title);
+
+    configure(options.window(title.to_owned(), egui_context.get_mut())).show(
+        egui_context.get_mut(),
+        |ui| {
+            InspectorTheme::apply_selected(ui);
+            locks::ui_locked(ui, lock.as_deref(), |ui| contents(world, ui));
+        },
+    );
+
+    InspectorProfiler::record_in(world, title, start.elapsed());
+}
+
+/// Add the plugins every window plugin of this module depends on
+fn add_window_plugins(app: &mut bevy_app::App, plugin_name: &str) {
+    check_default_plugins(app, plugin_name);
+
+    if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+        app.add_plugins(DefaultInspectorConfigPlugin);
+    }
+    if !app.is_plugin_added::<EguiPlugin>() {
+        app.add_plugins(EguiPlugin);
+    }
+    if !app.is_plugin_added::<InspectorWindowsPlugin>() {
+        app.add_plugins(InspectorWindowsPlugin);
+    }
+}
+
+#[derive(Clone)]
+struct WindowOptions {
+    title: Option<String>,
+    default_pos: Option<egui::Pos2>,
+    default_open: bool,
+    edit_mode: EditMode,
+    update_mode: InspectorUpdateMode,
+    hotkey: Option<KeyCode>,
+}
+
+impl Default for WindowOptions {
+    fn default() -> Self {
+        WindowOptions {
+            title: None,
+            default_pos: None,
+            default_open: true,
+            edit_mode: EditMode::Immediate,
+            update_mode: InspectorUpdateMode::EveryFrame,
+            hotkey: None,
+        }
+    }
+}
+
+impl WindowOptions {
+    fn title(&self, default_title: impl Into<String>) -> String {
+        self.title.clone().unwrap_or_else(|| default_title.into())
+    }
+
+    /// Toggle the window when its [`hotkey`](WindowOptions::hotkey) is pressed
+    fn add_hotkey(&self, app: &mut bevy_app::App, default_title: impl Into<String>) {
+        let Some(key) = self.hotkey else {
+            return;
+        };
+        let title = self.title(default_title);
+        let toggle = move |keys: Option<Res<ButtonInput<KeyCode>>>,
+                           mut windows: ResMut<InspectorWindows>| {
+            if keys.is_some_and(|keys| keys.just_pressed(key)) {
+                windows.toggle(&title);
+            }
+        };
+        app.add_systems(
+            PreUpdate,
+            toggle
+                .after(toggle_inspector_windows)
+                .before(send_window_toggled_events),
+        );
+    }
+
+    fn window(&self, title: String, ctx: &egui::Context) -> egui::Window<'static> {
+        let style = theme::selected_style(ctx);
+        edit_mode::set_edit_mode(ctx, egui::Id::new(&title), self.edit_mode);
+        edit_mode::set_update_mode(ctx, egui::Id::new(&title), self.update_mode);
+        let mut window = egui::Window::new(title)
+            .default_open(self.default_open)
+            .frame(egui::Frame::window(&style));
+        if let Some(pos) = self.default_pos {
+            window = window.default_pos(pos);
+        }
+        window
+    }
+}
+
+fn check_default_plugins(app: &bevy_app::App, name: &str) {
+    if !app.is_plugin_added::<TypeRegistrationPlugin>() {
+        panic!(
+            r#"`{name}` should be added after the default plugins:
+        .add_plugins(DefaultPlugins)
+        .add_plugins({name}::default())
+            "#,
+            name = name,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+    use bevy_utils::{Duration, Instant};
+
+    use bevy_input::{keyboard::KeyCode, ButtonInput};
+
+    use bevy_ecs::prelude::*;
+
+    use bevy_egui::EguiContext;
+    use bevy_window::PrimaryWindow;
+
+    use super::{
+        DiagnosticsHistory, EventLog, InspectorEguiContext, InspectorWindows,
+        RegisterInspectableEvent, WindowOptions, WorldInspectorPlugin,
+    };
+
+    #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    enum GameState {
+        #[default]
+        Playing,
+        Dev,
+    }
+
+    #[test]
+    fn hotkey_toggles_window() {
+        let mut app = bevy_app::App::new();
+        app.init_resource::<InspectorWindows>()
+            .init_resource::<ButtonInput<KeyCode>>();
+        let window = WindowOptions {
+            hotkey: Some(KeyCode::F1),
+            ..Default::default()
+        };
+        window.add_hotkey(&mut app, "Inspector");
+
+        app.world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::F1);
+        app.update();
+        let windows = app.world.resource::<InspectorWindows>();
+        assert!(!windows.is_visible("Inspector"));
+        assert!(windows.is_visible("Other"));
+    }
+
+    #[test]
+    fn show_works_while_all_windows_are_hidden() {
+        let mut windows = InspectorWindows::hidden();
+        windows.show("Inspector");
+        assert!(windows.is_visible("Inspector"));
+        assert!(!windows.is_visible("Other"));
+
+        windows.hide("Other");
+        windows.show_all();
+        assert!(windows.is_visible("Inspector"));
+        assert!(!windows.is_visible("Other"));
+
+        windows.hide_all();
+        assert!(!windows.is_visible("Inspector"));
+    }
+
+    #[test]
+    fn run_in_state_only_runs_in_that_state() {
+        let mut world = World::new();
+        world.init_resource::<State<GameState>>();
+        let plugin = WorldInspectorPlugin::new().run_in_state(GameState::Dev);
+        let mut condition = plugin.window.condition.lock().unwrap().take().unwrap();
+        condition.initialize(&mut world);
+        assert!(!condition.run((), &mut world));
+
+        world.insert_resource(State::new(GameState::Dev));
+        assert!(condition.run((), &mut world));
+    }
+
+    #[test]
+    fn diagnostics_history_records_new_measurements() {
+        const PATH: DiagnosticPath = DiagnosticPath::const_new("test");
+        let mut store = DiagnosticsStore::default();
+        store.add(Diagnostic::new(PATH));
+        let mut history = DiagnosticsHistory::new(2);
+
+        let start = Instant::now();
+        for (i, value) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+            let diagnostic = store.get_mut(&PATH).unwrap();
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: start + Duration::from_millis(i as u64),
+                value,
+            });
+            history.record(&store);
+            // the same measurement isn't recorded twice
+            history.record(&store);
+        }
+
+        assert_eq!(history.values(&PATH).collect::<Vec<_>>(), [2.0, 3.0]);
+    }
+
+    #[derive(bevy_ecs::event::Event, bevy_reflect::Reflect, Clone)]
+    struct TestEvent(u32);
+
+    #[test]
+    fn event_log_keeps_latest_events() {
+        let mut app = bevy_app::App::new();
+        app.register_inspectable_event::<TestEvent>();
+        app.world.resource_mut::<EventLog>().capacity = 2;
+
+        for i in 0..3 {
+            app.world.send_event(TestEvent(i));
+        }
+        app.update();
+
+        let log = app.world.resource::<EventLog>();
+        let logged: Vec<_> = log
+            .events()
+            .map(|event| event.event.downcast_ref::<TestEvent>().unwrap().0)
+            .collect();
+        assert_eq!(logged, [1, 2]);
+        assert_eq!(log.types().collect::<Vec<_>>(), ["TestEvent"]);
+    }
+
+    #[test]
+    fn configured_window_context_is_used() {
+        let mut world = World::new();
+        let primary = EguiContext::default();
+        let secondary = EguiContext::default();
+        world.spawn((PrimaryWindow, primary.clone()));
+        let window = world.spawn(secondary.clone()).id();
+
+        let mut context = super::inspector_egui_context(&mut world).unwrap();
+        assert_eq!(context.get_mut(), primary.clone().get_mut());
+
+        world.insert_resource(InspectorEguiContext {
+            window: Some(window),
+        });
+        let mut context = super::inspector_egui_context(&mut world).unwrap();
+        assert_eq!(context.get_mut(), secondary.clone().get_mut());
     }
 }
@@ -0,0 +1,386 @@
+//! Copying values as JSON or RON and pasting them back, for duplicating values between entities and sharing them in bug reports.
+//!
+//! Pasted RON must match the type of the value it is pasted into. Pasting JSON is lenient: object keys are matched to struct fields ignoring case, `_` and `-`,
+//! so `maxSpeed`, `max-speed` and `MAX_SPEED` all set `max_speed`. Unknown keys are ignored,
+//! fields missing from the JSON keep their value and numbers and bools may be quoted.
+
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    List, Reflect, ReflectFromReflect, ReflectMut, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+
+/// Serialize `value` as pretty-printed JSON, without the type path wrapping the RON scene format uses
+pub fn to_json(value: &dyn Reflect, type_registry: &TypeRegistry) -> Result<String, String> {
+    let serializer = TypedReflectSerializer::new(value, type_registry);
+    serde_json::to_string_pretty(&serializer).map_err(|error| error.to_string())
+}
+
+/// Serialize `value` as pretty-printed RON
+pub fn to_ron(value: &dyn Reflect, type_registry: &TypeRegistry) -> Result<String, String> {
+    let serializer = TypedReflectSerializer::new(value, type_registry);
+    ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default())
+        .map_err(|error| error.to_string())
+}
+
+/// Parse `json` and apply it to `value`, see the [module docs](self) for how fields are matched.
+///
+/// On error, fields before the failing one may already have been applied.
+pub fn apply_json(
+    value: &mut dyn Reflect,
+    json: &str,
+    type_registry: &TypeRegistry,
+) -> Result<(), String> {
+    let json: Value = serde_json::from_str(json).map_err(|error| error.to_string())?;
+    apply_json_value(value, &json, type_registry)
+}
+
+/// Parse `ron` as the type of `value` and replace `value` with it, failing if the RON is of another type
+pub fn apply_ron(
+    value: &mut dyn Reflect,
+    ron: &str,
+    type_registry: &TypeRegistry,
+) -> Result<(), String> {
+    let registration = registration(value, type_registry)?;
+    let mut deserializer = ron::Deserializer::from_str(ron).map_err(|error| error.to_string())?;
+    let deserialized = TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|error| format!("expected {}: {error}", registration.type_info().type_path()))?;
+    deserializer.end().map_err(|error| error.to_string())?;
+    set_deserialized(value, registration, deserialized)
+}
+
+/// Apply `text` as JSON if it parses as JSON, and as RON otherwise
+pub fn apply_text(
+    value: &mut dyn Reflect,
+    text: &str,
+    type_registry: &TypeRegistry,
+) -> Result<(), String> {
+    match serde_json::from_str::<Value>(text) {
+        Ok(json) => apply_json_value(value, &json, type_registry),
+        Err(_) => apply_ron(value, text, type_registry),
+    }
+}
+
+/// Apply an already parsed JSON value to `value`, see [`apply_json`]
+pub fn apply_json_value(
+    value: &mut dyn Reflect,
+    json: &Value,
+    type_registry: &TypeRegistry,
+) -> Result<(), String> {
+    match (value.reflect_mut(), json) {
+        (ReflectMut::Struct(value), Value::Object(object)) => {
+            for i in 0..value.field_len() {
+                let name = value.name_at(i).unwrap().to_owned();
+                let Some(field_json) = object
+                    .iter()
+                    .find(|(key, _)| normalize_key(key) == normalize_key(&name))
+                    .map(|(_, field_json)| field_json)
+                else {
+                    continue;
+                };
+                let field = value.field_at_mut(i).unwrap();
+                apply_json_value(field, field_json, type_registry)
+                    .map_err(|error| format!("{name}: {error}"))?;
+            }
+            return Ok(());
+        }
+        (ReflectMut::TupleStruct(value), Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate().take(value.field_len()) {
+                let field = value.field_mut(i).unwrap();
+                apply_json_value(field, item, type_registry)
+                    .map_err(|error| format!("{i}: {error}"))?;
+            }
+            return Ok(());
+        }
+        (ReflectMut::Tuple(value), Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate().take(value.field_len()) {
+                let field = value.field_mut(i).unwrap();
+                apply_json_value(field, item, type_registry)
+                    .map_err(|error| format!("{i}: {error}"))?;
+            }
+            return Ok(());
+        }
+        (ReflectMut::Array(value), Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate().take(value.len()) {
+                let element = value.get_mut(i).unwrap();
+                apply_json_value(element, item, type_registry)
+                    .map_err(|error| format!("{i}: {error}"))?;
+            }
+            return Ok(());
+        }
+        (ReflectMut::List(value), Value::Array(items)) => {
+            while value.len() > items.len() {
+                value.pop();
+            }
+            for (i, item) in items.iter().enumerate() {
+                match value.get_mut(i) {
+                    Some(element) => apply_json_value(element, item, type_registry),
+                    None => deserialize_element(value, item, type_registry)
+                        .map(|element| value.push(element)),
+                }
+                .map_err(|error| format!("{i}: {error}"))?;
+            }
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    deserialize_into(value, json, type_registry)
+}
+
+/// Replace `value` with `json` deserialized as the type of `value`
+fn deserialize_into(
+    value: &mut dyn Reflect,
+    json: &Value,
+    type_registry: &TypeRegistry,
+) -> Result<(), String> {
+    let registration = registration(value, type_registry)?;
+    let deserialized = deserialize(registration, json, type_registry)?;
+    set_deserialized(value, registration, deserialized)
+}
+
+fn registration<'a>(
+    value: &dyn Reflect,
+    type_registry: &'a TypeRegistry,
+) -> Result<&'a TypeRegistration, String> {
+    value
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get(info.type_id()))
+        .ok_or_else(|| format!("{} is not registered", value.reflect_type_path()))
+}
+
+/// Replace `value` with the `deserialized` value of its type
+fn set_deserialized(
+    value: &mut dyn Reflect,
+    registration: &TypeRegistration,
+    deserialized: Box<dyn Reflect>,
+) -> Result<(), String> {
+    let type_path = value.reflect_type_path().to_owned();
+    match registration
+        .data::<ReflectFromReflect>()
+        .and_then(|from_reflect| from_reflect.from_reflect(deserialized.as_ref()))
+    {
+        Some(concrete) => value
+            .set(concrete)
+            .map_err(|_| format!("failed to set {type_path}")),
+        None => {
+            value.apply(deserialized.as_ref());
+            Ok(())
+        }
+    }
+}
+
+/// Deserialize `json` as a new element for `list`
+fn deserialize_element(
+    list: &dyn List,
+    json: &Value,
+    type_registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, String> {
+    let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
+        return Err(format!("{} is not registered", list.reflect_type_path()));
+    };
+    let registration = type_registry
+        .get(info.item_type_id())
+        .ok_or_else(|| format!("{} is not registered", info.item_type_path_table().path()))?;
+    let element = deserialize(registration, json, type_registry)?;
+    Ok(registration
+        .data::<ReflectFromReflect>()
+        .and_then(|from_reflect| from_reflect.from_reflect(element.as_ref()))
+        .unwrap_or(element))
+}
+
+fn deserialize(
+    registration: &TypeRegistration,
+    json: &Value,
+    type_registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, String> {
+    let deserialize =
+        |json: Value| TypedReflectDeserializer::new(registration, type_registry).deserialize(json);
+    let deserialized = deserialize(json.clone()).or_else(|error| match json {
+        // spreadsheets and web forms often send numbers and bools as strings
+        Value::String(text) => serde_json::from_str::<Value>(text)
+            .ok()
+            .and_then(|parsed| deserialize(parsed).ok())
+            .ok_or(error),
+        _ => Err(error),
+    });
+    deserialized
+        .map_err(|error| format!("expected {}: {error}", registration.type_info().type_path()))
+}
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[derive(Clone, Default)]
+struct PasteState {
+    text: String,
+    error: Option<String>,
+}
+
+fn ui_for_copy_buttons(
+    ui: &mut egui::Ui,
+    value: &dyn Reflect,
+    type_registry: &TypeRegistry,
+    state: &mut PasteState,
+) {
+    for (label, serialize) in [
+        (
+            "Copy as JSON",
+            to_json as fn(&dyn Reflect, &TypeRegistry) -> _,
+        ),
+        ("Copy as RON", to_ron),
+    ] {
+        if ui.button(label).clicked() {
+            match serialize(value, type_registry) {
+                Ok(text) => {
+                    ui.output_mut(|output| output.copied_text = text);
+                    state.error = None;
+                    ui.close_menu();
+                }
+                Err(error) => state.error = Some(error),
+            }
+        }
+    }
+}
+
+/// Menu entries for copying `value` as JSON or RON, for use inside of the context menu of a read-only value
+pub fn ui_for_copy_menu(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    value: &dyn Reflect,
+    type_registry: &TypeRegistry,
+) {
+    let id = id.with("clipboard menu");
+    let mut state = ui.data_mut(|data| data.get_temp::<PasteState>(id).unwrap_or_default());
+    ui_for_copy_buttons(ui, value, type_registry, &mut state);
+    if let Some(error) = &state.error {
+        ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+    ui.data_mut(|data| data.insert_temp(id, state));
+}
+
+/// Menu entries for copying `value` as JSON or RON and pasting JSON or RON into it, for use inside of a context menu.
+///
+/// Returns `true` if a value was pasted into `value`.
+pub fn ui_for_clipboard_menu(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    value: &mut dyn Reflect,
+    type_registry: &TypeRegistry,
+) -> bool {
+    let id = id.with("clipboard menu");
+    let mut state = ui.data_mut(|data| data.get_temp::<PasteState>(id).unwrap_or_default());
+    ui_for_copy_buttons(ui, value, type_registry, &mut state);
+
+    ui.separator();
+    ui.add(
+        egui::TextEdit::multiline(&mut state.text)
+            .code_editor()
+            .desired_rows(3)
+            .hint_text("Paste JSON or RON here"),
+    );
+    let mut changed = false;
+    if ui.button("Paste").clicked() {
+        match apply_text(value, &state.text, type_registry) {
+            Ok(()) => {
+                changed = true;
+                state = PasteState::default();
+                ui.close_menu();
+            }
+            Err(error) => state.error = Some(error),
+        }
+    }
+    if let Some(error) = &state.error {
+        ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, state));
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    use super::{apply_json, apply_ron, apply_text, to_json, to_ron};
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    struct Settings {
+        max_speed: f32,
+        name: String,
+        weights: Vec<u32>,
+        offset: (i32, i32),
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+        registry.register::<Vec<u32>>();
+        registry.register::<(i32, i32)>();
+        registry
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let registry = registry();
+        let settings = Settings {
+            max_speed: 2.5,
+            name: "player".into(),
+            weights: vec![1, 2],
+            offset: (-1, 1),
+        };
+        let json = to_json(&settings, &registry).unwrap();
+
+        let mut pasted = Settings::default();
+        apply_json(&mut pasted, &json, &registry).unwrap();
+        assert_eq!(pasted, settings);
+    }
+
+    #[test]
+    fn lenient_json() {
+        let registry = registry();
+        let mut settings = Settings {
+            name: "kept".into(),
+            weights: vec![1, 2, 3],
+            ..Default::default()
+        };
+        let json = r#"{ "maxSpeed": "0.3", "Weights": [4], "offset": [5], "unknown": true }"#;
+        apply_json(&mut settings, json, &registry).unwrap();
+
+        assert_eq!(
+            settings,
+            Settings {
+                max_speed: 0.3,
+                name: "kept".into(),
+                weights: vec![4],
+                offset: (5, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn ron_round_trip_checks_the_type() {
+        let registry = registry();
+        let settings = Settings {
+            max_speed: 1.5,
+            name: "boss".into(),
+            weights: vec![3],
+            offset: (2, -2),
+        };
+        let ron = to_ron(&settings, &registry).unwrap();
+
+        let mut pasted = Settings::default();
+        apply_text(&mut pasted, &ron, &registry).unwrap();
+        assert_eq!(pasted, settings);
+
+        let mut offset = (0i32, 0i32);
+        assert!(apply_ron(&mut offset, &ron, &registry).is_err());
+        assert_eq!(offset, (0, 0));
+    }
+}
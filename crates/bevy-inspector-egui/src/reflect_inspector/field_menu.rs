@@ -0,0 +1,69 @@
+//! Extra entries in the context menu of field labels, see [`with_field_menu`].
+
+use std::sync::Arc;
+
+type FieldMenuFn = dyn Fn(&mut egui::Ui, &str) + Send + Sync;
+
+#[derive(Clone)]
+struct FieldMenu {
+    type_path: String,
+    add_entries: Arc<FieldMenuFn>,
+}
+
+fn id() -> egui::Id {
+    egui::Id::new("bevy-inspector-egui field menu")
+}
+
+/// Add entries to the context menu of the fields of values of the type at `type_path`, while `add_contents` displays them.
+///
+/// `add_entries` is called with the name of the field whose menu is open. Fields of nested values of other types
+/// don't get the entries.
+///
+/// ```rust
+/// use bevy_inspector_egui::reflect_inspector::{field_menu, ui_for_value};
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+///
+/// fn ui(ui: &mut egui::Ui, value: &mut dyn Reflect, type_registry: &TypeRegistry) {
+///     let type_path = value.reflect_type_path().to_owned();
+///     field_menu::with_field_menu(
+///         ui,
+///         type_path,
+///         |ui, field| {
+///             if ui.button("Log").clicked() {
+///                 println!("clicked {field}");
+///             }
+///         },
+///         |ui| ui_for_value(value, ui, type_registry),
+///     );
+/// }
+/// ```
+pub fn with_field_menu<R>(
+    ui: &mut egui::Ui,
+    type_path: impl Into<String>,
+    add_entries: impl Fn(&mut egui::Ui, &str) + Send + Sync + 'static,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let previous = ui.data(|data| data.get_temp::<FieldMenu>(id()));
+    let menu = FieldMenu {
+        type_path: type_path.into(),
+        add_entries: Arc::new(add_entries),
+    };
+    ui.data_mut(|data| data.insert_temp(id(), menu));
+    let inner = add_contents(ui);
+    ui.data_mut(|data| match previous {
+        Some(previous) => data.insert_temp(id(), previous),
+        None => data.remove::<FieldMenu>(id()),
+    });
+    inner
+}
+
+/// Add the entries of the enclosing [`with_field_menu`] for the field `field` of a value of the type at `type_path`
+pub(crate) fn ui_for_field_menu(ui: &mut egui::Ui, type_path: &str, field: &str) {
+    let Some(menu) = ui.data(|data| data.get_temp::<FieldMenu>(id())) else {
+        return;
+    };
+    if menu.type_path == type_path {
+        ui.separator();
+        (menu.add_entries)(ui, field);
+    }
+}
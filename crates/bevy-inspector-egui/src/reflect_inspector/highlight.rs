@@ -0,0 +1,141 @@
+//! Tinting the labels of fields whose value changed since the previous frame, see [`with_changed_fields_highlighted`].
+//!
+//! Changes are found by comparing every field with a copy of its value from the frame it was last displayed in,
+//! using [`Reflect::reflect_partial_eq`]. Fields of types without a partial equality are never highlighted.
+//! The tint fades out over [`HIGHLIGHT_SECONDS`], so that changes of a single frame are still visible.
+
+use std::sync::Arc;
+
+use bevy_reflect::Reflect;
+
+use crate::theme::InspectorTheme;
+
+/// How long the label of a changed field stays tinted
+pub const HIGHLIGHT_SECONDS: f64 = 0.6;
+
+#[derive(Clone)]
+struct FieldHistory {
+    previous: Arc<dyn Reflect>,
+    changed_at: Option<f64>,
+}
+
+fn enabled_id() -> egui::Id {
+    egui::Id::new("bevy-inspector-egui highlight changed fields")
+}
+
+/// Highlight the fields changing while `add_contents` displays values, if `enabled`.
+///
+/// ```rust
+/// use bevy_inspector_egui::reflect_inspector::{highlight, ui_for_value};
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+///
+/// fn ui(ui: &mut egui::Ui, value: &mut dyn Reflect, type_registry: &TypeRegistry) {
+///     highlight::with_changed_fields_highlighted(ui, true, |ui| {
+///         ui_for_value(value, ui, type_registry);
+///     });
+/// }
+/// ```
+pub fn with_changed_fields_highlighted<R>(
+    ui: &mut egui::Ui,
+    enabled: bool,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let was_enabled = is_enabled(ui.ctx());
+    ui.data_mut(|data| data.insert_temp(enabled_id(), enabled));
+    let inner = add_contents(ui);
+    ui.data_mut(|data| data.insert_temp(enabled_id(), was_enabled));
+    inner
+}
+
+fn is_enabled(ctx: &egui::Context) -> bool {
+    ctx.data(|data| data.get_temp(enabled_id()))
+        .unwrap_or(false)
+}
+
+/// Remember `field` displayed with `id`, returning how strongly to highlight it from `1.0` right after a change to `0.0`.
+///
+/// Returns `None` if highlighting is disabled or `field` didn't change recently.
+pub(crate) fn field_change(ui: &egui::Ui, id: egui::Id, field: &dyn Reflect) -> Option<f32> {
+    if !is_enabled(ui.ctx()) {
+        return None;
+    }
+    let id = id.with("field history");
+    let now = ui.input(|input| input.time);
+    let history = ui.data(|data| data.get_temp::<FieldHistory>(id));
+
+    let is_changed = history
+        .as_ref()
+        .is_some_and(|history| history.previous.reflect_partial_eq(field) == Some(false));
+    let mut changed_at = history.as_ref().and_then(|history| history.changed_at);
+    if is_changed {
+        changed_at = Some(now);
+    }
+    if history.is_none() || is_changed {
+        let history = FieldHistory {
+            previous: Arc::from(field.clone_value()),
+            changed_at,
+        };
+        ui.data_mut(|data| data.insert_temp(id, history));
+    }
+
+    highlight_strength(now - changed_at?)
+}
+
+/// How strongly a field changed `elapsed` seconds ago is highlighted
+fn highlight_strength(elapsed: f64) -> Option<f32> {
+    (elapsed < HIGHLIGHT_SECONDS).then(|| 1.0 - (elapsed / HIGHLIGHT_SECONDS) as f32)
+}
+
+/// Tint the background of the UI added by `add_contents` with the highlight color of the [`InspectorTheme`] at `strength`
+pub(crate) fn ui_for_highlight(
+    ui: &mut egui::Ui,
+    strength: Option<f32>,
+    add_contents: impl FnOnce(&mut egui::Ui) -> egui::Response,
+) {
+    let Some(strength) = strength else {
+        add_contents(ui);
+        return;
+    };
+    // reserve a shape below the contents, to be filled once their size is known
+    let background = ui.painter().add(egui::Shape::Noop);
+    let rect = add_contents(ui).rect;
+    let color = InspectorTheme::get(ui.ctx())
+        .palette(ui.visuals().dark_mode)
+        .highlight;
+    let fill = color.gamma_multiply(0.4 * strength);
+    ui.painter().set(
+        background,
+        egui::Shape::rect_filled(rect.expand(1.0), 2.0, fill),
+    );
+    ui.ctx().request_repaint();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field_change, highlight_strength, with_changed_fields_highlighted};
+
+    #[test]
+    fn changed_fields_are_highlighted() {
+        let ctx = egui::Context::default();
+        let id = egui::Id::new("field");
+        let frame = |value: f32, enabled: bool| {
+            let mut strength = None;
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    with_changed_fields_highlighted(ui, enabled, |ui| {
+                        strength = field_change(ui, id, &value);
+                    });
+                });
+            });
+            strength
+        };
+
+        assert_eq!(frame(1.0, true), None);
+        assert_eq!(frame(1.0, true), None);
+        assert_eq!(frame(2.0, true), Some(1.0));
+        assert_eq!(frame(3.0, false), None);
+
+        assert_eq!(highlight_strength(0.3), Some(0.5));
+        assert_eq!(highlight_strength(1.0), None);
+    }
+}
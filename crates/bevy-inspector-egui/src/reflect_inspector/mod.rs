@@ -4,7 +4,7 @@
 //! **Basic usage**
 //! ```rust
 //! use bevy_reflect::{Reflect, TypeRegistry};
-//! use bevy_inspector_egui::reflect_inspector::{ui_for_value, InspectorUi, Context};
+//! use bevy_inspector_egui::reflect_inspector::{ui_for_value, InspectorUi, InspectorWidget, Context};
 //!
 //! #[derive(Reflect)]
 //! struct Data {
@@ -19,6 +19,9 @@
 //!
 //!     // alternatively, if you are using an empty `Context`:
 //!     let _changed = ui_for_value(data, ui, type_registry);
+//!
+//!     // or as a widget, e.g. inside of your own layouts
+//!     let _changed = ui.add(InspectorWidget::new(data, type_registry)).changed();
 //! }
 //! ```
 //!
@@ -31,7 +34,7 @@
 //! use bevy_ecs::prelude::*;
 //! use bevy_ecs::system::CommandQueue;
 //! use bevy_asset::Handle;
-//! use bevy_pbr::StandardMaterial;
+//! use bevy::pbr::StandardMaterial;
 //!
 //! #[derive(Reflect)]
 //! struct Data {
@@ -61,22 +64,36 @@
 
 use crate::{
     egui_utils::{add_button, down_button, remove_button, up_button},
+    i18n::InspectorI18n,
     inspector_egui_impls::{iter_all_eq, InspectorEguiImpl},
-    inspector_options::{InspectorOptions, ReflectInspectorOptions, Target},
+    inspector_options::{
+        std_options::{ElementLabels, ListOptions},
+        CustomWidget, InspectorButton, InspectorOptions, ReflectInspectorOptions, Target,
+    },
+    notes,
     restricted_world_view::RestrictedWorldView,
+    theme::InspectorStyle,
 };
 use bevy_ecs::system::CommandQueue;
 use bevy_reflect::{std_traits::ReflectDefault, DynamicStruct};
 use bevy_reflect::{
     Array, DynamicEnum, DynamicTuple, DynamicVariant, Enum, EnumInfo, List, ListInfo, Map, Reflect,
     ReflectMut, ReflectRef, Struct, StructInfo, Tuple, TupleInfo, TupleStruct, TupleStructInfo,
-    TypeInfo, TypeRegistry, ValueInfo, VariantInfo, VariantType,
+    TypeData, TypeInfo, TypeRegistry, ValueInfo, VariantInfo, VariantType,
 };
 use egui::{Grid, WidgetText};
 use std::any::{Any, TypeId};
 use std::borrow::Cow;
 
+pub mod clipboard;
 pub(crate) mod errors;
+pub mod field_menu;
+pub mod highlight;
+pub(crate) mod panics;
+pub mod plot;
+pub mod reset;
+pub mod trait_objects;
+pub mod validation;
 
 /// Display the value without any [`Context`] or short circuiting behaviour.
 /// This means that for example bevy's `Handle<StandardMaterial>` values cannot be displayed,
@@ -102,6 +119,78 @@ pub fn ui_for_value_readonly(value: &dyn Reflect, ui: &mut egui::Ui, type_regist
         .ui_for_reflect_readonly(value, ui);
 }
 
+enum WidgetValue<'a> {
+    Mut(&'a mut dyn Reflect),
+    Readonly(&'a dyn Reflect),
+}
+
+/// The inspector UI of a value as an [`egui::Widget`], for adding it to any [`egui::Ui`] with [`egui::Ui::add`].
+///
+/// The response covers the whole UI of the value and is [changed](egui::Response::changed) if the value was edited.
+/// Like with [`ui_for_value`], values which need access to the world like `Handle<StandardMaterial>` can't be displayed.
+pub struct InspectorWidget<'a> {
+    value: WidgetValue<'a>,
+    type_registry: &'a TypeRegistry,
+    id: Option<egui::Id>,
+    options: &'a dyn Any,
+}
+
+impl<'a> InspectorWidget<'a> {
+    pub fn new(value: &'a mut dyn Reflect, type_registry: &'a TypeRegistry) -> Self {
+        InspectorWidget {
+            value: WidgetValue::Mut(value),
+            type_registry,
+            id: None,
+            options: &(),
+        }
+    }
+
+    /// Display `value` without allowing to edit it
+    pub fn readonly(value: &'a dyn Reflect, type_registry: &'a TypeRegistry) -> Self {
+        InspectorWidget {
+            value: WidgetValue::Readonly(value),
+            type_registry,
+            id: None,
+            options: &(),
+        }
+    }
+
+    /// Use `id_source` for the state of the widget, like whether collapsing headers are open.
+    /// By default the id depends on where the widget is placed in the [`egui::Ui`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id = Some(egui::Id::new(id_source));
+        self
+    }
+
+    /// Display the value with `options`, see [`InspectorUi::ui_for_reflect_with_options`]
+    pub fn options(mut self, options: &'a dyn Any) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl egui::Widget for InspectorWidget<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let id = self.id.unwrap_or_else(|| ui.next_auto_id());
+        let mut cx = Context::default();
+        let mut env = InspectorUi::new_no_short_circuit(self.type_registry, &mut cx);
+        let egui::InnerResponse {
+            inner: changed,
+            mut response,
+        } = ui.scope(|ui| match self.value {
+            WidgetValue::Mut(value) => env.ui_for_reflect_with_options(value, ui, id, self.options),
+            WidgetValue::Readonly(value) => {
+                env.ui_for_reflect_readonly_with_options(value, ui, id, self.options);
+                false
+            }
+        });
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
 #[derive(Default)]
 pub struct Context<'a> {
     pub world: Option<RestrictedWorldView<'a>>,
@@ -220,18 +309,45 @@ impl InspectorUi<'_, '_> {
             }
         }
 
+        // custom widgets are user code, so a panic in them only disables the widget.
+        // a widget of the field takes precedence over one registered for the whole type
+        let widget = options.downcast_ref::<CustomWidget>().or_else(|| {
+            self.type_registry
+                .get_type_data::<CustomWidget>(Any::type_id(value))
+        });
+        if let Some(widget) = widget {
+            let type_name = panics::type_name(value);
+            return panics::catch_widget_panic(ui, id, type_name, |ui| {
+                widget.ui(value, ui, self.context)
+            })
+            .unwrap_or(false);
+        }
+
         if let Some(s) = self
             .type_registry
             .get_type_data::<InspectorEguiImpl>(Any::type_id(value))
         {
-            return s.execute(value.as_any_mut(), ui, options, id, self.reborrow());
+            let type_name = panics::type_name(value);
+            return panics::catch_widget_panic(ui, id, type_name, |ui| {
+                s.execute(value.as_any_mut(), ui, options, id, self.reborrow())
+            })
+            .unwrap_or(false);
         }
 
         if let Some(changed) = (self.short_circuit)(self, value, ui, id, options) {
             return changed;
         }
 
-        match value.reflect_mut() {
+        let type_registry = self.type_registry;
+        if let Some(field_options) =
+            transparent_field_options(type_registry, Any::type_id(value), options)
+        {
+            if let Some(field) = transparent_field_mut(value) {
+                return self.ui_for_reflect_with_options(field, ui, id, field_options);
+            }
+        }
+
+        let changed = match value.reflect_mut() {
             ReflectMut::Struct(value) => self.ui_for_struct(value, ui, id, options),
             ReflectMut::TupleStruct(value) => self.ui_for_tuple_struct(value, ui, id, options),
             ReflectMut::Tuple(value) => self.ui_for_tuple(value, ui, id, options),
@@ -240,7 +356,16 @@ impl InspectorUi<'_, '_> {
             ReflectMut::Map(value) => self.ui_for_reflect_map(value, ui, id, options),
             ReflectMut::Enum(value) => self.ui_for_enum(value, ui, id, options),
             ReflectMut::Value(value) => self.ui_for_value(value, ui, id, options),
+        };
+
+        let Some(options) = options.downcast_ref::<InspectorOptions>() else {
+            return changed;
+        };
+        let changed = changed | ui_for_buttons(value, ui, options.buttons());
+        if let Some(on_change) = options.on_change().filter(|_| changed) {
+            on_change(value);
         }
+        changed
     }
 
     /// Draws the inspector UI for the given value with some options in a read-only way.
@@ -269,7 +394,9 @@ impl InspectorUi<'_, '_> {
             .type_registry
             .get_type_data::<InspectorEguiImpl>(Any::type_id(value))
         {
-            s.execute_readonly(value.as_any(), ui, options, id, self.reborrow());
+            panics::catch_widget_panic(ui, id, panics::type_name(value), |ui| {
+                s.execute_readonly(value.as_any(), ui, options, id, self.reborrow())
+            });
             return;
         }
 
@@ -277,6 +404,15 @@ impl InspectorUi<'_, '_> {
             return;
         }
 
+        let type_registry = self.type_registry;
+        if let Some(field_options) =
+            transparent_field_options(type_registry, Any::type_id(value), options)
+        {
+            if let Some(field) = transparent_field(value) {
+                return self.ui_for_reflect_readonly_with_options(field, ui, id, field_options);
+            }
+        }
+
         match value.reflect_ref() {
             ReflectRef::Struct(value) => self.ui_for_struct_readonly(value, ui, id, options),
             ReflectRef::TupleStruct(value) => {
@@ -333,7 +469,10 @@ impl InspectorUi<'_, '_> {
             .type_registry
             .get_type_data::<InspectorEguiImpl>(type_id)
         {
-            return s.execute_many(ui, options, id, self.reborrow(), values, projector);
+            return panics::catch_widget_panic(ui, id, info.type_path(), |ui| {
+                s.execute_many(ui, options, id, self.reborrow(), values, projector)
+            })
+            .unwrap_or(false);
         }
 
         if let Some(changed) =
@@ -342,6 +481,30 @@ impl InspectorUi<'_, '_> {
             return changed;
         }
 
+        let type_registry = self.type_registry;
+        if let Some(field_options) = transparent_field_options(type_registry, type_id, options) {
+            let field = match info {
+                TypeInfo::Struct(info) if info.field_len() == 1 => info
+                    .field_at(0)
+                    .map(|field| (field.type_id(), field.type_path())),
+                TypeInfo::TupleStruct(info) if info.field_len() == 1 => info
+                    .field_at(0)
+                    .map(|field| (field.type_id(), field.type_path())),
+                _ => None,
+            };
+            if let Some((field_type_id, field_type_path)) = field {
+                return self.ui_for_reflect_many_with_options(
+                    field_type_id,
+                    field_type_path,
+                    ui,
+                    id,
+                    field_options,
+                    values,
+                    &|value| transparent_field_mut(projector(value)).unwrap(),
+                );
+            }
+        }
+
         match info {
             TypeInfo::Struct(info) => {
                 self.ui_for_struct_many(info, ui, id, options, values, projector)
@@ -373,29 +536,79 @@ impl InspectorUi<'_, '_> {
     }
 }
 
-enum ListOp {
+pub(crate) enum ListOp {
     AddElement(usize),
     RemoveElement(usize),
     MoveElementUp(usize),
     MoveElementDown(usize),
+    Clear,
+}
+
+/// Split the options of a list or array into whether its size is fixed, the labels and the options for its elements
+fn list_options(options: &dyn Any) -> (bool, &ElementLabels, &dyn Any) {
+    static DEFAULT_LABELS: ElementLabels = ElementLabels::Index;
+    match options.downcast_ref::<ListOptions<Box<dyn TypeData>>>() {
+        Some(options) => (
+            options.fixed_size,
+            &options.labels,
+            options.element.as_any(),
+        ),
+        None => (false, &DEFAULT_LABELS, options),
+    }
+}
+
+/// Label an element of an array or read-only list, which are unlabeled unless labels were specified
+fn ui_for_custom_element_label(ui: &mut egui::Ui, labels: &ElementLabels, index: usize) {
+    if !matches!(labels, ElementLabels::Index) {
+        ui.label(labels.label(index));
+    }
 }
 
-fn ui_for_empty_collection(ui: &mut egui::Ui, label: impl Into<WidgetText>) -> bool {
+fn ui_for_empty_collection(
+    ui: &mut egui::Ui,
+    label: impl Into<WidgetText>,
+    fixed_size: bool,
+) -> bool {
     let mut add = false;
     ui.vertical_centered(|ui| {
         ui.label(label);
-        if add_button(ui).on_hover_text("Add element").clicked() {
+        if !fixed_size && add_button(ui).on_hover_text("Add element").clicked() {
             add = true;
         }
     });
     add
 }
 
-fn ui_for_empty_list(ui: &mut egui::Ui) -> bool {
-    ui_for_empty_collection(ui, "(Empty List)")
+pub(crate) fn ui_for_empty_list(ui: &mut egui::Ui, fixed_size: bool) -> bool {
+    ui_for_empty_collection(ui, "(Empty List)", fixed_size)
+}
+
+/// Controls below a non-empty list for appending an element and removing all elements
+pub(crate) fn ui_for_list_footer(ui: &mut egui::Ui, len: usize) -> Option<ListOp> {
+    let mut op = None;
+    if len == 0 {
+        return op;
+    }
+    ui.separator();
+    ui.horizontal(|ui| {
+        if add_button(ui)
+            .on_hover_text("Add element at the end")
+            .clicked()
+        {
+            op = Some(ListOp::AddElement(len));
+        }
+        if ui
+            .small_button("Clear")
+            .on_hover_text("Remove all elements")
+            .clicked()
+        {
+            op = Some(ListOp::Clear);
+        }
+    });
+    op
 }
 
-fn ui_for_list_controls(ui: &mut egui::Ui, index: usize, len: usize) -> Option<ListOp> {
+pub(crate) fn ui_for_list_controls(ui: &mut egui::Ui, index: usize, len: usize) -> Option<ListOp> {
     use ListOp::*;
     let mut op = None;
     ui.horizontal_top(|ui| {
@@ -443,22 +656,255 @@ impl InspectorUi<'_, '_> {
         options: &dyn Any,
     ) -> bool {
         let mut changed = false;
-        Grid::new(id).show(ui, |ui| {
+        let i18n = InspectorI18n::get(ui.ctx());
+        field_grid(id, ui).show(ui, |ui| {
             for i in 0..value.field_len() {
-                ui.label(value.name_at(i).unwrap());
-                let field = value.field_at_mut(i).unwrap();
-                changed |= self.ui_for_reflect_with_options(
-                    field,
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
-                );
+                if !inspector_options_field_visible(options, Target::Field(i), value.as_reflect()) {
+                    continue;
+                }
+                let name = value.name_at(i).unwrap();
+                let note_path = notes::field_path(value.reflect_type_path(), name);
+                let header_id = id.with(name);
+                let label = inspector_options_field_label(options, Target::Field(i));
+                let label = i18n
+                    .field_label(value.reflect_type_path(), name, label)
+                    .into_owned();
+                // fields with an accessor are only written through its setter
+                let has_accessor = inspector_options_has_accessor(options, Target::Field(i));
+                let default = (!has_accessor)
+                    .then(|| {
+                        reset::field_default(
+                            ui,
+                            options,
+                            Target::Field(i),
+                            value.field_at(i).unwrap(),
+                            self.type_registry,
+                        )
+                    })
+                    .flatten();
+                let error = validation::field_error(options, Target::Field(i), value.as_reflect());
+                let highlight = highlight::field_change(ui, id.with(i), value.field_at(i).unwrap());
+                ui.horizontal(|ui| {
+                    highlight::ui_for_highlight(ui, highlight, |ui| {
+                        notes::ui_for_field_label_with_menu(ui, &label, &note_path, |ui| {
+                            if !has_accessor {
+                                ui.separator();
+                                changed |= clipboard::ui_for_clipboard_menu(
+                                    ui,
+                                    id.with(i),
+                                    value.field_at_mut(i).unwrap(),
+                                    self.type_registry,
+                                );
+                                plot::ui_for_plot_toggle(
+                                    ui,
+                                    id.with(i),
+                                    value.field_at(i).unwrap(),
+                                );
+                                field_menu::ui_for_field_menu(
+                                    ui,
+                                    value.reflect_type_path(),
+                                    value.name_at(i).unwrap(),
+                                );
+                            }
+                        })
+                    });
+                    if let Some(default) = default {
+                        changed |=
+                            reset::ui_for_reset_button(ui, value.field_at_mut(i).unwrap(), default);
+                    }
+                    if let Some(error) = &error {
+                        validation::ui_for_error_badge(ui, error);
+                    }
+                });
+                changed |= validation::ui_with_error(ui, error.as_deref(), |ui| {
+                    if let Some(field_changed) = self.ui_for_field_accessor(
+                        value.as_reflect_mut(),
+                        Target::Field(i),
+                        ui,
+                        id.with(i),
+                        options,
+                    ) {
+                        return field_changed;
+                    }
+                    let dynamic_options =
+                        inspector_options_dynamic(options, Target::Field(i), value.as_reflect());
+                    let field_options = dynamic_options.as_deref().map_or_else(
+                        || inspector_options_struct_field(options, i),
+                        |options| options.as_any(),
+                    );
+                    let field = value.field_at_mut(i).unwrap();
+                    if self.is_nested_struct(field, field_options) {
+                        nested_struct_header(field, header_id)
+                            .show(ui, |ui| {
+                                self.ui_for_reflect_with_options(
+                                    field,
+                                    ui,
+                                    id.with(i),
+                                    field_options,
+                                )
+                            })
+                            .body_returned
+                            .unwrap_or(false)
+                    } else if plot::is_plotted(ui, id.with(i)) {
+                        ui.vertical(|ui| {
+                            let changed = self.ui_for_reflect_with_options(
+                                field,
+                                ui,
+                                id.with(i),
+                                field_options,
+                            );
+                            plot::ui_for_plot(ui, id.with(i), field);
+                            changed
+                        })
+                        .inner
+                    } else {
+                        self.ui_for_reflect_with_options(field, ui, id.with(i), field_options)
+                    }
+                });
                 ui.end_row();
             }
         });
         changed
     }
 
+    /// Display `field` followed by its reset button, if the field at `target` of the value with the `options` has one.
+    ///
+    /// `error` is the error of the field's validator, which outlines the field.
+    #[allow(clippy::too_many_arguments)]
+    fn ui_for_field_with_reset(
+        &mut self,
+        field: &mut dyn Reflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        field_options: &dyn Any,
+        options: &dyn Any,
+        target: Target,
+        error: Option<&str>,
+    ) -> bool {
+        let Some(default) = reset::field_default(ui, options, target, field, self.type_registry)
+        else {
+            return validation::ui_with_error(ui, error, |ui| {
+                self.ui_for_reflect_with_options(field, ui, id, field_options)
+            });
+        };
+        ui.horizontal(|ui| {
+            let changed = validation::ui_with_error(ui, error, |ui| {
+                self.ui_for_reflect_with_options(field, ui, id, field_options)
+            });
+            changed | reset::ui_for_reset_button(ui, field, default)
+        })
+        .inner
+    }
+
+    /// Display the field at `target` of `value` through its [`FieldAccessor`](crate::inspector_options::FieldAccessor) instead of accessing it directly.
+    ///
+    /// Returns `None` if the field has no accessor, and only calls the setter if the field was changed.
+    fn ui_for_field_accessor(
+        &mut self,
+        value: &mut dyn Reflect,
+        target: Target,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) -> Option<bool> {
+        let options = options.downcast_ref::<InspectorOptions>()?;
+        let accessor = options.accessor(target)?;
+        let field_options = options.get(target).unwrap_or(&());
+        let mut field = (accessor.get)(value)?;
+
+        let Some(set) = accessor.set else {
+            self.ui_for_reflect_readonly_with_options(field.as_ref(), ui, id, field_options);
+            return Some(false);
+        };
+        let changed = self.ui_for_reflect_with_options(field.as_mut(), ui, id, field_options);
+        if changed {
+            set(value, field);
+        }
+        Some(changed)
+    }
+
+    fn ui_for_field_accessor_readonly(
+        &mut self,
+        value: &dyn Reflect,
+        target: Target,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) -> Option<()> {
+        let options = options.downcast_ref::<InspectorOptions>()?;
+        let field = (options.accessor(target)?.get)(value)?;
+        let field_options = options.get(target).unwrap_or(&());
+        self.ui_for_reflect_readonly_with_options(field.as_ref(), ui, id, field_options);
+        Some(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn ui_for_field_accessor_many(
+        &mut self,
+        type_id: TypeId,
+        type_path: &str,
+        target: Target,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        values: &mut [&mut dyn Reflect],
+        projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+    ) -> Option<bool> {
+        let options = options.downcast_ref::<InspectorOptions>()?;
+        let accessor = options.accessor(target)?;
+        let field_options = options.get(target).unwrap_or(&());
+        let mut fields = values
+            .iter_mut()
+            .map(|value| (accessor.get)(projector(*value)))
+            .collect::<Option<Vec<_>>>()?;
+        let mut field_refs: Vec<&mut dyn Reflect> =
+            fields.iter_mut().map(|field| field.as_mut()).collect();
+
+        let Some(set) = accessor.set else {
+            ui.add_enabled_ui(false, |ui| {
+                self.ui_for_reflect_many_with_options(
+                    type_id,
+                    type_path,
+                    ui,
+                    id,
+                    field_options,
+                    &mut field_refs,
+                    &|field| field,
+                )
+            });
+            return Some(false);
+        };
+        let changed = self.ui_for_reflect_many_with_options(
+            type_id,
+            type_path,
+            ui,
+            id,
+            field_options,
+            &mut field_refs,
+            &|field| field,
+        );
+        if changed {
+            for (value, field) in values.iter_mut().zip(fields) {
+                set(projector(*value), field);
+            }
+        }
+        Some(changed)
+    }
+
+    /// Whether `value` is a struct without a custom UI, which is displayed under a collapsing header when nested in another struct
+    fn is_nested_struct(&self, value: &dyn Reflect, options: &dyn Any) -> bool {
+        matches!(value.reflect_ref(), ReflectRef::Struct(_))
+            && !options.is::<CustomWidget>()
+            && self
+                .type_registry
+                .get_type_data::<InspectorEguiImpl>(Any::type_id(value))
+                .is_none()
+            && self
+                .type_registry
+                .get_type_data::<CustomWidget>(Any::type_id(value))
+                .is_none()
+    }
+
     fn ui_for_struct_readonly(
         &mut self,
         value: &dyn Struct,
@@ -466,16 +912,75 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
-        Grid::new(id).show(ui, |ui| {
+        let i18n = InspectorI18n::get(ui.ctx());
+        field_grid(id, ui).show(ui, |ui| {
             for i in 0..value.field_len() {
-                ui.label(value.name_at(i).unwrap());
-                let field = value.field_at(i).unwrap();
-                self.ui_for_reflect_readonly_with_options(
-                    field,
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
+                if !inspector_options_field_visible(options, Target::Field(i), value.as_reflect()) {
+                    continue;
+                }
+                let name = value.name_at(i).unwrap();
+                let note_path = notes::field_path(value.reflect_type_path(), name);
+                let label = inspector_options_field_label(options, Target::Field(i));
+                let label = i18n.field_label(value.reflect_type_path(), name, label);
+                let has_accessor = inspector_options_has_accessor(options, Target::Field(i));
+                let highlight = highlight::field_change(ui, id.with(i), value.field_at(i).unwrap());
+                highlight::ui_for_highlight(ui, highlight, |ui| {
+                    notes::ui_for_field_label_with_menu(ui, &label, &note_path, |ui| {
+                        ui.separator();
+                        clipboard::ui_for_copy_menu(
+                            ui,
+                            id.with(i),
+                            value.field_at(i).unwrap(),
+                            self.type_registry,
+                        );
+                        if !has_accessor {
+                            plot::ui_for_plot_toggle(ui, id.with(i), value.field_at(i).unwrap());
+                            field_menu::ui_for_field_menu(ui, value.reflect_type_path(), name);
+                        }
+                    })
+                });
+                if self
+                    .ui_for_field_accessor_readonly(
+                        value.as_reflect(),
+                        Target::Field(i),
+                        ui,
+                        id.with(i),
+                        options,
+                    )
+                    .is_some()
+                {
+                    ui.end_row();
+                    continue;
+                }
+                let dynamic_options =
+                    inspector_options_dynamic(options, Target::Field(i), value.as_reflect());
+                let field_options = dynamic_options.as_deref().map_or_else(
+                    || inspector_options_struct_field(options, i),
+                    |options| options.as_any(),
                 );
+                let field = value.field_at(i).unwrap();
+                if self.is_nested_struct(field, field_options) {
+                    nested_struct_header(field, id.with(name)).show(ui, |ui| {
+                        self.ui_for_reflect_readonly_with_options(
+                            field,
+                            ui,
+                            id.with(i),
+                            field_options,
+                        )
+                    });
+                } else if plot::is_plotted(ui, id.with(i)) {
+                    ui.vertical(|ui| {
+                        self.ui_for_reflect_readonly_with_options(
+                            field,
+                            ui,
+                            id.with(i),
+                            field_options,
+                        );
+                        plot::ui_for_plot(ui, id.with(i), field);
+                    });
+                } else {
+                    self.ui_for_reflect_readonly_with_options(field, ui, id.with(i), field_options);
+                }
                 ui.end_row();
             }
         });
@@ -491,9 +996,33 @@ impl InspectorUi<'_, '_> {
         projector: impl Fn(&mut dyn Reflect) -> &mut dyn Reflect,
     ) -> bool {
         let mut changed = false;
-        Grid::new(id).show(ui, |ui| {
+        let i18n = InspectorI18n::get(ui.ctx());
+        field_grid(id, ui).show(ui, |ui| {
             for (i, field) in info.iter().enumerate() {
-                ui.label(field.name());
+                if !inspector_options_field_visible_many(
+                    options,
+                    Target::Field(i),
+                    values,
+                    &projector,
+                ) {
+                    continue;
+                }
+                let label = inspector_options_field_label(options, Target::Field(i));
+                ui.label(&*i18n.field_label(info.type_path(), field.name(), label));
+                if let Some(field_changed) = self.ui_for_field_accessor_many(
+                    field.type_id(),
+                    field.type_path(),
+                    Target::Field(i),
+                    ui,
+                    id.with(i),
+                    options,
+                    values,
+                    &projector,
+                ) {
+                    changed |= field_changed;
+                    ui.end_row();
+                    continue;
+                }
                 changed |= self.ui_for_reflect_many_with_options(
                     field.type_id(),
                     field.type_path(),
@@ -522,16 +1051,46 @@ impl InspectorUi<'_, '_> {
         maybe_grid(value.field_len(), ui, id, |ui, label| {
             (0..value.field_len())
                 .map(|i| {
+                    if !inspector_options_field_visible(
+                        options,
+                        Target::Field(i),
+                        value.as_reflect(),
+                    ) {
+                        return false;
+                    }
                     if label {
                         ui.label(i.to_string());
                     }
-                    let field = value.field_mut(i).unwrap();
-                    let changed = self.ui_for_reflect_with_options(
-                        field,
+                    let accessor_changed = self.ui_for_field_accessor(
+                        value.as_reflect_mut(),
+                        Target::Field(i),
                         ui,
                         id.with(i),
-                        inspector_options_struct_field(options, i),
+                        options,
                     );
+                    let changed = accessor_changed.unwrap_or_else(|| {
+                        let dynamic_options = inspector_options_dynamic(
+                            options,
+                            Target::Field(i),
+                            value.as_reflect(),
+                        );
+                        let field_options = dynamic_options.as_deref().map_or_else(
+                            || inspector_options_struct_field(options, i),
+                            |options| options.as_any(),
+                        );
+                        let error =
+                            validation::field_error(options, Target::Field(i), value.as_reflect());
+                        let field = value.field_mut(i).unwrap();
+                        self.ui_for_field_with_reset(
+                            field,
+                            ui,
+                            id.with(i),
+                            field_options,
+                            options,
+                            Target::Field(i),
+                            error.as_deref(),
+                        )
+                    });
                     ui.end_row();
                     changed
                 })
@@ -548,16 +1107,31 @@ impl InspectorUi<'_, '_> {
     ) {
         maybe_grid_readonly(value.field_len(), ui, id, |ui, label| {
             for i in 0..value.field_len() {
+                if !inspector_options_field_visible(options, Target::Field(i), value.as_reflect()) {
+                    continue;
+                }
                 if label {
                     ui.label(i.to_string());
                 }
-                let field = value.field(i).unwrap();
-                self.ui_for_reflect_readonly_with_options(
-                    field,
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
-                );
+                if self
+                    .ui_for_field_accessor_readonly(
+                        value.as_reflect(),
+                        Target::Field(i),
+                        ui,
+                        id.with(i),
+                        options,
+                    )
+                    .is_none()
+                {
+                    let dynamic_options =
+                        inspector_options_dynamic(options, Target::Field(i), value.as_reflect());
+                    let field_options = dynamic_options.as_deref().map_or_else(
+                        || inspector_options_struct_field(options, i),
+                        |options| options.as_any(),
+                    );
+                    let field = value.field(i).unwrap();
+                    self.ui_for_reflect_readonly_with_options(field, ui, id.with(i), field_options);
+                }
                 ui.end_row();
             }
         })
@@ -576,21 +1150,41 @@ impl InspectorUi<'_, '_> {
             info.iter()
                 .enumerate()
                 .map(|(i, field)| {
+                    if !inspector_options_field_visible_many(
+                        options,
+                        Target::Field(i),
+                        values,
+                        &projector,
+                    ) {
+                        return false;
+                    }
                     if label {
                         ui.label(i.to_string());
                     }
-                    let changed = self.ui_for_reflect_many_with_options(
+                    let accessor_changed = self.ui_for_field_accessor_many(
                         field.type_id(),
                         field.type_path(),
+                        Target::Field(i),
                         ui,
                         id.with(i),
-                        inspector_options_struct_field(options, i),
+                        options,
                         values,
-                        &|a| match projector(a).reflect_mut() {
-                            ReflectMut::TupleStruct(strukt) => strukt.field_mut(i).unwrap(),
-                            _ => unreachable!(),
-                        },
+                        &projector,
                     );
+                    let changed = accessor_changed.unwrap_or_else(|| {
+                        self.ui_for_reflect_many_with_options(
+                            field.type_id(),
+                            field.type_path(),
+                            ui,
+                            id.with(i),
+                            inspector_options_struct_field(options, i),
+                            values,
+                            &|a| match projector(a).reflect_mut() {
+                                ReflectMut::TupleStruct(strukt) => strukt.field_mut(i).unwrap(),
+                                _ => unreachable!(),
+                            },
+                        )
+                    });
                     ui.end_row();
                     changed
                 })
@@ -702,9 +1296,11 @@ impl InspectorUi<'_, '_> {
             };
             match op {
                 AddElement(i) => {
-                    let default = self
-                        .get_default_value_for(info.item_type_id())
-                        .or_else(|| list.get(i).map(Reflect::clone_value));
+                    let default = self.get_default_value_for(info.item_type_id()).or_else(|| {
+                        // appending at the end clones the last element
+                        let template = list.get(i).or_else(|| list.get(i.checked_sub(1)?))?;
+                        Some(template.clone_value())
+                    });
                     if let Some(new_value) = default {
                         list.insert(i, new_value);
                     } else {
@@ -738,6 +1334,10 @@ impl InspectorUi<'_, '_> {
                     list.remove(i + 2);
                     changed = true;
                 }
+                Clear => {
+                    while list.pop().is_some() {}
+                    changed = true;
+                }
             }
         }
         changed
@@ -752,25 +1352,28 @@ impl InspectorUi<'_, '_> {
     ) -> bool {
         use ListOp::*;
         let mut changed = false;
+        let (fixed_size, labels, options) = list_options(options);
 
         ui.vertical(|ui| {
             let mut op = None;
             let len = list.len();
-            if len == 0 && ui_for_empty_list(ui) {
+            if len == 0 && ui_for_empty_list(ui, fixed_size) {
                 op = Some(AddElement(0))
             }
             for i in 0..len {
-                egui::Grid::new((id, i)).show(ui, |ui| {
-                    ui.label(i.to_string());
+                field_grid((id, i), ui).show(ui, |ui| {
+                    ui.label(labels.label(i));
                     let val = list.get_mut(i).unwrap();
                     ui.horizontal_top(|ui| {
                         changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
                     });
                     ui.end_row();
 
-                    let item_op = ui_for_list_controls(ui, i, len);
-                    if item_op.is_some() {
-                        op = item_op;
+                    if !fixed_size {
+                        let item_op = ui_for_list_controls(ui, i, len);
+                        if item_op.is_some() {
+                            op = item_op;
+                        }
                     }
                 });
 
@@ -778,6 +1381,9 @@ impl InspectorUi<'_, '_> {
                     ui.separator();
                 }
             }
+            if !fixed_size {
+                op = ui_for_list_footer(ui, len).or(op);
+            }
 
             let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
                 return;
@@ -809,11 +1415,13 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        let (_, labels, options) = list_options(options);
         ui.vertical(|ui| {
             let len = list.len();
             for i in 0..len {
                 let val = list.get(i).unwrap();
                 ui.horizontal_top(|ui| {
+                    ui_for_custom_element_label(ui, labels, i);
                     self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options)
                 });
 
@@ -835,6 +1443,7 @@ impl InspectorUi<'_, '_> {
     ) -> bool {
         use ListOp::*;
         let mut changed = false;
+        let (fixed_size, labels, options) = list_options(options);
 
         let same_len =
             iter_all_eq(
@@ -854,7 +1463,7 @@ impl InspectorUi<'_, '_> {
         ui.vertical(|ui| {
             let mut op = None;
 
-            if len == 0 && ui_for_empty_list(ui) {
+            if len == 0 && ui_for_empty_list(ui, fixed_size) {
                 op = Some(AddElement(0));
             }
 
@@ -867,8 +1476,8 @@ impl InspectorUi<'_, '_> {
                     })
                     .collect();
 
-                egui::Grid::new((id, i)).show(ui, |ui| {
-                    ui.label(i.to_string());
+                field_grid((id, i), ui).show(ui, |ui| {
+                    ui.label(labels.label(i));
                     ui.horizontal_top(|ui| {
                         changed |= self.ui_for_reflect_many_with_options(
                             info.item_type_id(),
@@ -881,9 +1490,11 @@ impl InspectorUi<'_, '_> {
                         );
                     });
                     ui.end_row();
-                    let item_op = ui_for_list_controls(ui, i, len);
-                    if item_op.is_some() {
-                        op = item_op;
+                    if !fixed_size {
+                        let item_op = ui_for_list_controls(ui, i, len);
+                        if item_op.is_some() {
+                            op = item_op;
+                        }
                     }
                 });
 
@@ -891,6 +1502,9 @@ impl InspectorUi<'_, '_> {
                     ui.separator();
                 }
             }
+            if !fixed_size {
+                op = ui_for_list_footer(ui, len).or(op);
+            }
 
             let error_id = id.with("error");
             let error = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(error_id));
@@ -933,7 +1547,7 @@ impl InspectorUi<'_, '_> {
         });
         let mut to_delete: Option<usize> = None;
 
-        egui::Grid::new(id).show(ui, |ui| {
+        field_grid(id, ui).show(ui, |ui| {
             for i in 0..map.len() {
                 if let Some((key, value)) = map.get_at_mut(i) {
                     self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &());
@@ -1017,7 +1631,7 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         _options: &dyn Any,
     ) {
-        egui::Grid::new(id).show(ui, |ui| {
+        field_grid(id, ui).show(ui, |ui| {
             for (i, (key, value)) in map.iter().enumerate() {
                 self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &());
                 self.ui_for_reflect_readonly_with_options(value, ui, id.with(i), &());
@@ -1034,12 +1648,14 @@ impl InspectorUi<'_, '_> {
         options: &dyn Any,
     ) -> bool {
         let mut changed = false;
+        let (_, labels, options) = list_options(options);
 
         ui.vertical(|ui| {
             let len = array.len();
             for i in 0..len {
                 let val = array.get_mut(i).unwrap();
                 ui.horizontal_top(|ui| {
+                    ui_for_custom_element_label(ui, labels, i);
                     changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
                 });
 
@@ -1059,11 +1675,13 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        let (_, labels, options) = list_options(options);
         ui.vertical(|ui| {
             let len = array.len();
             for i in 0..len {
                 let val = array.get(i).unwrap();
                 ui.horizontal_top(|ui| {
+                    ui_for_custom_element_label(ui, labels, i);
                     self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options);
                 });
 
@@ -1091,6 +1709,7 @@ impl InspectorUi<'_, '_> {
         };
 
         let mut changed = false;
+        let i18n = InspectorI18n::get(ui.ctx());
 
         ui.vertical(|ui| {
             let changed_variant =
@@ -1106,22 +1725,46 @@ impl InspectorUi<'_, '_> {
                 maybe_grid_label_if(value.field_len(), ui, id, always_show_label, |ui, label| {
                     (0..value.field_len())
                         .map(|i| {
+                            let target = Target::VariantField {
+                                variant_index,
+                                field_index: i,
+                            };
+                            if !inspector_options_field_visible(options, target, value.as_reflect())
+                            {
+                                return false;
+                            }
                             if label {
                                 if let Some(name) = value.name_at(i) {
-                                    ui.label(name);
+                                    let label = inspector_options_field_label(options, target);
+                                    let type_path = value.reflect_type_path();
+                                    ui.label(&*i18n.field_label(type_path, name, label));
                                 } else {
                                     ui.label(i.to_string());
                                 }
                             }
-                            let field_value = value
-                                .field_at_mut(i)
-                                .expect("invalid reflect impl: field len");
-                            let changed = self.ui_for_reflect_with_options(
-                                field_value,
+                            let accessor_changed = self.ui_for_field_accessor(
+                                value.as_reflect_mut(),
+                                target,
                                 ui,
                                 id.with(i),
-                                inspector_options_enum_variant_field(options, variant_index, i),
+                                options,
                             );
+                            let changed = accessor_changed.unwrap_or_else(|| {
+                                let error =
+                                    validation::field_error(options, target, value.as_reflect());
+                                let field_value = value
+                                    .field_at_mut(i)
+                                    .expect("invalid reflect impl: field len");
+                                self.ui_for_field_with_reset(
+                                    field_value,
+                                    ui,
+                                    id.with(i),
+                                    inspector_options_enum_variant_field(options, variant_index, i),
+                                    options,
+                                    target,
+                                    error.as_deref(),
+                                )
+                            });
                             ui.end_row();
                             changed
                         })
@@ -1178,10 +1821,36 @@ impl InspectorUi<'_, '_> {
                 changed |=
                     maybe_grid_label_if(field_len, ui, id, always_show_label, |ui, label| {
                         let handle = |(field_index, field_name, field_type_id, field_type_name)| {
+                            let target = Target::VariantField {
+                                variant_index,
+                                field_index,
+                            };
+                            if !inspector_options_field_visible_many(
+                                options, target, values, &projector,
+                            ) {
+                                return false;
+                            }
                             if label {
                                 ui.label(field_name);
                             }
 
+                            if self
+                                .ui_for_field_accessor_many(
+                                    field_type_id,
+                                    field_type_name,
+                                    target,
+                                    ui,
+                                    id.with(field_index),
+                                    options,
+                                    values,
+                                    &projector,
+                                )
+                                .is_some()
+                            {
+                                ui.end_row();
+                                return false;
+                            }
+
                             let mut variants_across: Vec<&mut dyn Reflect> = values
                                 .iter_mut()
                                 .map(|value| match projector(*value).reflect_mut() {
@@ -1319,6 +1988,7 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        let i18n = InspectorI18n::get(ui.ctx());
         ui.vertical(|ui| {
             let active_variant = value.variant_name();
             ui.add_enabled_ui(false, |ui| {
@@ -1335,21 +2005,45 @@ impl InspectorUi<'_, '_> {
                 always_show_label,
                 |ui, label| {
                     for i in 0..value.field_len() {
+                        let target = Target::VariantField {
+                            variant_index: value.variant_index(),
+                            field_index: i,
+                        };
+                        if !inspector_options_field_visible(options, target, value.as_reflect()) {
+                            continue;
+                        }
                         if label {
                             if let Some(name) = value.name_at(i) {
-                                ui.label(name);
+                                let label = inspector_options_field_label(options, target);
+                                let type_path = value.reflect_type_path();
+                                ui.label(&*i18n.field_label(type_path, name, label));
                             } else {
                                 ui.label(i.to_string());
                             }
                         }
-                        let field_value =
-                            value.field_at(i).expect("invalid reflect impl: field len");
-                        self.ui_for_reflect_readonly_with_options(
-                            field_value,
-                            ui,
-                            id.with(i),
-                            inspector_options_enum_variant_field(options, value.variant_index(), i),
-                        );
+                        if self
+                            .ui_for_field_accessor_readonly(
+                                value.as_reflect(),
+                                target,
+                                ui,
+                                id.with(i),
+                                options,
+                            )
+                            .is_none()
+                        {
+                            let field_value =
+                                value.field_at(i).expect("invalid reflect impl: field len");
+                            self.ui_for_reflect_readonly_with_options(
+                                field_value,
+                                ui,
+                                id.with(i),
+                                inspector_options_enum_variant_field(
+                                    options,
+                                    value.variant_index(),
+                                    i,
+                                ),
+                            );
+                        }
                         ui.end_row();
                     }
                 },
@@ -1450,6 +2144,40 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
     }
 }
 
+/// Display the `#[inspector(button = "..")]` methods of `value`, returning `true` if one was called
+fn ui_for_buttons(value: &mut dyn Reflect, ui: &mut egui::Ui, buttons: &[InspectorButton]) -> bool {
+    if buttons.is_empty() {
+        return false;
+    }
+    let mut changed = false;
+    ui.horizontal_wrapped(|ui| {
+        for button in buttons {
+            if ui.button(button.name).clicked() {
+                (button.call)(value);
+                changed = true;
+            }
+        }
+    });
+    changed
+}
+
+/// Header for a struct nested in a struct field. Its id only depends on the field name,
+/// so its open state is kept in egui's persisted memory across frames and restarts.
+fn nested_struct_header(value: &dyn Reflect, id: egui::Id) -> egui::CollapsingHeader {
+    egui::CollapsingHeader::new(value.reflect_short_type_path())
+        .id_source(id)
+        .default_open(true)
+}
+
+/// A grid of labels and values, with the [`label_width`](crate::theme::InspectorStyle::label_width) of the inspector style
+fn field_grid(id_source: impl std::hash::Hash, ui: &egui::Ui) -> Grid {
+    let grid = Grid::new(id_source);
+    match InspectorStyle::get(ui.ctx()).label_width {
+        Some(width) => grid.min_col_width(width),
+        None => grid,
+    }
+}
+
 #[must_use]
 fn maybe_grid(
     i: usize,
@@ -1460,7 +2188,7 @@ fn maybe_grid(
     match i {
         0 => false,
         1 => f(ui, false),
-        _ => Grid::new(id).show(ui, |ui| f(ui, true)).inner,
+        _ => field_grid(id, ui).show(ui, |ui| f(ui, true)).inner,
     }
 }
 #[must_use]
@@ -1474,7 +2202,7 @@ fn maybe_grid_label_if(
     match i {
         0 => false,
         1 if !always_show_label => f(ui, false),
-        _ => Grid::new(id).show(ui, |ui| f(ui, true)).inner,
+        _ => field_grid(id, ui).show(ui, |ui| f(ui, true)).inner,
     }
 }
 
@@ -1488,7 +2216,7 @@ fn maybe_grid_readonly(
         0 => {}
         1 => f(ui, false),
         _ => {
-            Grid::new(id).show(ui, |ui| f(ui, true));
+            field_grid(id, ui).show(ui, |ui| f(ui, true));
         }
     }
 }
@@ -1503,7 +2231,7 @@ fn maybe_grid_readonly_label_if(
         0 => {}
         1 if !always_show_label => f(ui, false),
         _ => {
-            Grid::new(id).show(ui, |ui| f(ui, true));
+            field_grid(id, ui).show(ui, |ui| f(ui, true));
         }
     }
 }
@@ -1564,6 +2292,137 @@ fn inspector_options_enum_variant_field<'a>(
         .unwrap_or(&())
 }
 
+/// The options for the only field of a value of a type with `#[inspector(transparent)]`, or `None` for other types.
+///
+/// These are the options of the field itself if the value has its own options, or the options given for the value otherwise.
+fn transparent_field_options<'a>(
+    type_registry: &'a TypeRegistry,
+    type_id: TypeId,
+    options: &'a dyn Any,
+) -> Option<&'a dyn Any> {
+    let own_options = &type_registry
+        .get_type_data::<ReflectInspectorOptions>(type_id)?
+        .0;
+    if !own_options.is_transparent() {
+        return None;
+    }
+    if options.is::<()>() || std::ptr::addr_eq(options, own_options) {
+        Some(own_options.get(Target::Field(0)).unwrap_or(&()))
+    } else {
+        Some(options)
+    }
+}
+
+fn transparent_field(value: &dyn Reflect) -> Option<&dyn Reflect> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) if value.field_len() == 1 => value.field_at(0),
+        ReflectRef::TupleStruct(value) if value.field_len() == 1 => value.field(0),
+        _ => None,
+    }
+}
+
+fn transparent_field_mut(value: &mut dyn Reflect) -> Option<&mut dyn Reflect> {
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) if value.field_len() == 1 => value.field_at_mut(0),
+        ReflectMut::TupleStruct(value) if value.field_len() == 1 => value.field_mut(0),
+        _ => None,
+    }
+}
+
+/// The options of the field at `target` depending on the rest of `value`, see [`InspectorOptions::set_dynamic_options`]
+fn inspector_options_dynamic(
+    options: &dyn Any,
+    target: Target,
+    value: &dyn Reflect,
+) -> Option<Box<dyn TypeData>> {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.dynamic_options(target, value))
+}
+
+/// The `#[inspector(label = "..")]` of the field at `target`
+fn inspector_options_field_label(options: &dyn Any, target: Target) -> Option<&'static str> {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.label(target))
+}
+
+/// Whether the field at `target` is read and written through a [`FieldAccessor`](crate::inspector_options::FieldAccessor)
+fn inspector_options_has_accessor(options: &dyn Any, target: Target) -> bool {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .is_some_and(|options| options.accessor(target).is_some())
+}
+
+/// Whether the field at `target` of `value` is shown, see [`InspectorOptions::set_visible_if`]
+fn inspector_options_field_visible(options: &dyn Any, target: Target, value: &dyn Reflect) -> bool {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .map_or(true, |options| options.is_visible(target, value))
+}
+
+/// Whether the field at `target` is shown for any of the `values`
+fn inspector_options_field_visible_many(
+    options: &dyn Any,
+    target: Target,
+    values: &mut [&mut dyn Reflect],
+    projector: &impl Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+) -> bool {
+    values
+        .iter_mut()
+        .any(|value| inspector_options_field_visible(options, target, projector(*value)))
+}
+
 fn or(a: bool, b: bool) -> bool {
     a || b
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    use super::{ui_for_value, Context, InspectorWidget};
+    use crate::inspector_egui_impls::{register_raw, InspectorEguiImpl};
+
+    #[derive(Reflect)]
+    struct Data {
+        value: f32,
+    }
+
+    #[test]
+    fn widget_reports_size_of_the_value_ui() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Data>();
+        type_registry.register_type_data::<f32, InspectorEguiImpl>();
+
+        let mut data = Data { value: 1.0 };
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.add(InspectorWidget::new(&mut data, &type_registry));
+                assert!(!response.changed());
+                assert!(response.rect.height() > 0.0);
+            });
+        });
+    }
+
+    fn reset(data: &mut Data, _: &mut egui::Ui, _: &mut Context) -> bool {
+        data.value = 0.0;
+        true
+    }
+
+    #[test]
+    fn registered_widget_replaces_the_ui_of_the_type() {
+        let mut type_registry = TypeRegistry::default();
+        register_raw::<Data>(&mut type_registry, reset);
+
+        let mut data = Data { value: 1.0 };
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                assert!(ui_for_value(&mut data, ui, &type_registry));
+            });
+        });
+        assert_eq!(data.value, 0.0);
+    }
+}
@@ -0,0 +1,109 @@
+//! Catching panics of custom widgets, so that a buggy [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl)
+//! or [`CustomWidget`](crate::inspector_options::CustomWidget) only disables itself instead of crashing the app.
+//!
+//! This relies on unwinding, so on targets or profiles with `panic = "abort"`, like `wasm32-unknown-unknown`, panics still abort.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use bevy_reflect::Reflect;
+use egui::FontId;
+
+use crate::egui_utils::layout_job;
+
+/// Message of a widget which panicked, kept in egui's memory until the widget is re-enabled
+#[derive(Clone)]
+struct Panicked(String);
+
+/// Run `widget`, which displays a value of the type `type_name`.
+///
+/// If it panics, it is replaced by an error card with the panic message in this and the following frames,
+/// until it is re-enabled using the button on the card. Returns `None` while the widget is disabled.
+pub(crate) fn catch_widget_panic<R>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_name: &str,
+    widget: impl FnOnce(&mut egui::Ui) -> R,
+) -> Option<R> {
+    let id = ui.id().with(id).with(type_name).with("panicked widget");
+    if let Some(panicked) = ui.data(|data| data.get_temp::<Panicked>(id)) {
+        if ui_for_panicked(ui, type_name, &panicked.0) {
+            ui.data_mut(|data| data.remove::<Panicked>(id));
+        }
+        return None;
+    }
+
+    match panic::catch_unwind(AssertUnwindSafe(|| widget(ui))) {
+        Ok(result) => Some(result),
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            bevy_log::error!("The inspector widget for {type_name} panicked: {message}");
+            ui.data_mut(|data| data.insert_temp(id, Panicked(message)));
+            None
+        }
+    }
+}
+
+/// The type path of `value` without borrowing it, for use with [`catch_widget_panic`]
+pub(crate) fn type_name(value: &dyn Reflect) -> &'static str {
+    value
+        .get_represented_type_info()
+        .map_or("<unknown type>", |info| info.type_path())
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_owned()
+    }
+}
+
+/// Display the error card of a panicked widget, returning whether it should be re-enabled
+fn ui_for_panicked(ui: &mut egui::Ui, type_name: &str, message: &str) -> bool {
+    let mut retry = false;
+    egui::Frame::group(ui.style())
+        .stroke(egui::Stroke::new(1.0, ui.visuals().error_fg_color))
+        .show(ui, |ui| {
+            ui.label(layout_job(&[
+                (FontId::proportional(13.0), "The widget for "),
+                (
+                    FontId::monospace(12.0),
+                    &pretty_type_name::pretty_type_name_str(type_name),
+                ),
+                (FontId::proportional(13.0), " panicked:\n"),
+                (FontId::monospace(12.0), message),
+            ]));
+            retry = ui
+                .button("Re-enable")
+                .on_hover_text("Show the widget again, which may panic again")
+                .clicked();
+        });
+    retry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catch_widget_panic;
+
+    #[test]
+    fn panicking_widget_is_disabled() {
+        let ctx = egui::Context::default();
+        let frame = |widget: &dyn Fn() -> bool| {
+            let mut result = None;
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let id = egui::Id::new("field");
+                    result = catch_widget_panic(ui, id, "Buggy", |_| widget());
+                });
+            });
+            result
+        };
+
+        assert_eq!(frame(&|| true), Some(true));
+        assert_eq!(frame(&|| panic!("buggy widget")), None);
+        // stays disabled until it is re-enabled
+        assert_eq!(frame(&|| true), None);
+    }
+}
@@ -0,0 +1,136 @@
+//! Plots of numeric fields over time, toggled from the context menu of the field's label.
+//!
+//! While a plot is enabled, the value of the field is recorded every frame it is displayed, and the recent values
+//! are drawn below its widget. The plots are stored in the egui memory, so they don't survive a restart.
+
+use std::collections::VecDeque;
+
+use bevy_reflect::Reflect;
+
+/// How many seconds of values a new plot shows
+pub const DEFAULT_PLOT_SECONDS: f64 = 10.0;
+
+/// The recorded values of a plotted field
+#[derive(Clone, Debug)]
+struct FieldPlot {
+    seconds: f64,
+    /// The egui time and value of every recorded frame, oldest first
+    samples: VecDeque<(f64, f64)>,
+}
+
+impl FieldPlot {
+    fn new() -> Self {
+        FieldPlot {
+            seconds: DEFAULT_PLOT_SECONDS,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Append `value` at `time` and drop the values older than the plotted duration
+    fn record(&mut self, time: f64, value: f64) {
+        self.samples.push_back((time, value));
+        while self
+            .samples
+            .front()
+            .is_some_and(|&(recorded, _)| time - recorded > self.seconds)
+        {
+            self.samples.pop_front();
+        }
+    }
+}
+
+fn plot_id(id: egui::Id) -> egui::Id {
+    id.with("field plot")
+}
+
+/// The value of `value` if it is a primitive number
+pub fn numeric_value(value: &dyn Reflect) -> Option<f64> {
+    macro_rules! numbers {
+        ($($ty:ty),*) => {
+            $(if let Some(value) = value.downcast_ref::<$ty>() {
+                return Some(*value as f64);
+            })*
+        };
+    }
+    numbers!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    None
+}
+
+/// Whether the field displayed with `id` is plotted
+pub fn is_plotted(ui: &egui::Ui, id: egui::Id) -> bool {
+    ui.data(|data| data.get_temp::<FieldPlot>(plot_id(id)).is_some())
+}
+
+/// Plot the field displayed with `id`, or stop plotting it and forget its values
+pub fn set_plotted(ui: &egui::Ui, id: egui::Id, plotted: bool) {
+    ui.data_mut(|data| match plotted {
+        true => data.insert_temp(plot_id(id), FieldPlot::new()),
+        false => data.remove::<FieldPlot>(plot_id(id)),
+    });
+}
+
+/// A toggle for plotting the field `value` displayed with `id`, for use inside of its context menu.
+///
+/// Nothing is added if `value` isn't a number.
+pub(crate) fn ui_for_plot_toggle(ui: &mut egui::Ui, id: egui::Id, value: &dyn Reflect) {
+    if numeric_value(value).is_none() {
+        return;
+    }
+    let mut plotted = is_plotted(ui, id);
+    if ui.checkbox(&mut plotted, "Plot over time").changed() {
+        set_plotted(ui, id, plotted);
+    }
+}
+
+/// Record `value` and draw its recent values, if the field displayed with `id` is plotted
+pub(crate) fn ui_for_plot(ui: &mut egui::Ui, id: egui::Id, value: &dyn Reflect) {
+    let Some(mut plot) = ui.data(|data| data.get_temp::<FieldPlot>(plot_id(id))) else {
+        return;
+    };
+    let Some(value) = numeric_value(value) else {
+        return;
+    };
+    plot.record(ui.input(|input| input.time), value);
+    // keep recording while the value isn't changing
+    ui.ctx().request_repaint();
+
+    let values: Vec<f64> = plot.samples.iter().map(|&(_, value)| value).collect();
+    ui.horizontal(|ui| {
+        crate::egui_utils::sparkline(ui, &values).on_hover_ui(|ui| {
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            if min <= max {
+                ui.label(format!("min {min:.3}"));
+                ui.label(format!("max {max:.3}"));
+            }
+        });
+        ui.add(
+            egui::DragValue::new(&mut plot.seconds)
+                .clamp_range(0.5..=600.0)
+                .speed(0.5)
+                .suffix("s"),
+        )
+        .on_hover_text("Plotted duration");
+    });
+
+    ui.data_mut(|data| data.insert_temp(plot_id(id), plot));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{numeric_value, FieldPlot};
+
+    #[test]
+    fn plots_keep_the_plotted_duration() {
+        let mut plot = FieldPlot::new();
+        plot.seconds = 2.0;
+        for frame in 0..=10 {
+            plot.record(frame as f64 * 0.5, frame as f64);
+        }
+        let times: Vec<f64> = plot.samples.iter().map(|&(time, _)| time).collect();
+        assert_eq!(times, [3.0, 3.5, 4.0, 4.5, 5.0]);
+
+        assert_eq!(numeric_value(&3u16), Some(3.0));
+        assert_eq!(numeric_value(&String::new()), None);
+    }
+}
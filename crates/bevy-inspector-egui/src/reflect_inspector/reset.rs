@@ -0,0 +1,145 @@
+//! Buttons resetting fields to their defaults, see [`FieldReset`].
+//!
+//! Fields get a reset button with `#[inspector(reset)]` or `#[inspector(default = ..)]`, or for every field whose type
+//! is registered with `#[reflect(Default)]` when [`InspectorStyle::reset_buttons`] is enabled.
+
+use std::any::Any;
+
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, ReflectMut, TypeRegistry};
+
+use crate::{
+    inspector_options::{FieldReset, InspectorOptions, ReflectInspectorOptions, Target},
+    theme::InspectorStyle,
+};
+
+/// The value the reset button of the field at `target` sets `field` to, or `None` if it has no reset button.
+///
+/// `options` are the options of the value containing the field.
+pub(crate) fn field_default(
+    ui: &egui::Ui,
+    options: &dyn Any,
+    target: Target,
+    field: &dyn Reflect,
+    type_registry: &TypeRegistry,
+) -> Option<Box<dyn Reflect>> {
+    let reset = options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.reset(target));
+    let reset = match reset {
+        Some(reset) => reset,
+        None if InspectorStyle::get(ui.ctx()).reset_buttons => FieldReset::Default,
+        None => return None,
+    };
+    match reset {
+        FieldReset::Default => type_default(field.as_any().type_id(), type_registry),
+        FieldReset::Value(value) => Some(value()),
+    }
+}
+
+/// The default of the whole `value`, with the fields which have an `#[inspector(default = ..)]` set to it.
+///
+/// Returns `None` if the type of `value` isn't registered with `#[reflect(Default)]`.
+pub fn value_default(
+    value: &dyn Reflect,
+    type_registry: &TypeRegistry,
+) -> Option<Box<dyn Reflect>> {
+    let type_id = value.as_any().type_id();
+    let mut default = type_default(type_id, type_registry)?;
+    let Some(options) = type_registry.get_type_data::<ReflectInspectorOptions>(type_id) else {
+        return Some(default);
+    };
+
+    let field_value = |i| match options.0.reset(Target::Field(i)) {
+        Some(FieldReset::Value(value)) => Some(value()),
+        _ => None,
+    };
+    match default.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for i in 0..value.field_len() {
+                if let (Some(field), Some(field_value)) = (value.field_at_mut(i), field_value(i)) {
+                    set(field, field_value);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for i in 0..value.field_len() {
+                if let (Some(field), Some(field_value)) = (value.field_mut(i), field_value(i)) {
+                    set(field, field_value);
+                }
+            }
+        }
+        _ => {}
+    }
+    Some(default)
+}
+
+fn type_default(
+    type_id: std::any::TypeId,
+    type_registry: &TypeRegistry,
+) -> Option<Box<dyn Reflect>> {
+    type_registry
+        .get_type_data::<ReflectDefault>(type_id)
+        .map(ReflectDefault::default)
+}
+
+/// Replace `value` with `new`, unlike [`Reflect::apply`] also dropping extra elements of lists and maps
+pub(crate) fn set(value: &mut dyn Reflect, new: Box<dyn Reflect>) {
+    if let Err(new) = value.set(new) {
+        value.apply(new.as_ref());
+    }
+}
+
+/// Display a button setting `field` to `default`, which is disabled while they are equal.
+///
+/// Returns whether the field was reset.
+pub(crate) fn ui_for_reset_button(
+    ui: &mut egui::Ui,
+    field: &mut dyn Reflect,
+    default: Box<dyn Reflect>,
+) -> bool {
+    let is_default = field.reflect_partial_eq(default.as_ref()) == Some(true);
+    let reset = ui
+        .add_enabled(!is_default, egui::Button::new("↺").small())
+        .on_hover_text("Reset to default")
+        .clicked();
+    if reset {
+        set(field, default);
+    }
+    reset
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeRegistry};
+
+    use super::value_default;
+    use crate::inspector_options::{FieldReset, InspectorOptions, ReflectInspectorOptions, Target};
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Camera {
+        zoom: f32,
+        targets: Vec<u32>,
+    }
+
+    #[test]
+    fn value_default_uses_field_defaults() {
+        let mut type_registry = TypeRegistry::new();
+        type_registry.register::<Camera>();
+        let mut options = InspectorOptions::new();
+        options.set_reset(Target::Field(0), FieldReset::Value(|| Box::new(1.5f32)));
+        type_registry
+            .get_mut(std::any::TypeId::of::<Camera>())
+            .unwrap()
+            .insert(ReflectInspectorOptions(options));
+
+        let camera = Camera {
+            zoom: 3.0,
+            targets: vec![1, 2],
+        };
+        let default = value_default(&camera, &type_registry).unwrap();
+        let default = default.downcast_ref::<Camera>().unwrap();
+        assert_eq!(default.zoom, 1.5);
+        assert!(default.targets.is_empty());
+    }
+}
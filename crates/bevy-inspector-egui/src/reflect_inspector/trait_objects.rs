@@ -0,0 +1,368 @@
+//! Boxed trait objects in reflected values, with a dropdown switching between the registered concrete types.
+//!
+//! Fields of the type `Box<dyn Trait>` can't be reflected, so they are wrapped in a [`TraitObject`] instead.
+//! The trait needs [`Reflect`] as a supertrait, and every concrete type that should be selectable in the inspector
+//! is registered with [`register_trait_object_type`]:
+//!
+//! ```rust
+//! use bevy_ecs::prelude::*;
+//! use bevy_reflect::{Reflect, TypeRegistry};
+//! use bevy_inspector_egui::reflect_inspector::trait_objects::{register_trait_object_type, TraitObject};
+//!
+//! trait Behavior: Reflect {
+//!     fn speed(&self) -> f32;
+//! }
+//!
+//! #[derive(Reflect, Default)]
+//! struct Patrol { speed: f32 }
+//! impl Behavior for Patrol {
+//!     fn speed(&self) -> f32 { self.speed }
+//! }
+//!
+//! #[derive(Reflect, Default)]
+//! struct Idle;
+//! impl Behavior for Idle {
+//!     fn speed(&self) -> f32 { 0.0 }
+//! }
+//!
+//! #[derive(Component, Reflect)]
+//! #[reflect(Component)]
+//! struct Ai {
+//!     behavior: TraitObject<dyn Behavior>,
+//! }
+//!
+//! fn register(type_registry: &mut TypeRegistry) {
+//!     type_registry.register::<Ai>();
+//!     register_trait_object_type::<dyn Behavior, Patrol>(type_registry, |patrol| patrol);
+//!     register_trait_object_type::<dyn Behavior, Idle>(type_registry, |idle| idle);
+//! }
+//!
+//! let ai = Ai { behavior: TraitObject::new(Box::new(Idle)) };
+//! assert_eq!(ai.behavior.speed(), 0.0);
+//! ```
+//!
+//! The wrapper is transparent to reflection, it has the kind and fields of the boxed value.
+//! It can't be created with [`FromReflect`] or deserialized though, so values containing it can be edited,
+//! but not pasted or loaded from scenes.
+
+use std::{
+    any::{Any, TypeId},
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+use bevy_reflect::{
+    utility::{GenericTypeInfoCell, GenericTypePathCell},
+    FromReflect, GetTypeRegistration, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+    TypePath, TypeRegistration, TypeRegistry, Typed, ValueInfo,
+};
+
+use super::InspectorUi;
+use crate::inspector_egui_impls::{InspectorEguiImpl, InspectorPrimitive};
+
+/// A boxed trait object `T`, like `TraitObject<dyn Behavior>`, whose concrete type can be switched in the inspector.
+///
+/// See the [module docs](self).
+pub struct TraitObject<T: ?Sized>(pub Box<T>);
+
+impl<T: ?Sized> TraitObject<T> {
+    pub fn new(value: Box<T>) -> Self {
+        TraitObject(value)
+    }
+}
+
+impl<T: ?Sized> Deref for TraitObject<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for TraitObject<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// The concrete types selectable for [`TraitObject<T>`] values, registered as type data of `TraitObject<T>`
+pub struct TraitObjectTypes<T: ?Sized> {
+    types: Vec<TraitObjectType<T>>,
+}
+
+struct TraitObjectType<T: ?Sized> {
+    type_id: TypeId,
+    short_path: &'static str,
+    create: Arc<dyn Fn() -> Box<T> + Send + Sync>,
+}
+
+impl<T: ?Sized> Clone for TraitObjectTypes<T> {
+    fn clone(&self) -> Self {
+        let types = self.types.iter().map(|ty| TraitObjectType {
+            type_id: ty.type_id,
+            short_path: ty.short_path,
+            create: Arc::clone(&ty.create),
+        });
+        TraitObjectTypes {
+            types: types.collect(),
+        }
+    }
+}
+
+impl<T: ?Sized> TraitObjectTypes<T> {
+    /// The short type paths of the registered types, in the order they were registered in
+    pub fn short_paths(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.types.iter().map(|ty| ty.short_path)
+    }
+}
+
+/// Register `C` as a concrete type of [`TraitObject<T>`], created with its [`Default`] value when selected in the inspector.
+///
+/// `into_trait_object` converts the box, which is usually just `|value| value`.
+/// `TraitObject<T>` and `C` are registered in `type_registry` as well.
+pub fn register_trait_object_type<T, C>(
+    type_registry: &mut TypeRegistry,
+    into_trait_object: fn(Box<C>) -> Box<T>,
+) where
+    T: ?Sized + Reflect,
+    C: Reflect + TypePath + Default + GetTypeRegistration,
+{
+    type_registry.register::<C>();
+    type_registry.register::<TraitObject<T>>();
+    let registration = type_registry
+        .get_mut(TypeId::of::<TraitObject<T>>())
+        .unwrap();
+    if registration.data::<InspectorEguiImpl>().is_none() {
+        registration.insert(InspectorEguiImpl::of_with_many::<TraitObject<T>>(
+            crate::inspector_egui_impls::many_unimplemented::<TraitObject<T>>,
+        ));
+    }
+
+    let ty = TraitObjectType {
+        type_id: TypeId::of::<C>(),
+        short_path: C::short_type_path(),
+        create: Arc::new(move || into_trait_object(Box::<C>::default())),
+    };
+    match registration.data_mut::<TraitObjectTypes<T>>() {
+        Some(types) => {
+            if !types.types.iter().any(|known| known.type_id == ty.type_id) {
+                types.types.push(ty);
+            }
+        }
+        None => registration.insert(TraitObjectTypes { types: vec![ty] }),
+    }
+}
+
+impl<T: ?Sized + Reflect> InspectorPrimitive for TraitObject<T> {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let types = env
+            .type_registry
+            .get_type_data::<TraitObjectTypes<T>>(TypeId::of::<Self>())
+            .cloned();
+        let current = Any::type_id(self.0.as_any());
+
+        let mut changed = false;
+        let mut selected = None;
+        egui::ComboBox::from_id_source(id.with("type"))
+            .selected_text(self.0.reflect_short_type_path())
+            .show_ui(ui, |ui| {
+                for ty in types.iter().flat_map(|types| &types.types) {
+                    if ui
+                        .selectable_label(ty.type_id == current, ty.short_path)
+                        .clicked()
+                    {
+                        selected = Some((ty.type_id, Arc::clone(&ty.create)));
+                    }
+                }
+            });
+        if let Some((type_id, create)) = selected {
+            if type_id != current {
+                self.0 = create();
+                changed = true;
+            }
+        }
+
+        changed |=
+            env.ui_for_reflect_with_options(self.0.as_reflect_mut(), ui, id.with("value"), &());
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        ui.label(self.0.reflect_short_type_path());
+        env.ui_for_reflect_readonly_with_options(self.0.as_reflect(), ui, id.with("value"), &());
+    }
+}
+
+impl<T: ?Sized + Reflect> TypePath for TraitObject<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                "bevy_inspector_egui::reflect_inspector::trait_objects::TraitObject<{}>",
+                std::any::type_name::<T>()
+            )
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!("TraitObject<{}>", pretty_type_name::pretty_type_name::<T>())
+        })
+    }
+}
+
+impl<T: ?Sized + Reflect> Typed for TraitObject<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+    }
+}
+
+impl<T: ?Sized + Reflect> GetTypeRegistration for TraitObject<T> {
+    fn get_type_registration() -> TypeRegistration {
+        TypeRegistration::of::<Self>()
+    }
+}
+
+impl<T: ?Sized + Reflect> TraitObject<T> {
+    /// `value`, or the boxed value if it is a [`TraitObject<T>`] itself
+    fn unwrap_reflect(value: &dyn Reflect) -> &dyn Reflect {
+        match value.downcast_ref::<Self>() {
+            Some(value) => value.0.as_reflect(),
+            None => value,
+        }
+    }
+}
+
+// everything except for the `Any` conversions is forwarded to the boxed value
+impl<T: ?Sized + Reflect> Reflect for TraitObject<T> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        self.0.get_represented_type_info()
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        self.0.apply(Self::unwrap_reflect(value));
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take::<Self>()?;
+        Ok(())
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        self.0.reflect_ref()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        self.0.reflect_mut()
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        self.0.into_reflect().reflect_owned()
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        self.0.clone_value()
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        self.0.reflect_partial_eq(Self::unwrap_reflect(value))
+    }
+
+    fn debug(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.debug(f)
+    }
+}
+
+/// Trait objects can't be created from reflection without knowing the concrete type at compile time
+impl<T: ?Sized + Reflect> FromReflect for TraitObject<T> {
+    fn from_reflect(_: &dyn Reflect) -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    use super::{register_trait_object_type, TraitObject, TraitObjectTypes};
+
+    trait Shape: Reflect {
+        fn area(&self) -> f32;
+    }
+
+    #[derive(Reflect, Default)]
+    struct Square {
+        side: f32,
+    }
+    impl Shape for Square {
+        fn area(&self) -> f32 {
+            self.side * self.side
+        }
+    }
+
+    #[derive(Reflect, Default)]
+    struct Point;
+    impl Shape for Point {
+        fn area(&self) -> f32 {
+            0.0
+        }
+    }
+
+    #[test]
+    fn trait_objects_forward_to_the_boxed_value() {
+        let mut type_registry = TypeRegistry::new();
+        register_trait_object_type::<dyn Shape, Square>(&mut type_registry, |square| square);
+        register_trait_object_type::<dyn Shape, Point>(&mut type_registry, |point| point);
+        register_trait_object_type::<dyn Shape, Point>(&mut type_registry, |point| point);
+        let types = type_registry
+            .get_type_data::<TraitObjectTypes<dyn Shape>>(std::any::TypeId::of::<
+                TraitObject<dyn Shape>,
+            >())
+            .unwrap();
+        assert_eq!(types.short_paths().collect::<Vec<_>>(), ["Square", "Point"]);
+
+        let mut shape = TraitObject::<dyn Shape>::new(Box::new(Square { side: 2.0 }));
+        shape.apply(&Square { side: 3.0 });
+        assert_eq!(shape.area(), 9.0);
+        assert_eq!(shape.reflect_partial_eq(&Square { side: 3.0 }), Some(true));
+        assert!(shape.clone_value().represents::<Square>());
+    }
+}
@@ -0,0 +1,54 @@
+//! Showing values which violate an invariant, without preventing them from being edited.
+//!
+//! Derived fields are validated with `#[inspector(validate = "method")]`, see
+//! [`InspectorOptions::set_validator`](crate::inspector_options::InspectorOptions::set_validator). Their widget is
+//! outlined in red with the error on hover, and a badge next to the field name shows the error as well.
+//!
+//! [`InspectorPrimitive`](crate::inspector_egui_impls::InspectorPrimitive) impls and custom widgets can report
+//! their own errors with [`ui_with_error`].
+
+use std::any::Any;
+
+use bevy_reflect::Reflect;
+
+use crate::inspector_options::{InspectorOptions, Target};
+
+/// The error of the validator of the field at `target` of `value`, or `None` if it is valid or has no validator
+pub(crate) fn field_error(
+    options: &dyn Any,
+    target: Target,
+    value: &dyn Reflect,
+) -> Option<String> {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.validate(target, value).err())
+}
+
+/// Display `add_contents`, outlined in red with `error` on hover if there is one
+pub fn ui_with_error<R>(
+    ui: &mut egui::Ui,
+    error: Option<&str>,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let Some(error) = error else {
+        return add_contents(ui);
+    };
+    let response = ui.scope(add_contents);
+    let error_color = ui.visuals().error_fg_color;
+    ui.painter().rect_stroke(
+        response.response.rect.expand(1.0),
+        ui.visuals().widgets.inactive.rounding,
+        egui::Stroke::new(1.0, error_color),
+    );
+    response
+        .response
+        .on_hover_text(egui::RichText::new(error).color(error_color));
+    response.inner
+}
+
+/// A warning sign in the error color, with `error` on hover
+pub fn ui_for_error_badge(ui: &mut egui::Ui, error: &str) -> egui::Response {
+    let error_color = ui.visuals().error_fg_color;
+    ui.label(egui::RichText::new("⚠").color(error_color))
+        .on_hover_text(egui::RichText::new(error).color(error_color))
+}
@@ -0,0 +1,821 @@
+//! Inspecting the world of an app running elsewhere, like a headless server or a build on a phone.
+//!
+//! Enabled by the `remote` feature and not available on the web. The inspected app adds the [`RemoteInspectorServerPlugin`],
+//! which answers [`Request`]s about its entities, components and resources sent over TCP.
+//! A viewer app adds the [`RemoteInspectorClientPlugin`] and displays them in a window, writing edits back to the server:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::remote::{RemoteInspectorClientPlugin, RemoteInspectorServerPlugin};
+//!
+//! fn main() {
+//!     let viewer = std::env::args().any(|arg| arg == "--viewer");
+//!
+//!     let mut app = App::new();
+//!     app.add_plugins(DefaultPlugins);
+//!     match viewer {
+//!         true => app.add_plugins(RemoteInspectorClientPlugin::new("192.168.0.10:15703")),
+//!         false => app.add_plugins(RemoteInspectorServerPlugin::new("0.0.0.0:15703")),
+//!     };
+//!     app.run();
+//! }
+//! ```
+//!
+//! Values are sent as JSON using their [`Reflect`] serialization, so the viewer can only display types which are
+//! registered in its own type registry, usually by sharing the crate defining them with the inspected app.
+//! The protocol has no authentication, only listen on addresses reachable from trusted networks.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Mutex,
+    },
+};
+
+use bevy_app::{Plugin, Startup, Update};
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiPlugin;
+use bevy_log::{error, warn};
+use bevy_reflect::{
+    serde::{ReflectSerializer, UntypedReflectDeserializer},
+    Reflect, TypeRegistry,
+};
+use bevy_utils::HashMap;
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+
+use crate::{
+    bevy_inspector::edit_mode,
+    events::{self, InspectorEvent},
+    quick::{self, InspectorSet, InspectorWindows, InspectorWindowsPlugin},
+    reflect_inspector,
+    theme::InspectorTheme,
+    utils::guess_entity_name::guess_entity_name,
+    DefaultInspectorConfigPlugin,
+};
+
+/// Address the [`RemoteInspectorServerPlugin`] listens on and the [`RemoteInspectorClientPlugin`] connects to by default
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:15703";
+
+/// Title of the window of the [`RemoteInspectorClientPlugin`], for use with [`InspectorWindows`]
+pub const REMOTE_TITLE: &str = "Remote Inspector";
+
+/// Seconds between requests for the values displayed by the client
+const REFRESH_INTERVAL: f64 = 0.5;
+
+/// A message from the client to the server, sent as one line of JSON.
+///
+/// Entities are identified by [`Entity::to_bits`], values are serialized using [`ReflectSerializer`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Request {
+    /// List all entities, answered with [`Response::Entities`]
+    Entities,
+    /// The reflected components of an entity, answered with [`Response::Entity`]
+    Entity(u64),
+    /// List all reflected resources, answered with [`Response::Resources`]
+    Resources,
+    /// The value of the resource with the type path, answered with [`Response::Resource`]
+    Resource(String),
+    /// Apply the value to the component of its type on the entity
+    SetComponent {
+        entity: u64,
+        value: serde_json::Value,
+    },
+    /// Apply the value to the resource of its type
+    SetResource { value: serde_json::Value },
+}
+
+/// An entity in [`Response::Entities`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteEntity {
+    /// The entity as [`Entity::to_bits`]
+    pub entity: u64,
+    /// The name shown in the world inspector
+    pub name: String,
+}
+
+/// A message from the server to the client, sent as one line of JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Response {
+    Entities(Vec<RemoteEntity>),
+    /// The serialized components of the entity, leaving out those which can't be serialized
+    Entity {
+        entity: u64,
+        components: Vec<serde_json::Value>,
+    },
+    /// Type paths of the reflected resources
+    Resources(Vec<String>),
+    /// The serialized value of the resource with the type path
+    Resource {
+        type_path: String,
+        value: serde_json::Value,
+    },
+    /// Edits were applied
+    Applied,
+    Error(String),
+}
+
+fn serialize(value: &dyn Reflect, type_registry: &TypeRegistry) -> Option<serde_json::Value> {
+    serde_json::to_value(ReflectSerializer::new(value, type_registry)).ok()
+}
+
+fn deserialize(
+    value: serde_json::Value,
+    type_registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, String> {
+    UntypedReflectDeserializer::new(type_registry)
+        .deserialize(value)
+        .map_err(|error| error.to_string())
+}
+
+/// The entity sent as [`Entity::to_bits`], or an error for bits no entity can have
+fn parse_entity(bits: u64) -> Result<Entity, Response> {
+    Entity::try_from_bits(bits)
+        .map_err(|error| Response::Error(format!("invalid entity {bits}: {error}")))
+}
+
+/// Answer `request` using the contents of `world`.
+///
+/// This is what the [`RemoteInspectorServerPlugin`] does for every request it receives.
+pub fn handle_request(world: &mut World, request: Request) -> Response {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    match request {
+        Request::Entities => {
+            let mut entities: Vec<_> = world.iter_entities().map(|entity| entity.id()).collect();
+            entities.sort();
+            let entities = entities
+                .into_iter()
+                .map(|entity| RemoteEntity {
+                    entity: entity.to_bits(),
+                    name: guess_entity_name(world, entity),
+                })
+                .collect();
+            Response::Entities(entities)
+        }
+        Request::Entity(bits) => {
+            let entity = match parse_entity(bits) {
+                Ok(entity) => entity,
+                Err(response) => return response,
+            };
+            let Some(entity_ref) = world.get_entity(entity) else {
+                return Response::Error(format!("entity {entity:?} does not exist"));
+            };
+            let components = entity_ref
+                .archetype()
+                .components()
+                .filter_map(|component_id| world.components().get_info(component_id)?.type_id())
+                .filter_map(|type_id| {
+                    let reflect_component =
+                        type_registry.get_type_data::<ReflectComponent>(type_id)?;
+                    serialize(reflect_component.reflect(entity_ref)?, &type_registry)
+                })
+                .collect();
+            Response::Entity {
+                entity: bits,
+                components,
+            }
+        }
+        Request::Resources => {
+            let mut resources: Vec<_> = type_registry
+                .iter()
+                .filter(|registration| {
+                    registration
+                        .data::<ReflectResource>()
+                        .is_some_and(|reflect_resource| reflect_resource.reflect(world).is_some())
+                })
+                .map(|registration| registration.type_info().type_path().to_owned())
+                .collect();
+            resources.sort();
+            Response::Resources(resources)
+        }
+        Request::Resource(type_path) => {
+            let value = type_registry
+                .get_with_type_path(&type_path)
+                .and_then(|registration| registration.data::<ReflectResource>())
+                .and_then(|reflect_resource| reflect_resource.reflect(world))
+                .and_then(|resource| serialize(resource, &type_registry));
+            match value {
+                Some(value) => Response::Resource { type_path, value },
+                None => Response::Error(format!("resource {type_path} can't be serialized")),
+            }
+        }
+        Request::SetComponent { entity, value } => {
+            let entity = match parse_entity(entity) {
+                Ok(entity) => entity,
+                Err(response) => return response,
+            };
+            let value = match deserialize(value, &type_registry) {
+                Ok(value) => value,
+                Err(error) => return Response::Error(error),
+            };
+            let Some(reflect_component) = value
+                .get_represented_type_info()
+                .and_then(|info| type_registry.get_type_data::<ReflectComponent>(info.type_id()))
+            else {
+                return Response::Error(format!(
+                    "{} is not a component",
+                    value.reflect_type_path()
+                ));
+            };
+            let Some(entity_ref) = world.get_entity(entity) else {
+                return Response::Error(format!("entity {entity:?} does not exist"));
+            };
+            // the component may have been removed since the client received it
+            if reflect_component.reflect(entity_ref).is_none() {
+                return Response::Error(format!(
+                    "entity {entity:?} has no {}",
+                    value.reflect_type_path()
+                ));
+            }
+            reflect_component.apply(&mut world.entity_mut(entity), value.as_ref());
+
+            let path = value.reflect_type_path().to_owned();
+            let entity = Some(entity);
+            events::send(world, InspectorEvent::ValueChanged { entity, path });
+            Response::Applied
+        }
+        Request::SetResource { value } => {
+            let value = match deserialize(value, &type_registry) {
+                Ok(value) => value,
+                Err(error) => return Response::Error(error),
+            };
+            let Some(reflect_resource) = value
+                .get_represented_type_info()
+                .and_then(|info| type_registry.get_type_data::<ReflectResource>(info.type_id()))
+            else {
+                return Response::Error(format!("{} is not a resource", value.reflect_type_path()));
+            };
+            if reflect_resource.reflect(world).is_none() {
+                return Response::Error(format!("{} does not exist", value.reflect_type_path()));
+            }
+            reflect_resource.apply(world, value.as_ref());
+
+            let path = value.reflect_type_path().to_owned();
+            events::send(world, InspectorEvent::ValueChanged { entity: None, path });
+            Response::Applied
+        }
+    }
+}
+
+/// Write `message` as one line of JSON
+fn send_message(stream: &TcpStream, message: &impl Serialize) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(message)?;
+    line.push(b'\n');
+    let mut stream = stream;
+    stream.write_all(&line)
+}
+
+/// Read lines of JSON from `stream` and pass them to `send` until either side is closed
+fn receive_messages<T: for<'de> Deserialize<'de>>(
+    stream: &TcpStream,
+    mut send: impl FnMut(T) -> bool,
+) {
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            return;
+        };
+        match serde_json::from_str(&line) {
+            Ok(message) => {
+                if !send(message) {
+                    return;
+                }
+            }
+            Err(error) => warn!("invalid message from the remote inspector: {error}"),
+        }
+    }
+}
+
+/// Plugin answering [`Request`]s of remote inspectors connecting to its address
+pub struct RemoteInspectorServerPlugin {
+    address: String,
+}
+
+impl Default for RemoteInspectorServerPlugin {
+    fn default() -> Self {
+        RemoteInspectorServerPlugin::new(DEFAULT_ADDRESS)
+    }
+}
+
+impl RemoteInspectorServerPlugin {
+    /// Listen on `address`, e.g. `"0.0.0.0:15703"` to accept connections from other devices
+    pub fn new(address: impl Into<String>) -> Self {
+        RemoteInspectorServerPlugin {
+            address: address.into(),
+        }
+    }
+}
+
+/// Requests received by the server, together with the writing thread of the connection to answer on
+#[derive(Resource)]
+struct RemoteServer {
+    requests: Mutex<Receiver<(Request, Sender<Response>)>>,
+}
+
+impl Plugin for RemoteInspectorServerPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        let listener = match TcpListener::bind(&self.address) {
+            Ok(listener) => listener,
+            Err(error) => {
+                error!("remote inspector can't listen on {}: {error}", self.address);
+                return;
+            }
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || accept_connections(listener, sender));
+        app.insert_resource(RemoteServer {
+            requests: Mutex::new(receiver),
+        })
+        .add_systems(Update, answer_requests);
+    }
+}
+
+fn accept_connections(listener: TcpListener, sender: Sender<(Request, Sender<Response>)>) {
+    for stream in listener.incoming().flatten() {
+        let Ok(writer) = stream.try_clone() else {
+            continue;
+        };
+        // responses are written on their own thread, so a slow client doesn't block the frame answering it
+        let (response_sender, responses) = mpsc::channel();
+        std::thread::spawn(move || write_messages(&writer, responses));
+
+        let sender = sender.clone();
+        std::thread::spawn(move || {
+            receive_messages(&stream, |request| {
+                sender.send((request, response_sender.clone())).is_ok()
+            });
+        });
+    }
+}
+
+/// Write the `messages` to `stream` until all their senders are dropped or writing fails, then close the connection
+fn write_messages<T: Serialize>(stream: &TcpStream, messages: Receiver<T>) -> std::io::Result<()> {
+    let result = messages
+        .iter()
+        .try_for_each(|message| send_message(stream, &message));
+    let _ = stream.shutdown(Shutdown::Both);
+    result
+}
+
+fn answer_requests(world: &mut World) {
+    let requests: Vec<_> = world
+        .resource::<RemoteServer>()
+        .requests
+        .lock()
+        .unwrap()
+        .try_iter()
+        .collect();
+    for (request, responses) in requests {
+        let response = handle_request(world, request);
+        // the client disconnected if this fails, which its receiving thread notices as well
+        let _ = responses.send(response);
+    }
+}
+
+/// Plugin showing the world of a [`RemoteInspectorServerPlugin`] in a window titled [`REMOTE_TITLE`]
+pub struct RemoteInspectorClientPlugin {
+    address: String,
+}
+
+impl Default for RemoteInspectorClientPlugin {
+    fn default() -> Self {
+        RemoteInspectorClientPlugin::new(DEFAULT_ADDRESS)
+    }
+}
+
+impl RemoteInspectorClientPlugin {
+    /// Connect to the server at `address`
+    pub fn new(address: impl Into<String>) -> Self {
+        RemoteInspectorClientPlugin {
+            address: address.into(),
+        }
+    }
+}
+
+impl Plugin for RemoteInspectorClientPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        if !app.is_plugin_added::<InspectorWindowsPlugin>() {
+            app.add_plugins(InspectorWindowsPlugin);
+        }
+        app.insert_resource(RemoteClient::new(self.address.clone()))
+            .add_systems(Startup, |mut client: ResMut<RemoteClient>| client.connect())
+            .add_systems(Update, remote_inspector_ui.in_set(InspectorSet));
+    }
+}
+
+/// Connection of the [`RemoteInspectorClientPlugin`] and the values last received from the server
+#[derive(Resource)]
+pub struct RemoteClient {
+    address: String,
+    connection: Option<Connection>,
+    connected: bool,
+    error: Option<String>,
+    last_refresh: f64,
+    entities: Vec<RemoteEntity>,
+    components: HashMap<u64, Vec<RemoteValue>>,
+    resources: Vec<String>,
+    resource_values: HashMap<String, RemoteValue>,
+}
+
+/// Channels to the thread connecting to the server and writing the requests, and from the thread reading the responses
+struct Connection {
+    requests: Sender<Request>,
+    events: Mutex<Receiver<ConnectionEvent>>,
+}
+
+enum ConnectionEvent {
+    Connected,
+    Response(Response),
+    Lost(String),
+}
+
+/// Connect to `address` and send the `requests` until they're dropped, passing the responses to `events`
+fn run_connection(address: String, requests: Receiver<Request>, events: Sender<ConnectionEvent>) {
+    let stream = address.to_socket_addrs().and_then(|mut addresses| {
+        let address = addresses
+            .next()
+            .ok_or(std::io::ErrorKind::AddrNotAvailable)?;
+        TcpStream::connect(address)
+    });
+    let (stream, reader) = match stream.and_then(|stream| Ok((stream.try_clone()?, stream))) {
+        Ok(streams) => streams,
+        Err(error) => {
+            let _ = events.send(ConnectionEvent::Lost(format!(
+                "can't connect to {address}: {error}"
+            )));
+            return;
+        }
+    };
+    let _ = events.send(ConnectionEvent::Connected);
+
+    let responses = events.clone();
+    std::thread::spawn(move || {
+        receive_messages(&reader, |response| {
+            responses.send(ConnectionEvent::Response(response)).is_ok()
+        });
+        let _ = responses.send(ConnectionEvent::Lost("connection closed".to_owned()));
+    });
+    if let Err(error) = write_messages(&stream, requests) {
+        let _ = events.send(ConnectionEvent::Lost(format!("connection lost: {error}")));
+    }
+}
+
+/// A value received from the server, or the reason it can't be displayed
+enum RemoteValue {
+    Value(Box<dyn Reflect>),
+    Unknown(String),
+}
+
+impl RemoteClient {
+    fn new(address: String) -> Self {
+        RemoteClient {
+            address,
+            connection: None,
+            connected: false,
+            error: None,
+            last_refresh: f64::NEG_INFINITY,
+            entities: Vec::new(),
+            components: HashMap::default(),
+            resources: Vec::new(),
+            resource_values: HashMap::default(),
+        }
+    }
+
+    /// The address of the server
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    /// Connect to the server in the background, replacing the previous connection
+    pub fn connect(&mut self) {
+        let (requests, request_receiver) = mpsc::channel();
+        let (event_sender, events) = mpsc::channel();
+        let address = self.address.clone();
+        std::thread::spawn(move || run_connection(address, request_receiver, event_sender));
+        self.connection = Some(Connection {
+            requests,
+            events: Mutex::new(events),
+        });
+        self.connected = false;
+        self.error = None;
+        self.last_refresh = f64::NEG_INFINITY;
+    }
+
+    /// Queue `request` to be sent to the server once connected
+    pub fn send(&mut self, request: &Request) {
+        if let Some(connection) = &self.connection {
+            // the connection was lost if this fails, which is received as an event
+            let _ = connection.requests.send(request.clone());
+        }
+    }
+
+    /// Take the responses received since the last call
+    fn receive(&mut self, type_registry: &TypeRegistry) {
+        let Some(connection) = &self.connection else {
+            return;
+        };
+        let events: Vec<_> = connection.events.lock().unwrap().try_iter().collect();
+        for event in events {
+            let response = match event {
+                ConnectionEvent::Connected => {
+                    self.connected = true;
+                    continue;
+                }
+                ConnectionEvent::Response(response) => response,
+                ConnectionEvent::Lost(error) => {
+                    self.error = Some(error);
+                    self.connection = None;
+                    self.connected = false;
+                    return;
+                }
+            };
+            match response {
+                Response::Entities(entities) => self.entities = entities,
+                Response::Entity { entity, components } => {
+                    let components = components
+                        .into_iter()
+                        .map(|value| RemoteValue::new(value, type_registry))
+                        .collect();
+                    self.components.insert(entity, components);
+                }
+                Response::Resources(resources) => self.resources = resources,
+                Response::Resource { type_path, value } => {
+                    let value = RemoteValue::new(value, type_registry);
+                    self.resource_values.insert(type_path, value);
+                }
+                Response::Applied => {}
+                Response::Error(error) => self.error = Some(error),
+            }
+        }
+    }
+}
+
+impl RemoteValue {
+    fn new(value: serde_json::Value, type_registry: &TypeRegistry) -> Self {
+        // the serialization is a map from the type path to the value
+        let type_path = value
+            .as_object()
+            .and_then(|object| object.keys().next().cloned())
+            .unwrap_or_default();
+        match deserialize(value, type_registry) {
+            Ok(value) => {
+                RemoteValue::Value(edit_mode::clone_concrete(value.as_ref(), type_registry))
+            }
+            Err(_) => RemoteValue::Unknown(format!("{type_path} is not registered in the viewer")),
+        }
+    }
+
+    /// Display the value and return its serialization if it was edited
+    fn ui(&mut self, ui: &mut egui::Ui, type_registry: &TypeRegistry) -> Option<serde_json::Value> {
+        match self {
+            RemoteValue::Value(value) => {
+                let name = value.reflect_short_type_path().to_owned();
+                let mut changed = false;
+                egui::CollapsingHeader::new(name).show(ui, |ui| {
+                    changed = reflect_inspector::ui_for_value(value.as_mut(), ui, type_registry);
+                });
+                changed
+                    .then(|| serialize(value.as_ref(), type_registry))
+                    .flatten()
+            }
+            RemoteValue::Unknown(reason) => {
+                ui.label(egui::RichText::new(reason.as_str()).weak());
+                None
+            }
+        }
+    }
+}
+
+fn remote_inspector_ui(world: &mut World) {
+    if !world
+        .get_resource::<InspectorWindows>()
+        .map_or(true, |windows| windows.is_visible(REMOTE_TITLE))
+    {
+        return;
+    }
+    let Some(mut egui_context) = quick::inspector_egui_context(world) else {
+        return;
+    };
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    world.resource_scope(|_, mut client: Mut<RemoteClient>| {
+        let client = client.bypass_change_detection();
+        client.receive(&type_registry);
+
+        let ctx = egui_context.get_mut();
+        egui::Window::new(REMOTE_TITLE)
+            .default_size((320.0, 480.0))
+            .show(ctx, |ui| {
+                InspectorTheme::apply_selected(ui);
+                ui_for_remote_client(ui, client, &type_registry);
+            });
+    });
+}
+
+fn ui_for_remote_client(
+    ui: &mut egui::Ui,
+    client: &mut RemoteClient,
+    type_registry: &TypeRegistry,
+) {
+    ui.horizontal(|ui| {
+        let status = match (client.is_connected(), client.connection.is_some()) {
+            (true, _) => format!("Connected to {}", client.address),
+            (false, true) => format!("Connecting to {}", client.address),
+            (false, false) => format!("Not connected to {}", client.address),
+        };
+        ui.label(status);
+        if ui.button("Reconnect").clicked() {
+            client.connect();
+        }
+    });
+    if let Some(error) = &client.error {
+        let color = InspectorTheme::get(ui.ctx())
+            .palette(ui.visuals().dark_mode)
+            .negative;
+        ui.colored_label(color, error);
+    }
+
+    // values being dragged aren't refreshed, so they don't jump back while waiting for the server
+    let now = ui.input(|input| input.time);
+    let refresh = now - client.last_refresh >= REFRESH_INTERVAL && !ui.ctx().is_using_pointer();
+    if refresh {
+        client.last_refresh = now;
+        client.send(&Request::Entities);
+        client.send(&Request::Resources);
+    }
+    ui.ctx()
+        .request_repaint_after(std::time::Duration::from_secs_f64(REFRESH_INTERVAL));
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        let mut requests = Vec::new();
+
+        egui::CollapsingHeader::new("Entities")
+            .default_open(true)
+            .show(ui, |ui| {
+                for entity in &client.entities {
+                    let header = egui::CollapsingHeader::new(&entity.name).id_source(entity.entity);
+                    let response = header.show(ui, |ui| {
+                        let Some(components) = client.components.get_mut(&entity.entity) else {
+                            ui.spinner();
+                            return;
+                        };
+                        for component in components {
+                            if let Some(value) = component.ui(ui, type_registry) {
+                                let entity = entity.entity;
+                                requests.push(Request::SetComponent { entity, value });
+                            }
+                        }
+                    });
+                    if refresh && response.body_returned.is_some() {
+                        requests.push(Request::Entity(entity.entity));
+                    }
+                }
+            });
+
+        egui::CollapsingHeader::new("Resources").show(ui, |ui| {
+            for type_path in &client.resources {
+                let name = type_path.rsplit("::").next().unwrap_or(type_path);
+                let response =
+                    ui.collapsing(name, |ui| match client.resource_values.get_mut(type_path) {
+                        Some(resource) => {
+                            if let Some(value) = resource.ui(ui, type_registry) {
+                                requests.push(Request::SetResource { value });
+                            }
+                        }
+                        None => {
+                            ui.spinner();
+                        }
+                    });
+                if refresh && response.body_returned.is_some() {
+                    requests.push(Request::Resource(type_path.clone()));
+                }
+            }
+        });
+
+        for request in &requests {
+            client.send(request);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    use super::{
+        deserialize, handle_request, receive_messages, send_message, serialize, RemoteClient,
+        Request, Response,
+    };
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    #[test]
+    fn requests_read_and_write_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn(Health(10.0)).id();
+
+        let Response::Entities(entities) = handle_request(&mut world, Request::Entities) else {
+            panic!("expected entities");
+        };
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].entity, entity.to_bits());
+
+        let response = handle_request(&mut world, Request::Entity(entity.to_bits()));
+        let Response::Entity { components, .. } = response else {
+            panic!("expected components");
+        };
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Health>();
+        let health = deserialize(components[0].clone(), &type_registry).unwrap();
+        assert_eq!(health.reflect_partial_eq(&Health(10.0)), Some(true));
+
+        let value = serialize(&Health(5.0), &type_registry).unwrap();
+        let request = Request::SetComponent {
+            entity: entity.to_bits(),
+            value,
+        };
+        assert_eq!(handle_request(&mut world, request), Response::Applied);
+        assert_eq!(world.get::<Health>(entity), Some(&Health(5.0)));
+    }
+
+    #[test]
+    fn invalid_requests_are_answered_with_errors() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn_empty().id();
+
+        // a generation of zero is invalid
+        let invalid = Request::Entity(u64::from(u32::MAX));
+        assert!(matches!(
+            handle_request(&mut world, invalid),
+            Response::Error(_)
+        ));
+
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Health>();
+        let value = serialize(&Health(5.0), &type_registry).unwrap();
+        let missing_component = Request::SetComponent {
+            entity: entity.to_bits(),
+            value,
+        };
+        assert!(matches!(
+            handle_request(&mut world, missing_component),
+            Response::Error(_)
+        ));
+        assert!(world.get::<Health>(entity).is_none());
+    }
+
+    #[test]
+    fn client_connects_and_sends_in_the_background() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            receive_messages(&stream, |request: Request| {
+                assert_eq!(request, Request::Resources);
+                let response = Response::Resources(vec!["Time".to_owned()]);
+                send_message(&stream, &response).is_ok()
+            });
+        });
+
+        let mut client = RemoteClient::new(address);
+        client.connect();
+        client.send(&Request::Resources);
+
+        let type_registry = TypeRegistry::default();
+        let start = std::time::Instant::now();
+        while client.resources.is_empty() {
+            assert!(start.elapsed().as_secs() < 10, "no response received");
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            client.receive(&type_registry);
+        }
+        assert!(client.is_connected());
+        assert_eq!(client.resources, ["Time"]);
+    }
+}
@@ -33,7 +33,7 @@ type EntityComponent = (Entity, TypeId);
 /// use std::any::TypeId;
 /// use bevy_inspector_egui::restricted_world_view::RestrictedWorldView;
 /// # use bevy_asset::Assets;
-/// # use bevy_pbr::StandardMaterial;
+/// # use bevy::pbr::StandardMaterial;
 ///
 /// let mut world = World::new();
 /// let mut world = RestrictedWorldView::new(&mut world);
@@ -275,6 +275,22 @@ impl<'w> RestrictedWorldView<'w> {
         unsafe { self.get_resource_unchecked_mut() }
     }
 
+    /// Gets a shared reference to the component `C` of `entity`, also for components which don't implement [`Reflect`]
+    pub fn get_entity_component<C: Component>(&self, entity: Entity) -> Result<&'_ C, Error> {
+        let component = (entity, TypeId::of::<C>());
+        if !self.allows_access_to_component(component) {
+            return Err(Error::NoAccessToComponent(component));
+        }
+
+        // SAFETY: we have access to (entity, C), and the returned reference borrows `&self`
+        unsafe {
+            self.world()
+                .get_entity(entity)
+                .and_then(|entity| entity.get::<C>())
+        }
+        .ok_or(Error::ComponentDoesNotExist(component))
+    }
+
     /// Gets mutable reference to two resources. Panics if `R1 = R2`.
     pub fn get_two_resources_mut<R1: Resource, R2: Resource>(
         &mut self,
@@ -379,6 +395,7 @@ impl<'w> RestrictedWorldView<'w> {
     }
 
     // SAFETY: must ensure distinct access
+    #[allow(clippy::mut_from_ref)]
     pub(crate) unsafe fn get_entity_component_reflect_unchecked(
         &self,
         entity: Entity,
@@ -0,0 +1,242 @@
+//! Snapshot tests for inspector UIs, which run in an off-screen egui context without a window or GPU.
+//!
+//! Enabled by the `testing` feature. A [`InspectorSnapshot`] contains every text painted by the UI with its position,
+//! so it can be compared with a snapshot stored by a previous run, either as its [`rows`](InspectorSnapshot::rows) or
+//! serialized with `serde`. Shapes without text like the check mark of a checkbox aren't part of the snapshot.
+//!
+//! ```rust
+//! use bevy_inspector_egui::{testing, InspectorOptions, inspector_options::ReflectInspectorOptions};
+//! use bevy_reflect::Reflect;
+//!
+//! #[derive(Reflect, Default, InspectorOptions)]
+//! #[reflect(InspectorOptions)]
+//! struct Config {
+//!     #[inspector(suffix = " m")]
+//!     distance: f32,
+//! }
+//!
+//! let type_registry = testing::type_registry();
+//! type_registry.write().register::<Config>();
+//!
+//! let mut config = Config { distance: 2.5 };
+//! let snapshot = testing::snapshot_value(&mut config, &type_registry.read());
+//! assert!(snapshot.rows().contains(&"distance 2.5 m".to_owned()));
+//! ```
+//!
+//! Values which need a world, like components, can be shown with the functions in
+//! [`bevy_inspector`](crate::bevy_inspector) inside of [`snapshot_ui`]:
+//!
+//! ```rust,no_run
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_inspector_egui::{bevy_inspector, testing};
+//! # fn test(world: &mut World) {
+//! let snapshot = testing::snapshot_ui(|ui| bevy_inspector::ui_for_world_entities(world, ui));
+//! # }
+//! ```
+
+use std::fmt;
+
+use bevy_app::App;
+use bevy_core::TypeRegistrationPlugin;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::{Reflect, TypeRegistry};
+use serde::{Deserialize, Serialize};
+
+use crate::{inspector_egui_impls, reflect_inspector};
+
+/// The size of the screen the UI is laid out in
+pub const SCREEN_SIZE: egui::Vec2 = egui::vec2(800.0, 600.0);
+
+/// A text painted by the UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotText {
+    pub text: String,
+    /// The `[min_x, min_y, max_x, max_y]` of the text, rounded to whole points
+    pub rect: [f32; 4],
+}
+
+impl SnapshotText {
+    fn center_y(&self) -> f32 {
+        (self.rect[1] + self.rect[3]) / 2.0
+    }
+}
+
+/// All texts painted in a frame of the UI, in the order they were painted
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InspectorSnapshot {
+    pub texts: Vec<SnapshotText>,
+}
+
+impl InspectorSnapshot {
+    fn from_shapes(shapes: &[egui::epaint::ClippedShape]) -> Self {
+        let mut texts = Vec::new();
+        for clipped in shapes {
+            collect_texts(&clipped.shape, &mut texts);
+        }
+        InspectorSnapshot { texts }
+    }
+
+    /// The texts on the same height joined by spaces from left to right, one string per row from top to bottom
+    pub fn rows(&self) -> Vec<String> {
+        let mut texts: Vec<&SnapshotText> = self.texts.iter().collect();
+        texts.sort_by(|a, b| a.center_y().total_cmp(&b.center_y()));
+
+        let mut rows: Vec<Vec<&SnapshotText>> = Vec::new();
+        for text in texts {
+            match rows.last_mut() {
+                // a text belongs to the row if its center is within the first text of the row
+                Some(row) if text.center_y() <= row[0].rect[3] => row.push(text),
+                _ => rows.push(vec![text]),
+            }
+        }
+        rows.into_iter()
+            .map(|mut row| {
+                row.sort_by(|a, b| a.rect[0].total_cmp(&b.rect[0]));
+                row.iter()
+                    .map(|text| text.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for InspectorSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+fn collect_texts(shape: &egui::Shape, texts: &mut Vec<SnapshotText>) {
+    match shape {
+        egui::Shape::Vec(shapes) => {
+            for shape in shapes {
+                collect_texts(shape, texts);
+            }
+        }
+        egui::Shape::Text(text) => {
+            let content = text.galley.text();
+            if content.trim().is_empty() {
+                return;
+            }
+            let rect = text.galley.rect.translate(text.pos.to_vec2());
+            texts.push(SnapshotText {
+                text: content.to_owned(),
+                rect: [
+                    rect.min.x.round(),
+                    rect.min.y.round(),
+                    rect.max.x.round(),
+                    rect.max.y.round(),
+                ],
+            });
+        }
+        _ => {}
+    }
+}
+
+/// An off-screen egui context for running an inspector UI for multiple frames, e.g. with input events in between.
+pub struct InspectorHarness {
+    ctx: egui::Context,
+    /// The input of the next frame, which is reset after every frame except for the screen size
+    pub input: egui::RawInput,
+}
+
+impl Default for InspectorHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InspectorHarness {
+    pub fn new() -> Self {
+        InspectorHarness {
+            ctx: egui::Context::default(),
+            input: Self::empty_input(),
+        }
+    }
+
+    fn empty_input() -> egui::RawInput {
+        egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, SCREEN_SIZE)),
+            ..Default::default()
+        }
+    }
+
+    pub fn ctx(&self) -> &egui::Context {
+        &self.ctx
+    }
+
+    /// Run one frame showing `add_contents` in a central panel, and return the texts it painted
+    pub fn run(&mut self, mut add_contents: impl FnMut(&mut egui::Ui)) -> InspectorSnapshot {
+        let input = std::mem::replace(&mut self.input, Self::empty_input());
+        let output = self.ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+        });
+        InspectorSnapshot::from_shapes(&output.shapes)
+    }
+}
+
+/// A type registry with the types registered by Bevy's [`TypeRegistrationPlugin`] and the inspector's widgets for
+/// them and `glam` types.
+///
+/// The widgets for other Bevy types are only registered by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin)
+/// once the plugins registering the types are added, so add it to an [`App`] with those plugins to snapshot them.
+pub fn type_registry() -> AppTypeRegistry {
+    let mut app = App::new();
+    app.add_plugins(TypeRegistrationPlugin);
+    let type_registry = app.world.resource::<AppTypeRegistry>().clone();
+    {
+        let mut type_registry = type_registry.write();
+        inspector_egui_impls::register_std_impls(&mut type_registry);
+        #[cfg(feature = "glam")]
+        inspector_egui_impls::register_glam_impls(&mut type_registry);
+    }
+    type_registry
+}
+
+/// Show `add_contents` in an off-screen egui context and return the texts it painted.
+///
+/// The UI is run for two frames, since some widgets like grids are only laid out correctly after the first one.
+pub fn snapshot_ui(mut add_contents: impl FnMut(&mut egui::Ui)) -> InspectorSnapshot {
+    let mut harness = InspectorHarness::new();
+    harness.run(&mut add_contents);
+    harness.run(add_contents)
+}
+
+/// Snapshot the UI for editing `value`, see [`reflect_inspector::ui_for_value`]
+pub fn snapshot_value(value: &mut dyn Reflect, type_registry: &TypeRegistry) -> InspectorSnapshot {
+    snapshot_ui(|ui| {
+        reflect_inspector::ui_for_value(value, ui, type_registry);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::Reflect;
+
+    use super::{snapshot_value, type_registry};
+
+    #[derive(Reflect, Default)]
+    struct Player {
+        name: String,
+        health: u32,
+        alive: bool,
+    }
+
+    #[test]
+    fn snapshot_rows_show_fields_with_values() {
+        let type_registry = type_registry();
+        type_registry.write().register::<Player>();
+
+        let mut player = Player {
+            name: "Ferris".to_owned(),
+            health: 80,
+            alive: true,
+        };
+        let snapshot = snapshot_value(&mut player, &type_registry.read());
+        assert_eq!(snapshot.rows(), ["name Ferris", "health 80", "alive"]);
+    }
+}
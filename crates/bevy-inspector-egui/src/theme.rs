@@ -0,0 +1,347 @@
+//! Accessibility presets for the colors and text sizes of the inspector, and the [`InspectorStyle`] of its windows.
+//!
+//! The selected theme is stored in the egui [`Context`](egui::Context)'s memory, so it applies to all
+//! inspector UI drawn with that context, including the change highlighting of the `highlight_changes` feature.
+//! The windows of the [`quick`](crate::quick) plugins apply the [`selected_style`] to their contents and frame.
+//!
+//! ```rust
+//! use bevy_inspector_egui::theme::InspectorTheme;
+//!
+//! fn setup(ctx: &egui::Context) {
+//!     InspectorTheme::HighContrast.set(ctx);
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiContext;
+use egui::{Color32, Stroke};
+
+/// Style settings applied to all inspector windows, before the [`InspectorTheme`].
+///
+/// Insert or modify this resource to restyle the windows of the [`quick`](crate::quick) plugins. Settings which are `None` keep
+/// the style of the egui context. The resource is copied into the memory of every egui context when it changes,
+/// see [`InspectorStyle::set`] for UI drawn without the [`quick`](crate::quick) plugins.
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::theme::InspectorStyle;
+///
+/// fn configure(mut style: ResMut<InspectorStyle>) {
+///     style.dark_mode = Some(false);
+///     style.font_size = Some(16.0);
+///     style.label_width = Some(120.0);
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone, PartialEq)]
+pub struct InspectorStyle {
+    /// Replaces the style of the egui context entirely, before the other settings apply
+    pub custom: Option<Arc<egui::Style>>,
+    /// Use the dark or light [`egui::Visuals`]
+    pub dark_mode: Option<bool>,
+    /// Space between widgets, see [`egui::style::Spacing::item_spacing`]
+    pub item_spacing: Option<egui::Vec2>,
+    /// Width of sliders and text fields
+    pub widget_width: Option<f32>,
+    /// Minimum width of the columns in the grids of fields, which keeps the values of nested structs aligned
+    pub label_width: Option<f32>,
+    /// Size of the body text, with the other text styles scaled along
+    pub font_size: Option<f32>,
+    /// Show reset buttons next to all fields whose types are registered with `#[reflect(Default)]`,
+    /// not only next to fields with `#[inspector(reset)]` or `#[inspector(default = ..)]`
+    pub reset_buttons: bool,
+}
+
+impl InspectorStyle {
+    fn id() -> egui::Id {
+        egui::Id::new("bevy-inspector-egui style")
+    }
+
+    /// Use `style` as the base of all inspector UI, instead of the style of the egui context
+    pub fn with_custom(style: egui::Style) -> Self {
+        InspectorStyle {
+            custom: Some(Arc::new(style)),
+            ..Default::default()
+        }
+    }
+
+    /// The style stored for `ctx`
+    pub fn get(ctx: &egui::Context) -> InspectorStyle {
+        ctx.data(|data| data.get_temp(Self::id()))
+            .unwrap_or_default()
+    }
+
+    /// Use this style for all inspector UI drawn with `ctx`
+    pub fn set(&self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(), self.clone()));
+    }
+
+    /// Modify `style` according to these settings
+    pub fn apply(&self, style: &mut egui::Style) {
+        if let Some(custom) = &self.custom {
+            *style = (**custom).clone();
+        }
+        if let Some(dark_mode) = self.dark_mode {
+            style.visuals = match dark_mode {
+                true => egui::Visuals::dark(),
+                false => egui::Visuals::light(),
+            };
+        }
+        if let Some(item_spacing) = self.item_spacing {
+            style.spacing.item_spacing = item_spacing;
+        }
+        if let Some(widget_width) = self.widget_width {
+            style.spacing.slider_width = widget_width;
+            style.spacing.text_edit_width = widget_width;
+        }
+        if let Some(font_size) = self.font_size {
+            let body = style
+                .text_styles
+                .get(&egui::TextStyle::Body)
+                .map_or(font_size, |font_id| font_id.size);
+            let scale = font_size / body;
+            for font_id in style.text_styles.values_mut() {
+                font_id.size *= scale;
+            }
+        }
+    }
+}
+
+/// Copy the [`InspectorStyle`] resource into the memory of the egui contexts, when it changed or a context was added
+pub fn sync_inspector_style(
+    style: Option<Res<InspectorStyle>>,
+    mut contexts: Query<&mut EguiContext>,
+) {
+    let Some(style) = style else {
+        return;
+    };
+    for mut context in &mut contexts {
+        if style.is_changed() || context.is_added() {
+            style.set(context.get_mut());
+        }
+    }
+}
+
+/// The style of `ctx` with its [`InspectorStyle`] and [`InspectorTheme`] applied
+pub fn selected_style(ctx: &egui::Context) -> egui::Style {
+    let mut style = (*ctx.style()).clone();
+    InspectorStyle::get(ctx).apply(&mut style);
+    InspectorTheme::get(ctx).apply(&mut style);
+    style
+}
+
+/// A preset of colors and text sizes for the inspector
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InspectorTheme {
+    /// Use the style of the egui context unchanged
+    #[default]
+    Default,
+    /// Pure black and white with thicker widget outlines
+    HighContrast,
+    /// Blue and orange accents instead of green and red, distinguishable with deuteranopia and protanopia
+    ColorBlind,
+    /// Larger text and widgets
+    LargeText,
+    /// Larger drag areas, sliders, scroll bars and spacing for touch screens
+    Touch,
+}
+
+/// Semantic colors used by the inspector for highlights and differences
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThemePalette {
+    /// Outline of values that changed this frame
+    pub highlight: Color32,
+    /// Something increased or was added
+    pub positive: Color32,
+    /// Something decreased or was removed
+    pub negative: Color32,
+}
+
+impl InspectorTheme {
+    pub const ALL: [InspectorTheme; 5] = [
+        InspectorTheme::Default,
+        InspectorTheme::HighContrast,
+        InspectorTheme::ColorBlind,
+        InspectorTheme::LargeText,
+        InspectorTheme::Touch,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InspectorTheme::Default => "Default",
+            InspectorTheme::HighContrast => "High contrast",
+            InspectorTheme::ColorBlind => "Color-blind safe",
+            InspectorTheme::LargeText => "Large text",
+            InspectorTheme::Touch => "Touch",
+        }
+    }
+
+    fn id() -> egui::Id {
+        egui::Id::new("bevy-inspector-egui theme")
+    }
+
+    /// The theme selected for `ctx`
+    pub fn get(ctx: &egui::Context) -> InspectorTheme {
+        ctx.data(|data| data.get_temp(Self::id()))
+            .unwrap_or_default()
+    }
+
+    /// Select this theme for all inspector UI drawn with `ctx`
+    pub fn set(self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(), self));
+    }
+
+    /// Colors for highlights and differences matching this theme
+    pub fn palette(self, dark_mode: bool) -> ThemePalette {
+        match (self, dark_mode) {
+            (InspectorTheme::HighContrast, true) => ThemePalette {
+                highlight: Color32::YELLOW,
+                positive: Color32::from_rgb(255, 80, 80),
+                negative: Color32::from_rgb(80, 255, 80),
+            },
+            (InspectorTheme::HighContrast, false) => ThemePalette {
+                highlight: Color32::from_rgb(0, 0, 200),
+                positive: Color32::from_rgb(180, 0, 0),
+                negative: Color32::from_rgb(0, 110, 0),
+            },
+            // Okabe-Ito colors
+            (InspectorTheme::ColorBlind, _) => ThemePalette {
+                highlight: Color32::from_rgb(240, 228, 66),
+                positive: Color32::from_rgb(230, 159, 0),
+                negative: Color32::from_rgb(86, 180, 233),
+            },
+            (InspectorTheme::Default | InspectorTheme::LargeText | InspectorTheme::Touch, _) => {
+                ThemePalette {
+                    highlight: Color32::GOLD,
+                    positive: Color32::RED,
+                    negative: Color32::GREEN,
+                }
+            }
+        }
+    }
+
+    /// Modify `style` according to this theme
+    pub fn apply(self, style: &mut egui::Style) {
+        let visuals = &mut style.visuals;
+        match self {
+            InspectorTheme::Default => {}
+            InspectorTheme::HighContrast => {
+                let (fg, bg) = if visuals.dark_mode {
+                    (Color32::WHITE, Color32::BLACK)
+                } else {
+                    (Color32::BLACK, Color32::WHITE)
+                };
+                visuals.override_text_color = Some(fg);
+                visuals.window_fill = bg;
+                visuals.panel_fill = bg;
+                visuals.extreme_bg_color = bg;
+                visuals.window_stroke = Stroke::new(2.0, fg);
+                for widget in [
+                    &mut visuals.widgets.noninteractive,
+                    &mut visuals.widgets.inactive,
+                    &mut visuals.widgets.hovered,
+                    &mut visuals.widgets.active,
+                    &mut visuals.widgets.open,
+                ] {
+                    widget.bg_stroke = Stroke::new(1.5, fg);
+                    widget.fg_stroke.color = fg;
+                }
+                visuals.widgets.inactive.bg_fill = bg;
+                visuals.widgets.inactive.weak_bg_fill = bg;
+                visuals.selection.stroke = Stroke::new(2.0, fg);
+            }
+            InspectorTheme::ColorBlind => {
+                let palette = self.palette(visuals.dark_mode);
+                visuals.selection.bg_fill = Color32::from_rgb(0, 114, 178);
+                visuals.selection.stroke.color = Color32::WHITE;
+                visuals.hyperlink_color = Color32::from_rgb(86, 180, 233);
+                visuals.warn_fg_color = palette.highlight;
+                visuals.error_fg_color = Color32::from_rgb(230, 159, 0);
+            }
+            InspectorTheme::LargeText => {
+                for font_id in style.text_styles.values_mut() {
+                    font_id.size *= 1.4;
+                }
+                style.spacing.interact_size *= 1.4;
+                style.spacing.icon_width *= 1.4;
+            }
+            InspectorTheme::Touch => {
+                // big enough to hit with a finger, most of all the drag areas of numbers
+                let spacing = &mut style.spacing;
+                spacing.interact_size.x *= 1.5;
+                spacing.interact_size.y = spacing.interact_size.y.max(36.0);
+                spacing.slider_width *= 1.6;
+                spacing.item_spacing *= 1.5;
+                spacing.button_padding *= 1.6;
+                spacing.icon_width *= 1.6;
+                spacing.icon_width_inner *= 1.6;
+                spacing.scroll.bar_width *= 2.0;
+                style.interaction.resize_grab_radius_side *= 2.0;
+            }
+        }
+    }
+
+    /// The style of `ctx` with this theme applied
+    pub fn style(self, ctx: &egui::Context) -> egui::Style {
+        let mut style = (*ctx.style()).clone();
+        self.apply(&mut style);
+        style
+    }
+
+    /// Apply the [`InspectorStyle`] and theme selected for the context of `ui` to its style
+    pub fn apply_selected(ui: &mut egui::Ui) {
+        let style = InspectorStyle::get(ui.ctx());
+        if style != InspectorStyle::default() {
+            style.apply(ui.style_mut());
+        }
+        let theme = InspectorTheme::get(ui.ctx());
+        if theme != InspectorTheme::Default {
+            theme.apply(ui.style_mut());
+        }
+    }
+}
+
+/// Display a combo box for selecting the [`InspectorTheme`] of the context of `ui`.
+///
+/// Returns `true` if the theme was changed.
+pub fn ui_for_theme(ui: &mut egui::Ui) -> bool {
+    let mut theme = InspectorTheme::get(ui.ctx());
+    let mut changed = false;
+    egui::ComboBox::from_label("Theme")
+        .selected_text(theme.name())
+        .show_ui(ui, |ui| {
+            for option in InspectorTheme::ALL {
+                changed |= ui
+                    .selectable_value(&mut theme, option, option.name())
+                    .changed();
+            }
+        });
+    if changed {
+        theme.set(ui.ctx());
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{selected_style, InspectorStyle, InspectorTheme};
+
+    #[test]
+    fn style_applies_before_theme() {
+        let ctx = egui::Context::default();
+        let style = InspectorStyle {
+            dark_mode: Some(false),
+            font_size: Some(20.0),
+            label_width: Some(150.0),
+            ..Default::default()
+        };
+        style.set(&ctx);
+        InspectorTheme::LargeText.set(&ctx);
+
+        let selected = selected_style(&ctx);
+        assert!(!selected.visuals.dark_mode);
+        let body = &selected.text_styles[&egui::TextStyle::Body];
+        assert!((body.size - 28.0).abs() < 1e-4);
+        assert_eq!(InspectorStyle::get(&ctx).label_width, Some(150.0));
+    }
+}
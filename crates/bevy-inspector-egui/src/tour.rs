@@ -0,0 +1,266 @@
+//! A guided tour introducing the features of the inspector to first-time users.
+//!
+//! The tour is an overlay of steps drawn next to the window they describe, with buttons to go through the steps
+//! or dismiss the tour. Its progress is kept in the persisted memory of the egui [`Context`](egui::Context).
+//! To only show it on the first run of an app whose egui memory isn't persisted, use [`Tour::with_marker_file`].
+//!
+//! ```rust
+//! use bevy_inspector_egui::tour::Tour;
+//!
+//! fn ui(ctx: &egui::Context) {
+//!     Tour::default()
+//!         .with_marker_file("target/inspector-tour-done")
+//!         .show(ctx, Some("World Inspector"));
+//! }
+//! ```
+//!
+//! The [`WorldInspectorPlugin`](crate::quick::WorldInspectorPlugin) shows it when configured using
+//! [`with_tour`](crate::quick::WorldInspectorPlugin::with_tour).
+
+use std::path::PathBuf;
+
+use egui::{Align2, Color32, Id, LayerId, Order, Stroke};
+
+/// A single step of a [`Tour`]
+#[derive(Clone, Debug)]
+pub struct TourStep {
+    pub title: String,
+    pub text: String,
+    /// Title of the window to highlight. If `None`, the window passed to [`Tour::show`] is used.
+    pub window: Option<String>,
+}
+
+impl TourStep {
+    pub fn new(title: impl Into<String>, text: impl Into<String>) -> Self {
+        TourStep {
+            title: title.into(),
+            text: text.into(),
+            window: None,
+        }
+    }
+
+    pub fn with_window(mut self, window: impl Into<String>) -> Self {
+        self.window = Some(window.into());
+        self
+    }
+}
+
+/// An onboarding overlay going through a list of [`TourStep`]s.
+///
+/// The default tour introduces the world inspector, its hotkeys and context menus.
+#[derive(Clone, Debug)]
+pub struct Tour {
+    steps: Vec<TourStep>,
+    marker_file: Option<PathBuf>,
+}
+
+impl Default for Tour {
+    fn default() -> Self {
+        Tour::new(vec![
+            TourStep::new(
+                "World inspector",
+                "This window lists the entities, resources and assets of your app. \
+                Expand an entity to see and edit its components.",
+            ),
+            TourStep::new(
+                "Hotkeys",
+                "Press F12 to hide or show all inspector windows at once. \
+                With the InspectorPickingPlugin, Ctrl+click an entity in the game to focus it here.",
+            ),
+            TourStep::new(
+                "Entity references",
+                "Fields holding an entity have a ➡ button jumping to that entity \
+                and a 🔍 button for searching another one to assign.",
+            ),
+            TourStep::new(
+                "Context menus",
+                "Right-click a component header to copy it as JSON or RON and to paste JSON into it. \
+                Right-click a field name to attach a note to it.",
+            ),
+            TourStep::new(
+                "Settings",
+                "The settings section lets you pick a high contrast, color-blind safe or large text theme, \
+                and restart this tour.",
+            ),
+        ])
+    }
+}
+
+/// Index of the current step, and whether the tour was dismissed
+type TourState = (usize, bool);
+
+impl Tour {
+    pub fn new(steps: Vec<TourStep>) -> Self {
+        Tour {
+            steps,
+            marker_file: None,
+        }
+    }
+
+    /// Create the file at `path` once the tour is dismissed, and don't show the tour when it exists.
+    pub fn with_marker_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.marker_file = Some(path.into());
+        self
+    }
+
+    pub fn steps(&self) -> &[TourStep] {
+        &self.steps
+    }
+
+    fn id() -> Id {
+        Id::new("bevy-inspector-egui tour")
+    }
+
+    fn state(&self, ctx: &egui::Context) -> TourState {
+        let marker_checked = Self::id().with("marker checked");
+        let check_marker = !ctx.data(|data| data.get_temp::<bool>(marker_checked).is_some());
+        if check_marker {
+            ctx.data_mut(|data| data.insert_temp(marker_checked, true));
+            if self.marker_file.as_ref().is_some_and(|path| path.exists()) {
+                ctx.data_mut(|data| data.insert_persisted::<TourState>(Self::id(), (0, true)));
+            }
+        }
+        ctx.data_mut(|data| data.get_persisted::<TourState>(Self::id()))
+            .unwrap_or_default()
+    }
+
+    fn set_state(&self, ctx: &egui::Context, state: TourState) {
+        ctx.data_mut(|data| data.insert_persisted(Self::id(), state));
+    }
+
+    /// Whether the tour was dismissed in `ctx`, or the marker file exists
+    pub fn is_dismissed(&self, ctx: &egui::Context) -> bool {
+        self.state(ctx).1
+    }
+
+    /// Stop showing the tour and create the marker file, if configured
+    pub fn dismiss(&self, ctx: &egui::Context) -> std::io::Result<()> {
+        self.set_state(ctx, (0, true));
+        match &self.marker_file {
+            Some(path) => std::fs::write(path, ""),
+            None => Ok(()),
+        }
+    }
+
+    /// Show the tour again from the first step and remove the marker file, if configured
+    pub fn restart(&self, ctx: &egui::Context) -> std::io::Result<()> {
+        self.set_state(ctx, (0, false));
+        match &self.marker_file {
+            Some(path) if path.exists() => std::fs::remove_file(path),
+            _ => Ok(()),
+        }
+    }
+
+    /// Draw the current step unless the tour was dismissed.
+    ///
+    /// Steps are placed next to the window titled [`TourStep::window`], or `default_window`,
+    /// and in the center of the screen if that window isn't shown.
+    pub fn show(&self, ctx: &egui::Context, default_window: Option<&str>) {
+        let (step_index, dismissed) = self.state(ctx);
+        if dismissed || self.steps.is_empty() {
+            return;
+        }
+        let step_index = step_index.min(self.steps.len() - 1);
+        let step = &self.steps[step_index];
+
+        let window_rect = step
+            .window
+            .as_deref()
+            .or(default_window)
+            .and_then(|title| ctx.memory(|memory| memory.area_rect(Id::new(title))));
+
+        let mut area = egui::Area::new(Self::id().with("overlay"))
+            .order(Order::Foreground)
+            .constrain(true);
+        area = match window_rect {
+            Some(rect) => {
+                let painter = ctx.layer_painter(LayerId::new(
+                    Order::Foreground,
+                    Self::id().with("highlight"),
+                ));
+                let stroke = Stroke::new(2.0, ctx.style().visuals.selection.stroke.color);
+                painter.rect_stroke(rect.expand(4.0), 6.0, stroke);
+                area.fixed_pos(rect.right_top() + egui::vec2(12.0, 0.0))
+            }
+            None => area.anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO),
+        };
+
+        let mut next_state = None;
+        area.show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_max_width(260.0);
+                ui.horizontal(|ui| {
+                    ui.strong(&step.title);
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.colored_label(
+                            Color32::GRAY,
+                            format!("{}/{}", step_index + 1, self.steps.len()),
+                        );
+                    });
+                });
+                ui.label(&step.text);
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(step_index > 0, egui::Button::new("Back"))
+                        .clicked()
+                    {
+                        next_state = Some((step_index - 1, false));
+                    }
+                    let last = step_index + 1 == self.steps.len();
+                    if ui.button(if last { "Done" } else { "Next" }).clicked() {
+                        next_state = Some(if last {
+                            (0, true)
+                        } else {
+                            (step_index + 1, false)
+                        });
+                    }
+                    if !last && ui.small_button("Skip tour").clicked() {
+                        next_state = Some((0, true));
+                    }
+                });
+            });
+        });
+
+        match next_state {
+            Some((_, true)) => {
+                let _ = self.dismiss(ctx);
+            }
+            Some(state) => self.set_state(ctx, state),
+            None => {}
+        }
+    }
+}
+
+/// Display a button restarting `tour`
+pub fn ui_for_restart(ui: &mut egui::Ui, tour: &Tour) {
+    if ui.button("Restart tour").clicked() {
+        let _ = tour.restart(ui.ctx());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tour;
+
+    #[test]
+    fn marker_file_dismisses_tour() {
+        let path = std::env::temp_dir().join("bevy-inspector-egui-tour-marker-test");
+        let _ = std::fs::remove_file(&path);
+        let tour = Tour::default().with_marker_file(&path);
+
+        let ctx = egui::Context::default();
+        assert!(!tour.is_dismissed(&ctx));
+        tour.dismiss(&ctx).unwrap();
+        assert!(tour.is_dismissed(&ctx));
+        assert!(path.exists());
+
+        // a fresh context, as on the next run of the app
+        let ctx = egui::Context::default();
+        assert!(tour.is_dismissed(&ctx));
+
+        tour.restart(&ctx).unwrap();
+        assert!(!tour.is_dismissed(&ctx));
+        assert!(!path.exists());
+    }
+}
@@ -58,33 +58,68 @@ pub mod guess_entity_name {
         entity: Entity,
         archetype: &Archetype,
     ) -> String {
+        // ordered by priority, so that e.g. a camera with a `Window` is still labeled as a camera
         #[rustfmt::skip]
         let associations = &[
             ("bevy_window::window::PrimaryWindow", "Primary Window"),
             ("bevy_core_pipeline::core_3d::camera_3d::Camera3d", "Camera3d"),
             ("bevy_core_pipeline::core_2d::camera_2d::Camera2d", "Camera2d"),
+            ("bevy_render::camera::camera::Camera", "Camera"),
             ("bevy_pbr::light::PointLight", "PointLight"),
+            ("bevy_pbr::light::SpotLight", "SpotLight"),
             ("bevy_pbr::light::DirectionalLight", "DirectionalLight"),
             ("bevy_text::text::Text", "Text"),
+            ("bevy_ui::widget::button::Button", "Button"),
             ("bevy_ui::ui_node::Node", "Node"),
+            ("bevy_sprite::sprite::Sprite", "Sprite"),
             ("bevy_asset::handle::Handle<bevy_pbr::pbr_material::StandardMaterial>", "Pbr Mesh"),
+            ("bevy_asset::handle::Handle<bevy_render::mesh::mesh::Mesh>", "Mesh"),
+            ("bevy_asset::handle::Handle<bevy_scene::scene::Scene>", "Scene"),
             ("bevy_window::window::Window", "Window"),
         ];
 
-        let type_names = archetype.components().filter_map(|id| {
-            let name = world.components().get_info(id)?.name();
-            Some(name)
-        });
+        let type_names: Vec<&str> = archetype
+            .components()
+            .filter_map(|id| Some(world.components().get_info(id)?.name()))
+            .collect();
 
-        for component_type in type_names {
-            if let Some(name) = associations
-                .iter()
-                .find_map(|&(name, matches)| (component_type == name).then_some(matches))
-            {
-                return format!("{name} ({entity:?})");
-            }
+        if let Some(name) = associations
+            .iter()
+            .find_map(|&(type_name, name)| type_names.contains(&type_name).then_some(name))
+        {
+            return format!("{name} ({entity:?})");
         }
 
         format!("Entity ({entity:?})")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::world::World;
+    use bevy_window::{PrimaryWindow, Window};
+
+    use super::guess_entity_name::guess_entity_name;
+
+    #[test]
+    fn entity_names_prefer_name_then_primary_component() {
+        let mut world = World::new();
+        let named = world.spawn((Name::new("Player"), Window::default())).id();
+        let window = world.spawn((Window::default(), PrimaryWindow)).id();
+        let empty = world.spawn_empty().id();
+
+        assert_eq!(
+            guess_entity_name(&world, named),
+            format!("Player ({named:?})")
+        );
+        assert_eq!(
+            guess_entity_name(&world, window),
+            format!("Primary Window ({window:?})")
+        );
+        assert_eq!(
+            guess_entity_name(&world, empty),
+            format!("Entity ({empty:?})")
+        );
+    }
+}
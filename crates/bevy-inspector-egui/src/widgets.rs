@@ -0,0 +1,459 @@
+//! The widgets of the inspector as standalone functions, for use in arbitrary egui code.
+//!
+//! Unlike the [`reflect_inspector`](crate::reflect_inspector), these work on plain values which
+//! don't need to implement [`Reflect`](bevy_reflect::Reflect) or be registered in a type registry.
+//! Every function returns `true` if the value was changed.
+//!
+//! ```rust
+//! use bevy_inspector_egui::{inspector_options::std_options::NumberOptions, widgets};
+//!
+//! fn ui(ui: &mut egui::Ui, speed: &mut f32, weights: &mut Vec<f32>) {
+//!     widgets::number(ui, speed, &NumberOptions::positive());
+//!     widgets::list(ui, egui::Id::new("weights"), weights, |ui, _, weight| {
+//!         widgets::number(ui, weight, &NumberOptions::between(0.0, 1.0))
+//!     });
+//! }
+//! ```
+
+use bevy_math::Vec2;
+#[cfg(feature = "bevy_render")]
+use bevy_render::color::Color;
+
+#[cfg(feature = "bevy_render")]
+use crate::curves::{ColorGradient, GradientStop};
+use crate::{
+    curves::{Curve, CurveKey},
+    inspector_egui_impls,
+    inspector_options::std_options::{CodeLanguage, NumberOptions},
+    reflect_inspector::{ui_for_empty_list, ui_for_list_controls, ui_for_list_footer, ListOp},
+};
+
+/// A text field for a value which is written and parsed as text, like an `IpAddr`, `SocketAddr` or `Uuid`.
+///
+/// The value is only written once the text parses. Until then, the typed text is kept under `id`
+/// and the parse error is shown when hovering the field.
+pub fn parsed<T>(ui: &mut egui::Ui, id: egui::Id, value: &mut T) -> bool
+where
+    T: std::str::FromStr + std::fmt::Display + PartialEq,
+    T::Err: std::fmt::Display,
+{
+    inspector_egui_impls::parsed_text(ui, id, value)
+}
+
+/// A drag value or slider for a number, respecting the bounds, speed, prefix and suffix of `options`
+pub fn number<T: egui::emath::Numeric>(
+    ui: &mut egui::Ui,
+    value: &mut T,
+    options: &NumberOptions<T>,
+) -> bool {
+    inspector_egui_impls::display_number(value, options, ui, 0.1)
+}
+
+/// Number fields for `values` with a toggle linking them, like for uniform scaling.
+///
+/// While linked, editing one value scales the others by the same factor. The toggle state is kept under `id`.
+pub fn linked_numbers<T: egui::emath::Numeric>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    values: &mut [T],
+    options: &NumberOptions<T>,
+) -> bool {
+    let before = values.to_vec();
+    let mut changed = false;
+    let mut linked = false;
+    ui.horizontal(|ui| {
+        linked = inspector_egui_impls::ui_for_link_toggle(ui, id);
+        for value in values.iter_mut() {
+            changed |= inspector_egui_impls::display_number(value, options, ui, 0.1);
+        }
+    });
+    if changed && linked {
+        inspector_egui_impls::scale_linked(values, &before);
+    }
+    changed
+}
+
+/// A color picker button, with the channels selected in `options` next to it
+#[cfg(feature = "bevy_render")]
+pub fn color(
+    ui: &mut egui::Ui,
+    color: &mut Color,
+    options: &crate::inspector_options::std_options::ColorOptions,
+) -> bool {
+    inspector_egui_impls::color_ui(color, options, ui)
+}
+
+/// A multiline monospace editor for `code`, with the syntax of `language` highlighted
+pub fn code_editor(ui: &mut egui::Ui, code: &mut String, language: CodeLanguage) -> bool {
+    inspector_egui_impls::code_editor(ui, code, language).changed()
+}
+
+/// A square canvas for picking a point between `min` and `max` by clicking or dragging.
+///
+/// The y axis points up, like in bevy's 2D coordinate system.
+pub fn vec2_canvas(ui: &mut egui::Ui, value: &mut Vec2, min: Vec2, max: Vec2) -> bool {
+    let size = egui::Vec2::splat(ui.spacing().slider_width);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click_and_drag());
+    let range = (max - min).max(Vec2::splat(f32::EPSILON));
+
+    let mut changed = false;
+    if let Some(pos) = response.interact_pointer_pos() {
+        let t = (pos - rect.min) / rect.size();
+        let new = (min + Vec2::new(t.x, 1.0 - t.y) * range).clamp(min, max);
+        if new != *value {
+            *value = new;
+            changed = true;
+        }
+    }
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let painter = ui.painter_at(rect);
+        painter.rect(rect, 2.0, ui.visuals().extreme_bg_color, visuals.bg_stroke);
+
+        let to_screen = |point: Vec2| {
+            let t = (point - min) / range;
+            egui::pos2(
+                rect.left() + t.x * rect.width(),
+                rect.bottom() - t.y * rect.height(),
+            )
+        };
+        let origin = to_screen(Vec2::ZERO);
+        let axis = egui::Stroke::new(1.0, ui.visuals().weak_text_color());
+        painter.hline(rect.x_range(), origin.y, axis);
+        painter.vline(origin.x, rect.y_range(), axis);
+        painter.circle_filled(
+            to_screen(value.clamp(min, max)),
+            4.0,
+            visuals.fg_stroke.color,
+        );
+    }
+
+    response.on_hover_text(format!("x: {:.3}, y: {:.3}", value.x, value.y));
+    changed
+}
+
+/// An editable list with controls for adding, removing and reordering elements, drawing each element with `element_ui`.
+///
+/// New elements are created using [`Default`].
+pub fn list<T: Default>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    list: &mut Vec<T>,
+    mut element_ui: impl FnMut(&mut egui::Ui, usize, &mut T) -> bool,
+) -> bool {
+    let mut changed = false;
+    ui.vertical(|ui| {
+        let mut op = None;
+        let len = list.len();
+        if len == 0 && ui_for_empty_list(ui, false) {
+            op = Some(ListOp::AddElement(0));
+        }
+        for (i, element) in list.iter_mut().enumerate() {
+            egui::Grid::new((id, i)).show(ui, |ui| {
+                ui.label(i.to_string());
+                ui.horizontal_top(|ui| changed |= element_ui(ui, i, element));
+                ui.end_row();
+
+                let item_op = ui_for_list_controls(ui, i, len);
+                if item_op.is_some() {
+                    op = item_op;
+                }
+            });
+
+            if i != len - 1 {
+                ui.separator();
+            }
+        }
+        op = ui_for_list_footer(ui, len).or(op);
+
+        let Some(op) = op else {
+            return;
+        };
+        match op {
+            ListOp::AddElement(i) => list.insert(i, T::default()),
+            ListOp::RemoveElement(i) => {
+                list.remove(i);
+            }
+            ListOp::MoveElementUp(i) => list.swap(i - 1, i),
+            ListOp::MoveElementDown(i) => list.swap(i, i + 1),
+            ListOp::Clear => list.clear(),
+        }
+        changed = true;
+    });
+    changed
+}
+
+/// The position of the key `i` when moving it to `position`, clamped between its neighbors so that the keys stay sorted
+fn clamped_key_position(positions: &[f32], i: usize, position: f32) -> f32 {
+    let min = match i {
+        0 => 0.0,
+        i => positions[i - 1],
+    };
+    let max = positions.get(i + 1).copied().unwrap_or(1.0).max(min);
+    position.clamp(min, max)
+}
+
+/// The range of values shown by [`curve`], which fits the keys and always includes `0.0` and `1.0`
+fn curve_value_range(curve: &Curve) -> (f32, f32) {
+    curve.keys.iter().fold((0.0f32, 1.0f32), |(min, max), key| {
+        (min.min(key.value), max.max(key.value))
+    })
+}
+
+const KEY_RADIUS: f32 = 4.0;
+
+/// A plot of `curve` with draggable keys, for positions between `0.0` and `1.0`.
+///
+/// Double-click the plot to add a key and right-click a key to remove it. The position and value of the selected key
+/// are shown below the plot, and the selection is kept under `id`.
+pub fn curve(ui: &mut egui::Ui, id: egui::Id, curve: &mut Curve) -> bool {
+    let selected_id = id.with("selected curve key");
+    let mut selected = ui.data(|data| data.get_temp::<usize>(selected_id));
+    let mut changed = false;
+
+    let width = ui.spacing().slider_width * 1.5;
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(width, width * 0.5), egui::Sense::click());
+    let rect = rect.shrink(KEY_RADIUS);
+    let (min, max) = curve_value_range(curve);
+    let to_screen = |position: f32, value: f32| {
+        egui::pos2(
+            egui::lerp(rect.x_range(), position),
+            egui::remap(value, min..=max, rect.bottom()..=rect.top()),
+        )
+    };
+    let from_screen = |pos: egui::Pos2| {
+        let position = egui::remap_clamp(pos.x, rect.x_range(), 0.0..=1.0);
+        let value = egui::remap_clamp(pos.y, rect.bottom()..=rect.top(), min..=max);
+        (position, value)
+    };
+
+    let positions: Vec<f32> = curve.keys.iter().map(|key| key.position).collect();
+    let mut remove = None;
+    for (i, key) in curve.keys.iter_mut().enumerate() {
+        let center = to_screen(key.position, key.value);
+        let key_rect = egui::Rect::from_center_size(center, egui::Vec2::splat(KEY_RADIUS * 3.0));
+        let key_response = ui.interact(
+            key_rect,
+            id.with(("curve key", i)),
+            egui::Sense::click_and_drag(),
+        );
+        if key_response.clicked() || key_response.drag_started() {
+            selected = Some(i);
+        }
+        if let (true, Some(pointer)) = (key_response.dragged(), key_response.interact_pointer_pos())
+        {
+            let (position, value) = from_screen(pointer);
+            key.position = clamped_key_position(&positions, i, position);
+            key.value = value;
+            changed = true;
+        }
+        if key_response.secondary_clicked() && positions.len() > 1 {
+            remove = Some(i);
+        }
+    }
+    if let Some(i) = remove {
+        curve.keys.remove(i);
+        selected = None;
+        changed = true;
+    }
+    if let (true, Some(pointer)) = (response.double_clicked(), response.interact_pointer_pos()) {
+        let (position, value) = from_screen(pointer);
+        let i = curve.keys.partition_point(|key| key.position <= position);
+        curve.keys.insert(i, CurveKey::new(position, value));
+        selected = Some(i);
+        changed = true;
+    }
+    let selected = selected.filter(|&i| i < curve.keys.len());
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let painter = ui.painter();
+        painter.rect(
+            rect.expand(KEY_RADIUS),
+            2.0,
+            ui.visuals().extreme_bg_color,
+            visuals.bg_stroke,
+        );
+        let axis = egui::Stroke::new(1.0, ui.visuals().weak_text_color());
+        painter.hline(rect.x_range(), to_screen(0.0, 0.0).y, axis);
+
+        let mut points = vec![to_screen(0.0, curve.sample(0.0))];
+        points.extend(
+            curve
+                .keys
+                .iter()
+                .map(|key| to_screen(key.position, key.value)),
+        );
+        points.push(to_screen(1.0, curve.sample(1.0)));
+        painter.add(egui::Shape::line(points, visuals.fg_stroke));
+        for (i, key) in curve.keys.iter().enumerate() {
+            let color = match selected == Some(i) {
+                true => ui.visuals().selection.bg_fill,
+                false => visuals.fg_stroke.color,
+            };
+            painter.circle_filled(to_screen(key.position, key.value), KEY_RADIUS, color);
+        }
+    }
+    response.on_hover_text("Double-click to add a key, right-click a key to remove it");
+
+    if let Some(i) = selected {
+        let positions: Vec<f32> = curve.keys.iter().map(|key| key.position).collect();
+        let key = &mut curve.keys[i];
+        ui.horizontal(|ui| {
+            let (min, max) = (
+                clamped_key_position(&positions, i, 0.0),
+                clamped_key_position(&positions, i, 1.0),
+            );
+            ui.label("Position");
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut key.position)
+                        .speed(0.01)
+                        .clamp_range(min..=max),
+                )
+                .changed();
+            ui.label("Value");
+            changed |= ui
+                .add(egui::DragValue::new(&mut key.value).speed(0.01))
+                .changed();
+        });
+    }
+
+    ui.data_mut(|data| match selected {
+        Some(i) => data.insert_temp(selected_id, i),
+        None => data.remove::<usize>(selected_id),
+    });
+    changed
+}
+
+#[cfg(feature = "bevy_render")]
+fn color32(color: Color) -> egui::Color32 {
+    let [r, g, b, a] = color.as_rgba_u8();
+    egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+/// A preview strip of `gradient` with draggable stops below it, for positions between `0.0` and `1.0`.
+///
+/// Double-click the strip to add a stop and right-click a stop to remove it. The color and position of the selected stop
+/// are shown below the strip, and the selection is kept under `id`.
+#[cfg(feature = "bevy_render")]
+pub fn gradient(ui: &mut egui::Ui, id: egui::Id, gradient: &mut ColorGradient) -> bool {
+    const STRIP_HEIGHT: f32 = 16.0;
+    const MARKER_SIZE: f32 = 8.0;
+    const COLUMNS: usize = 64;
+
+    let selected_id = id.with("selected gradient stop");
+    let mut selected = ui.data(|data| data.get_temp::<usize>(selected_id));
+    let mut changed = false;
+
+    let width = ui.spacing().slider_width * 1.5;
+    let size = egui::vec2(width, STRIP_HEIGHT + MARKER_SIZE);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+    let strip = egui::Rect::from_min_size(rect.min, egui::vec2(width, STRIP_HEIGHT));
+    let to_screen = |position: f32| egui::lerp(strip.x_range(), position);
+    let from_screen = |x: f32| egui::remap_clamp(x, strip.x_range(), 0.0..=1.0);
+
+    let positions: Vec<f32> = gradient.stops.iter().map(|stop| stop.position).collect();
+    let mut remove = None;
+    for (i, stop) in gradient.stops.iter_mut().enumerate() {
+        let marker = egui::Rect::from_min_size(
+            egui::pos2(to_screen(stop.position) - MARKER_SIZE / 2.0, strip.bottom()),
+            egui::Vec2::splat(MARKER_SIZE),
+        );
+        let marker_response = ui.interact(
+            marker,
+            id.with(("gradient stop", i)),
+            egui::Sense::click_and_drag(),
+        );
+        if marker_response.clicked() || marker_response.drag_started() {
+            selected = Some(i);
+        }
+        if let (true, Some(pointer)) = (
+            marker_response.dragged(),
+            marker_response.interact_pointer_pos(),
+        ) {
+            stop.position = clamped_key_position(&positions, i, from_screen(pointer.x));
+            changed = true;
+        }
+        if marker_response.secondary_clicked() && positions.len() > 1 {
+            remove = Some(i);
+        }
+    }
+    if let Some(i) = remove {
+        gradient.stops.remove(i);
+        selected = None;
+        changed = true;
+    }
+    if let (true, Some(pointer)) = (response.double_clicked(), response.interact_pointer_pos()) {
+        let position = from_screen(pointer.x);
+        let i = gradient
+            .stops
+            .partition_point(|stop| stop.position <= position);
+        let color = gradient.sample(position);
+        gradient.stops.insert(i, GradientStop::new(position, color));
+        selected = Some(i);
+        changed = true;
+    }
+    let selected = selected.filter(|&i| i < gradient.stops.len());
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let painter = ui.painter();
+        let column_width = width / COLUMNS as f32;
+        for column in 0..COLUMNS {
+            let position = (column as f32 + 0.5) / COLUMNS as f32;
+            let min = egui::pos2(strip.left() + column as f32 * column_width, strip.top());
+            let column_rect =
+                egui::Rect::from_min_size(min, egui::vec2(column_width, STRIP_HEIGHT));
+            painter.rect_filled(column_rect, 0.0, color32(gradient.sample(position)));
+        }
+        painter.rect_stroke(strip, 0.0, visuals.bg_stroke);
+
+        for (i, stop) in gradient.stops.iter().enumerate() {
+            let x = to_screen(stop.position);
+            let stroke = match selected == Some(i) {
+                true => ui.visuals().selection.stroke,
+                false => visuals.fg_stroke,
+            };
+            let points = vec![
+                egui::pos2(x, strip.bottom()),
+                egui::pos2(x + MARKER_SIZE / 2.0, rect.bottom()),
+                egui::pos2(x - MARKER_SIZE / 2.0, rect.bottom()),
+            ];
+            painter.add(egui::Shape::convex_polygon(
+                points,
+                color32(stop.color),
+                stroke,
+            ));
+        }
+    }
+    response.on_hover_text("Double-click to add a stop, right-click a stop to remove it");
+
+    if let Some(i) = selected {
+        let positions: Vec<f32> = gradient.stops.iter().map(|stop| stop.position).collect();
+        let stop = &mut gradient.stops[i];
+        ui.horizontal(|ui| {
+            changed |= color(ui, &mut stop.color, &Default::default());
+            let (min, max) = (
+                clamped_key_position(&positions, i, 0.0),
+                clamped_key_position(&positions, i, 1.0),
+            );
+            ui.label("Position");
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut stop.position)
+                        .speed(0.01)
+                        .clamp_range(min..=max),
+                )
+                .changed();
+        });
+    }
+
+    ui.data_mut(|data| match selected {
+        Some(i) => data.insert_temp(selected_id, i),
+        None => data.remove::<usize>(selected_id),
+    });
+    changed
+}
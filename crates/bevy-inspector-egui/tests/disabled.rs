@@ -0,0 +1,54 @@
+//! Run with `cargo test -p bevy-inspector-egui --no-default-features --test disabled`
+#![cfg(not(feature = "enabled"))]
+
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_inspector::history::TrackComponentHistory;
+use bevy_inspector_egui::prelude::*;
+use bevy_inspector_egui::quick::RegisterInspectableEvent;
+use bevy_reflect::FromType;
+
+#[test]
+fn disabled_derive_has_no_options() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(min = 2.0, validate = "check")]
+        a: f32,
+    }
+
+    impl Test {
+        // still used by the attribute, without warnings
+        fn check(&self) -> Result<(), &'static str> {
+            Err("never called")
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert_eq!(options.iter().count(), 0);
+}
+
+#[test]
+fn disabled_plugins_add_nothing() {
+    #[derive(Component, Reflect)]
+    struct Health(f32);
+
+    #[derive(Event, Reflect, Clone)]
+    struct Damage(f32);
+
+    let mut app = App::new();
+    let components = app.world.components().len();
+
+    app.add_plugins(DefaultInspectorConfigPlugin)
+        .add_plugins(
+            WorldInspectorPlugin::new()
+                .with_title("Inspector")
+                .with_default_pos((10.0, 10.0))
+                .with_hotkey(KeyCode::F1),
+        )
+        .add_plugins(ResourceInspectorPlugin::<Time>::new().with_ab_toggle(KeyCode::F2))
+        .track_component_history::<Health>();
+    assert_eq!(app.world.components().len(), components);
+
+    // the event is still added, since the game sends it
+    app.register_inspectable_event::<Damage>();
+    assert!(app.world.contains_resource::<Events<Damage>>());
+}